@@ -0,0 +1,120 @@
+//! Comparison of the pairing algorithms for the genetic algorithm on the Schwefel function.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (420.9687, 420.9687, ...) for any xi lying in [-500.0; 500.0].
+//! f(x') = 0
+//!
+//! The example runs the genetic algorithm several times for every pairing and prints
+//! the average value of the goal function for the best individual by generations.
+use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, IterativeOptimizer};
+use ew_testfunc;
+
+/// Gene type
+type Gene = f32;
+
+/// Chromosomes type
+type Chromosomes = Vec<Gene>;
+
+fn create_optimizer<'a>(
+    pairing: Box<dyn genetic::Pairing<Chromosomes> + 'a>,
+    generations: usize,
+) -> genetic::GeneticOptimizer<'a, Chromosomes> {
+    let minval: Gene = -500.0;
+    let maxval: Gene = 500.0;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    let creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+
+    let single_cross = cross::FloatCrossExp::new();
+    let cross = cross::VecCrossAllGenes::new(Box::new(single_cross));
+
+    let mutation_probability = 10.0;
+    let mutation_gene_count = 3;
+    let single_mutation = mutation::BitwiseMutation::new(mutation_gene_count);
+    let mutation = mutation::VecMutation::new(mutation_probability, Box::new(single_mutation));
+
+    genetic::GeneticOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::schwefel)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(generations)))
+        .creator(Box::new(creator))
+        .pairing(pairing)
+        .cross(Box::new(cross))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(pre_birth::vec_float::CheckChromoInterval::new(
+            intervals,
+        ))])
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build()
+}
+
+/// Returns the average goal value of the best individual for every generation.
+fn get_average_convergence(
+    create_pairing: &dyn Fn() -> Box<dyn genetic::Pairing<Chromosomes>>,
+    run_count: usize,
+    generations: usize,
+) -> Vec<f64> {
+    let mut convergence = vec![0.0; generations];
+    for _ in 0..run_count {
+        let mut optimizer = create_optimizer(create_pairing(), generations);
+        let mut generation = 0;
+        while let Some((_, goal_value)) = optimizer.step() {
+            convergence[generation] += goal_value / run_count as f64;
+            generation += 1;
+        }
+    }
+
+    convergence
+}
+
+fn main() {
+    let run_count = 20;
+    let generations = 500;
+    let families_count = 50;
+
+    let pairings: Vec<(&str, Box<dyn Fn() -> Box<dyn genetic::Pairing<Chromosomes>>>)> = vec![
+        (
+            "Tournament",
+            Box::new(move || Box::new(pairing::Tournament::new(families_count).rounds_count(5))),
+        ),
+        (
+            "Roulette",
+            Box::new(move || Box::new(pairing::RoulettePairing::new(families_count))),
+        ),
+        (
+            "Rank",
+            Box::new(move || Box::new(pairing::RankPairing::new(families_count, 2.0))),
+        ),
+        (
+            "SUS",
+            Box::new(move || Box::new(pairing::StochasticUniversalSampling::new(families_count))),
+        ),
+    ];
+
+    let convergences: Vec<Vec<f64>> = pairings
+        .iter()
+        .map(|(_, create_pairing)| {
+            get_average_convergence(create_pairing.as_ref(), run_count, generations)
+        })
+        .collect();
+
+    let mut header = format!("{:<12}", "Generation");
+    for (name, _) in &pairings {
+        header += &format!("{:>16}", name);
+    }
+    println!("{}", header);
+
+    for generation in (0..generations).step_by(25).chain(Some(generations - 1)) {
+        let mut line = format!("{:<12}", generation + 1);
+        for convergence in &convergences {
+            line += &format!("{:>16.6}", convergence[generation]);
+        }
+        println!("{}", line);
+    }
+}
@@ -13,18 +13,16 @@
 //! * `Generation` - a number of iteration of genetic algorithm.
 use std::fs::File;
 use std::io;
-use std::sync::mpsc;
-use std::thread;
 
 use ew::genetic::{
     self, creation, cross, mutation, pairing, pre_birth, selection, GeneticOptimizer,
 };
 use ew::tools::statistics::{
-    get_predicate_success_vec_solution, CallCountData, GoalCalcStatistics,
-    StatFunctionsConvergence, StatFunctionsGoal, StatFunctionsSolution,
+    get_predicate_success_vec_solution, CallCountData, StatFunctionsConvergence,
+    StatFunctionsGoal, StatFunctionsSolution,
 };
-use ew::tools::{logging, statistics, stopchecker};
-use ew::{Goal, GoalFromFunction, Optimizer};
+use ew::tools::{statistics, stopchecker};
+use ew::{Goal, GoalFromFunction};
 use ew_testfunc;
 
 /// Gene type
@@ -196,56 +194,17 @@ fn main() {
     println!("Run count per CPU:{:8}", run_count);
     print!("Run optimizations... ");
 
-    // Statistics from all runnings
-    let mut full_stat = statistics::Statistics::new();
-    let mut full_call_count = CallCountData::new();
-
-    let (tx, rx) = mpsc::channel();
-
-    for _ in 0..cpu {
-        let current_tx = mpsc::Sender::clone(&tx);
-
-        thread::spawn(move || {
-            let mut local_full_stat = statistics::Statistics::new();
-            let mut local_full_call_count = CallCountData::new();
-
-            for _ in 0..run_count {
-                // Statistics from single run
-                let mut statistics_data = statistics::Statistics::new();
-                let mut call_count = CallCountData::new();
-                {
-                    // Make a trait object for goal function
-                    let mut goal_object = GoalFromFunction::new(ew_testfunc::schwefel);
-                    let goal = GoalCalcStatistics::new(&mut goal_object, &mut call_count);
-
-                    let mut optimizer = create_optimizer(dimension, Box::new(goal));
-
-                    // Add logger to collect statistics
-                    let stat_logger =
-                        Box::new(statistics::StatisticsLogger::new(&mut statistics_data));
-                    let loggers: Vec<Box<dyn logging::Logger<Chromosomes>>> = vec![stat_logger];
-                    optimizer.set_loggers(loggers);
-
-                    // Run optimization
-                    optimizer.find_min();
-                }
-
-                // Add current running statistics to full statistics
-                local_full_stat.unite(statistics_data);
-                local_full_call_count.unite(call_count);
-            }
-            current_tx
-                .send((local_full_stat, local_full_call_count))
-                .unwrap();
-        });
-    }
-
-    // Collect data from threads
-    for _ in 0..cpu {
-        let (statistics_data, call_count) = rx.recv().unwrap();
-        full_stat.unite(statistics_data);
-        full_call_count.unite(call_count);
-    }
+    // Run optimizations in all CPUs and gather statistics from all runnings
+    let (full_stat, full_call_count) = statistics::run_parallel(
+        || Box::new(GoalFromFunction::new(ew_testfunc::schwefel)),
+        |goal, logger| {
+            let mut optimizer = create_optimizer(dimension, goal);
+            optimizer.set_loggers(vec![logger]);
+            Box::new(optimizer)
+        },
+        run_count * cpu,
+        cpu,
+    );
 
     println!("OK");
 
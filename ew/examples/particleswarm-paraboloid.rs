@@ -38,6 +38,8 @@ fn main() {
         vec![Box::new(postmove::MoveToBoundary::new(intervals.clone()))];
 
     // Velocity calculator
+    // The classic equation without the constriction coefficient may be used with the velocity limit
+    // (see `post_velocity_calc` below):
     // let velocity_calculator = velocitycalc::ClassicVelocityCalculator::new(phi_personal, phi_global);
     let velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
 
@@ -0,0 +1,86 @@
+//! Comparison of the sequential and parallel calculation of the goal function.
+//! Run with the "parallel" feature:
+//!
+//! cargo run --release --features parallel --example particleswarm-schwefel-parallel
+use std::time::Instant;
+
+use ew::{
+    tools::stopchecker,
+    GoalFromFunction, Optimizer,
+    particleswarm::{
+        self,
+        initializing,
+        postmove,
+        velocitycalc,
+        PostMove,
+    },
+};
+
+use ew_testfunc;
+
+type Coordinate = f64;
+
+fn create_optimizer(seed: u64, parallel: bool) -> particleswarm::ParticleSwarmOptimizer<'static, Coordinate> {
+    // General parameters
+    let minval: Coordinate = -500.0;
+    let maxval: Coordinate = 500.0;
+    let particles_count = 2000;
+    let dimension = 500;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi_personal = 3.2;
+    let phi_global = 1.0;
+    let k = 0.9;
+
+    // Goal function
+    let goal = GoalFromFunction::new(ew_testfunc::schwefel);
+
+    // Particles initializers
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    // PostMove
+    let post_moves: Vec<Box<dyn PostMove<Coordinate>>> = vec![Box::new(postmove::MoveToBoundary::new(intervals.clone()))];
+
+    // Velocity calculator
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
+    velocity_calculator.set_seed(seed);
+
+    // Stop checker
+    let stop_checker = stopchecker::MaxIterations::new(100);
+
+    let builder = particleswarm::ParticleSwarmOptimizer::builder()
+        .stop_checker(Box::new(stop_checker))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(velocity_initializer))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(post_moves);
+
+    if parallel {
+        builder.parallel_goal(Box::new(goal)).build()
+    } else {
+        builder.goal(Box::new(goal)).build()
+    }
+}
+
+fn main() {
+    let seed = 42;
+
+    let mut optimizer_sequential = create_optimizer(seed, false);
+    let start_sequential = Instant::now();
+    let result_sequential = optimizer_sequential.find_min().unwrap();
+    let time_sequential = start_sequential.elapsed();
+
+    let mut optimizer_parallel = create_optimizer(seed, true);
+    let start_parallel = Instant::now();
+    let result_parallel = optimizer_parallel.find_min().unwrap();
+    let time_parallel = start_parallel.elapsed();
+
+    println!("Threads: {}", num_cpus::get());
+    println!("Sequential: goal = {}, time = {:?}", result_sequential.1, time_sequential);
+    println!("Parallel:   goal = {}, time = {:?}", result_parallel.1, time_parallel);
+    println!(
+        "Speedup: {:.2}",
+        time_sequential.as_secs_f64() / time_parallel.as_secs_f64()
+    );
+}
@@ -0,0 +1,856 @@
+//! The module with the Covariance Matrix Adaptation Evolution Strategy (CMA-ES).
+//!
+//! Hansen, N.; Ostermeier, A. (2001). "Completely derandomized self-adaptation in evolution
+//! strategies". Evolutionary Computation, 9(2), pp.159-195.
+//!
+//! Hansen, N. (2016). "The CMA Evolution Strategy: A Tutorial". arXiv:1604.00772.
+//!
+//! On every iteration the algorithm samples `lambda` points from the multivariate normal
+//! distribution N(m, sigma^2 * C). The new mean `m` is the weighted average of the `mu` best
+//! points. The step size `sigma` is updated by the cumulative step-size adaptation (CSA),
+//! the covariance matrix `C` is updated by the rank-one update (with the evolution path) and
+//! the rank-mu update.
+//!
+//! # Terms
+//! * `mean` (m) - the mean of the search distribution.
+//! * `sigma` - the step size.
+//! * `lambda` - the count of the sampled points (offspring) per iteration.
+//! * `mu` - the count of the best points which are used to update the distribution.
+
+use std::cmp::Ordering;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::tools::{compare_values, get_standard_normal};
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::{
+    call_iteration_callback, Agent, AgentsState, AlgorithmState, Direction, Goal, GoalValue,
+    IterativeOptimizer, Optimizer, Solution,
+};
+
+type Coordinate = Vec<f64>;
+type Matrix = Vec<Vec<f64>>;
+
+/// Single sampled point for CMA-ES.
+#[derive(Debug, Clone)]
+pub struct CmaEsIndividual {
+    coordinates: Coordinate,
+
+    /// The value which the algorithm minimizes.
+    value: GoalValue,
+}
+
+impl CmaEsIndividual {
+    /// Returns the point in the search space.
+    pub fn get_coordinates(&self) -> &Coordinate {
+        &self.coordinates
+    }
+
+    /// Returns the value which the algorithm minimizes. The value is the negated value of
+    /// the goal function after `find_max`.
+    pub fn get_value(&self) -> GoalValue {
+        self.value
+    }
+}
+
+impl Agent<Coordinate> for CmaEsIndividual {
+    fn get_goal(&self) -> GoalValue {
+        self.value
+    }
+
+    fn get_parameter(&self) -> &Coordinate {
+        &self.coordinates
+    }
+}
+
+/// The state of the CMA-ES algorithm.
+pub struct CmaEsState {
+    mean: Coordinate,
+    sigma: f64,
+
+    /// The sampled points of the last iteration sorted by the value (the best point is first).
+    offspring: Vec<CmaEsIndividual>,
+
+    /// The best point found during the algorithm running.
+    best: Option<CmaEsIndividual>,
+    iteration: usize,
+    direction: Direction,
+}
+
+impl CmaEsState {
+    fn new() -> Self {
+        Self {
+            mean: vec![],
+            sigma: 0.0,
+            offspring: vec![],
+            best: None,
+            iteration: 0,
+            direction: Direction::Minimize,
+        }
+    }
+
+    /// Returns the mean of the search distribution.
+    pub fn get_mean(&self) -> &Coordinate {
+        &self.mean
+    }
+
+    /// Returns the current step size.
+    pub fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Returns the sampled points of the last iteration sorted from the best to the worst.
+    pub fn get_offspring(&self) -> &[CmaEsIndividual] {
+        &self.offspring
+    }
+
+    /// Returns the best point found during the algorithm running if exists.
+    pub fn get_best(&self) -> Option<&CmaEsIndividual> {
+        self.best.as_ref()
+    }
+
+    fn reset(&mut self) {
+        self.mean.clear();
+        self.sigma = 0.0;
+        self.offspring.clear();
+        self.best = None;
+        self.iteration = 0;
+    }
+
+    fn update_best(&mut self, candidate: &CmaEsIndividual) {
+        let is_better = match &self.best {
+            None => true,
+            Some(best) => compare_values(candidate.value, best.value) == Ordering::Less,
+        };
+
+        if is_better {
+            self.best = Some(candidate.clone());
+        }
+    }
+}
+
+impl AlgorithmState<Coordinate> for CmaEsState {
+    fn get_best_solution(&self) -> Option<Solution<Coordinate>> {
+        self.best.as_ref().map(|individual| {
+            (
+                individual.coordinates.clone(),
+                self.direction.apply(individual.value),
+            )
+        })
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&Coordinate> {
+        self.offspring
+            .iter()
+            .map(|individual| &individual.coordinates)
+            .collect()
+    }
+}
+
+impl AgentsState<Coordinate> for CmaEsState {
+    type Agent = CmaEsIndividual;
+
+    fn get_agents(&self) -> Vec<&Self::Agent> {
+        self.offspring.iter().collect()
+    }
+}
+
+/// Returns the identity matrix with size `n` x `n`.
+fn identity(n: usize) -> Matrix {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect()
+}
+
+/// Returns the Euclidean norm of the vector.
+fn norm(x: &[f64]) -> f64 {
+    x.iter().map(|xi| xi * xi).sum::<f64>().sqrt()
+}
+
+/// Multiply the matrix by the Givens rotation in the plane (`p`, `q`) from the right.
+fn rotate_columns(matrix: &mut [Vec<f64>], p: usize, q: usize, c: f64, s: f64) {
+    for row in matrix.iter_mut() {
+        let x_p = row[p];
+        let x_q = row[q];
+        row[p] = c * x_p - s * x_q;
+        row[q] = s * x_p + c * x_q;
+    }
+}
+
+/// Returns eigenvalues and eigenvectors of the symmetric matrix by the cyclic Jacobi method.
+/// The eigenvector for the eigenvalue `i` is the column `i` of the returned matrix.
+fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Matrix) {
+    let n = matrix.len();
+    let mut a: Matrix = matrix.to_vec();
+    let mut v = identity(n);
+    let max_sweeps = 100;
+
+    for _ in 0..max_sweeps {
+        let mut off_diagonal = 0.0;
+        let mut diagonal = 0.0;
+        for (i, row) in a.iter().enumerate() {
+            diagonal += row[i] * row[i];
+            off_diagonal += row[i + 1..].iter().map(|value| value * value).sum::<f64>();
+        }
+
+        if off_diagonal <= 1e-30 * diagonal || off_diagonal == 0.0 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                // The rotation which zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // A = A * J
+                rotate_columns(&mut a, p, q, c, s);
+
+                // A = J^T * A
+                let (head, tail) = a.split_at_mut(q);
+                for (a_pk, a_qk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    let old_pk = *a_pk;
+                    let old_qk = *a_qk;
+                    *a_pk = c * old_pk - s * old_qk;
+                    *a_qk = s * old_pk + c * old_qk;
+                }
+
+                // V = V * J
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+    }
+
+    let eigenvalues = (0..n).map(|i| a[i][i]).collect();
+    (eigenvalues, v)
+}
+
+/// The CMA-ES optimizer. The optimizer implements the `Optimizer` trait.
+///
+/// The default strategy parameters are taken from the tutorial by N. Hansen.
+pub struct CmaEsOptimizer<'a> {
+    goal: Box<dyn Goal<Coordinate> + 'a>,
+    stop_checker: Box<dyn StopChecker<Coordinate> + 'a>,
+    loggers: Vec<Box<dyn Logger<Coordinate> + 'a>>,
+
+    initial_mean: Coordinate,
+    sigma0: f64,
+
+    // Strategy parameters
+    lambda: usize,
+    weights: Vec<f64>,
+    mueff: f64,
+    cc: f64,
+    cs: f64,
+    c1: f64,
+    cmu: f64,
+    damps: f64,
+    chi_n: f64,
+
+    // Dynamic parameters
+    /// The evolution path for the covariance matrix.
+    pc: Vec<f64>,
+
+    /// The evolution path for the step size.
+    ps: Vec<f64>,
+
+    /// The covariance matrix.
+    c: Matrix,
+
+    /// The eigenvectors of the covariance matrix (columns).
+    b: Matrix,
+
+    /// The square roots of the eigenvalues of the covariance matrix.
+    d: Vec<f64>,
+
+    /// The count of the goal function calculations.
+    eval_count: usize,
+
+    /// The value of `eval_count` at the last eigendecomposition.
+    eigen_eval_count: usize,
+
+    state: CmaEsState,
+    random: StdRng,
+
+    /// True if the algorithm was initialized.
+    initialized: bool,
+}
+
+impl<'a> CmaEsOptimizer<'a> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop criterion.
+    /// * `initial_mean` - the initial mean of the search distribution (the start point).
+    ///   Must not be empty.
+    /// * `sigma0` - the initial step size. Must be greater than 0. The optimum should lie
+    ///   within `initial_mean` +- 3 * `sigma0` for every coordinate.
+    /// * `lambda` - the count of the sampled points per iteration. Must be at least 2.
+    ///   If `None`, the default value 4 + floor(3 * ln(n)) is used, where n is the dimension.
+    pub fn new(
+        goal: Box<dyn Goal<Coordinate> + 'a>,
+        stop_checker: Box<dyn StopChecker<Coordinate> + 'a>,
+        initial_mean: Vec<f64>,
+        sigma0: f64,
+        lambda: Option<usize>,
+    ) -> Self {
+        assert!(!initial_mean.is_empty());
+        assert!(sigma0 > 0.0);
+
+        let n = initial_mean.len();
+        let n_f64 = n as f64;
+        let lambda = lambda.unwrap_or(4 + (3.0 * n_f64.ln()).floor() as usize);
+        assert!(lambda >= 2);
+
+        // Recombination weights
+        let mu = lambda / 2;
+        let raw_weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let weights_sum: f64 = raw_weights.iter().sum();
+        let weights: Vec<f64> = raw_weights.iter().map(|w| w / weights_sum).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        // Adaptation parameters
+        let cc = (4.0 + mueff / n_f64) / (n_f64 + 4.0 + 2.0 * mueff / n_f64);
+        let cs = (mueff + 2.0) / (n_f64 + mueff + 5.0);
+        let c1 = 2.0 / ((n_f64 + 1.3).powi(2) + mueff);
+        let cmu =
+            (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((n_f64 + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (n_f64 + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = n_f64.sqrt() * (1.0 - 1.0 / (4.0 * n_f64) + 1.0 / (21.0 * n_f64 * n_f64));
+
+        Self {
+            goal,
+            stop_checker,
+            loggers: vec![],
+            initial_mean,
+            sigma0,
+            lambda,
+            weights,
+            mueff,
+            cc,
+            cs,
+            c1,
+            cmu,
+            damps,
+            chi_n,
+            pc: vec![],
+            ps: vec![],
+            c: vec![],
+            b: vec![],
+            d: vec![],
+            eval_count: 0,
+            eigen_eval_count: 0,
+            state: CmaEsState::new(),
+            random: StdRng::from_entropy(),
+            initialized: false,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<Coordinate> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Coordinate> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Returns the current state of the algorithm.
+    pub fn get_state(&self) -> &CmaEsState {
+        &self.state
+    }
+
+    /// Returns the count of the sampled points per iteration.
+    pub fn get_lambda(&self) -> usize {
+        self.lambda
+    }
+
+    /// Returns the count of the best points which are used to update the distribution.
+    pub fn get_mu(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns the current covariance matrix.
+    pub fn get_covariance(&self) -> &[Vec<f64>] {
+        &self.c
+    }
+
+    fn dimension(&self) -> usize {
+        self.initial_mean.len()
+    }
+
+    fn create_individual(&mut self, coordinates: Coordinate) -> CmaEsIndividual {
+        let value = self.state.direction.apply(self.goal.get(&coordinates));
+        self.eval_count += 1;
+        CmaEsIndividual { coordinates, value }
+    }
+
+    fn init(&mut self, direction: Direction) {
+        let n = self.dimension();
+
+        self.state.reset();
+        self.state.direction = direction;
+        self.state.mean = self.initial_mean.clone();
+        self.state.sigma = self.sigma0;
+
+        self.pc = vec![0.0; n];
+        self.ps = vec![0.0; n];
+        self.c = identity(n);
+        self.b = identity(n);
+        self.d = vec![1.0; n];
+        self.eval_count = 0;
+        self.eigen_eval_count = 0;
+
+        let start = self.create_individual(self.initial_mean.clone());
+        self.state.update_best(&start);
+
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.state);
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<Coordinate>> {
+        self.init(direction);
+        self.next_iterations()
+    }
+
+    /// Returns the point mean + sigma * B * D * z, where z is sampled from N(0, I).
+    fn sample(&mut self) -> Coordinate {
+        let n = self.dimension();
+        let random = &mut self.random;
+        let dz: Vec<f64> = self
+            .d
+            .iter()
+            .map(|d_i| d_i * get_standard_normal(random))
+            .collect();
+
+        (0..n)
+            .map(|i| {
+                let y_i: f64 = (0..n).map(|j| self.b[i][j] * dz[j]).sum();
+                self.state.mean[i] + self.state.sigma * y_i
+            })
+            .collect()
+    }
+
+    /// Returns C^(-1/2) * x.
+    fn mul_inv_sqrt_c(&self, x: &[f64]) -> Vec<f64> {
+        let n = self.dimension();
+
+        // B^T * x / D
+        let scaled: Vec<f64> = (0..n)
+            .map(|j| (0..n).map(|i| self.b[i][j] * x[i]).sum::<f64>() / self.d[j])
+            .collect();
+
+        (0..n)
+            .map(|i| (0..n).map(|j| self.b[i][j] * scaled[j]).sum())
+            .collect()
+    }
+
+    /// Update B and D from C. The decomposition is done lazily to achieve O(n^2) per
+    /// the goal function calculation.
+    fn update_eigen(&mut self) {
+        let n = self.dimension() as f64;
+        let period = self.lambda as f64 / (self.c1 + self.cmu) / n / 10.0;
+        if ((self.eval_count - self.eigen_eval_count) as f64) <= period {
+            return;
+        }
+
+        self.eigen_eval_count = self.eval_count;
+
+        // Enforce symmetry
+        let size = self.c.len();
+        for i in 0..size {
+            for j in i + 1..size {
+                let value = (self.c[i][j] + self.c[j][i]) / 2.0;
+                self.c[i][j] = value;
+                self.c[j][i] = value;
+            }
+        }
+
+        let (eigenvalues, eigenvectors) = symmetric_eigen(&self.c);
+        if eigenvalues.iter().any(|value| !value.is_finite()) {
+            return;
+        }
+
+        self.d = eigenvalues
+            .iter()
+            .map(|value| value.max(f64::MIN_POSITIVE).sqrt())
+            .collect();
+        self.b = eigenvectors;
+    }
+
+    fn do_iteration(&mut self) {
+        let n = self.dimension();
+        let mu = self.weights.len();
+
+        // Sample and evaluate the offspring
+        let mut offspring: Vec<CmaEsIndividual> = Vec::with_capacity(self.lambda);
+        for _ in 0..self.lambda {
+            let coordinates = self.sample();
+            offspring.push(self.create_individual(coordinates));
+        }
+
+        offspring.sort_by(|a, b| compare_values(a.value, b.value));
+        self.state.update_best(&offspring[0]);
+
+        // Update the mean
+        let old_mean = self.state.mean.clone();
+        let sigma = self.state.sigma;
+        let new_mean: Coordinate = (0..n)
+            .map(|i| {
+                self.weights
+                    .iter()
+                    .zip(offspring.iter())
+                    .map(|(w, individual)| w * individual.coordinates[i])
+                    .sum()
+            })
+            .collect();
+        let mean_shift: Vec<f64> = new_mean
+            .iter()
+            .zip(old_mean.iter())
+            .map(|(new_x, old_x)| (new_x - old_x) / sigma)
+            .collect();
+
+        // Update the evolution paths
+        let cs_coeff = (self.cs * (2.0 - self.cs) * self.mueff).sqrt();
+        let inv_sqrt_shift = self.mul_inv_sqrt_c(&mean_shift);
+        for (ps_i, shift_i) in self.ps.iter_mut().zip(inv_sqrt_shift.iter()) {
+            *ps_i = (1.0 - self.cs) * *ps_i + cs_coeff * shift_i;
+        }
+
+        let ps_norm = norm(&self.ps);
+        let generation = (self.eval_count / self.lambda) as i32;
+        let hsig = ps_norm / (1.0 - (1.0 - self.cs).powi(2 * generation)).sqrt() / self.chi_n
+            < 1.4 + 2.0 / (n as f64 + 1.0);
+        let hsig = if hsig { 1.0 } else { 0.0 };
+
+        let cc_coeff = (self.cc * (2.0 - self.cc) * self.mueff).sqrt();
+        for (pc_i, shift_i) in self.pc.iter_mut().zip(mean_shift.iter()) {
+            *pc_i = (1.0 - self.cc) * *pc_i + hsig * cc_coeff * shift_i;
+        }
+
+        // Update the covariance matrix
+        let steps: Vec<Vec<f64>> = offspring[..mu]
+            .iter()
+            .map(|individual| {
+                individual
+                    .coordinates
+                    .iter()
+                    .zip(old_mean.iter())
+                    .map(|(x, old_x)| (x - old_x) / sigma)
+                    .collect()
+            })
+            .collect();
+
+        let old_coeff = 1.0 - self.c1 - self.cmu;
+        let hsig_correction = (1.0 - hsig) * self.cc * (2.0 - self.cc);
+        for i in 0..n {
+            for j in 0..n {
+                let rank_one = self.pc[i] * self.pc[j] + hsig_correction * self.c[i][j];
+                let rank_mu: f64 = self
+                    .weights
+                    .iter()
+                    .zip(steps.iter())
+                    .map(|(w, step)| w * step[i] * step[j])
+                    .sum();
+                self.c[i][j] = old_coeff * self.c[i][j] + self.c1 * rank_one + self.cmu * rank_mu;
+            }
+        }
+
+        // Update the step size
+        self.state.sigma *= ((self.cs / self.damps) * (ps_norm / self.chi_n - 1.0)).exp();
+
+        self.update_eigen();
+
+        self.state.mean = new_mean;
+        self.state.offspring = offspring;
+        self.state.iteration += 1;
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.state);
+        }
+    }
+}
+
+impl<'a> Optimizer<Coordinate> for CmaEsOptimizer<'a> {
+    fn find_min(&mut self) -> Option<Solution<Coordinate>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<Coordinate>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove the state of the algorithm and go to iteration 0.
+    fn reset(&mut self) {
+        self.state.reset();
+        self.state.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+impl<'a> IterativeOptimizer<Coordinate> for CmaEsOptimizer<'a> {
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&Coordinate, GoalValue)>),
+    ) -> Option<Solution<Coordinate>> {
+        if !self.initialized {
+            self.init(self.state.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.state);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.state) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.state);
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.state);
+        }
+
+        self.state.get_best_solution()
+    }
+
+    fn step(&mut self) -> Option<Solution<Coordinate>> {
+        if !self.initialized {
+            self.init(self.state.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.state) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.state.get_best_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::stopchecker::MaxIterations;
+    use crate::GoalFromFunction;
+
+    fn sum_sqr(x: &Vec<f64>) -> f64 {
+        x.iter().map(|xi| xi * xi).sum()
+    }
+
+    fn create_optimizer(
+        dimension: usize,
+        iterations: usize,
+        lambda: Option<usize>,
+    ) -> CmaEsOptimizer<'static> {
+        let mut optimizer = CmaEsOptimizer::new(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(iterations)),
+            vec![3.0; dimension],
+            1.0,
+            lambda,
+        );
+        optimizer.set_seed(42);
+        optimizer
+    }
+
+    fn check_eigen(matrix: Matrix) {
+        let n = matrix.len();
+        let (values, vectors) = symmetric_eigen(&matrix);
+
+        for k in 0..n {
+            for i in 0..n {
+                let av: f64 = (0..n).map(|j| matrix[i][j] * vectors[j][k]).sum();
+                assert!((av - values[k] * vectors[i][k]).abs() < 1e-10);
+            }
+
+            let column: Vec<f64> = (0..n).map(|i| vectors[i][k]).collect();
+            assert!((norm(&column) - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn eigen_diagonal() {
+        let (mut values, _) = symmetric_eigen(&[vec![3.0, 0.0], vec![0.0, 1.0]]);
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(values, vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn eigen_2x2() {
+        let matrix = vec![vec![2.0, 1.0], vec![1.0, 2.0]];
+        let (mut values, _) = symmetric_eigen(&matrix);
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((values[0] - 1.0).abs() < 1e-10);
+        assert!((values[1] - 3.0).abs() < 1e-10);
+        check_eigen(matrix);
+    }
+
+    #[test]
+    fn eigen_3x3() {
+        check_eigen(vec![
+            vec![4.0, 1.0, -2.0],
+            vec![1.0, 2.0, 0.5],
+            vec![-2.0, 0.5, 3.0],
+        ]);
+    }
+
+    #[test]
+    fn default_parameters() {
+        // lambda = 4 + floor(3 * ln(10)) = 10
+        let optimizer = create_optimizer(10, 1, None);
+        assert_eq!(optimizer.get_lambda(), 10);
+        assert_eq!(optimizer.get_mu(), 5);
+
+        let weights_sum: f64 = optimizer.weights.iter().sum();
+        assert!((weights_sum - 1.0).abs() < 1e-12);
+        assert!(optimizer.weights.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn custom_lambda() {
+        let optimizer = create_optimizer(2, 1, Some(20));
+        assert_eq!(optimizer.get_lambda(), 20);
+        assert_eq!(optimizer.get_mu(), 10);
+    }
+
+    #[test]
+    fn init_state() {
+        let mut optimizer = create_optimizer(2, 0, None);
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+        assert_eq!(solution, vec![3.0, 3.0]);
+        assert_eq!(goal_value, 18.0);
+
+        let state = optimizer.get_state();
+        assert_eq!(state.get_mean(), &vec![3.0, 3.0]);
+        assert_eq!(state.get_sigma(), 1.0);
+        assert!(state.get_offspring().is_empty());
+        assert_eq!(optimizer.get_covariance(), identity(2).as_slice());
+    }
+
+    #[test]
+    fn offspring_sorted() {
+        let mut optimizer = create_optimizer(3, 1, Some(8));
+        optimizer.find_min();
+
+        let offspring = optimizer.get_state().get_offspring();
+        assert_eq!(offspring.len(), 8);
+        assert!(offspring
+            .windows(2)
+            .all(|pair| pair[0].get_value() <= pair[1].get_value()));
+        assert_eq!(optimizer.get_state().get_iteration(), 1);
+    }
+
+    #[test]
+    fn sphere() {
+        let mut optimizer = create_optimizer(5, 300, None);
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+        assert!(goal_value < 1e-10);
+        assert!(solution.iter().all(|x| x.abs() < 1e-5));
+
+        // The covariance matrix must stay symmetric and positive
+        let c = optimizer.get_covariance();
+        for i in 0..5 {
+            assert!(c[i][i] > 0.0);
+        }
+    }
+
+    #[test]
+    fn find_max() {
+        let mut optimizer = CmaEsOptimizer::new(
+            Box::new(GoalFromFunction::new(|x: &Vec<f64>| -sum_sqr(x))),
+            Box::new(MaxIterations::new(300)),
+            vec![3.0, -2.0],
+            1.0,
+            None,
+        );
+        optimizer.set_seed(42);
+
+        let (solution, goal_value) = optimizer.find_max().unwrap();
+        assert!(goal_value <= 0.0);
+        assert!(goal_value > -1e-10);
+        assert!(solution.iter().all(|x| x.abs() < 1e-5));
+    }
+
+    #[test]
+    fn seed() {
+        let result_1 = create_optimizer(3, 50, None).find_min().unwrap();
+        let result_2 = create_optimizer(3, 50, None).find_min().unwrap();
+        assert_eq!(result_1, result_2);
+    }
+
+    #[test]
+    fn nan_goal() {
+        let mut optimizer = CmaEsOptimizer::new(
+            Box::new(GoalFromFunction::new(|x: &Vec<f64>| {
+                if x[0] < 0.0 {
+                    f64::NAN
+                } else {
+                    sum_sqr(x)
+                }
+            })),
+            Box::new(MaxIterations::new(50)),
+            vec![3.0, 3.0],
+            1.0,
+            None,
+        );
+        optimizer.set_seed(42);
+
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+        assert!(!goal_value.is_nan());
+        assert!(solution[0] >= 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_empty_mean() {
+        create_optimizer(0, 1, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_sigma() {
+        CmaEsOptimizer::new(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(1)),
+            vec![1.0, 2.0],
+            0.0,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_lambda() {
+        create_optimizer(2, 1, Some(1));
+    }
+}
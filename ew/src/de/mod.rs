@@ -0,0 +1,676 @@
+//! The module with the differential evolution algorithm.
+//!
+//! Storn, R.; Price, K. (1997). "Differential evolution - a simple and efficient heuristic for
+//! global optimization over continuous spaces". Journal of Global Optimization, 11(4),
+//! pp.341-359.
+//!
+//! For every individual (target vector) in the population the algorithm creates the donor
+//! vector by `DeMutation`, then creates the trial vector by `DeCrossover` of the target vector
+//! and the donor vector. The trial vector replaces the target vector in the next generation
+//! if the value of the goal function for the trial vector is not worse.
+//!
+//! # Terms
+//! * `F` - differential weight.
+//! * `CR` - crossover rate.
+
+use num::Float;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::tools::compare_values;
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::{
+    call_iteration_callback, Agent, AgentsState, AlgorithmState, Direction, Goal, GoalValue,
+    IterativeOptimizer, Optimizer, Solution,
+};
+
+type Coordinate<T> = Vec<T>;
+
+/// The strategies of the differential evolution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    /// DE/rand/1/bin. The donor vector is x_r1 + F * (x_r2 - x_r3), where x_r1, x_r2, x_r3 are
+    /// random distinct individuals. The binomial crossover is used.
+    Rand1Bin,
+
+    /// DE/best/1/bin. The donor vector is x_best + F * (x_r1 - x_r2), where x_best is
+    /// the best individual, x_r1, x_r2 are random distinct individuals. The binomial crossover
+    /// is used.
+    Best1Bin,
+}
+
+/// The trait to create the donor vector for the target individual.
+///
+/// `T` - type of the coordinates of a point in the search space.
+pub trait DeMutation<T> {
+    /// Returns the donor vector for the individual with index `target_index`.
+    /// `random` - the random numbers generator of the optimizer.
+    fn mutation(
+        &mut self,
+        population: &DePopulation<T>,
+        target_index: usize,
+        random: &mut StdRng,
+    ) -> Coordinate<T>;
+}
+
+/// The trait to create the trial vector from the target vector and the donor vector.
+///
+/// `T` - type of the coordinates of a point in the search space.
+pub trait DeCrossover<T> {
+    /// Returns the trial vector.
+    /// `random` - the random numbers generator of the optimizer.
+    fn cross(&mut self, target: &[T], donor: &[T], random: &mut StdRng) -> Coordinate<T>;
+}
+
+/// Single point in the search space for the differential evolution.
+///
+/// `T` - type of the coordinates of a point in the search space.
+#[derive(Debug, Clone)]
+pub struct DeIndividual<T> {
+    coordinates: Coordinate<T>,
+    value: GoalValue,
+}
+
+impl<T> DeIndividual<T> {
+    /// Returns the point in the search space.
+    pub fn get_coordinates(&self) -> &Coordinate<T> {
+        &self.coordinates
+    }
+
+    /// Returns the value which the algorithm minimizes. The value is the negated value of
+    /// the goal function after `find_max`.
+    pub fn get_value(&self) -> GoalValue {
+        self.value
+    }
+}
+
+impl<T> Agent<Coordinate<T>> for DeIndividual<T> {
+    fn get_goal(&self) -> GoalValue {
+        self.value
+    }
+
+    fn get_parameter(&self) -> &Coordinate<T> {
+        &self.coordinates
+    }
+}
+
+/// The population of the differential evolution algorithm.
+///
+/// `T` - type of the coordinates of a point in the search space.
+pub struct DePopulation<T> {
+    individuals: Vec<DeIndividual<T>>,
+    best_index: Option<usize>,
+    iteration: usize,
+    direction: Direction,
+}
+
+impl<T: Clone> DePopulation<T> {
+    fn new() -> Self {
+        Self {
+            individuals: vec![],
+            best_index: None,
+            iteration: 0,
+            direction: Direction::Minimize,
+        }
+    }
+
+    /// Returns the individuals count.
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    /// Returns true if the population does not contain individuals.
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+
+    /// Returns all individuals.
+    pub fn get_individuals(&self) -> &[DeIndividual<T>] {
+        &self.individuals
+    }
+
+    /// Returns the best individual if exists.
+    pub fn get_best(&self) -> Option<&DeIndividual<T>> {
+        self.best_index.map(|index| &self.individuals[index])
+    }
+
+    /// Returns the index of the best individual if exists.
+    pub fn get_best_index(&self) -> Option<usize> {
+        self.best_index
+    }
+
+    fn reset(&mut self) {
+        self.individuals.clear();
+        self.best_index = None;
+        self.iteration = 0;
+    }
+
+    fn update_best(&mut self) {
+        self.best_index = self
+            .individuals
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| compare_values(a.value, b.value))
+            // The best value is NaN only if all the values are NaN
+            .filter(|(_, individual)| !individual.value.is_nan())
+            .map(|(index, _)| index);
+    }
+}
+
+impl<T: Clone> AlgorithmState<Coordinate<T>> for DePopulation<T> {
+    fn get_best_solution(&self) -> Option<Solution<Coordinate<T>>> {
+        self.get_best().map(|individual| {
+            (
+                individual.coordinates.clone(),
+                self.direction.apply(individual.value),
+            )
+        })
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&Coordinate<T>> {
+        self.individuals
+            .iter()
+            .map(|individual| &individual.coordinates)
+            .collect()
+    }
+}
+
+impl<T: Clone> AgentsState<Coordinate<T>> for DePopulation<T> {
+    type Agent = DeIndividual<T>;
+
+    fn get_agents(&self) -> Vec<&Self::Agent> {
+        self.individuals.iter().collect()
+    }
+}
+
+/// Returns `count` random distinct indexes in the range [0; `len`) except `exclude`.
+fn get_random_indexes(len: usize, count: usize, exclude: usize, random: &mut StdRng) -> Vec<usize> {
+    assert!(len > count);
+    let between = Uniform::new(0, len);
+    let mut indexes: Vec<usize> = Vec::with_capacity(count);
+    while indexes.len() < count {
+        let index = between.sample(random);
+        if index != exclude && !indexes.contains(&index) {
+            indexes.push(index);
+        }
+    }
+
+    indexes
+}
+
+/// The mutation for DE/rand/1: the donor vector is x_r1 + F * (x_r2 - x_r3), where x_r1,
+/// x_r2, x_r3 are random distinct individuals which differ from the target individual.
+/// The population must contain at least 4 individuals.
+pub struct RandMutation {
+    f: f64,
+}
+
+impl RandMutation {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `f` - differential weight. Must be greater than 0. The usual values are in the range
+    ///   [0.4; 1].
+    pub fn new(f: f64) -> Self {
+        assert!(f > 0.0);
+        Self { f }
+    }
+}
+
+impl<T: Float> DeMutation<T> for RandMutation {
+    fn mutation(
+        &mut self,
+        population: &DePopulation<T>,
+        target_index: usize,
+        random: &mut StdRng,
+    ) -> Coordinate<T> {
+        let indexes = get_random_indexes(population.len(), 3, target_index, random);
+        let x_1 = &population.individuals[indexes[0]].coordinates;
+        let x_2 = &population.individuals[indexes[1]].coordinates;
+        let x_3 = &population.individuals[indexes[2]].coordinates;
+        let f = T::from(self.f).unwrap();
+
+        x_1.iter()
+            .zip(x_2.iter().zip(x_3.iter()))
+            .map(|(x_1, (x_2, x_3))| *x_1 + f * (*x_2 - *x_3))
+            .collect()
+    }
+}
+
+/// The mutation for DE/best/1: the donor vector is x_best + F * (x_r1 - x_r2), where x_best is
+/// the best individual, x_r1, x_r2 are random distinct individuals which differ from the target
+/// individual. The population must contain at least 3 individuals.
+pub struct BestMutation {
+    f: f64,
+}
+
+impl BestMutation {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `f` - differential weight. Must be greater than 0. The usual values are in the range
+    ///   [0.4; 1].
+    pub fn new(f: f64) -> Self {
+        assert!(f > 0.0);
+        Self { f }
+    }
+}
+
+impl<T: Float> DeMutation<T> for BestMutation {
+    fn mutation(
+        &mut self,
+        population: &DePopulation<T>,
+        target_index: usize,
+        random: &mut StdRng,
+    ) -> Coordinate<T> {
+        let best_index = population.best_index.unwrap_or(target_index);
+        let indexes = get_random_indexes(population.len(), 2, target_index, random);
+        let x_best = &population.individuals[best_index].coordinates;
+        let x_1 = &population.individuals[indexes[0]].coordinates;
+        let x_2 = &population.individuals[indexes[1]].coordinates;
+        let f = T::from(self.f).unwrap();
+
+        x_best
+            .iter()
+            .zip(x_1.iter().zip(x_2.iter()))
+            .map(|(x_best, (x_1, x_2))| *x_best + f * (*x_1 - *x_2))
+            .collect()
+    }
+}
+
+/// The binomial crossover. Every coordinate of the trial vector is taken from the donor vector
+/// with probability CR or from the target vector otherwise. At least one coordinate (selected
+/// at random) is taken from the donor vector.
+pub struct BinomialCrossover {
+    cr: f64,
+}
+
+impl BinomialCrossover {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `cr` - crossover rate. Must be in the range [0; 1]. The usual values are in the range
+    ///   [0.1; 0.9].
+    pub fn new(cr: f64) -> Self {
+        assert!((0.0..=1.0).contains(&cr));
+        Self { cr }
+    }
+}
+
+impl<T: Clone> DeCrossover<T> for BinomialCrossover {
+    fn cross(&mut self, target: &[T], donor: &[T], random: &mut StdRng) -> Coordinate<T> {
+        assert_eq!(target.len(), donor.len());
+        if target.is_empty() {
+            return vec![];
+        }
+
+        let j_rand = Uniform::new(0, target.len()).sample(random);
+        let between = Uniform::new(0.0, 1.0);
+
+        target
+            .iter()
+            .zip(donor.iter())
+            .enumerate()
+            .map(|(j, (target_x, donor_x))| {
+                if j == j_rand || between.sample(random) < self.cr {
+                    donor_x.clone()
+                } else {
+                    target_x.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// The differential evolution optimizer. The optimizer implements the `Optimizer` trait.
+///
+/// The coordinates of the trial vectors are limited by the intervals of the search space.
+///
+/// `T` - type of the coordinates of a point in the search space.
+pub struct DifferentialEvolutionOptimizer<'a, T> {
+    goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+    stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+    mutation: Box<dyn DeMutation<T> + 'a>,
+    crossover: Box<dyn DeCrossover<T> + 'a>,
+    loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>,
+    population_size: usize,
+    intervals: Vec<(T, T)>,
+    population: DePopulation<T>,
+    random: StdRng,
+
+    /// True if the population was created.
+    initialized: bool,
+}
+
+impl<'a, T: Float + 'a> DifferentialEvolutionOptimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop criterion.
+    /// * `population_size` - individuals count. Must be at least 4.
+    /// * `intervals` - intervals (min, max) of the search space for every coordinate.
+    ///   The initial population is created at random in the intervals.
+    /// * `f` - differential weight. Must be greater than 0.
+    /// * `cr` - crossover rate. Must be in the range [0; 1].
+    /// * `strategy` - the strategy of the algorithm.
+    pub fn new(
+        goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+        stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+        population_size: usize,
+        intervals: Vec<(T, T)>,
+        f: f64,
+        cr: f64,
+        strategy: Strategy,
+    ) -> Self {
+        assert!(population_size >= 4);
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        let mutation: Box<dyn DeMutation<T> + 'a> = match strategy {
+            Strategy::Rand1Bin => Box::new(RandMutation::new(f)),
+            Strategy::Best1Bin => Box::new(BestMutation::new(f)),
+        };
+
+        Self {
+            goal,
+            stop_checker,
+            mutation,
+            crossover: Box::new(BinomialCrossover::new(cr)),
+            loggers: vec![],
+            population_size,
+            intervals,
+            population: DePopulation::new(),
+            random: StdRng::from_entropy(),
+            initialized: false,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Replace the mutation of the strategy.
+    pub fn set_mutation(&mut self, mutation: Box<dyn DeMutation<T> + 'a>) {
+        self.mutation = mutation;
+    }
+
+    /// Replace the crossover of the strategy.
+    pub fn set_crossover(&mut self, crossover: Box<dyn DeCrossover<T> + 'a>) {
+        self.crossover = crossover;
+    }
+
+    /// Returns the current population.
+    pub fn get_population(&self) -> &DePopulation<T> {
+        &self.population
+    }
+
+    /// Calculate goal function for the point and apply the optimization direction.
+    fn calc_goal(&mut self, coordinates: &Coordinate<T>) -> GoalValue {
+        self.population.direction.apply(self.goal.get(coordinates))
+    }
+
+    /// Create the initial population.
+    fn init(&mut self, direction: Direction) {
+        self.population.reset();
+        self.population.direction = direction;
+
+        for _ in 0..self.population_size {
+            let random = &mut self.random;
+            let coordinates: Coordinate<T> = self
+                .intervals
+                .iter()
+                .map(|interval| {
+                    let between = Uniform::new_inclusive(
+                        interval.0.to_f64().unwrap(),
+                        interval.1.to_f64().unwrap(),
+                    );
+                    T::from(between.sample(random)).unwrap()
+                })
+                .collect();
+            let value = self.calc_goal(&coordinates);
+            self.population
+                .individuals
+                .push(DeIndividual { coordinates, value });
+        }
+
+        self.population.update_best();
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.population);
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<Coordinate<T>>> {
+        self.init(direction);
+        self.next_iterations()
+    }
+
+    /// Create the next generation.
+    fn do_iteration(&mut self) {
+        let mut trials = Vec::with_capacity(self.population.len());
+        for n in 0..self.population.len() {
+            let donor = self
+                .mutation
+                .mutation(&self.population, n, &mut self.random);
+            let mut trial = self.crossover.cross(
+                &self.population.individuals[n].coordinates,
+                &donor,
+                &mut self.random,
+            );
+
+            for (x, interval) in trial.iter_mut().zip(self.intervals.iter()) {
+                if *x < interval.0 {
+                    *x = interval.0;
+                } else if *x > interval.1 {
+                    *x = interval.1;
+                }
+            }
+            trials.push(trial);
+        }
+
+        for (n, trial) in trials.into_iter().enumerate() {
+            let value = self.calc_goal(&trial);
+            let target_value = self.population.individuals[n].value;
+            if value <= target_value || target_value.is_nan() {
+                self.population.individuals[n] = DeIndividual {
+                    coordinates: trial,
+                    value,
+                };
+            }
+        }
+
+        self.population.update_best();
+        self.population.iteration += 1;
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.population);
+        }
+    }
+}
+
+impl<'a, T: Float + 'a> Optimizer<Coordinate<T>> for DifferentialEvolutionOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<Solution<Coordinate<T>>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<Coordinate<T>>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove all individuals and go to iteration 0.
+    fn reset(&mut self) {
+        self.population.reset();
+        self.population.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+impl<'a, T: Float + 'a> IterativeOptimizer<Coordinate<T>>
+    for DifferentialEvolutionOptimizer<'a, T>
+{
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&Coordinate<T>, GoalValue)>),
+    ) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.population.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.population);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.population) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.population);
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.population);
+        }
+
+        self.population.get_best_solution()
+    }
+
+    fn step(&mut self) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.population.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.population) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.population.get_best_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create population with the given points.
+    fn create_population(points: Vec<Vec<f64>>) -> DePopulation<f64> {
+        let mut population = DePopulation::new();
+        population.individuals = points
+            .into_iter()
+            .map(|coordinates| {
+                let value = coordinates.iter().map(|x| x * x).sum();
+                DeIndividual { coordinates, value }
+            })
+            .collect();
+        population.update_best();
+        population
+    }
+
+    #[test]
+    fn random_indexes() {
+        let mut random = StdRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let indexes = get_random_indexes(4, 3, 2, &mut random);
+            assert_eq!(indexes.len(), 3);
+            assert!(!indexes.contains(&2));
+            assert!(indexes.contains(&0));
+            assert!(indexes.contains(&1));
+            assert!(indexes.contains(&3));
+        }
+    }
+
+    #[test]
+    fn update_best() {
+        let mut population = create_population(vec![vec![2.0], vec![-1.0], vec![3.0]]);
+        assert_eq!(population.get_best_index(), Some(1));
+
+        population.individuals[2].value = f64::NAN;
+        population.individuals[0].value = -1.0;
+        population.update_best();
+        assert_eq!(population.get_best_index(), Some(0));
+    }
+
+    #[test]
+    fn rand_mutation() {
+        // All individuals except the target are equal, so the difference is zero
+        let population =
+            create_population(vec![vec![5.0, 5.0], vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0]]);
+        let mut mutation = RandMutation::new(0.8);
+        let mut random = StdRng::seed_from_u64(1);
+
+        let donor = mutation.mutation(&population, 0, &mut random);
+        assert_eq!(donor, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn best_mutation() {
+        let population =
+            create_population(vec![vec![5.0, 5.0], vec![0.0, 1.0], vec![2.0, 2.0], vec![2.0, 2.0]]);
+        let mut mutation = BestMutation::new(0.5);
+        let mut random = StdRng::seed_from_u64(1);
+
+        // x_r1 and x_r2 are selected from the individuals 2 and 3 or 1 and one of 2, 3
+        for _ in 0..10 {
+            let donor = mutation.mutation(&population, 0, &mut random);
+            assert!(
+                donor == vec![0.0, 1.0]
+                    || donor == vec![-1.0, 0.5]
+                    || donor == vec![1.0, 1.5]
+            );
+        }
+    }
+
+    #[test]
+    fn binomial_crossover() {
+        let mut random = StdRng::seed_from_u64(1);
+        let target = vec![0.0; 100];
+        let donor = vec![1.0; 100];
+
+        let trial = BinomialCrossover::new(0.0).cross(&target, &donor, &mut random);
+        assert_eq!(trial.iter().filter(|x| **x == 1.0).count(), 1);
+
+        let trial = BinomialCrossover::new(1.0).cross(&target, &donor, &mut random);
+        assert_eq!(trial, donor);
+
+        let trial = BinomialCrossover::new(0.5).cross(&target, &donor, &mut random);
+        let donor_count = trial.iter().filter(|x| **x == 1.0).count();
+        assert!(donor_count > 30 && donor_count < 70);
+    }
+
+    #[test]
+    #[should_panic]
+    fn binomial_crossover_invalid_cr() {
+        BinomialCrossover::new(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rand_mutation_invalid_f() {
+        RandMutation::new(0.0);
+    }
+}
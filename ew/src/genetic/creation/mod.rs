@@ -1,2 +1,4 @@
 //! The module with algorithms with initial creation of individuals
+pub mod permutation;
 pub mod vec_float;
+pub mod vec_int;
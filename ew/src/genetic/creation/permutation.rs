@@ -0,0 +1,127 @@
+//! The module with Creators for the case when chromosomes are permutations of the integers
+//! [0; n) (for example, the order of the cities in the traveling salesman problem).
+//! The Creators used to create the first generation of individuals.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::genetic::Creator;
+
+/// Creator to initialize population by individuals with the random permutations of
+/// the integers 0, 1, ..., n - 1. All permutations have the same probability.
+pub struct RandomPermutationCreator {
+    population_size: usize,
+    n: usize,
+    random: StdRng,
+}
+
+impl RandomPermutationCreator {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `n` - length of the permutation (genes count in the chromosome). Must be greater than 0.
+    pub fn new(population_size: usize, n: usize) -> Self {
+        assert!(population_size > 0);
+        assert!(n > 0);
+
+        Self {
+            population_size,
+            n,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl Creator<Vec<usize>> for RandomPermutationCreator {
+    fn create(&mut self) -> Vec<Vec<usize>> {
+        let mut population = Vec::with_capacity(self.population_size * 2);
+
+        for _ in 0..self.population_size {
+            let mut chromo: Vec<usize> = (0..self.n).collect();
+            chromo.shuffle(&mut self.random);
+            population.push(chromo);
+        }
+
+        population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::pre_birth::permutation::is_permutation;
+
+    #[test]
+    fn random_permutation_valid() {
+        let mut creator = RandomPermutationCreator::new(100, 10);
+        creator.set_seed(1);
+
+        let chromosomes = creator.create();
+        assert_eq!(chromosomes.len(), 100);
+        for chromosome in &chromosomes {
+            assert_eq!(chromosome.len(), 10);
+            assert!(is_permutation(chromosome));
+        }
+
+        // The permutations are random
+        assert!(chromosomes
+            .iter()
+            .any(|chromosome| chromosome != &chromosomes[0]));
+    }
+
+    #[test]
+    fn random_permutation_positions() {
+        let n = 5;
+        let mut creator = RandomPermutationCreator::new(5000, n);
+        creator.set_seed(1);
+
+        // Every element is in every position with the probability 1 / n
+        let mut counts = vec![vec![0; n]; n];
+        for chromosome in creator.create() {
+            for (position, element) in chromosome.iter().enumerate() {
+                counts[position][*element] += 1;
+            }
+        }
+
+        for position_counts in counts {
+            for count in position_counts {
+                assert!((count as f64 / 1000.0 - 1.0).abs() < 0.15);
+            }
+        }
+    }
+
+    #[test]
+    fn random_permutation_single() {
+        let mut creator = RandomPermutationCreator::new(3, 1);
+        assert_eq!(creator.create(), vec![vec![0], vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn random_permutation_seed() {
+        let mut creator_1 = RandomPermutationCreator::new(10, 20);
+        let mut creator_2 = RandomPermutationCreator::new(10, 20);
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        assert_eq!(creator_1.create(), creator_2.create());
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_permutation_empty_population() {
+        RandomPermutationCreator::new(0, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_permutation_empty_chromosome() {
+        RandomPermutationCreator::new(10, 0);
+    }
+}
@@ -4,9 +4,13 @@
 
 use num::NumCast;
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
+use crate::genetic::mutation::SelfAdaptiveMutationChromosome;
 use crate::genetic::Creator;
+use crate::{Goal, GoalValue};
+use crate::tools::latin_hypercube;
 
 /// Creator to initialize population by individuals with random genes in the preset
 /// intervals.
@@ -14,7 +18,7 @@ use crate::genetic::Creator;
 pub struct RandomCreator<G: NumCast + PartialOrd> {
     population_size: usize,
     intervals: Vec<(G, G)>,
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl<G: NumCast + PartialOrd> RandomCreator<G> {
@@ -34,13 +38,18 @@ impl<G: NumCast + PartialOrd> RandomCreator<G> {
             assert!(interval.0 < interval.1);
         }
 
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self {
             population_size,
             intervals,
             random,
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<G: NumCast + PartialOrd> Creator<Vec<G>> for RandomCreator<G> {
@@ -65,9 +74,197 @@ impl<G: NumCast + PartialOrd> Creator<Vec<G>> for RandomCreator<G> {
     }
 }
 
+/// Creator to initialize population with the Latin hypercube sampling. Every interval is
+/// divided into `population_size` equal strata. Every stratum of every gene interval contains
+/// the gene of exactly one individual. The gene value inside the stratum is random.
+/// The creator gives more uniform covering of the search space than `RandomCreator`.
+/// `G` - type of genes. Chromosome is vector of the genes.
+pub struct LatinHypercubeCreator<G: NumCast + PartialOrd> {
+    population_size: usize,
+    intervals: Vec<(G, G)>,
+    random: StdRng,
+}
+
+impl<G: NumCast + PartialOrd> LatinHypercubeCreator<G> {
+    /// Constructor.
+    ///
+    /// `G` - type of genes. Chromosome is vector of the genes.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `intervals` - vector of the tuples (minval, maxval). Length of the `intervals` must
+    ///   equal genes count in the chromosome.
+    pub fn new(population_size: usize, intervals: Vec<(G, G)>) -> Self {
+        assert!(population_size > 0);
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 < interval.1);
+        }
+
+        Self {
+            population_size,
+            intervals,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: NumCast + PartialOrd> Creator<Vec<G>> for LatinHypercubeCreator<G> {
+    fn create(&mut self) -> Vec<Vec<G>> {
+        latin_hypercube(&self.intervals, self.population_size, &mut self.random)
+    }
+}
+
+/// Creator to initialize population with the opposition-based learning.
+/// The creator creates `population_size` individuals with random genes in the preset intervals
+/// and the opposite individuals with the genes `x_i' = min_i + max_i - x_i`. The creator keeps
+/// the better individual from every pair (so the goal function is calculated
+/// `2 * population_size` times during the creation). The optimizer uses the calculated values
+/// of the selected individuals and does not calculate the goal function for them again.
+///
+/// If the goal function is not available (the `create` method is called), the creator returns
+/// `population_size / 2` random individuals and their opposite individuals (plus one random
+/// individual if `population_size` is odd).
+///
+/// `G` - type of genes. Chromosome is vector of the genes.
+pub struct OppositionCreator<G: NumCast + PartialOrd> {
+    random_creator: RandomCreator<G>,
+    population_size: usize,
+    intervals: Vec<(G, G)>,
+}
+
+impl<G: NumCast + PartialOrd + Clone> OppositionCreator<G> {
+    /// Constructor.
+    ///
+    /// `G` - type of genes. Chromosome is vector of the genes.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `intervals` - vector of the tuples (minval, maxval). Length of the `intervals` must
+    ///   equal genes count in the chromosome.
+    pub fn new(population_size: usize, intervals: Vec<(G, G)>) -> Self {
+        Self {
+            random_creator: RandomCreator::new(population_size, intervals.clone()),
+            population_size,
+            intervals,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random_creator.set_seed(seed);
+    }
+
+    /// Returns the opposite chromosome.
+    fn get_opposite(&self, chromosome: &[G]) -> Vec<G> {
+        chromosome
+            .iter()
+            .zip(self.intervals.iter())
+            .map(|(gene, (min, max))| {
+                let opposite =
+                    min.to_f64().unwrap() + max.to_f64().unwrap() - gene.to_f64().unwrap();
+                G::from(opposite).unwrap()
+            })
+            .collect()
+    }
+}
+
+impl<G: NumCast + PartialOrd + Clone> Creator<Vec<G>> for OppositionCreator<G> {
+    fn create(&mut self) -> Vec<Vec<G>> {
+        let random_chromosomes = self.random_creator.create();
+        let pairs_count = self.population_size / 2;
+
+        let mut population = Vec::with_capacity(self.population_size);
+        let random_count = self.population_size - pairs_count;
+        for chromosome in random_chromosomes.into_iter().take(random_count) {
+            let opposite = if population.len() < 2 * pairs_count {
+                Some(self.get_opposite(&chromosome))
+            } else {
+                None
+            };
+
+            population.push(chromosome);
+            population.extend(opposite);
+        }
+
+        population
+    }
+
+    fn create_with_goal(&mut self, goal: &mut dyn Goal<Vec<G>>) -> Vec<(Vec<G>, Option<GoalValue>)> {
+        self.random_creator
+            .create()
+            .into_iter()
+            .map(|chromosome| {
+                let opposite = self.get_opposite(&chromosome);
+                let value = goal.get(&chromosome);
+                let opposite_value = goal.get(&opposite);
+
+                // NaN is worse than any other value
+                if opposite_value < value || (value.is_nan() && !opposite_value.is_nan()) {
+                    (opposite, Some(opposite_value))
+                } else {
+                    (chromosome, Some(value))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Creator to initialize population for the self-adaptive mutation. The variables are random
+/// values in the preset intervals (as for `RandomCreator`), all mutation step sizes are equal to
+/// the initial value.
+/// `G` - type of genes.
+pub struct SelfAdaptiveCreator<G: NumCast + PartialOrd> {
+    random_creator: RandomCreator<G>,
+    initial_sigma: G,
+}
+
+impl<G: NumCast + PartialOrd + Clone> SelfAdaptiveCreator<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `intervals` - vector of the tuples (minval, maxval) for the variables.
+    /// * `initial_sigma` - the initial mutation step size for all variables. Must be greater
+    ///   than 0.
+    pub fn new(population_size: usize, intervals: Vec<(G, G)>, initial_sigma: G) -> Self {
+        assert!(initial_sigma > G::from(0).unwrap());
+        Self {
+            random_creator: RandomCreator::new(population_size, intervals),
+            initial_sigma,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random_creator.set_seed(seed);
+    }
+}
+
+impl<G: NumCast + PartialOrd + Clone> Creator<SelfAdaptiveMutationChromosome<G>>
+    for SelfAdaptiveCreator<G>
+{
+    fn create(&mut self) -> Vec<SelfAdaptiveMutationChromosome<G>> {
+        self.random_creator
+            .create()
+            .into_iter()
+            .map(|variables| {
+                let sigmas = vec![self.initial_sigma.clone(); variables.len()];
+                SelfAdaptiveMutationChromosome::new(variables, sigmas)
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::GoalFromFunction;
 
     #[test]
     fn test_population_size() {
@@ -98,6 +295,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seed() {
+        let population_size = 10;
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0), (100.0, 110.0)];
+        let mut creator_1 = RandomCreator::new(population_size, intervals.clone());
+        let mut creator_2 = RandomCreator::new(population_size, intervals);
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        let chromosomes_1: Vec<Vec<f64>> = creator_1.create();
+        let chromosomes_2: Vec<Vec<f64>> = creator_2.create();
+        assert_eq!(chromosomes_1, chromosomes_2);
+    }
+
     #[test]
     #[should_panic]
     fn empty_population() {
@@ -137,4 +348,122 @@ mod tests {
         let intervals = vec![(0.0, 1.0), (10.0, 0.0)];
         RandomCreator::new(population_size, intervals);
     }
+
+    #[test]
+    fn latin_hypercube_strata() {
+        let population_size = 20;
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0), (100.0, 110.0)];
+        let mut creator = LatinHypercubeCreator::new(population_size, intervals.clone());
+
+        let chromosomes: Vec<Vec<f64>> = creator.create();
+        assert_eq!(chromosomes.len(), population_size);
+
+        for (gene, (min, max)) in intervals.iter().enumerate() {
+            let stratum_width = (max - min) / population_size as f64;
+            let mut strata = vec![0; population_size];
+            for chromosome in &chromosomes {
+                let stratum = ((chromosome[gene] - min) / stratum_width).floor() as usize;
+                strata[stratum.min(population_size - 1)] += 1;
+            }
+
+            assert!(strata.iter().all(|&count| count == 1));
+        }
+    }
+
+    #[test]
+    fn latin_hypercube_seed() {
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0)];
+        let mut creator_1 = LatinHypercubeCreator::new(10, intervals.clone());
+        let mut creator_2 = LatinHypercubeCreator::new(10, intervals);
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        let chromosomes_1: Vec<Vec<f64>> = creator_1.create();
+        let chromosomes_2: Vec<Vec<f64>> = creator_2.create();
+        assert_eq!(chromosomes_1, chromosomes_2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn latin_hypercube_empty_population() {
+        LatinHypercubeCreator::new(0, vec![(0.0, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn latin_hypercube_invalid_intervals() {
+        LatinHypercubeCreator::new(10, vec![(1.0, 0.0)]);
+    }
+
+    #[test]
+    fn opposition_without_goal() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 20.0)];
+        let mut creator = OppositionCreator::new(5, intervals);
+
+        let chromosomes: Vec<Vec<f64>> = creator.create();
+        assert_eq!(chromosomes.len(), 5);
+
+        for n in 0..2 {
+            let chromosome = &chromosomes[2 * n];
+            let opposite = &chromosomes[2 * n + 1];
+            assert!((chromosome[0] + opposite[0] - 1.0).abs() < 1e-10);
+            assert!((chromosome[1] + opposite[1] - 10.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn opposition_with_goal() {
+        let population_size = 100;
+        let intervals = vec![(0.0, 1.0), (-10.0, 20.0)];
+        let mut creator = OppositionCreator::new(population_size, intervals);
+        let mut goal = GoalFromFunction::new(|x: &Vec<f64>| x[0]);
+
+        let chromosomes = creator.create_with_goal(&mut goal);
+        assert_eq!(chromosomes.len(), population_size);
+
+        // The better individual from the pair is always less than 0.5
+        for (chromosome, value) in chromosomes {
+            assert_eq!(value, Some(chromosome[0]));
+            assert!(chromosome[0] <= 0.5);
+            assert!(chromosome[1] >= -10.0);
+            assert!(chromosome[1] <= 20.0);
+        }
+    }
+
+    #[test]
+    fn opposition_seed() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 20.0)];
+        let mut creator_1 = OppositionCreator::new(10, intervals.clone());
+        let mut creator_2 = OppositionCreator::new(10, intervals);
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        let mut goal = GoalFromFunction::new(|x: &Vec<f64>| x[0] + x[1]);
+        let chromosomes_1 = creator_1.create_with_goal(&mut goal);
+        let chromosomes_2 = creator_2.create_with_goal(&mut goal);
+        assert_eq!(chromosomes_1, chromosomes_2);
+    }
+
+    #[test]
+    fn self_adaptive_creator() {
+        let intervals = vec![(-1.0_f64, 1.0), (10.0, 20.0)];
+        let mut creator = SelfAdaptiveCreator::new(10, intervals.clone(), 0.5);
+        creator.set_seed(1);
+
+        let population = creator.create();
+        assert_eq!(population.len(), 10);
+        for chromosomes in population {
+            assert_eq!(chromosomes.sigmas, vec![0.5, 0.5]);
+            for (x, interval) in chromosomes.variables.iter().zip(intervals.iter()) {
+                assert!(*x >= interval.0);
+                assert!(*x <= interval.1);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn self_adaptive_creator_invalid_sigma() {
+        SelfAdaptiveCreator::new(10, vec![(0.0_f64, 1.0)], 0.0);
+    }
 }
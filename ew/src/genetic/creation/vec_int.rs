@@ -0,0 +1,142 @@
+//! The module with Creators for the case when chromosomes are Vec<G>, where G is an integer type
+//! (vector of the integer genes). Gene - single value in chromosome.
+//! The Creators used to create the first generation of individuals.
+
+use num::PrimInt;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::genetic::Creator;
+
+/// Creator to initialize population by individuals with random integer genes in the preset
+/// intervals.
+/// `G` - type of genes. Chromosome is vector of the genes.
+pub struct RandomCreator<G: PrimInt + SampleUniform> {
+    population_size: usize,
+    random: StdRng,
+    random_intervals: Vec<Uniform<G>>,
+}
+
+impl<G: PrimInt + SampleUniform> RandomCreator<G> {
+    /// Constructor.
+    ///
+    /// `G` - type of genes. Chromosome is vector of the genes.
+    ///
+    /// # Parameters
+    /// * `population_size` - individuals count in the first generation.
+    /// * `intervals` - vector of the tuples (minval, maxval). Length of the `intervals` must
+    ///   equal genes count in the chromosome. The values of `minval` and `maxval` will be
+    ///   included in random interval. `minval` may be equal to `maxval`, in this case the gene
+    ///   is constant.
+    pub fn new(population_size: usize, intervals: Vec<(G, G)>) -> Self {
+        assert!(population_size > 0);
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        let random_intervals = intervals
+            .iter()
+            .map(|(min, max)| Uniform::new_inclusive(*min, *max))
+            .collect();
+
+        Self {
+            population_size,
+            random: StdRng::from_entropy(),
+            random_intervals,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: PrimInt + SampleUniform> Creator<Vec<G>> for RandomCreator<G> {
+    fn create(&mut self) -> Vec<Vec<G>> {
+        let mut population = Vec::with_capacity(self.population_size * 2);
+
+        for _ in 0..self.population_size {
+            let random = &mut self.random;
+            let chromo = self
+                .random_intervals
+                .iter()
+                .map(|between| between.sample(random))
+                .collect();
+            population.push(chromo);
+        }
+
+        population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_population_size() {
+        let population_size = 10;
+        let intervals = vec![(0_i64, 1_i64)];
+        let mut creator = RandomCreator::new(population_size, intervals);
+
+        let chromosomes = creator.create();
+        assert_eq!(chromosomes.len(), population_size);
+    }
+
+    #[test]
+    fn test_intervals() {
+        let population_size = 1000;
+        let intervals = vec![(0_i64, 1_i64), (-10, 10), (100, 100)];
+        let mut creator = RandomCreator::new(population_size, intervals.clone());
+
+        let chromosomes = creator.create();
+        let mut counts = vec![0; 21];
+        for chromosome in chromosomes {
+            assert!(chromosome[0] == 0 || chromosome[0] == 1);
+
+            assert!(chromosome[1] >= -10);
+            assert!(chromosome[1] <= 10);
+            counts[(chromosome[1] + 10) as usize] += 1;
+
+            assert_eq!(chromosome[2], 100);
+        }
+
+        // The interval boundaries are included
+        assert!(counts.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn test_seed() {
+        let population_size = 10;
+        let intervals = vec![(0_i64, 1_i64), (-10, 10), (100, 200)];
+        let mut creator_1 = RandomCreator::new(population_size, intervals.clone());
+        let mut creator_2 = RandomCreator::new(population_size, intervals);
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        assert_eq!(creator_1.create(), creator_2.create());
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_population() {
+        RandomCreator::new(0, vec![(0_i64, 1_i64)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_intervals() {
+        let intervals: Vec<(i64, i64)> = Vec::new();
+        RandomCreator::new(10, intervals);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_intervals() {
+        RandomCreator::new(10, vec![(0_i64, 1_i64), (10, 0)]);
+    }
+}
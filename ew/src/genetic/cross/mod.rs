@@ -4,10 +4,12 @@
 
 use std::mem;
 
+use crate::genetic::mutation::SelfAdaptiveMutationChromosome;
 use crate::genetic::Cross;
 use num::{Float, Num, NumCast};
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 /// Struct to cross all genes (`G` - type of genes) in chromosome of type Vec<G>.
 pub struct VecCrossAllGenes<G> {
@@ -24,13 +26,13 @@ pub struct FloatCrossGeometricMean;
 
 /// Bitwise cross. Use single point crossing. Result of cross is single child.
 pub struct CrossBitwise {
-    random: ThreadRng,
+    random: StdRng,
 }
 
 /// Bitwise cross for float type chromosomes. Exponent and mantissa will be crossed independently.
 /// Use single point crossing. The sign is taken from one of parents at random.
 pub struct FloatCrossExp {
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl CrossMean {
@@ -97,9 +99,14 @@ impl<G: Float> Cross<G> for FloatCrossGeometricMean {
 impl CrossBitwise {
     /// Constructor.
     pub fn new() -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self { random }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl Cross<f64> for CrossBitwise {
@@ -150,11 +157,168 @@ impl<G> Cross<Vec<G>> for VecCrossAllGenes<G> {
     }
 }
 
+/// Single point crossing for chromosomes of type Vec<G> (`G` - type of genes).
+/// The struct selects the random split point. The child gets genes before the point from
+/// the first parent and genes after the point from the second parent. The gene in the split
+/// point is crossed by the single gene cross. Result of cross is single child.
+pub struct VecCrossSinglePoint<G> {
+    single_cross: Box<dyn Cross<G>>,
+    random: StdRng,
+}
+
+impl<G> VecCrossSinglePoint<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `single_cross` - cross for the gene in the split point.
+    pub fn new(single_cross: Box<dyn Cross<G>>) -> Self {
+        Self {
+            single_cross,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Clone> Cross<Vec<G>> for VecCrossSinglePoint<G> {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let parent_1 = parents[0];
+        let parent_2 = parents[1];
+        let gene_count = parent_1.len();
+        if gene_count == 0 {
+            return vec![vec![]];
+        }
+
+        let pos = Uniform::new(0, gene_count).sample(&mut self.random);
+
+        let mut child = parent_1[..pos].to_vec();
+        child.append(&mut self.single_cross.cross(&[&parent_1[pos], &parent_2[pos]]));
+        child.extend_from_slice(&parent_2[pos + 1..]);
+        vec![child]
+    }
+}
+
+/// Two point crossing for chromosomes of type Vec<G> (`G` - type of genes).
+/// The struct selects two different random split points. The child gets genes between the
+/// points from the second parent and other genes from the first parent. The genes in the split
+/// points are crossed by the single gene cross. If chromosome contains single gene, the gene
+/// is crossed by the single gene cross. Result of cross is single child.
+pub struct VecCrossTwoPoint<G> {
+    single_cross: Box<dyn Cross<G>>,
+    random: StdRng,
+}
+
+impl<G> VecCrossTwoPoint<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `single_cross` - cross for the genes in the split points.
+    pub fn new(single_cross: Box<dyn Cross<G>>) -> Self {
+        Self {
+            single_cross,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Clone> Cross<Vec<G>> for VecCrossTwoPoint<G> {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let parent_1 = parents[0];
+        let parent_2 = parents[1];
+        let gene_count = parent_1.len();
+        match gene_count {
+            0 => return vec![vec![]],
+            1 => return vec![self.single_cross.cross(&[&parent_1[0], &parent_2[0]])],
+            _ => {}
+        }
+
+        let pos_1 = Uniform::new(0, gene_count - 1).sample(&mut self.random);
+        let pos_2 = Uniform::new(pos_1 + 1, gene_count).sample(&mut self.random);
+
+        let mut child = parent_1[..pos_1].to_vec();
+        child.append(&mut self.single_cross.cross(&[&parent_1[pos_1], &parent_2[pos_1]]));
+        child.extend_from_slice(&parent_2[pos_1 + 1..pos_2]);
+        child.append(&mut self.single_cross.cross(&[&parent_1[pos_2], &parent_2[pos_2]]));
+        child.extend_from_slice(&parent_1[pos_2 + 1..]);
+        vec![child]
+    }
+}
+
+/// Uniform crossing for chromosomes of type Vec<G> (`G` - type of genes).
+/// Every gene of the child is taken from the first parent with the given probability
+/// or from the second parent otherwise. Result of cross is single child.
+pub struct UniformCrossover {
+    probability: f64,
+    random: StdRng,
+}
+
+impl UniformCrossover {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `probability` - probability of taking a gene from the first parent.
+    ///   Must be in the range [0; 1]. The usual value is 0.5.
+    pub fn new(probability: f64) -> Self {
+        assert!((0.0..=1.0).contains(&probability));
+        Self {
+            probability,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Clone> Cross<Vec<G>> for UniformCrossover {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let between = Uniform::new(0.0, 1.0);
+        let child = parents[0]
+            .iter()
+            .zip(parents[1].iter())
+            .map(|(gene_1, gene_2)| {
+                if between.sample(&mut self.random) < self.probability {
+                    gene_1.clone()
+                } else {
+                    gene_2.clone()
+                }
+            })
+            .collect();
+
+        vec![child]
+    }
+}
+
 impl FloatCrossExp {
     pub fn new() -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self { random }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<T: Float> Cross<T> for FloatCrossExp {
@@ -190,6 +354,182 @@ impl<T: Float> Cross<T> for FloatCrossExp {
     }
 }
 
+/// Blend crossover (BLX-alpha) for float genes. The child gene is random value
+/// from the interval [min(p, q) - alpha * |p - q|, max(p, q) + alpha * |p - q|],
+/// where `p` and `q` - parents genes. Result of cross is single child.
+/// Use `VecCrossAllGenes` to cross vector chromosomes.
+pub struct BLXAlphaCrossover {
+    alpha: f64,
+    random: StdRng,
+}
+
+impl BLXAlphaCrossover {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `alpha` - the interval expansion. Must be greater or equal to 0. The typical value is 0.5.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha >= 0.0);
+        Self {
+            alpha,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Float> Cross<G> for BLXAlphaCrossover {
+    fn cross(&mut self, parents_genes: &[&G]) -> Vec<G> {
+        assert_eq!(parents_genes.len(), 2);
+        let gene_1 = parents_genes[0].to_f64().unwrap();
+        let gene_2 = parents_genes[1].to_f64().unwrap();
+
+        let expansion = self.alpha * (gene_1 - gene_2).abs();
+        let min = gene_1.min(gene_2) - expansion;
+        let max = gene_1.max(gene_2) + expansion;
+
+        let between = Uniform::new_inclusive(min, max);
+        vec![G::from(between.sample(&mut self.random)).unwrap()]
+    }
+}
+
+/// Arithmetic crossover for float genes. The child gene is weighted average of the parents
+/// genes: `child = weight * p + (1 - weight) * q`, where `p` and `q` - parents genes.
+/// Result of cross is single child. Use `VecCrossAllGenes` to cross vector chromosomes.
+pub struct ArithmeticCrossover {
+    weight: f64,
+}
+
+impl ArithmeticCrossover {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `weight` - weight of the first parent gene. Must be in the range [0, 1].
+    pub fn new(weight: f64) -> Self {
+        assert!((0.0..=1.0).contains(&weight));
+        Self { weight }
+    }
+}
+
+impl<G: Float> Cross<G> for ArithmeticCrossover {
+    fn cross(&mut self, parents_genes: &[&G]) -> Vec<G> {
+        assert_eq!(parents_genes.len(), 2);
+        vec![arithmetic_cross(*parents_genes[0], *parents_genes[1], self.weight)]
+    }
+}
+
+/// Arithmetic crossover for float genes with random weight. The child gene is weighted average
+/// of the parents genes: `child = w * p + (1 - w) * q`, where `p` and `q` - parents genes,
+/// `w` - random value in the range [0, 1] for every cross.
+/// Result of cross is single child. Use `VecCrossAllGenes` to cross vector chromosomes.
+pub struct RandomArithmeticCrossover {
+    random: StdRng,
+}
+
+impl RandomArithmeticCrossover {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl Default for RandomArithmeticCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Float> Cross<G> for RandomArithmeticCrossover {
+    fn cross(&mut self, parents_genes: &[&G]) -> Vec<G> {
+        assert_eq!(parents_genes.len(), 2);
+        let weight = Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random);
+        vec![arithmetic_cross(*parents_genes[0], *parents_genes[1], weight)]
+    }
+}
+
+fn arithmetic_cross<G: Float>(gene_1: G, gene_2: G, weight: f64) -> G {
+    let weight = G::from(weight).unwrap();
+    weight * gene_1 + (G::one() - weight) * gene_2
+}
+
+/// Simulated binary crossover (SBX) for vector chromosomes with float genes from the article
+/// Deb, K.; Agrawal, R. B. (1995). "Simulated Binary Crossover for Continuous Search Space".
+/// Complex Systems, 9(2), pp.115-148.
+/// Result of cross is two children. For every gene:
+/// c1 = 0.5 * ((1 + beta) * p + (1 - beta) * q),
+/// c2 = 0.5 * ((1 - beta) * p + (1 + beta) * q),
+/// beta = (2 * u) ^ (1 / (eta + 1)) if u <= 0.5,
+/// beta = (1 / (2 * (1 - u))) ^ (1 / (eta + 1)) if u > 0.5,
+/// `p`, `q` - parents genes,
+/// `u` - random value in the range [0, 1),
+/// `eta` - distribution index. The children are closer to the parents for the greater `eta`.
+pub struct SimulatedBinaryCrossover {
+    eta: f64,
+    random: StdRng,
+}
+
+impl SimulatedBinaryCrossover {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `eta` - distribution index. Must be greater or equal to 0. The typical values are
+    ///   2 - 5 for the distant children and 20 or greater for the children near the parents.
+    pub fn new(eta: f64) -> Self {
+        assert!(eta >= 0.0);
+        Self {
+            eta,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    fn get_beta(&mut self) -> f64 {
+        let u = Uniform::new(0.0, 1.0).sample(&mut self.random);
+        let power = 1.0 / (self.eta + 1.0);
+        if u <= 0.5 {
+            (2.0 * u).powf(power)
+        } else {
+            (1.0 / (2.0 * (1.0 - u))).powf(power)
+        }
+    }
+}
+
+impl<G: Float> Cross<Vec<G>> for SimulatedBinaryCrossover {
+    fn cross(&mut self, parents: &[&Vec<G>]) -> Vec<Vec<G>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        let mut child_1 = Vec::with_capacity(gene_count);
+        let mut child_2 = Vec::with_capacity(gene_count);
+
+        for (gene_1, gene_2) in parents[0].iter().zip(parents[1].iter()) {
+            let beta = G::from(self.get_beta()).unwrap();
+            let half = G::from(0.5).unwrap();
+
+            child_1.push(half * ((G::one() + beta) * *gene_1 + (G::one() - beta) * *gene_2));
+            child_2.push(half * ((G::one() - beta) * *gene_1 + (G::one() + beta) * *gene_2));
+        }
+
+        vec![child_1, child_2]
+    }
+}
+
 /// Single point crossing.
 ///
 /// # Parameters
@@ -408,3 +748,753 @@ pub fn cross_f64(parent_1: f64, parent_2: f64, pos: usize) -> f64 {
     let child_bits = cross_u64(parent_1_bits, parent_2_bits, pos);
     f64::from_bits(child_bits)
 }
+
+/// Cross for `SelfAdaptiveMutationChromosome` (`G` - type of genes). The variables and
+/// the mutation step sizes are crossed independently by the separate crosses for vectors.
+/// The children count is the least children count of the crosses.
+pub struct SelfAdaptiveCross<G> {
+    variables_cross: Box<dyn Cross<Vec<G>>>,
+    sigmas_cross: Box<dyn Cross<Vec<G>>>,
+}
+
+impl<G> SelfAdaptiveCross<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `variables_cross` - cross for the variables (points in the search space).
+    /// * `sigmas_cross` - cross for the mutation step sizes. `VecCrossAllGenes` with `CrossMean`
+    ///   is the usual choice.
+    pub fn new(
+        variables_cross: Box<dyn Cross<Vec<G>>>,
+        sigmas_cross: Box<dyn Cross<Vec<G>>>,
+    ) -> Self {
+        Self {
+            variables_cross,
+            sigmas_cross,
+        }
+    }
+}
+
+impl<G> Cross<SelfAdaptiveMutationChromosome<G>> for SelfAdaptiveCross<G> {
+    fn cross(
+        &mut self,
+        parents: &[&SelfAdaptiveMutationChromosome<G>],
+    ) -> Vec<SelfAdaptiveMutationChromosome<G>> {
+        let variables: Vec<&Vec<G>> = parents.iter().map(|parent| &parent.variables).collect();
+        let sigmas: Vec<&Vec<G>> = parents.iter().map(|parent| &parent.sigmas).collect();
+
+        let children_variables = self.variables_cross.cross(&variables);
+        let children_sigmas = self.sigmas_cross.cross(&sigmas);
+
+        children_variables
+            .into_iter()
+            .zip(children_sigmas)
+            .map(|(variables, sigmas)| SelfAdaptiveMutationChromosome::new(variables, sigmas))
+            .collect()
+    }
+}
+
+/// Returns the random segment `[start; end]` (the boundaries are included) for the chromosomes
+/// with `gene_count` genes. `gene_count` must be greater than 0.
+fn get_random_segment(random: &mut StdRng, gene_count: usize) -> (usize, usize) {
+    let between = Uniform::new(0, gene_count);
+    let pos_1 = between.sample(random);
+    let pos_2 = between.sample(random);
+    (pos_1.min(pos_2), pos_1.max(pos_2))
+}
+
+/// Order crossover (OX) for the permutation chromosomes from the book
+/// Davis, L. (1991). "Handbook of Genetic Algorithms". Van Nostrand Reinhold.
+/// The struct selects the random segment. The child gets the genes inside the segment from
+/// the first parent. The rest positions are filled (starting after the segment and wrapping
+/// around) by the genes of the second parent which are absent in the segment. The genes are
+/// taken in the order in the second parent starting after the segment. The second child is
+/// created with the swapped parents, so the result of cross is two children.
+///
+/// The children are valid permutations if the parents are valid permutations.
+pub struct OrderCrossover {
+    random: StdRng,
+}
+
+impl OrderCrossover {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Create the child with the segment `[start; end]` from `parent_1`.
+    fn create_child(
+        parent_1: &[usize],
+        parent_2: &[usize],
+        start: usize,
+        end: usize,
+    ) -> Vec<usize> {
+        let gene_count = parent_1.len();
+        let mut in_segment = vec![false; gene_count];
+        for &gene in &parent_1[start..=end] {
+            in_segment[gene] = true;
+        }
+
+        let mut child = parent_1.to_vec();
+        let mut pos = (end + 1) % gene_count;
+        for n in 0..gene_count {
+            let gene = parent_2[(end + 1 + n) % gene_count];
+            if !in_segment[gene] {
+                child[pos] = gene;
+                pos = (pos + 1) % gene_count;
+            }
+        }
+
+        child
+    }
+}
+
+impl Default for OrderCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cross<Vec<usize>> for OrderCrossover {
+    fn cross(&mut self, parents: &[&Vec<usize>]) -> Vec<Vec<usize>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        if gene_count == 0 {
+            return vec![vec![], vec![]];
+        }
+
+        let (start, end) = get_random_segment(&mut self.random, gene_count);
+        vec![
+            Self::create_child(parents[0], parents[1], start, end),
+            Self::create_child(parents[1], parents[0], start, end),
+        ]
+    }
+}
+
+/// Partially mapped crossover (PMX) for the permutation chromosomes from the article
+/// Goldberg, D. E.; Lingle, R. (1985). "Alleles, loci, and the traveling salesman problem".
+/// Proceedings of the First International Conference on Genetic Algorithms and Their
+/// Applications, pp.154-159.
+/// The struct selects the random segment. The child gets the genes inside the segment from
+/// the first parent. Every gene of the second parent inside the segment which is absent in
+/// the child is placed to the position found by the mapping between the parents segments.
+/// The rest positions are filled by the genes of the second parent in the same positions.
+/// The second child is created with the swapped parents, so the result of cross is two children.
+///
+/// The children are valid permutations if the parents are valid permutations.
+pub struct PartiallyMappedCrossover {
+    random: StdRng,
+}
+
+impl PartiallyMappedCrossover {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Create the child with the segment `[start; end]` from `parent_1`.
+    fn create_child(
+        parent_1: &[usize],
+        parent_2: &[usize],
+        start: usize,
+        end: usize,
+    ) -> Vec<usize> {
+        let gene_count = parent_1.len();
+
+        // The position of every gene in the second parent
+        let mut positions_2 = vec![0; gene_count];
+        for (pos, &gene) in parent_2.iter().enumerate() {
+            positions_2[gene] = pos;
+        }
+
+        let mut child: Vec<Option<usize>> = vec![None; gene_count];
+        let mut in_child = vec![false; gene_count];
+        for pos in start..=end {
+            child[pos] = Some(parent_1[pos]);
+            in_child[parent_1[pos]] = true;
+        }
+
+        for (pos, &gene) in parent_2.iter().enumerate().take(end + 1).skip(start) {
+            if in_child[gene] {
+                continue;
+            }
+
+            // Follow the mapping until the position outside the segment
+            let mut target = pos;
+            while target >= start && target <= end {
+                target = positions_2[parent_1[target]];
+            }
+
+            child[target] = Some(gene);
+            in_child[gene] = true;
+        }
+
+        child
+            .into_iter()
+            .zip(parent_2.iter())
+            .map(|(gene, gene_2)| gene.unwrap_or(*gene_2))
+            .collect()
+    }
+}
+
+impl Default for PartiallyMappedCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cross<Vec<usize>> for PartiallyMappedCrossover {
+    fn cross(&mut self, parents: &[&Vec<usize>]) -> Vec<Vec<usize>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let gene_count = parents[0].len();
+        if gene_count == 0 {
+            return vec![vec![], vec![]];
+        }
+
+        let (start, end) = get_random_segment(&mut self.random, gene_count);
+        vec![
+            Self::create_child(parents[0], parents[1], start, end),
+            Self::create_child(parents[1], parents[0], start, end),
+        ]
+    }
+}
+
+/// Cycle crossover (CX) for the permutation chromosomes from the article
+/// Oliver, I. M.; Smith, D. J.; Holland, J. R. C. (1987). "A study of permutation crossover
+/// operators on the traveling salesman problem". Proceedings of the Second International
+/// Conference on Genetic Algorithms and Their Applications, pp.224-230.
+/// The positions of the genes are divided into cycles. The cycle starts from the first free
+/// position, the next position of the cycle is the position in the first parent of the gene
+/// of the second parent in the current position. The first child gets the genes of the odd
+/// cycles from the first parent and the genes of the even cycles from the second parent,
+/// the second child gets the rest genes. Every gene of the children is in the same position
+/// as in one of the parents. Result of cross is two children.
+///
+/// The children are valid permutations if the parents are valid permutations.
+pub struct CycleCrossover;
+
+impl CycleCrossover {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CycleCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cross<Vec<usize>> for CycleCrossover {
+    fn cross(&mut self, parents: &[&Vec<usize>]) -> Vec<Vec<usize>> {
+        assert_eq!(parents.len(), 2);
+        assert_eq!(parents[0].len(), parents[1].len());
+
+        let parent_1 = parents[0];
+        let parent_2 = parents[1];
+        let gene_count = parent_1.len();
+
+        // The position of every gene in the first parent
+        let mut positions_1 = vec![0; gene_count];
+        for (pos, &gene) in parent_1.iter().enumerate() {
+            positions_1[gene] = pos;
+        }
+
+        let mut child_1 = parent_1.clone();
+        let mut child_2 = parent_2.clone();
+        let mut visited = vec![false; gene_count];
+        let mut swap_cycle = false;
+        for start in 0..gene_count {
+            if visited[start] {
+                continue;
+            }
+
+            let mut pos = start;
+            while !visited[pos] {
+                visited[pos] = true;
+                if swap_cycle {
+                    child_1[pos] = parent_2[pos];
+                    child_2[pos] = parent_1[pos];
+                }
+                pos = positions_1[parent_2[pos]];
+            }
+
+            swap_cycle = !swap_cycle;
+        }
+
+        vec![child_1, child_2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::creation::permutation::RandomPermutationCreator;
+    use crate::genetic::pre_birth::permutation::is_permutation;
+    use crate::genetic::Creator;
+
+    #[test]
+    fn blx_alpha_interval() {
+        let mut crosser = BLXAlphaCrossover::new(0.5);
+        for _ in 0..1000 {
+            let child = crosser.cross(&[&1.0_f64, &3.0_f64]);
+            assert_eq!(child.len(), 1);
+            assert!(child[0] >= 0.0);
+            assert!(child[0] <= 4.0);
+
+            let child = crosser.cross(&[&3.0_f64, &1.0_f64]);
+            assert!(child[0] >= 0.0);
+            assert!(child[0] <= 4.0);
+        }
+    }
+
+    #[test]
+    fn blx_alpha_expanded() {
+        // The children must be out of the parents interval sometimes
+        let mut crosser = BLXAlphaCrossover::new(0.5);
+        crosser.set_seed(42);
+        let outside_count = (0..1000)
+            .map(|_| crosser.cross(&[&1.0_f64, &3.0_f64])[0])
+            .filter(|&gene| gene < 1.0 || gene > 3.0)
+            .count();
+
+        assert!(outside_count > 0);
+    }
+
+    #[test]
+    fn blx_alpha_zero() {
+        let mut crosser = BLXAlphaCrossover::new(0.0);
+        for _ in 0..1000 {
+            let child = crosser.cross(&[&1.0_f32, &3.0_f32]);
+            assert!(child[0] >= 1.0);
+            assert!(child[0] <= 3.0);
+        }
+    }
+
+    #[test]
+    fn blx_alpha_equal_parents() {
+        let mut crosser = BLXAlphaCrossover::new(0.5);
+        assert_eq!(crosser.cross(&[&2.0_f64, &2.0_f64]), vec![2.0]);
+    }
+
+    #[test]
+    fn blx_alpha_vector() {
+        let mut crosser = VecCrossAllGenes::new(Box::new(BLXAlphaCrossover::new(0.5)));
+        let parent_1 = vec![0.0_f64, 10.0, -5.0];
+        let parent_2 = vec![1.0_f64, 20.0, -5.0];
+
+        let children = crosser.cross(&[&parent_1, &parent_2]);
+        assert_eq!(children.len(), 1);
+        assert!(children[0][0] >= -0.5 && children[0][0] <= 1.5);
+        assert!(children[0][1] >= 5.0 && children[0][1] <= 25.0);
+        assert_eq!(children[0][2], -5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn blx_alpha_invalid() {
+        BLXAlphaCrossover::new(-0.1);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut crosser = ArithmeticCrossover::new(0.25);
+        let child = crosser.cross(&[&0.0_f64, &4.0_f64]);
+        assert_eq!(child, vec![3.0]);
+    }
+
+    #[test]
+    fn arithmetic_between_parents() {
+        for &weight in &[0.1, 0.5, 0.9] {
+            let mut crosser = ArithmeticCrossover::new(weight);
+            let child = crosser.cross(&[&-1.0_f64, &3.0_f64]);
+            assert!(child[0] > -1.0);
+            assert!(child[0] < 3.0);
+        }
+    }
+
+    #[test]
+    fn arithmetic_vector() {
+        let mut crosser = VecCrossAllGenes::new(Box::new(ArithmeticCrossover::new(0.5)));
+        let children = crosser.cross(&[&vec![0.0_f32, 10.0], &vec![2.0_f32, 20.0]]);
+        assert_eq!(children, vec![vec![1.0, 15.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn arithmetic_invalid_weight() {
+        ArithmeticCrossover::new(1.5);
+    }
+
+    #[test]
+    fn random_arithmetic() {
+        let mut crosser = RandomArithmeticCrossover::new();
+        crosser.set_seed(42);
+        let children: Vec<f64> = (0..1000)
+            .map(|_| crosser.cross(&[&-1.0_f64, &3.0_f64])[0])
+            .collect();
+
+        assert!(children.iter().all(|&child| (-1.0..=3.0).contains(&child)));
+
+        // The weight is different for every cross
+        assert!(children.iter().any(|&child| child != children[0]));
+    }
+
+    /// Returns average distance between children for SBX.
+    fn get_sbx_spread(eta: f64) -> f64 {
+        let run_count = 1000;
+        let mut crosser = SimulatedBinaryCrossover::new(eta);
+        crosser.set_seed(42);
+
+        let parent_1 = vec![0.0_f64];
+        let parent_2 = vec![1.0_f64];
+        let sum: f64 = (0..run_count)
+            .map(|_| {
+                let children = crosser.cross(&[&parent_1, &parent_2]);
+                (children[0][0] - children[1][0]).abs()
+            })
+            .sum();
+
+        sum / run_count as f64
+    }
+
+    #[test]
+    fn sbx_children() {
+        let mut crosser = SimulatedBinaryCrossover::new(2.0);
+        let parent_1 = vec![0.0_f64, 10.0, -5.0];
+        let parent_2 = vec![1.0_f64, 20.0, -5.0];
+
+        let children = crosser.cross(&[&parent_1, &parent_2]);
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].len(), 3);
+        assert_eq!(children[1].len(), 3);
+
+        // The children are symmetric relative to the parents center
+        for gene in 0..3 {
+            let parents_sum = parent_1[gene] + parent_2[gene];
+            let children_sum = children[0][gene] + children[1][gene];
+            assert!((parents_sum - children_sum).abs() < 1e-10);
+        }
+
+        // The children are equal to the parents for the equal genes
+        assert!((children[0][2] + 5.0).abs() < 1e-10);
+        assert!((children[1][2] + 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn sbx_eta() {
+        let spread_low = get_sbx_spread(2.0);
+        let spread_high = get_sbx_spread(20.0);
+
+        // The children are close to the parents for the great eta
+        assert!(spread_low > spread_high);
+        assert!((spread_high - 1.0).abs() < (spread_low - 1.0).abs());
+    }
+
+    #[test]
+    #[should_panic]
+    fn sbx_invalid_eta() {
+        SimulatedBinaryCrossover::new(-1.0);
+    }
+
+    #[test]
+    fn vec_cross_single_point() {
+        let mut crosser = VecCrossSinglePoint::new(Box::new(CrossMean::new()));
+        crosser.set_seed(1);
+        let parent_1 = vec![0.0_f64; 5];
+        let parent_2 = vec![2.0_f64; 5];
+
+        let mut positions = vec![false; 5];
+        for _ in 0..100 {
+            let children = crosser.cross(&[&parent_1, &parent_2]);
+            assert_eq!(children.len(), 1);
+
+            let child = &children[0];
+            assert_eq!(child.len(), 5);
+
+            let pos = child.iter().position(|gene| *gene == 1.0).unwrap();
+            positions[pos] = true;
+            assert!(child[..pos].iter().all(|gene| *gene == 0.0));
+            assert!(child[pos + 1..].iter().all(|gene| *gene == 2.0));
+        }
+
+        assert!(positions.iter().all(|pos| *pos));
+    }
+
+    #[test]
+    fn vec_cross_single_point_single_gene() {
+        let mut crosser = VecCrossSinglePoint::new(Box::new(CrossMean::new()));
+        let children = crosser.cross(&[&vec![0.0_f64], &vec![2.0_f64]]);
+        assert_eq!(children, vec![vec![1.0_f64]]);
+    }
+
+    #[test]
+    fn vec_cross_two_point() {
+        let mut crosser = VecCrossTwoPoint::new(Box::new(CrossMean::new()));
+        crosser.set_seed(1);
+        let parent_1 = vec![0.0_f64; 5];
+        let parent_2 = vec![2.0_f64; 5];
+
+        for _ in 0..100 {
+            let children = crosser.cross(&[&parent_1, &parent_2]);
+            assert_eq!(children.len(), 1);
+
+            let child = &children[0];
+            assert_eq!(child.len(), 5);
+
+            let points: Vec<usize> = (0..5).filter(|&n| child[n] == 1.0).collect();
+            assert_eq!(points.len(), 2);
+            let pos_1 = points[0];
+            let pos_2 = points[1];
+
+            assert!(child[..pos_1].iter().all(|gene| *gene == 0.0));
+            assert!(child[pos_1 + 1..pos_2].iter().all(|gene| *gene == 2.0));
+            assert!(child[pos_2 + 1..].iter().all(|gene| *gene == 0.0));
+        }
+    }
+
+    #[test]
+    fn vec_cross_two_point_short() {
+        let mut crosser = VecCrossTwoPoint::new(Box::new(CrossMean::new()));
+        let children = crosser.cross(&[&vec![0.0_f64], &vec![2.0_f64]]);
+        assert_eq!(children, vec![vec![1.0_f64]]);
+
+        let children = crosser.cross(&[&vec![0.0_f64, 0.0], &vec![2.0_f64, 2.0]]);
+        assert_eq!(children, vec![vec![1.0_f64, 1.0]]);
+    }
+
+    #[test]
+    fn uniform_crossover() {
+        let mut crosser = UniformCrossover::new(0.5);
+        crosser.set_seed(1);
+        let parent_1 = vec![0_i32; 1000];
+        let parent_2 = vec![1_i32; 1000];
+
+        let children = crosser.cross(&[&parent_1, &parent_2]);
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].len(), 1000);
+
+        let second_count = children[0].iter().filter(|gene| **gene == 1).count();
+        assert!(second_count > 400);
+        assert!(second_count < 600);
+    }
+
+    #[test]
+    fn uniform_crossover_probability() {
+        let parent_1 = vec![0_i32; 100];
+        let parent_2 = vec![1_i32; 100];
+
+        let mut crosser = UniformCrossover::new(1.0);
+        assert_eq!(crosser.cross(&[&parent_1, &parent_2]), vec![parent_1.clone()]);
+
+        let mut crosser = UniformCrossover::new(0.0);
+        assert_eq!(crosser.cross(&[&parent_1, &parent_2]), vec![parent_2.clone()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn uniform_crossover_invalid_probability() {
+        UniformCrossover::new(1.1);
+    }
+
+    #[test]
+    fn self_adaptive_cross() {
+        let mut crosser = SelfAdaptiveCross::new(
+            Box::new(VecCrossAllGenes::new(Box::new(CrossMean::new()))),
+            Box::new(VecCrossAllGenes::new(Box::new(FloatCrossGeometricMean::new()))),
+        );
+        let parent_1 = SelfAdaptiveMutationChromosome::new(vec![0.0_f64, 2.0], vec![1.0, 2.0]);
+        let parent_2 = SelfAdaptiveMutationChromosome::new(vec![2.0_f64, 4.0], vec![4.0, 8.0]);
+
+        let children = crosser.cross(&[&parent_1, &parent_2]);
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].variables, vec![1.0, 3.0]);
+        assert_eq!(children[0].sigmas, vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn order_crossover_known() {
+        let parent_1 = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let parent_2 = vec![8, 2, 6, 7, 1, 5, 4, 0, 3];
+
+        assert_eq!(
+            OrderCrossover::create_child(&parent_1, &parent_2, 3, 6),
+            vec![2, 7, 1, 3, 4, 5, 6, 0, 8]
+        );
+        assert_eq!(
+            OrderCrossover::create_child(&parent_2, &parent_1, 3, 6),
+            vec![2, 3, 6, 7, 1, 5, 4, 8, 0]
+        );
+    }
+
+    #[test]
+    fn order_crossover_whole_segment() {
+        let parent_1 = vec![3, 1, 0, 2];
+        let parent_2 = vec![0, 1, 2, 3];
+
+        assert_eq!(
+            OrderCrossover::create_child(&parent_1, &parent_2, 0, 3),
+            parent_1
+        );
+        assert_eq!(
+            OrderCrossover::create_child(&parent_1, &parent_2, 2, 2),
+            vec![1, 2, 0, 3]
+        );
+    }
+
+    #[test]
+    fn order_crossover_valid() {
+        let mut creator = RandomPermutationCreator::new(100, 20);
+        creator.set_seed(1);
+        let chromosomes = creator.create();
+
+        let mut crosser = OrderCrossover::new();
+        crosser.set_seed(1);
+        for parents in chromosomes.chunks(2) {
+            let children = crosser.cross(&[&parents[0], &parents[1]]);
+            assert_eq!(children.len(), 2);
+            for child in children {
+                assert_eq!(child.len(), 20);
+                assert!(is_permutation(&child));
+            }
+        }
+    }
+
+    #[test]
+    fn order_crossover_small() {
+        let mut crosser = OrderCrossover::new();
+        assert_eq!(crosser.cross(&[&vec![], &vec![]]), vec![Vec::<usize>::new(), Vec::new()]);
+        assert_eq!(crosser.cross(&[&vec![0], &vec![0]]), vec![vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn partially_mapped_crossover_known() {
+        let parent_1 = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let parent_2 = vec![8, 2, 6, 7, 1, 5, 4, 0, 3];
+
+        assert_eq!(
+            PartiallyMappedCrossover::create_child(&parent_1, &parent_2, 3, 6),
+            vec![8, 2, 1, 3, 4, 5, 6, 0, 7]
+        );
+        assert_eq!(
+            PartiallyMappedCrossover::create_child(&parent_2, &parent_1, 3, 6),
+            vec![0, 6, 2, 7, 1, 5, 4, 3, 8]
+        );
+    }
+
+    #[test]
+    fn partially_mapped_crossover_segment_bounds() {
+        let parent_1 = vec![3, 1, 0, 2];
+        let parent_2 = vec![0, 1, 2, 3];
+
+        // The whole chromosome
+        assert_eq!(
+            PartiallyMappedCrossover::create_child(&parent_1, &parent_2, 0, 3),
+            parent_1
+        );
+
+        // The single gene: 0 from the first parent, 2 is moved to the position of 0
+        assert_eq!(
+            PartiallyMappedCrossover::create_child(&parent_1, &parent_2, 2, 2),
+            vec![2, 1, 0, 3]
+        );
+    }
+
+    #[test]
+    fn partially_mapped_crossover_valid() {
+        let mut creator = RandomPermutationCreator::new(100, 20);
+        creator.set_seed(1);
+        let chromosomes = creator.create();
+
+        let mut crosser = PartiallyMappedCrossover::new();
+        crosser.set_seed(1);
+        for parents in chromosomes.chunks(2) {
+            let children = crosser.cross(&[&parents[0], &parents[1]]);
+            assert_eq!(children.len(), 2);
+            for child in children {
+                assert_eq!(child.len(), 20);
+                assert!(is_permutation(&child));
+            }
+        }
+    }
+
+    #[test]
+    fn partially_mapped_crossover_small() {
+        let mut crosser = PartiallyMappedCrossover::new();
+        assert_eq!(crosser.cross(&[&vec![], &vec![]]), vec![Vec::<usize>::new(), Vec::new()]);
+        assert_eq!(crosser.cross(&[&vec![0], &vec![0]]), vec![vec![0], vec![0]]);
+    }
+
+    #[test]
+    fn cycle_crossover_known() {
+        let parent_1 = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let parent_2 = vec![8, 2, 6, 7, 1, 5, 4, 0, 3];
+
+        let mut crosser = CycleCrossover::new();
+        assert_eq!(
+            crosser.cross(&[&parent_1, &parent_2]),
+            vec![
+                vec![0, 2, 6, 3, 1, 5, 4, 7, 8],
+                vec![8, 1, 2, 7, 4, 5, 6, 0, 3]
+            ]
+        );
+    }
+
+    #[test]
+    fn cycle_crossover_same_parents() {
+        let parent = vec![3, 1, 0, 2];
+
+        let mut crosser = CycleCrossover::new();
+        assert_eq!(
+            crosser.cross(&[&parent, &parent]),
+            vec![parent.clone(), parent.clone()]
+        );
+    }
+
+    #[test]
+    fn cycle_crossover_valid() {
+        let mut creator = RandomPermutationCreator::new(100, 20);
+        creator.set_seed(1);
+        let chromosomes = creator.create();
+
+        let mut crosser = CycleCrossover::new();
+        for parents in chromosomes.chunks(2) {
+            let children = crosser.cross(&[&parents[0], &parents[1]]);
+            assert_eq!(children.len(), 2);
+            for child in children {
+                assert_eq!(child.len(), 20);
+                assert!(is_permutation(&child));
+
+                // Every gene is in the position of the gene in one of the parents
+                for (pos, gene) in child.iter().enumerate() {
+                    assert!(*gene == parents[0][pos] || *gene == parents[1][pos]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cycle_crossover_empty() {
+        let mut crosser = CycleCrossover::new();
+        assert_eq!(crosser.cross(&[&vec![], &vec![]]), vec![Vec::<usize>::new(), Vec::new()]);
+    }
+}
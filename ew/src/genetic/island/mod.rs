@@ -0,0 +1,675 @@
+//! The module with the island model of the genetic algorithm.
+//!
+//! Every island is the independent `GeneticOptimizer` with its own population. All islands
+//! create new generations in turn. After every `migration_interval` generations the best
+//! individuals of every island migrate to other islands according to `MigrationPolicy`.
+//! The migrants replace the worst individuals of the target island.
+//!
+//! With the "parallel" feature the goal function of the new individuals of all islands which
+//! were created with the parallel goal function (`GeneticOptimizerBuilder::parallel_goal`) is
+//! calculated at once in several threads. The genetic operators of the islands run in the
+//! calling thread.
+//!
+//! # Terms
+//! * "Island" is a single `GeneticOptimizer`.
+//! * "Archipelago" is the set of all islands (the state of the algorithm).
+
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::{GeneticOptimizer, Individual};
+use crate::tools::compare_values;
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::{
+    call_iteration_callback, AlgorithmState, Direction, GoalValue, IterativeOptimizer, Optimizer,
+    Solution,
+};
+
+#[cfg(feature = "parallel")]
+use super::GoalEvaluator;
+#[cfg(feature = "parallel")]
+use crate::SyncGoal;
+
+/// The rule to select the target islands for the migrants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationPolicy {
+    /// The migrants of the island i move to the island i + 1. The migrants of the last island
+    /// move to the first island.
+    Ring,
+
+    /// The migrants of every island move to the randomly chosen other island.
+    Random,
+
+    /// The migrants of every island move to all other islands.
+    FullyConnected,
+}
+
+/// The set of all islands. The state of the island genetic algorithm.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct Archipelago<'a, T> {
+    islands: Vec<GeneticOptimizer<'a, T>>,
+    iteration: usize,
+    direction: Direction,
+}
+
+impl<'a, T: Clone> Archipelago<'a, T> {
+    /// Returns all islands.
+    pub fn get_islands(&self) -> &[GeneticOptimizer<'a, T>] {
+        &self.islands
+    }
+
+    /// Returns the best individual of all islands (the fitness is the value which the algorithm
+    /// minimizes).
+    fn get_best_individual(&self) -> Option<&Individual<T>> {
+        self.islands
+            .iter()
+            .filter_map(|island| island.population.get_best().as_ref())
+            .min_by(|individual_1, individual_2| {
+                compare_values(individual_1.get_fitness(), individual_2.get_fitness())
+            })
+    }
+}
+
+impl<'a, T: Clone> AlgorithmState<T> for Archipelago<'a, T> {
+    fn get_best_solution(&self) -> Option<Solution<T>> {
+        self.get_best_individual().map(|individual| {
+            (
+                individual.get_chromosomes().clone(),
+                self.direction.apply(individual.get_fitness()),
+            )
+        })
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&T> {
+        self.islands
+            .iter()
+            .flat_map(|island| island.population.iter())
+            .map(|individual| individual.get_chromosomes())
+            .collect()
+    }
+}
+
+/// The island model of the genetic algorithm. The optimizer implements the `Optimizer` trait.
+///
+/// The stop checkers of the islands are not used, all islands are stopped by the common stop
+/// checker. The loggers of the islands are called for every island generation and in the
+/// beginning and in the end of every `IterativeOptimizer::next_iterations` call.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct IslandGeneticOptimizer<'a, T> {
+    archipelago: Archipelago<'a, T>,
+    stop_checker: Box<dyn StopChecker<T> + 'a>,
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+    migration_interval: usize,
+    migration_count: usize,
+
+    /// Count of generations after the last migration.
+    generations_after_migration: usize,
+
+    migration_policy: MigrationPolicy,
+    random: StdRng,
+
+    /// True if the first generation was created.
+    initialized: bool,
+}
+
+impl<'a, T: Clone> IslandGeneticOptimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `islands` - the genetic optimizers for every island. Must contain at least 2 islands.
+    /// * `stop_checker` - the common stop criterion for all islands.
+    /// * `migration_interval` - count of generations between migrations. Must be greater than 0.
+    /// * `migration_count` - count of the best individuals which migrate from every island.
+    pub fn new(
+        islands: Vec<GeneticOptimizer<'a, T>>,
+        stop_checker: Box<dyn StopChecker<T> + 'a>,
+        migration_interval: usize,
+        migration_count: usize,
+    ) -> Self {
+        assert!(islands.len() >= 2);
+        assert!(migration_interval > 0);
+
+        Self {
+            archipelago: Archipelago {
+                islands,
+                iteration: 0,
+                direction: Direction::Minimize,
+            },
+            stop_checker,
+            loggers: vec![],
+            migration_interval,
+            migration_count,
+            generations_after_migration: 0,
+            migration_policy: MigrationPolicy::Ring,
+            random: StdRng::from_entropy(),
+            initialized: false,
+        }
+    }
+
+    /// Set the rule to select the target islands for the migrants. The default policy is
+    /// `MigrationPolicy::Ring`.
+    pub fn set_migration_policy(&mut self, migration_policy: MigrationPolicy) {
+        self.migration_policy = migration_policy;
+    }
+
+    /// Set seed for the random numbers generator of `MigrationPolicy::Random`. The islands use
+    /// their own random numbers generators.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<T> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<T> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Returns the state of all islands.
+    pub fn get_archipelago(&self) -> &Archipelago<'a, T> {
+        &self.archipelago
+    }
+
+    /// Returns the target island indexes for the migrants of the island `source`.
+    fn get_targets(&mut self, source: usize) -> Vec<usize> {
+        let islands_count = self.archipelago.islands.len();
+        match self.migration_policy {
+            MigrationPolicy::Ring => vec![(source + 1) % islands_count],
+            MigrationPolicy::Random => {
+                let between = Uniform::new(0, islands_count - 1);
+                let target = between.sample(&mut self.random);
+                if target >= source {
+                    vec![target + 1]
+                } else {
+                    vec![target]
+                }
+            }
+            MigrationPolicy::FullyConnected => {
+                (0..islands_count).filter(|&n| n != source).collect()
+            }
+        }
+    }
+
+    /// Move copies of the best individuals between islands.
+    fn migrate(&mut self) {
+        if self.migration_count == 0 {
+            return;
+        }
+
+        let islands_count = self.archipelago.islands.len();
+        let mut immigrants: Vec<Vec<Individual<T>>> = vec![vec![]; islands_count];
+
+        for source in 0..islands_count {
+            let mut emigrants: Vec<&Individual<T>> =
+                self.archipelago.islands[source].population.iter().collect();
+            emigrants.sort_by(|individual_1, individual_2| {
+                compare_values(individual_1.get_fitness(), individual_2.get_fitness())
+            });
+            emigrants.truncate(self.migration_count);
+            let emigrants: Vec<Individual<T>> = emigrants.into_iter().cloned().collect();
+
+            for target in self.get_targets(source) {
+                immigrants[target].extend(emigrants.iter().cloned());
+            }
+        }
+
+        for (island, island_immigrants) in self.archipelago.islands.iter_mut().zip(immigrants) {
+            let population = &mut island.population;
+
+            // The immigrants replace the worst individuals
+            population
+                .individuals
+                .sort_by(|individual_1, individual_2| {
+                    compare_values(individual_1.get_fitness(), individual_2.get_fitness())
+                });
+            let new_len = population.len().saturating_sub(island_immigrants.len());
+            population.individuals.truncate(new_len);
+
+            let iteration = population.get_iteration();
+            for mut individual in island_immigrants {
+                individual.birth_generation = iteration;
+                population.individuals.push(individual);
+            }
+
+            population.update_best_worst_individuals();
+        }
+    }
+
+    /// Create the first generation on every island.
+    fn init(&mut self, direction: Direction) {
+        self.archipelago.iteration = 0;
+        self.archipelago.direction = direction;
+        self.generations_after_migration = 0;
+        for island in &mut self.archipelago.islands {
+            island.init(direction);
+            island.population.update_best_worst_individuals();
+        }
+
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.archipelago);
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<T>> {
+        self.init(direction);
+        self.next_iterations()
+    }
+
+    /// Create the next generation on every island and migrate the individuals if needed.
+    fn do_iteration(&mut self) {
+        let islands = &mut self.archipelago.islands;
+        let children: Vec<Vec<T>> = islands
+            .iter_mut()
+            .map(|island| island.create_children())
+            .collect();
+        let goals = calc_goals(islands, &children);
+
+        for ((island, island_children), island_goals) in
+            islands.iter_mut().zip(children).zip(goals)
+        {
+            island.next_generation(island_children, island_goals);
+        }
+
+        self.archipelago.iteration += 1;
+        self.generations_after_migration += 1;
+        if self.generations_after_migration == self.migration_interval {
+            self.migrate();
+            self.generations_after_migration = 0;
+        }
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.archipelago);
+        }
+    }
+}
+
+impl<'a, T: Clone> IterativeOptimizer<T> for IslandGeneticOptimizer<'a, T> {
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&T, GoalValue)>),
+    ) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.archipelago.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.archipelago);
+        }
+
+        for island in &mut self.archipelago.islands {
+            for logger in &mut island.loggers {
+                logger.resume(&island.population);
+            }
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.archipelago) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.archipelago);
+        }
+
+        for island in &mut self.archipelago.islands {
+            for logger in &mut island.loggers {
+                logger.finish(&island.population);
+            }
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.archipelago);
+        }
+
+        self.archipelago.get_best_solution()
+    }
+
+    fn step(&mut self) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.archipelago.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.archipelago) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.archipelago.get_best_solution()
+    }
+}
+
+impl<'a, T: Clone> Optimizer<T> for IslandGeneticOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove all individuals on all islands and go to generation 0.
+    fn reset(&mut self) {
+        for island in &mut self.archipelago.islands {
+            island.reset();
+        }
+
+        self.archipelago.iteration = 0;
+        self.archipelago.direction = Direction::Minimize;
+        self.generations_after_migration = 0;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+/// Calculate the goal function for the new individuals of every island.
+#[cfg(not(feature = "parallel"))]
+fn calc_goals<T>(islands: &mut [GeneticOptimizer<T>], children: &[Vec<T>]) -> Vec<Vec<GoalValue>> {
+    islands
+        .iter_mut()
+        .zip(children)
+        .map(|(island, island_children)| island.population.evaluator.calc(island_children))
+        .collect()
+}
+
+/// Calculate the goal function for the new individuals of every island. The goal function
+/// of the islands with the parallel goal function is calculated in several threads at once.
+#[cfg(feature = "parallel")]
+fn calc_goals<T>(islands: &mut [GeneticOptimizer<T>], children: &[Vec<T>]) -> Vec<Vec<GoalValue>> {
+    let mut parallel_goals = {
+        let mut calc_function = None;
+        let mut tasks: Vec<(&dyn SyncGoal<T>, &T)> = vec![];
+        for (island, island_children) in islands.iter().zip(children) {
+            if let GoalEvaluator::Parallel(goal, function) = &island.population.evaluator {
+                let goal = goal.as_ref();
+                calc_function = Some(*function);
+                tasks.extend(island_children.iter().map(|chromosomes| (goal, chromosomes)));
+            }
+        }
+
+        match calc_function {
+            Some(function) => function(&tasks).into_iter(),
+            None => vec![].into_iter(),
+        }
+    };
+
+    islands
+        .iter_mut()
+        .zip(children)
+        .map(|(island, island_children)| match &mut island.population.evaluator {
+            GoalEvaluator::Parallel(..) => {
+                parallel_goals.by_ref().take(island_children.len()).collect()
+            }
+            evaluator => evaluator.calc(island_children),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::{creation, cross, mutation, pairing, selection};
+    use crate::tools::statistics::{Statistics, StatisticsLogger};
+    use crate::tools::stopchecker::MaxIterations;
+    use crate::GoalFromFunction;
+
+    fn sum_sqr(x: &Vec<f64>) -> f64 {
+        x.iter().map(|xi| xi * xi).sum()
+    }
+
+    fn create_island<'a>(population_size: usize) -> GeneticOptimizer<'a, Vec<f64>> {
+        let intervals = vec![(-10.0, 10.0); 2];
+        GeneticOptimizer::builder()
+            .goal(Box::new(GoalFromFunction::new(sum_sqr)))
+            .stop_checker(Box::new(MaxIterations::new(1)))
+            .creator(Box::new(creation::vec_float::RandomCreator::new(
+                population_size,
+                intervals,
+            )))
+            .pairing(Box::new(pairing::RandomPairing::new()))
+            .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(
+                cross::FloatCrossExp::new(),
+            ))))
+            .mutation(Box::new(mutation::VecMutation::new(
+                10.0,
+                Box::new(mutation::BitwiseMutation::new(2)),
+            )))
+            .selections(vec![Box::new(selection::LimitPopulation::new(
+                population_size,
+            ))])
+            .build()
+    }
+
+    fn create_optimizer<'a>(
+        islands_count: usize,
+        migration_count: usize,
+    ) -> IslandGeneticOptimizer<'a, Vec<f64>> {
+        let islands = (0..islands_count).map(|_| create_island(10)).collect();
+        IslandGeneticOptimizer::new(
+            islands,
+            Box::new(MaxIterations::new(10)),
+            5,
+            migration_count,
+        )
+    }
+
+    /// Replace the individuals on the island by the individuals with the chromosomes
+    /// `[value, 0.0]` and the fitness `value`.
+    fn set_population(island: &mut GeneticOptimizer<Vec<f64>>, values: &[f64]) {
+        island.population.individuals.clear();
+        for value in values {
+            island.population.push_with_goal(vec![*value, 0.0], *value);
+        }
+        island.population.update_best_worst_individuals();
+    }
+
+    fn get_fitness(island: &GeneticOptimizer<Vec<f64>>) -> Vec<f64> {
+        let mut fitness: Vec<f64> = island
+            .population
+            .iter()
+            .map(|individual| individual.get_fitness())
+            .collect();
+        fitness.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        fitness
+    }
+
+    #[test]
+    fn migrate_ring() {
+        let mut optimizer = create_optimizer(3, 2);
+        set_population(&mut optimizer.archipelago.islands[0], &[1.0, 2.0, 3.0, 4.0]);
+        set_population(
+            &mut optimizer.archipelago.islands[1],
+            &[10.0, 20.0, 30.0, 40.0],
+        );
+        set_population(
+            &mut optimizer.archipelago.islands[2],
+            &[100.0, 200.0, 300.0],
+        );
+
+        optimizer.migrate();
+
+        let islands = &optimizer.archipelago.islands;
+        assert_eq!(get_fitness(&islands[0]), vec![1.0, 2.0, 100.0, 200.0]);
+        assert_eq!(get_fitness(&islands[1]), vec![1.0, 2.0, 10.0, 20.0]);
+        assert_eq!(get_fitness(&islands[2]), vec![10.0, 20.0, 100.0]);
+        assert_eq!(islands[1].get_best_individual().unwrap().1, 1.0);
+    }
+
+    #[test]
+    fn migrate_fully_connected() {
+        let mut optimizer = create_optimizer(3, 1);
+        optimizer.set_migration_policy(MigrationPolicy::FullyConnected);
+        set_population(&mut optimizer.archipelago.islands[0], &[1.0, 2.0, 3.0, 4.0]);
+        set_population(
+            &mut optimizer.archipelago.islands[1],
+            &[10.0, 20.0, 30.0, 40.0],
+        );
+        set_population(
+            &mut optimizer.archipelago.islands[2],
+            &[100.0, 200.0, 300.0],
+        );
+
+        optimizer.migrate();
+
+        let islands = &optimizer.archipelago.islands;
+        assert_eq!(get_fitness(&islands[0]), vec![1.0, 2.0, 10.0, 100.0]);
+        assert_eq!(get_fitness(&islands[1]), vec![1.0, 10.0, 20.0, 100.0]);
+        assert_eq!(get_fitness(&islands[2]), vec![1.0, 10.0, 100.0]);
+    }
+
+    #[test]
+    fn migrate_nan() {
+        let mut optimizer = create_optimizer(2, 1);
+        set_population(&mut optimizer.archipelago.islands[0], &[f64::NAN, 2.0, 3.0]);
+        set_population(
+            &mut optimizer.archipelago.islands[1],
+            &[10.0, f64::NAN, 30.0],
+        );
+
+        optimizer.migrate();
+
+        let islands = &optimizer.archipelago.islands;
+        assert_eq!(get_fitness(&islands[0]), vec![2.0, 3.0, 10.0]);
+        assert_eq!(get_fitness(&islands[1]), vec![2.0, 10.0, 30.0]);
+    }
+
+    #[test]
+    fn migrate_zero_count() {
+        let mut optimizer = create_optimizer(2, 0);
+        set_population(&mut optimizer.archipelago.islands[0], &[1.0, 2.0]);
+        set_population(&mut optimizer.archipelago.islands[1], &[10.0, 20.0]);
+
+        optimizer.migrate();
+
+        let islands = &optimizer.archipelago.islands;
+        assert_eq!(get_fitness(&islands[0]), vec![1.0, 2.0]);
+        assert_eq!(get_fitness(&islands[1]), vec![10.0, 20.0]);
+    }
+
+    #[test]
+    fn random_targets() {
+        let mut optimizer = create_optimizer(4, 1);
+        optimizer.set_migration_policy(MigrationPolicy::Random);
+        optimizer.set_seed(1);
+
+        let mut counts = vec![0; 4];
+        for _ in 0..1000 {
+            let targets = optimizer.get_targets(2);
+            assert_eq!(targets.len(), 1);
+            assert_ne!(targets[0], 2);
+            counts[targets[0]] += 1;
+        }
+
+        assert_eq!(counts[2], 0);
+        assert!(counts[0] > 250);
+        assert!(counts[1] > 250);
+        assert!(counts[3] > 250);
+    }
+
+    #[test]
+    fn best_solution() {
+        let mut optimizer = create_optimizer(3, 1);
+        let (_, goal_value) = optimizer.find_min().unwrap();
+
+        assert_eq!(optimizer.get_archipelago().get_iteration(), 10);
+        assert_eq!(
+            optimizer.get_archipelago().get_agents_parameters().len(),
+            30
+        );
+
+        let best_island_value = optimizer
+            .get_archipelago()
+            .get_islands()
+            .iter()
+            .map(|island| island.get_best_individual().unwrap().1)
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(goal_value, best_island_value);
+    }
+
+    #[test]
+    fn find_max() {
+        let mut optimizer = create_optimizer(2, 1);
+        let (_, goal_value) = optimizer.find_max().unwrap();
+
+        for island in optimizer.get_archipelago().get_islands() {
+            assert!(island.get_best_individual().unwrap().1 <= goal_value);
+        }
+    }
+
+    #[test]
+    fn step() {
+        let mut optimizer = create_optimizer(2, 1);
+        let mut steps = 0;
+        while optimizer.step().is_some() {
+            steps += 1;
+        }
+
+        assert_eq!(steps, 10);
+        for island in optimizer.get_archipelago().get_islands() {
+            assert_eq!(island.population.get_iteration(), 10);
+        }
+    }
+
+    #[test]
+    fn island_loggers() {
+        let mut statistics = vec![Statistics::new(), Statistics::new()];
+        {
+            let islands = statistics
+                .iter_mut()
+                .map(|island_statistics| {
+                    let mut island = create_island(10);
+                    island.set_loggers(vec![Box::new(StatisticsLogger::new(island_statistics))]);
+                    island
+                })
+                .collect();
+            let mut optimizer =
+                IslandGeneticOptimizer::new(islands, Box::new(MaxIterations::new(10)), 5, 1);
+            optimizer.find_min();
+        }
+
+        // The loggers of the islands are finished with the optimizer
+        for island_statistics in &statistics {
+            assert_eq!(island_statistics.get_results().len(), 1);
+            assert!(island_statistics.get_results()[0].is_some());
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn calc_goals_parallel() {
+        let mut optimizer = create_optimizer(3, 1);
+        for (n, island) in optimizer.archipelago.islands.iter_mut().take(2).enumerate() {
+            let factor = (n + 1) as f64;
+            island.population.evaluator = GoalEvaluator::new_parallel(Box::new(
+                GoalFromFunction::new(move |x: &Vec<f64>| factor * sum_sqr(x)),
+            ));
+        }
+
+        let children = vec![
+            vec![vec![1.0, 0.0], vec![2.0, 0.0]],
+            vec![vec![3.0, 0.0]],
+            vec![vec![4.0, 0.0], vec![5.0, 0.0]],
+        ];
+        let goals = calc_goals(&mut optimizer.archipelago.islands, &children);
+        assert_eq!(goals, vec![vec![1.0, 4.0], vec![18.0], vec![16.0, 25.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_island() {
+        create_optimizer(1, 1);
+    }
+}
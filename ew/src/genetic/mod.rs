@@ -10,33 +10,105 @@
 
 pub mod creation;
 pub mod cross;
+pub mod island;
 pub mod mutation;
 pub mod pairing;
+pub mod population_size;
 pub mod pre_birth;
 pub mod selection;
 
 use std::cmp::Ordering;
 use std::f64;
+#[cfg(feature = "checkpoint")]
+use std::io;
 use std::ops;
+#[cfg(feature = "checkpoint")]
+use std::path::Path;
 use std::slice;
 
 use crate::tools::logging::Logger;
 use crate::tools::stopchecker::StopChecker;
-use crate::{Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Optimizer, Solution};
+use crate::{
+    call_iteration_callback, Agent, AgentsState, AlgorithmState, Direction, Goal, GoalValue,
+    IterativeOptimizer, NotSet, Optimizer, Solution,
+};
+
+#[cfg(feature = "parallel")]
+use crate::{calc_parallel, ParallelCalcFunction, SyncGoal};
+
+#[cfg(feature = "checkpoint")]
+use crate::tools::checkpoint;
+
+type GoalBox<'a, T> = Box<dyn Goal<T> + 'a>;
+type StopCheckerBox<'a, T> = Box<dyn StopChecker<T> + 'a>;
+type CreatorBox<'a, T> = Box<dyn Creator<T> + 'a>;
+type PairingBox<'a, T> = Box<dyn Pairing<T> + 'a>;
+type CrossBox<'a, T> = Box<dyn Cross<T> + 'a>;
+type MutationBox<'a, T> = Box<dyn Mutation<T> + 'a>;
+
+#[cfg(feature = "parallel")]
+type SyncGoalBox<'a, T> = Box<dyn SyncGoal<T> + 'a>;
+
+/// The goal function of the optimizer.
+enum GoalEvaluator<'a, T> {
+    /// The goal function is calculated for the chromosomes one by one.
+    Sequential(GoalBox<'a, T>),
+
+    /// The goal function is calculated for the chromosomes in several threads.
+    #[cfg(feature = "parallel")]
+    Parallel(SyncGoalBox<'a, T>, ParallelCalcFunction<T>),
+}
+
+impl<'a, T> GoalEvaluator<'a, T> {
+    #[cfg(feature = "parallel")]
+    fn new_parallel(goal: SyncGoalBox<'a, T>) -> Self
+    where
+        T: Send + Sync + 'a,
+    {
+        GoalEvaluator::Parallel(goal, calc_parallel::<T>)
+    }
+
+    /// Calculate goal function for all items in `chromosomes_list`.
+    fn calc(&mut self, chromosomes_list: &[T]) -> Vec<GoalValue> {
+        match self {
+            GoalEvaluator::Sequential(goal) => chromosomes_list
+                .iter()
+                .map(|chromosomes| goal.get(chromosomes))
+                .collect(),
+            #[cfg(feature = "parallel")]
+            GoalEvaluator::Parallel(goal, calc_function) => {
+                let goal = goal.as_ref();
+                let tasks: Vec<(&dyn SyncGoal<T>, &T)> = chromosomes_list
+                    .iter()
+                    .map(|chromosomes| (goal, chromosomes))
+                    .collect();
+                calc_function(&tasks)
+            }
+        }
+    }
+}
 
 /// Struct for single point (agent) in the search space
 ///
 /// `T` - type of a point in the search space for goal function (chromosomes).
 #[derive(Debug)]
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct Individual<T> {
     /// Point in the search space.
     chromosomes: T,
 
     /// Value of goal function for the point in the search space.
+    #[cfg_attr(feature = "checkpoint", serde(with = "crate::tools::checkpoint::goal_value"))]
     fitness: f64,
 
     /// True if individual will pass to text generation.
     alive: bool,
+
+    /// True if individual can't be killed in the current generation.
+    protected: bool,
+
+    /// Generation number when the individual was created.
+    birth_generation: usize,
 }
 
 impl<T: Clone> Clone for Individual<T> {
@@ -45,6 +117,8 @@ impl<T: Clone> Clone for Individual<T> {
             chromosomes: self.chromosomes.clone(),
             fitness: self.fitness,
             alive: self.alive,
+            protected: self.protected,
+            birth_generation: self.birth_generation,
         }
     }
 }
@@ -76,8 +150,29 @@ impl<T> Individual<T> {
     }
 
     /// Kill individual. The individual do not go into next generation.
+    /// The method does nothing if the individual is protected.
     pub fn kill(&mut self) {
-        self.alive = false;
+        if !self.protected {
+            self.alive = false;
+        }
+    }
+
+    /// Returns the generation number when the individual was created. The individuals of the first
+    /// generation have the birth generation 0, the children created from the parents of
+    /// the generation N have the birth generation N.
+    pub fn get_birth_generation(&self) -> usize {
+        self.birth_generation
+    }
+
+    /// Returns true if the individual can't be killed in the current generation.
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    /// Protect the individual from killing in the current generation (for example, by elitism).
+    /// The protection is removed after the selection.
+    pub fn protect(&mut self) {
+        self.protected = true;
     }
 }
 
@@ -85,8 +180,8 @@ impl<T> Individual<T> {
 ///
 /// `T` - type of a point in the search space for goal function (chromosomes).
 pub struct Population<'a, T> {
-    // Trait object for goal function.
-    goal: Box<dyn Goal<T> + 'a>,
+    // The goal function which is calculated sequentially or in several threads.
+    evaluator: GoalEvaluator<'a, T>,
 
     individuals: Vec<Individual<T>>,
 
@@ -98,6 +193,20 @@ pub struct Population<'a, T> {
 
     // Generation number.
     iteration: usize,
+
+    // Direction of the optimization. The fitness of individuals is stored for minimization.
+    direction: Direction,
+}
+
+/// The state of `Population` which is saved to the checkpoint.
+#[cfg(feature = "checkpoint")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PopulationCheckpoint<T> {
+    individuals: Vec<Individual<T>>,
+    best_individual: Option<Individual<T>>,
+    worst_individual: Option<Individual<T>>,
+    iteration: usize,
+    direction: Direction,
 }
 
 impl<'a, T: Clone> Population<'a, T> {
@@ -144,12 +253,18 @@ impl<'a, T> Population<'a, T> {
     /// # Parameters
     /// * `goal` - trait object for goal function
     fn new(goal: Box<dyn Goal<T> + 'a>) -> Self {
+        Self::with_evaluator(GoalEvaluator::Sequential(goal))
+    }
+
+    /// Create new `Population` struct with the sequential or parallel goal function.
+    fn with_evaluator(evaluator: GoalEvaluator<'a, T>) -> Self {
         Population {
-            goal,
+            evaluator,
             individuals: vec![],
             best_individual: None,
             worst_individual: None,
             iteration: 0,
+            direction: Direction::Minimize,
         }
     }
 
@@ -161,13 +276,15 @@ impl<'a, T> Population<'a, T> {
         self.iteration = 0;
     }
 
-    /// Create new `Individual` struct with `chromosomes` and add it to population.
-    fn push(&mut self, chromosomes: T) {
-        let fitness = self.goal.get(&chromosomes);
+    /// Create new `Individual` struct with `chromosomes` and calculated value of goal function
+    /// and add it to population.
+    fn push_with_goal(&mut self, chromosomes: T, goal: GoalValue) {
         let new_individual = Individual {
             chromosomes,
-            fitness,
+            fitness: self.direction.apply(goal),
             alive: true,
+            protected: false,
+            birth_generation: self.iteration,
         };
 
         self.individuals.push(new_individual);
@@ -176,8 +293,16 @@ impl<'a, T> Population<'a, T> {
     /// Create new individuals (`Individual` struct) for all items in `chromosomes_list` and add
     /// them to population.
     fn append(&mut self, chromosomes_list: Vec<T>) {
-        for chromosome in chromosomes_list {
-            self.push(chromosome);
+        let goals = self.evaluator.calc(&chromosomes_list);
+        self.append_with_goals(chromosomes_list, goals);
+    }
+
+    /// Create new individuals for all items in `chromosomes_list` with the calculated values
+    /// of the goal function and add them to population.
+    fn append_with_goals(&mut self, chromosomes_list: Vec<T>, goals: Vec<GoalValue>) {
+        assert_eq!(chromosomes_list.len(), goals.len());
+        for (chromosome, goal) in chromosomes_list.into_iter().zip(goals) {
+            self.push_with_goal(chromosome, goal);
         }
     }
 
@@ -271,6 +396,13 @@ impl<'a, T> Population<'a, T> {
     fn remove_dead(&mut self) {
         self.individuals.retain(|individual| individual.is_alive());
     }
+
+    /// Remove protection from all individuals.
+    fn unprotect_all(&mut self) {
+        for individual in &mut self.individuals {
+            individual.protected = false;
+        }
+    }
 }
 
 /// Index trait implementation for Population
@@ -293,13 +425,27 @@ impl<'a, T: Clone> AlgorithmState<T> for Population<'a, T> {
     fn get_best_solution(&self) -> Option<(T, f64)> {
         match &self.best_individual {
             None => None,
-            Some(individual) => Some((individual.chromosomes.clone(), individual.fitness)),
+            Some(individual) => Some((
+                individual.chromosomes.clone(),
+                self.direction.apply(individual.fitness),
+            )),
         }
     }
 
     fn get_iteration(&self) -> usize {
         self.iteration
     }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&T> {
+        self.individuals
+            .iter()
+            .map(|individual| individual.get_chromosomes())
+            .collect()
+    }
 }
 
 /// The trait to create initial individuals for population.
@@ -308,6 +454,33 @@ impl<'a, T: Clone> AlgorithmState<T> for Population<'a, T> {
 pub trait Creator<T> {
     /// Must return vector of the chromosomes of a new individuals for population
     fn create(&mut self) -> Vec<T>;
+
+    /// Must return vector of the chromosomes of a new individuals for population with
+    /// the values of the goal function if they were calculated. The creator may calculate
+    /// the goal function to select the chromosomes. The `goal` must be minimized (the optimizer
+    /// negates the goal function values for `find_max`). The optimizer calculates the goal
+    /// function only for the chromosomes without the values.
+    /// The optimizer calls this method. By default it calls `create`.
+    fn create_with_goal(&mut self, _goal: &mut dyn Goal<T>) -> Vec<(T, Option<GoalValue>)> {
+        self.create()
+            .into_iter()
+            .map(|chromosomes| (chromosomes, None))
+            .collect()
+    }
+}
+
+/// The goal function wrapper which calculates the goal function of the population
+/// and converts the goal function values for minimization.
+struct DirectedGoal<'a, 'b, T> {
+    evaluator: &'b mut GoalEvaluator<'a, T>,
+    direction: Direction,
+}
+
+impl<'a, 'b, T> Goal<T> for DirectedGoal<'a, 'b, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let values = self.evaluator.calc(slice::from_ref(x));
+        self.direction.apply(values[0])
+    }
 }
 
 /// The trait with cross algorithm.
@@ -328,6 +501,20 @@ pub trait Mutation<T> {
     /// chromosomes (possibly modified). New individuals will be created with the chromosomes after
     /// mutation.
     fn mutation(&mut self, chromosomes: &T) -> T;
+
+    /// The method is called by the mutations of the vector chromosomes (for example,
+    /// `VecMutation`) for the single gene with index `gene_index`. The mutation may use
+    /// the index to get settings for the gene (the search interval, for example).
+    /// Calls `mutation` by default.
+    fn mutation_at(&mut self, gene: &T, _gene_index: usize) -> T {
+        self.mutation(gene)
+    }
+
+    /// The method is called by the optimizer before the mutation of the children in every
+    /// generation. `generation` - number of the current (parents) generation, the first
+    /// generation has number 0. The mutation may use the value to change mutation intensity
+    /// during the algorithm running. Does nothing by default.
+    fn set_generation(&mut self, _generation: usize) {}
 }
 
 /// The trait may be used after mutation but before birth of the individuals.
@@ -356,6 +543,21 @@ pub trait Pairing<T> {
     fn get_pairs(&mut self, population: &Population<T>) -> Vec<Vec<usize>>;
 }
 
+/// The trait to change the population size during the algorithm running.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub trait PopulationSizeController<T> {
+    /// The method is called by the optimizer after the selection in every generation and must
+    /// return the population size for the next generation. The optimizer kills the worst
+    /// individuals if the population is greater than the returned size and adds new individuals
+    /// created by `Creator` if the population is less than the returned size.
+    fn desired_size(&mut self, current_population: &[Individual<T>]) -> usize;
+
+    /// The method is called by the optimizer before the first generation of every run.
+    /// Does nothing by default.
+    fn reset(&mut self) {}
+}
+
 /// The main struct for an user. `GeneticOptimizer` implements `Optimizer` trait and keep all parts
 /// of genetic algorithm as trait objects: `Creator`, `Pairing`, `Cross`, `Mutation`, `Selection`,
 /// `StopChecker` and, if needed, `Logger`.
@@ -371,10 +573,20 @@ pub struct GeneticOptimizer<'a, T> {
     selections: Vec<Box<dyn Selection<T> + 'a>>,
     pre_births: Vec<Box<dyn PreBirth<T> + 'a>>,
     loggers: Vec<Box<dyn Logger<T> + 'a>>,
+    population_size_controller: Option<Box<dyn PopulationSizeController<T> + 'a>>,
     population: Population<'a, T>,
+
+    /// True if the first generation was created.
+    initialized: bool,
 }
 
 impl<'a, T: Clone> GeneticOptimizer<'a, T> {
+    /// Create the builder for the optimizer.
+    pub fn builder() -> GeneticOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet, NotSet>
+    {
+        GeneticOptimizerBuilder::new()
+    }
+
     /// Create a new `GeneticOptimizer`.
     pub fn new(
         goal: Box<dyn Goal<T> + 'a>,
@@ -395,7 +607,9 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
             selections,
             pre_births,
             loggers: vec![],
+            population_size_controller: None,
             population: Population::new(goal),
+            initialized: false,
         }
     }
 
@@ -404,35 +618,65 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
     }
 
     /// Replace the trait object of pairing algorithm.
-    pub fn set_pairing(&mut self, pairing: Box<dyn Pairing<T>>) {
+    pub fn set_pairing(&mut self, pairing: Box<dyn Pairing<T> + 'a>) {
         self.pairing = pairing;
     }
 
     /// Replace the trait object of cross algorithm.
-    pub fn set_cross(&mut self, cross: Box<dyn Cross<T>>) {
+    pub fn set_cross(&mut self, cross: Box<dyn Cross<T> + 'a>) {
         self.cross = cross;
     }
 
     /// Replace the trait object of mutation algorithm.
-    pub fn set_mutation(&mut self, mutation: Box<dyn Mutation<T>>) {
+    pub fn set_mutation(&mut self, mutation: Box<dyn Mutation<T> + 'a>) {
         self.mutation = mutation;
     }
 
     /// Replace the trait object of selection algorithm.
-    pub fn set_selection(&mut self, selections: Vec<Box<dyn Selection<T>>>) {
+    pub fn set_selection(&mut self, selections: Vec<Box<dyn Selection<T> + 'a>>) {
         self.selections = selections;
     }
 
     /// Replace the trait object of selection algorithm.
-    pub fn set_pre_birth(&mut self, pre_births: Vec<Box<dyn PreBirth<T>>>) {
+    pub fn set_pre_birth(&mut self, pre_births: Vec<Box<dyn PreBirth<T> + 'a>>) {
         self.pre_births = pre_births;
     }
 
     /// Replace the trait object of stop checker algorithm.
-    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<T>>) {
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<T> + 'a>) {
         self.stop_checker = stop_checker;
     }
 
+    /// Set the controller which changes the population size during the algorithm running.
+    /// The population size is not changed by the optimizer if the controller is not set.
+    /// The selections should not limit the population size (`selection::LimitPopulation`)
+    /// in this case.
+    pub fn set_population_size_controller(
+        &mut self,
+        controller: Box<dyn PopulationSizeController<T> + 'a>,
+    ) {
+        self.population_size_controller = Some(controller);
+    }
+
+    /// Returns chromosomes and value of the goal function for the best individual
+    /// in the current generation or `None` if the best individual is not known yet.
+    pub fn get_best_individual(&self) -> Option<(&T, f64)> {
+        self.population.best_individual.as_ref().map(|individual| {
+            (
+                &individual.chromosomes,
+                self.population.direction.apply(individual.fitness),
+            )
+        })
+    }
+
+    /// Returns all individuals of the current generation.
+    ///
+    /// The fitness of individuals is the value which the algorithm minimizes,
+    /// so the fitness is the negated value of the goal function after `find_max`.
+    pub fn get_current_population(&self) -> &[Individual<T>] {
+        &self.population.individuals
+    }
+
     fn run_pairing(&mut self) -> Vec<T> {
         let pairs: Vec<Vec<usize>> = self.pairing.get_pairs(&self.population);
         let mut new_chromosomes: Vec<T> = Vec::with_capacity(pairs.len());
@@ -449,73 +693,509 @@ impl<'a, T: Clone> GeneticOptimizer<'a, T> {
 
         new_chromosomes
     }
-}
 
-impl<'a, T: Clone> IterativeOptimizer<T> for GeneticOptimizer<'a, T> {
-    /// Do new iterations of genetic algorithm.
-    fn next_iterations(&mut self) -> Option<Solution<T>> {
-        for logger in &mut self.loggers {
-            logger.resume(&self.population);
-        }
+    /// Change the population size to the size returned by the population size controller.
+    fn resize_population(&mut self) {
+        let desired_size = match &mut self.population_size_controller {
+            Some(controller) => controller.desired_size(&self.population.individuals),
+            None => return,
+        };
 
-        while !self.stop_checker.can_stop(&self.population) {
-            // Pairing
-            let mut children_chromo_list = self.run_pairing();
-
-            // Mutation
-            let mut children_mutants: Vec<T> = children_chromo_list
-                .iter_mut()
-                .map(|chromo| self.mutation.mutation(chromo))
-                .collect();
-
-            // May be change new chromosomes vector before birth
-            for pre_birth in &mut self.pre_births {
-                pre_birth.pre_birth(&self.population, &mut children_mutants);
+        let current_size = self.population.len();
+        if desired_size < current_size {
+            selection::kill_worst(&mut self.population, current_size - desired_size);
+            self.population.remove_dead();
+        } else if desired_size > current_size {
+            let count = desired_size - current_size;
+            let mut new_chromosomes: Vec<T> = Vec::with_capacity(count);
+            while new_chromosomes.len() < count {
+                let mut chromosomes = self.creator.create();
+                if chromosomes.is_empty() {
+                    break;
+                }
+
+                chromosomes.truncate(count - new_chromosomes.len());
+                new_chromosomes.append(&mut chromosomes);
             }
 
-            // Create new individuals by new chromosomes and add new individuals to population
-            self.population.append(children_mutants);
+            self.population.append(new_chromosomes);
+        }
+    }
 
-            // Selection
-            for selection in &mut self.selections {
-                selection.kill(&mut self.population);
+    /// Create the first generation.
+    fn init(&mut self, direction: Direction) {
+        self.population.reset();
+        self.population.direction = direction;
+        let mut goal = DirectedGoal {
+            evaluator: &mut self.population.evaluator,
+            direction,
+        };
+        let start_chromo_list = self.creator.create_with_goal(&mut goal);
+
+        // Create individuals from chromosomes. The goal function is calculated only for
+        // the chromosomes without the values.
+        let mut new_chromo_list = Vec::with_capacity(start_chromo_list.len());
+        for (chromosomes, value) in start_chromo_list {
+            match value {
+                Some(value) => self.population.push_with_goal(chromosomes, direction.apply(value)),
+                None => new_chromo_list.push(chromosomes),
             }
+        }
+        self.population.append(new_chromo_list);
+        self.stop_checker.reset();
+        if let Some(controller) = &mut self.population_size_controller {
+            controller.reset();
+        }
+        self.initialized = true;
 
-            self.population.remove_dead();
+        for logger in &mut self.loggers {
+            logger.start(&self.population);
+        }
+    }
 
-            self.population.update_best_worst_individuals();
+    /// Create the first generation and run genetic algorithm.
+    fn run(&mut self, direction: Direction) -> Option<Solution<T>> {
+        self.init(direction);
+        self.next_iterations()
+    }
 
-            self.population.next_iteration();
+    /// Create the next generation.
+    fn do_iteration(&mut self) {
+        let children = self.create_children();
+        let goals = self.population.evaluator.calc(&children);
+        self.next_generation(children, goals);
+    }
 
-            for logger in &mut self.loggers {
-                logger.next_iteration(&self.population);
-            }
+    /// Create the chromosomes of the new individuals (pairing, mutation and pre birth).
+    fn create_children(&mut self) -> Vec<T> {
+        // Pairing
+        let mut children_chromo_list = self.run_pairing();
+
+        // Mutation
+        self.mutation.set_generation(self.population.get_iteration());
+        let mut children_mutants: Vec<T> = children_chromo_list
+            .iter_mut()
+            .map(|chromo| self.mutation.mutation(chromo))
+            .collect();
+
+        // May be change new chromosomes vector before birth
+        for pre_birth in &mut self.pre_births {
+            pre_birth.pre_birth(&self.population, &mut children_mutants);
+        }
+
+        children_mutants
+    }
+
+    /// Add the new individuals with the calculated values of the goal function (`goals`)
+    /// to population and select the individuals for the next generation.
+    fn next_generation(&mut self, children: Vec<T>, goals: Vec<GoalValue>) {
+        // Create new individuals by new chromosomes and add new individuals to population
+        self.population.append_with_goals(children, goals);
+
+        // Selection
+        for selection in &mut self.selections {
+            selection.kill(&mut self.population);
+        }
+
+        self.population.remove_dead();
+        self.resize_population();
+        self.population.unprotect_all();
+
+        self.population.update_best_worst_individuals();
+
+        self.population.next_iteration();
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.population);
+        }
+    }
+}
+
+#[cfg(feature = "checkpoint")]
+impl<'a, T> GeneticOptimizer<'a, T>
+where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Save the state of the algorithm to the JSON file: the individuals of the current
+    /// generation, the best and the worst individuals, the generation number and
+    /// the direction of the optimization.
+    ///
+    /// The method is available with the "checkpoint" feature.
+    pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let state = PopulationCheckpoint {
+            individuals: self.population.individuals.clone(),
+            best_individual: self.population.best_individual.clone(),
+            worst_individual: self.population.worst_individual.clone(),
+            iteration: self.population.iteration,
+            direction: self.population.direction,
+        };
+
+        checkpoint::save(path, &state)
+    }
+
+    /// Replace the state of the algorithm with the state saved by `save_checkpoint`.
+    /// The optimization may be continued by `IterativeOptimizer::next_iterations` or
+    /// `IterativeOptimizer::step` after the loading.
+    ///
+    /// The goal function, the stop checker and the other parts of the algorithm are not saved
+    /// in the checkpoint, so the optimizer must be created with the same parts as the saved one.
+    /// Internal state of the parts (for example, random generators or the population size
+    /// controller) is not restored.
+    ///
+    /// The method is available with the "checkpoint" feature.
+    pub fn load_checkpoint<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let state: PopulationCheckpoint<T> = checkpoint::load(path)?;
+        if state.individuals.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the checkpoint does not contain individuals",
+            ));
+        }
+
+        self.population.individuals = state.individuals;
+        self.population.best_individual = state.best_individual;
+        self.population.worst_individual = state.worst_individual;
+        self.population.iteration = state.iteration;
+        self.population.direction = state.direction;
+        self.initialized = true;
+        Ok(())
+    }
+}
+
+/// The builder for `GeneticOptimizer`.
+///
+/// The `build()` method is available only after the goal function, the stop checker,
+/// the creator, the pairing, the cross and the mutation are set.
+/// Selections, pre births, loggers and the population size controller are optional.
+///
+/// ```compile_fail
+/// use ew::genetic::GeneticOptimizer;
+/// use ew::tools::stopchecker;
+///
+/// // The goal function and other required components are not set.
+/// let optimizer = GeneticOptimizer::<Vec<f32>>::builder()
+///     .stop_checker(Box::new(stopchecker::MaxIterations::new(10)))
+///     .build();
+/// ```
+pub struct GeneticOptimizerBuilder<'a, T, G, S, C, P, X, M> {
+    goal: G,
+    stop_checker: S,
+    creator: C,
+    pairing: P,
+    cross: X,
+    mutation: M,
+    selections: Vec<Box<dyn Selection<T> + 'a>>,
+    pre_births: Vec<Box<dyn PreBirth<T> + 'a>>,
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+    population_size_controller: Option<Box<dyn PopulationSizeController<T> + 'a>>,
+}
+
+impl<'a, T> GeneticOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet, NotSet> {
+    /// Constructor. All required components are not set.
+    pub fn new() -> Self {
+        GeneticOptimizerBuilder {
+            goal: NotSet,
+            stop_checker: NotSet,
+            creator: NotSet,
+            pairing: NotSet,
+            cross: NotSet,
+            mutation: NotSet,
+            selections: vec![],
+            pre_births: vec![],
+            loggers: vec![],
+            population_size_controller: None,
+        }
+    }
+}
+
+impl<'a, T> Default
+    for GeneticOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet, NotSet>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T, G, S, C, P, X, M> GeneticOptimizerBuilder<'a, T, G, S, C, P, X, M> {
+    /// Set the goal function which is calculated for the individuals one by one.
+    pub fn goal(
+        self,
+        goal: GoalBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, GoalBox<'a, T>, S, C, P, X, M> {
+        self.set_goal(goal)
+    }
+
+    /// Set the goal function which is calculated for the individuals in several threads.
+    /// The method is an alternative to `goal`: the optimizer has single goal function which is
+    /// used for all calculations, including the creation of the first generation.
+    /// Use `GoalCalcStatisticsArc` to count the goal function calls.
+    ///
+    /// The method is available with the "parallel" feature.
+    #[cfg(feature = "parallel")]
+    pub fn parallel_goal(
+        self,
+        goal: SyncGoalBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, SyncGoalBox<'a, T>, S, C, P, X, M> {
+        self.set_goal(goal)
+    }
+
+    fn set_goal<NG>(self, goal: NG) -> GeneticOptimizerBuilder<'a, T, NG, S, C, P, X, M> {
+        GeneticOptimizerBuilder {
+            goal,
+            stop_checker: self.stop_checker,
+            creator: self.creator,
+            pairing: self.pairing,
+            cross: self.cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn stop_checker(
+        self,
+        stop_checker: StopCheckerBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, G, StopCheckerBox<'a, T>, C, P, X, M> {
+        GeneticOptimizerBuilder {
+            goal: self.goal,
+            stop_checker,
+            creator: self.creator,
+            pairing: self.pairing,
+            cross: self.cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn creator(
+        self,
+        creator: CreatorBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, G, S, CreatorBox<'a, T>, P, X, M> {
+        GeneticOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            creator,
+            pairing: self.pairing,
+            cross: self.cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn pairing(
+        self,
+        pairing: PairingBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, G, S, C, PairingBox<'a, T>, X, M> {
+        GeneticOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            creator: self.creator,
+            pairing,
+            cross: self.cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn cross(
+        self,
+        cross: CrossBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, G, S, C, P, CrossBox<'a, T>, M> {
+        GeneticOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            creator: self.creator,
+            pairing: self.pairing,
+            cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn mutation(
+        self,
+        mutation: MutationBox<'a, T>,
+    ) -> GeneticOptimizerBuilder<'a, T, G, S, C, P, X, MutationBox<'a, T>> {
+        GeneticOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            creator: self.creator,
+            pairing: self.pairing,
+            cross: self.cross,
+            mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+        }
+    }
+
+    pub fn selections(mut self, selections: Vec<Box<dyn Selection<T> + 'a>>) -> Self {
+        self.selections = selections;
+        self
+    }
+
+    pub fn pre_births(mut self, pre_births: Vec<Box<dyn PreBirth<T> + 'a>>) -> Self {
+        self.pre_births = pre_births;
+        self
+    }
+
+    pub fn loggers(mut self, loggers: Vec<Box<dyn Logger<T> + 'a>>) -> Self {
+        self.loggers = loggers;
+        self
+    }
+
+    pub fn population_size_controller(
+        mut self,
+        controller: Box<dyn PopulationSizeController<T> + 'a>,
+    ) -> Self {
+        self.population_size_controller = Some(controller);
+        self
+    }
+}
+
+impl<'a, T: Clone>
+    GeneticOptimizerBuilder<
+        'a,
+        T,
+        GoalBox<'a, T>,
+        StopCheckerBox<'a, T>,
+        CreatorBox<'a, T>,
+        PairingBox<'a, T>,
+        CrossBox<'a, T>,
+        MutationBox<'a, T>,
+    >
+{
+    /// Create the optimizer from the components.
+    pub fn build(self) -> GeneticOptimizer<'a, T> {
+        self.build_with_evaluator(GoalEvaluator::Sequential)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, T: Clone + Send + Sync + 'a>
+    GeneticOptimizerBuilder<
+        'a,
+        T,
+        SyncGoalBox<'a, T>,
+        StopCheckerBox<'a, T>,
+        CreatorBox<'a, T>,
+        PairingBox<'a, T>,
+        CrossBox<'a, T>,
+        MutationBox<'a, T>,
+    >
+{
+    /// Create the optimizer which calculates the goal function in several threads.
+    pub fn build(self) -> GeneticOptimizer<'a, T> {
+        self.build_with_evaluator(GoalEvaluator::new_parallel)
+    }
+}
+
+impl<'a, T, G>
+    GeneticOptimizerBuilder<
+        'a,
+        T,
+        G,
+        StopCheckerBox<'a, T>,
+        CreatorBox<'a, T>,
+        PairingBox<'a, T>,
+        CrossBox<'a, T>,
+        MutationBox<'a, T>,
+    >
+{
+    fn build_with_evaluator<F>(self, create_evaluator: F) -> GeneticOptimizer<'a, T>
+    where
+        F: FnOnce(G) -> GoalEvaluator<'a, T>,
+    {
+        GeneticOptimizer {
+            stop_checker: self.stop_checker,
+            creator: self.creator,
+            pairing: self.pairing,
+            cross: self.cross,
+            mutation: self.mutation,
+            selections: self.selections,
+            pre_births: self.pre_births,
+            loggers: self.loggers,
+            population_size_controller: self.population_size_controller,
+            population: Population::with_evaluator(create_evaluator(self.goal)),
+            initialized: false,
+        }
+    }
+}
+
+impl<'a, T: Clone> IterativeOptimizer<T> for GeneticOptimizer<'a, T> {
+    /// Do new iterations of genetic algorithm.
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&T, GoalValue)>),
+    ) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.population.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.population);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.population) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.population);
         }
 
         for logger in &mut self.loggers {
             logger.finish(&self.population);
         }
 
-        match &self.population.best_individual {
-            None => None,
-            Some(individual) => Some((individual.chromosomes.clone(), individual.fitness)),
+        self.population.get_best_solution()
+    }
+
+    /// Create single new generation.
+    fn step(&mut self) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.population.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.population) {
+            return None;
         }
+
+        self.do_iteration();
+        self.population.get_best_solution()
     }
 }
 
 impl<'a, T: Clone> Optimizer<T> for GeneticOptimizer<'a, T> {
     /// Run genetic algorithm
     fn find_min(&mut self) -> Option<(T, f64)> {
-        self.population.reset();
-        let start_chromo_list = self.creator.create();
-
-        // Create individuals from chromosomes
-        self.population.append(start_chromo_list);
+        self.run(Direction::Minimize)
+    }
 
-        for logger in &mut self.loggers {
-            logger.start(&self.population);
-        }
+    /// Run genetic algorithm to search maximum
+    fn find_max(&mut self) -> Option<(T, f64)> {
+        self.run(Direction::Maximize)
+    }
 
-        self.next_iterations()
+    /// Remove all individuals and go to generation 0.
+    fn reset(&mut self) {
+        self.population.reset();
+        self.population.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
     }
 }
@@ -3,21 +3,25 @@
 //! chromosomes various types.
 
 use crate::genetic::Mutation;
+use crate::tools::get_standard_normal;
+use num::{Float, PrimInt};
+use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs;
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 use std::mem;
 
 /// The struct to change random bits in the chromosomes.
 pub struct BitwiseMutation {
-    random: ThreadRng,
+    random: StdRng,
     change_gene_count: usize,
 }
 
 /// Mutation for chromosomes of Vec<G>, where G - type of single gene.
 pub struct VecMutation<G> {
     probability: f64,
-    random: rngs::ThreadRng,
+    random: StdRng,
     single_mutation: Box<dyn Mutation<G>>,
 }
 
@@ -27,12 +31,17 @@ impl BitwiseMutation {
     /// # Parameters
     /// * `change_gene_count` - how many bits will changed by algorithm during mutation.
     pub fn new(change_gene_count: usize) -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self {
             random,
             change_gene_count,
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl Mutation<f32> for BitwiseMutation {
@@ -70,13 +79,18 @@ impl<G> VecMutation<G> {
     /// * `probability` - probability of mutation of single gene.
     /// * `single_mutation` - trait object with mutation algorithm for single gene.
     pub fn new(probability: f64, single_mutation: Box<dyn Mutation<G>>) -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self {
             probability,
             random,
             single_mutation,
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<G: Clone> Mutation<Vec<G>> for VecMutation<G> {
@@ -84,9 +98,9 @@ impl<G: Clone> Mutation<Vec<G>> for VecMutation<G> {
         let mutate = Uniform::new(0.0, 100.0);
         let mut result = Vec::with_capacity(chromosomes.len());
 
-        for chromo in chromosomes {
+        for (n, chromo) in chromosomes.iter().enumerate() {
             if mutate.sample(&mut self.random) < self.probability {
-                result.push(self.single_mutation.mutation(&chromo));
+                result.push(self.single_mutation.mutation_at(chromo, n));
             } else {
                 result.push(chromo.clone());
             }
@@ -94,4 +108,980 @@ impl<G: Clone> Mutation<Vec<G>> for VecMutation<G> {
 
         result
     }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.single_mutation.set_generation(generation);
+    }
+}
+
+/// The mutation for float genes which adds the normal distributed noise N(0, sigma^2)
+/// to the gene. Use `VecMutation` to mutate genes of Vec<G> with the given probability.
+pub struct GaussianMutation {
+    sigma: f64,
+    random: StdRng,
+}
+
+impl GaussianMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `sigma` - standard deviation of the noise. Must be greater or equal to 0.
+    pub fn new(sigma: f64) -> Self {
+        assert!(sigma >= 0.0);
+        Self {
+            sigma,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Returns the current standard deviation of the noise.
+    pub fn get_sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Change the standard deviation of the noise. Must be greater or equal to 0.
+    pub fn set_sigma(&mut self, sigma: f64) {
+        assert!(sigma >= 0.0);
+        self.sigma = sigma;
+    }
+}
+
+impl<G: Float> Mutation<G> for GaussianMutation {
+    fn mutation(&mut self, gene: &G) -> G {
+        let noise = self.sigma * get_standard_normal(&mut self.random);
+        *gene + G::from(noise).unwrap()
+    }
+}
+
+/// Polynomial mutation for float genes from the article
+/// Deb, K.; Goyal, M. (1996). "A combined genetic adaptive search (GeneAS) for engineering
+/// design". Computer Science and Informatics, 26(4), pp.30-45.
+/// The mutation is used with NSGA-II usually. The result of mutation is always inside
+/// the interval of the gene.
+///
+/// Use `VecMutation` to mutate genes of Vec<G>. In this case, the interval for the gene
+/// is selected by the gene index.
+pub struct PolynomialMutation<G> {
+    eta_m: f64,
+    intervals: Vec<(G, G)>,
+    random: StdRng,
+}
+
+impl<G: Float> PolynomialMutation<G> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `eta_m` - distribution index. Must be greater or equal to 0. The mutated gene is closer
+    ///   to the original gene for the greater `eta_m`. The typical value is 20.
+    /// * `intervals` - intervals `(min, max)` for every gene. If the vector contains single
+    ///   interval, the interval is used for all genes. The `mutation` method (without the gene
+    ///   index) can be used in this case only.
+    pub fn new(eta_m: f64, intervals: Vec<(G, G)>) -> Self {
+        assert!(eta_m >= 0.0);
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        Self {
+            eta_m,
+            intervals,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    fn mutate_in_interval(&mut self, gene: G, interval: (G, G)) -> G {
+        let min = interval.0.to_f64().unwrap();
+        let max = interval.1.to_f64().unwrap();
+        let width = max - min;
+        if width == 0.0 {
+            return interval.0;
+        }
+
+        let y = gene.to_f64().unwrap().max(min).min(max);
+        let delta_1 = (y - min) / width;
+        let delta_2 = (max - y) / width;
+        let power = 1.0 / (self.eta_m + 1.0);
+
+        let r = Uniform::new(0.0, 1.0).sample(&mut self.random);
+        let delta_q = if r < 0.5 {
+            let value = 2.0 * r + (1.0 - 2.0 * r) * (1.0 - delta_1).powf(self.eta_m + 1.0);
+            value.powf(power) - 1.0
+        } else {
+            let value =
+                2.0 * (1.0 - r) + 2.0 * (r - 0.5) * (1.0 - delta_2).powf(self.eta_m + 1.0);
+            1.0 - value.powf(power)
+        };
+
+        let result = (y + delta_q * width).max(min).min(max);
+        G::from(result).unwrap()
+    }
+}
+
+impl<G: Float> Mutation<G> for PolynomialMutation<G> {
+    fn mutation(&mut self, gene: &G) -> G {
+        assert_eq!(self.intervals.len(), 1);
+        self.mutate_in_interval(*gene, self.intervals[0])
+    }
+
+    fn mutation_at(&mut self, gene: &G, gene_index: usize) -> G {
+        let interval = if self.intervals.len() == 1 {
+            self.intervals[0]
+        } else {
+            self.intervals[gene_index]
+        };
+        self.mutate_in_interval(*gene, interval)
+    }
+}
+
+/// The chromosomes for the self-adaptive mutation. Every individual carries the mutation step
+/// sizes (`sigmas`) for every variable in addition to the variables (the point in the search
+/// space). The step sizes are evolved with the variables.
+///
+/// `G` - type of the variables and the step sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfAdaptiveMutationChromosome<G> {
+    /// The point in the search space.
+    pub variables: Vec<G>,
+
+    /// The mutation step sizes for every variable.
+    pub sigmas: Vec<G>,
+}
+
+impl<G> SelfAdaptiveMutationChromosome<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `variables` - the point in the search space.
+    /// * `sigmas` - the mutation step sizes. The length must be equal to the `variables` length.
+    pub fn new(variables: Vec<G>, sigmas: Vec<G>) -> Self {
+        assert_eq!(variables.len(), sigmas.len());
+        Self { variables, sigmas }
+    }
+}
+
+/// The self-adaptive mutation for `SelfAdaptiveMutationChromosome`. The mutation changes
+/// the step sizes first with the log-normal rule:
+/// sigma_i' = sigma_i * exp(tau * N(0, 1)),
+/// then adds the normal distributed noise to the variables:
+/// x_i' = x_i + N(0, sigma_i'^2).
+/// All genes are mutated.
+pub struct SelfAdaptiveMutation {
+    tau: Option<f64>,
+    min_sigma: f64,
+    random: StdRng,
+}
+
+impl SelfAdaptiveMutation {
+    /// Constructor. The learning rate `tau` is equal to 1 / sqrt(n) by default,
+    /// where n - variables count.
+    pub fn new() -> Self {
+        Self {
+            tau: None,
+            min_sigma: 0.0,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Set the learning rate for the step sizes. Must be greater or equal to 0.
+    pub fn set_tau(&mut self, tau: f64) {
+        assert!(tau >= 0.0);
+        self.tau = Some(tau);
+    }
+
+    /// Set the minimal step size. The step sizes less than the value will be replaced by it
+    /// to prevent the search stagnation. Must be greater or equal to 0. The default value is 0.
+    pub fn set_min_sigma(&mut self, min_sigma: f64) {
+        assert!(min_sigma >= 0.0);
+        self.min_sigma = min_sigma;
+    }
+}
+
+impl Default for SelfAdaptiveMutation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Float> Mutation<SelfAdaptiveMutationChromosome<G>> for SelfAdaptiveMutation {
+    fn mutation(
+        &mut self,
+        chromosomes: &SelfAdaptiveMutationChromosome<G>,
+    ) -> SelfAdaptiveMutationChromosome<G> {
+        let count = chromosomes.variables.len();
+        let tau = match self.tau {
+            Some(tau) => tau,
+            None => 1.0 / (count.max(1) as f64).sqrt(),
+        };
+
+        let mut variables = Vec::with_capacity(count);
+        let mut sigmas = Vec::with_capacity(count);
+
+        for (variable, sigma) in chromosomes.variables.iter().zip(chromosomes.sigmas.iter()) {
+            let new_sigma = (sigma.to_f64().unwrap()
+                * (tau * get_standard_normal(&mut self.random)).exp())
+            .max(self.min_sigma);
+            let noise = new_sigma * get_standard_normal(&mut self.random);
+
+            variables.push(*variable + G::from(noise).unwrap());
+            sigmas.push(G::from(new_sigma).unwrap());
+        }
+
+        SelfAdaptiveMutationChromosome { variables, sigmas }
+    }
+}
+
+/// Non-uniform mutation for float genes from the book
+/// Michalewicz, Z. (1996). "Genetic Algorithms + Data Structures = Evolution Programs".
+/// The mutation intensity decreases with the generation number. The mutated gene is
+/// y' = y + delta(t, max - y) or y' = y - delta(t, y - min) with equal probability,
+/// delta(t, z) = z * (1 - r ^ ((1 - t / T) ^ b)),
+/// `t` - current generation, `T` - max generations count, `r` - random value in the range [0, 1),
+/// `b` - parameter of the non-uniformity degree. The result of mutation is always inside
+/// the interval of the gene. The gene is not changed after `T` generations.
+///
+/// The mutation gets the generation number from the optimizer by `Mutation::set_generation`.
+/// Use `VecMutation` to mutate genes of Vec<G>. In this case, the interval for the gene
+/// is selected by the gene index.
+pub struct NonUniformMutation<G> {
+    b: f64,
+    max_generations: usize,
+    intervals: Vec<(G, G)>,
+    generation: usize,
+    random: StdRng,
+}
+
+impl<G: Float> NonUniformMutation<G> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `b` - the non-uniformity degree. Must be greater or equal to 0. The mutation intensity
+    ///   decreases faster for the greater `b`. The typical value is 5.
+    /// * `max_generations` - generation count after which the genes are not changed.
+    ///   Must be greater than 0.
+    /// * `intervals` - intervals `(min, max)` for every gene. If the vector contains single
+    ///   interval, the interval is used for all genes. The `mutation` method (without the gene
+    ///   index) can be used in this case only.
+    pub fn new(b: f64, max_generations: usize, intervals: Vec<(G, G)>) -> Self {
+        assert!(b >= 0.0);
+        assert!(max_generations > 0);
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        Self {
+            b,
+            max_generations,
+            intervals,
+            generation: 0,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    fn get_delta(&mut self, z: f64) -> f64 {
+        let time = self.generation.min(self.max_generations) as f64 / self.max_generations as f64;
+        let r: f64 = Uniform::new(0.0, 1.0).sample(&mut self.random);
+        z * (1.0 - r.powf((1.0 - time).powf(self.b)))
+    }
+
+    fn mutate_in_interval(&mut self, gene: G, interval: (G, G)) -> G {
+        let min = interval.0.to_f64().unwrap();
+        let max = interval.1.to_f64().unwrap();
+        let y = gene.to_f64().unwrap().max(min).min(max);
+
+        let result = if Uniform::new(0.0, 1.0).sample(&mut self.random) < 0.5 {
+            y + self.get_delta(max - y)
+        } else {
+            y - self.get_delta(y - min)
+        };
+
+        G::from(result.max(min).min(max)).unwrap()
+    }
+}
+
+impl<G: Float> Mutation<G> for NonUniformMutation<G> {
+    fn mutation(&mut self, gene: &G) -> G {
+        assert_eq!(self.intervals.len(), 1);
+        self.mutate_in_interval(*gene, self.intervals[0])
+    }
+
+    fn mutation_at(&mut self, gene: &G, gene_index: usize) -> G {
+        let interval = if self.intervals.len() == 1 {
+            self.intervals[0]
+        } else {
+            self.intervals[gene_index]
+        };
+        self.mutate_in_interval(*gene, interval)
+    }
+
+    fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+}
+
+/// Random resetting mutation for integer genes. The gene is replaced by the random integer
+/// from the gene interval (the boundaries are included), all values have the same probability.
+///
+/// Use `VecMutation` to mutate genes of Vec<G>. In this case, the interval for the gene
+/// is selected by the gene index.
+pub struct RandomResetMutation<G: PrimInt + SampleUniform> {
+    random_intervals: Vec<Uniform<G>>,
+    random: StdRng,
+}
+
+impl<G: PrimInt + SampleUniform> RandomResetMutation<G> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `intervals` - intervals `(min, max)` for every gene. If the vector contains single
+    ///   interval, the interval is used for all genes. The `mutation` method (without the gene
+    ///   index) can be used in this case only.
+    pub fn new(intervals: Vec<(G, G)>) -> Self {
+        assert!(!intervals.is_empty());
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        let random_intervals = intervals
+            .iter()
+            .map(|(min, max)| Uniform::new_inclusive(*min, *max))
+            .collect();
+
+        Self {
+            random_intervals,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: PrimInt + SampleUniform> Mutation<G> for RandomResetMutation<G> {
+    fn mutation(&mut self, _gene: &G) -> G {
+        assert_eq!(self.random_intervals.len(), 1);
+        self.random_intervals[0].sample(&mut self.random)
+    }
+
+    fn mutation_at(&mut self, _gene: &G, gene_index: usize) -> G {
+        let index = if self.random_intervals.len() == 1 {
+            0
+        } else {
+            gene_index
+        };
+        self.random_intervals[index].sample(&mut self.random)
+    }
+}
+
+/// Swap mutation for the permutation chromosomes (or any chromosomes of Vec<G> type).
+/// Every gene is swapped with the gene in the random position with the given probability,
+/// so the result is the permutation of the same genes. The probability is applied to every
+/// gene as in `VecMutation`.
+pub struct SwapMutation {
+    probability: f64,
+    random: StdRng,
+}
+
+impl SwapMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `probability` - probability of swapping of single gene in percent (as for
+    ///   `VecMutation`). Must be in the range [0; 100].
+    pub fn new(probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+        Self {
+            probability,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Clone> Mutation<Vec<G>> for SwapMutation {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        let mut result = chromosomes.clone();
+        let gene_count = result.len();
+        if gene_count < 2 {
+            return result;
+        }
+
+        let mutate = Uniform::new(0.0, 100.0);
+        let positions = Uniform::new(0, gene_count);
+        for pos in 0..gene_count {
+            if mutate.sample(&mut self.random) < self.probability {
+                let other_pos = positions.sample(&mut self.random);
+                result.swap(pos, other_pos);
+            }
+        }
+
+        result
+    }
+}
+
+/// Returns two different random positions `(i, j)`, `i < j`, for the chromosomes with
+/// `gene_count` genes. `gene_count` must be greater than 1.
+fn get_random_segment(random: &mut StdRng, gene_count: usize) -> (usize, usize) {
+    let pos_1 = Uniform::new(0, gene_count).sample(random);
+    let mut pos_2 = Uniform::new(0, gene_count - 1).sample(random);
+    if pos_2 >= pos_1 {
+        pos_2 += 1;
+    }
+
+    (pos_1.min(pos_2), pos_1.max(pos_2))
+}
+
+/// Inversion mutation for the permutation chromosomes (or any chromosomes of Vec<G> type).
+/// The mutation selects two different random positions `i < j` and reverses the genes
+/// `chromosomes[i..=j]`. For the traveling salesman problem the inversion changes only two
+/// edges of the tour. The chromosomes are mutated with the given probability.
+pub struct InversionMutation {
+    probability: f64,
+    random: StdRng,
+}
+
+impl InversionMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `probability` - probability of the chromosomes mutation in percent.
+    ///   Must be in the range [0; 100].
+    pub fn new(probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+        Self {
+            probability,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Clone> Mutation<Vec<G>> for InversionMutation {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        let mut result = chromosomes.clone();
+        let mutate = Uniform::new(0.0, 100.0);
+        if result.len() < 2 || mutate.sample(&mut self.random) >= self.probability {
+            return result;
+        }
+
+        let (start, end) = get_random_segment(&mut self.random, result.len());
+        result[start..=end].reverse();
+        result
+    }
+}
+
+/// Scramble mutation for the permutation chromosomes (or any chromosomes of Vec<G> type).
+/// The mutation selects two different random positions `i < j` and shuffles the genes
+/// `chromosomes[i..=j]` (Fisher-Yates shuffle). The genes outside the segment are not changed.
+/// The chromosomes are mutated with the given probability.
+pub struct ScrambleMutation {
+    probability: f64,
+    random: StdRng,
+}
+
+impl ScrambleMutation {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `probability` - probability of the chromosomes mutation in percent.
+    ///   Must be in the range [0; 100].
+    pub fn new(probability: f64) -> Self {
+        assert!((0.0..=100.0).contains(&probability));
+        Self {
+            probability,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    fn scramble<G>(&mut self, chromosomes: &mut [G], start: usize, end: usize) {
+        chromosomes[start..=end].shuffle(&mut self.random);
+    }
+}
+
+impl<G: Clone> Mutation<Vec<G>> for ScrambleMutation {
+    fn mutation(&mut self, chromosomes: &Vec<G>) -> Vec<G> {
+        let mut result = chromosomes.clone();
+        let mutate = Uniform::new(0.0, 100.0);
+        if result.len() < 2 || mutate.sample(&mut self.random) >= self.probability {
+            return result;
+        }
+
+        let (start, end) = get_random_segment(&mut self.random, result.len());
+        self.scramble(&mut result, start, end);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::pre_birth::permutation::is_permutation;
+
+    #[test]
+    fn gaussian_mutation() {
+        let sigma = 2.0;
+        let run_count = 10000;
+        let mut mutation = GaussianMutation::new(sigma);
+        mutation.set_seed(1);
+
+        let displacements: Vec<f64> = (0..run_count)
+            .map(|_| mutation.mutation(&10.0_f64) - 10.0)
+            .collect();
+
+        let mean = displacements.iter().sum::<f64>() / run_count as f64;
+        let deviation = (displacements.iter().map(|x| x * x).sum::<f64>()
+            / run_count as f64)
+            .sqrt();
+        let mean_abs = displacements.iter().map(|x| x.abs()).sum::<f64>() / run_count as f64;
+
+        assert!(mean.abs() < 0.1);
+        assert!((deviation - sigma).abs() < 0.1);
+
+        // The mean absolute displacement for normal distribution is sigma * sqrt(2 / pi)
+        let expected_abs = sigma * (2.0 / std::f64::consts::PI).sqrt();
+        assert!((mean_abs - expected_abs).abs() < 0.1);
+    }
+
+    #[test]
+    fn gaussian_mutation_zero_sigma() {
+        let mut mutation = GaussianMutation::new(1.0);
+        mutation.set_sigma(0.0);
+        assert_eq!(mutation.get_sigma(), 0.0);
+        assert_eq!(mutation.mutation(&3.0_f32), 3.0_f32);
+    }
+
+    #[test]
+    fn gaussian_mutation_vec() {
+        let mut mutation = VecMutation::new(100.0, Box::new(GaussianMutation::new(1.0)));
+        mutation.set_seed(1);
+        let chromosomes = vec![0.0_f64; 10];
+
+        let result = mutation.mutation(&chromosomes);
+        assert_eq!(result.len(), 10);
+        assert!(result.iter().all(|gene| *gene != 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gaussian_mutation_invalid_sigma() {
+        GaussianMutation::new(-1.0);
+    }
+
+    /// Returns average distance between the gene and the mutated gene.
+    fn get_polynomial_spread(eta_m: f64) -> f64 {
+        let run_count = 1000;
+        let mut mutation = PolynomialMutation::new(eta_m, vec![(0.0_f64, 1.0)]);
+        mutation.set_seed(1);
+
+        let sum: f64 = (0..run_count)
+            .map(|_| (mutation.mutation(&0.5) - 0.5).abs())
+            .sum();
+        sum / run_count as f64
+    }
+
+    #[test]
+    fn polynomial_mutation_eta() {
+        let spread_low = get_polynomial_spread(1.0);
+        let spread_high = get_polynomial_spread(50.0);
+        assert!(spread_low > spread_high);
+    }
+
+    #[test]
+    fn polynomial_mutation_intervals() {
+        let intervals = vec![(0.0_f64, 1.0), (-10.0, -5.0), (100.0, 100.0)];
+        let mut mutation = VecMutation::new(
+            100.0,
+            Box::new(PolynomialMutation::new(0.5, intervals.clone())),
+        );
+        mutation.set_seed(1);
+
+        let mut chromosomes = vec![0.0_f64, -5.0, 100.0];
+        for _ in 0..1000 {
+            chromosomes = mutation.mutation(&chromosomes);
+            for (gene, interval) in chromosomes.iter().zip(intervals.iter()) {
+                assert!(*gene >= interval.0);
+                assert!(*gene <= interval.1);
+            }
+        }
+    }
+
+    #[test]
+    fn polynomial_mutation_single_interval() {
+        let mut mutation = PolynomialMutation::new(1.0, vec![(-1.0_f32, 1.0)]);
+        for n in 0..100 {
+            let gene = mutation.mutation_at(&0.0, n);
+            assert!(gene >= -1.0 && gene <= 1.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn polynomial_mutation_without_index() {
+        let mut mutation = PolynomialMutation::new(1.0, vec![(0.0_f64, 1.0), (0.0, 2.0)]);
+        mutation.mutation(&0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn polynomial_mutation_invalid_interval() {
+        PolynomialMutation::new(1.0, vec![(1.0_f64, 0.0)]);
+    }
+
+    #[test]
+    fn self_adaptive_mutation() {
+        let mut mutation = SelfAdaptiveMutation::new();
+        mutation.set_seed(1);
+        let chromosomes = SelfAdaptiveMutationChromosome::new(vec![0.0_f64; 5], vec![1.0; 5]);
+
+        let result = mutation.mutation(&chromosomes);
+        assert_eq!(result.variables.len(), 5);
+        assert_eq!(result.sigmas.len(), 5);
+        assert!(result.variables.iter().all(|x| *x != 0.0));
+        assert!(result.sigmas.iter().all(|sigma| *sigma > 0.0 && *sigma != 1.0));
+    }
+
+    #[test]
+    fn self_adaptive_mutation_zero_tau() {
+        let mut mutation = SelfAdaptiveMutation::new();
+        mutation.set_tau(0.0);
+        let chromosomes = SelfAdaptiveMutationChromosome::new(vec![0.0_f64; 3], vec![2.0; 3]);
+
+        // The step sizes are not changed if tau is zero
+        let result = mutation.mutation(&chromosomes);
+        assert_eq!(result.sigmas, vec![2.0; 3]);
+    }
+
+    #[test]
+    fn self_adaptive_mutation_min_sigma() {
+        let mut mutation = SelfAdaptiveMutation::new();
+        mutation.set_min_sigma(0.5);
+        let chromosomes = SelfAdaptiveMutationChromosome::new(vec![0.0_f32; 3], vec![0.0; 3]);
+
+        let result = mutation.mutation(&chromosomes);
+        assert_eq!(result.sigmas, vec![0.5; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn self_adaptive_chromosome_invalid() {
+        SelfAdaptiveMutationChromosome::new(vec![0.0_f64; 3], vec![1.0; 2]);
+    }
+
+    /// Returns average distance between the gene and the mutated gene.
+    fn get_non_uniform_spread(generation: usize) -> f64 {
+        let run_count = 1000;
+        let mut mutation = NonUniformMutation::new(2.0, 100, vec![(-1.0_f64, 1.0)]);
+        mutation.set_seed(1);
+        mutation.set_generation(generation);
+
+        let sum: f64 = (0..run_count)
+            .map(|_| mutation.mutation(&0.0).abs())
+            .sum();
+        sum / run_count as f64
+    }
+
+    #[test]
+    fn non_uniform_mutation_generation() {
+        let spread_start = get_non_uniform_spread(0);
+        let spread_middle = get_non_uniform_spread(50);
+        let spread_end = get_non_uniform_spread(99);
+
+        assert!(spread_start > spread_middle);
+        assert!(spread_middle > spread_end);
+
+        // The genes are not changed after the last generation
+        assert_eq!(get_non_uniform_spread(100), 0.0);
+        assert_eq!(get_non_uniform_spread(200), 0.0);
+    }
+
+    #[test]
+    fn non_uniform_mutation_intervals() {
+        let intervals = vec![(0.0_f64, 1.0), (-10.0, -5.0), (100.0, 100.0)];
+        let mut mutation = VecMutation::new(
+            100.0,
+            Box::new(NonUniformMutation::new(5.0, 10, intervals.clone())),
+        );
+        mutation.set_seed(1);
+
+        let mut chromosomes = vec![0.0_f64, -5.0, 100.0];
+        for generation in 0..20 {
+            mutation.set_generation(generation);
+            let mutant = mutation.mutation(&chromosomes);
+            for (gene, interval) in mutant.iter().zip(intervals.iter()) {
+                assert!(*gene >= interval.0);
+                assert!(*gene <= interval.1);
+            }
+
+            // `VecMutation` passes the generation number to the single gene mutation
+            if generation >= 10 {
+                assert_eq!(mutant, chromosomes);
+            }
+            chromosomes = mutant;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_uniform_mutation_invalid_generations() {
+        NonUniformMutation::new(5.0, 0, vec![(0.0_f64, 1.0)]);
+    }
+
+    #[test]
+    fn random_reset_mutation_intervals() {
+        let intervals = vec![(0_i64, 1), (-5, 5), (100, 100)];
+        let mut mutation = VecMutation::new(
+            100.0,
+            Box::new(RandomResetMutation::new(intervals.clone())),
+        );
+        mutation.set_seed(1);
+
+        let mut counts = vec![0; 11];
+        let mut chromosomes = vec![0_i64, -5, 100];
+        for _ in 0..1000 {
+            chromosomes = mutation.mutation(&chromosomes);
+            for (gene, interval) in chromosomes.iter().zip(intervals.iter()) {
+                assert!(*gene >= interval.0);
+                assert!(*gene <= interval.1);
+            }
+            counts[(chromosomes[1] + 5) as usize] += 1;
+        }
+
+        // All values of the interval are possible
+        assert!(counts.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn random_reset_mutation_single_interval() {
+        let mut mutation = RandomResetMutation::new(vec![(-2_i32, 2)]);
+        mutation.set_seed(1);
+        for n in 0..100 {
+            let gene = mutation.mutation_at(&0, n);
+            assert!(gene >= -2 && gene <= 2);
+
+            let gene = mutation.mutation(&0);
+            assert!(gene >= -2 && gene <= 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_reset_mutation_without_index() {
+        let mut mutation = RandomResetMutation::new(vec![(0_i64, 1), (0, 2)]);
+        mutation.mutation(&0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_reset_mutation_invalid_interval() {
+        RandomResetMutation::new(vec![(1_i64, 0)]);
+    }
+
+    #[test]
+    fn swap_mutation_valid() {
+        let mut mutation = SwapMutation::new(20.0);
+        mutation.set_seed(1);
+
+        let mut chromosomes: Vec<usize> = (0..20).collect();
+        let mut changed = false;
+        for _ in 0..100 {
+            let new_chromosomes = mutation.mutation(&chromosomes);
+            assert_eq!(new_chromosomes.len(), 20);
+            assert!(is_permutation(&new_chromosomes));
+
+            changed |= new_chromosomes != chromosomes;
+            chromosomes = new_chromosomes;
+        }
+
+        assert!(changed);
+    }
+
+    #[test]
+    fn swap_mutation_probability() {
+        let chromosomes: Vec<usize> = (0..10).collect();
+
+        let mut mutation = SwapMutation::new(0.0);
+        assert_eq!(mutation.mutation(&chromosomes), chromosomes);
+
+        // Every gene is swapped, but the swap with itself is possible
+        let mut mutation = SwapMutation::new(100.0);
+        mutation.set_seed(1);
+        let new_chromosomes = mutation.mutation(&chromosomes);
+        assert!(is_permutation(&new_chromosomes));
+        assert_ne!(new_chromosomes, chromosomes);
+    }
+
+    #[test]
+    fn swap_mutation_small() {
+        let mut mutation = SwapMutation::new(100.0);
+        assert_eq!(mutation.mutation(&Vec::<usize>::new()), Vec::<usize>::new());
+        assert_eq!(mutation.mutation(&vec![0_usize]), vec![0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_mutation_invalid_probability() {
+        SwapMutation::new(101.0);
+    }
+
+    #[test]
+    fn random_segment() {
+        let mut random = StdRng::seed_from_u64(1);
+        let mut counts = vec![vec![0; 5]; 5];
+        for _ in 0..10000 {
+            let (start, end) = get_random_segment(&mut random, 5);
+            assert!(start < end);
+            assert!(end < 5);
+            counts[start][end] += 1;
+        }
+
+        // All segments are possible
+        for start in 0..5 {
+            for end in start + 1..5 {
+                assert!(counts[start][end] > 0);
+            }
+        }
+
+        assert_eq!(get_random_segment(&mut random, 2), (0, 1));
+    }
+
+    #[test]
+    fn inversion_mutation_reversed() {
+        let mut mutation = InversionMutation::new(100.0);
+        mutation.set_seed(1);
+
+        let chromosomes: Vec<usize> = (0..20).collect();
+        for _ in 0..100 {
+            let new_chromosomes = mutation.mutation(&chromosomes);
+            assert!(is_permutation(&new_chromosomes));
+
+            // The genes before and after the reversed segment are not changed
+            let start = new_chromosomes
+                .iter()
+                .zip(chromosomes.iter())
+                .position(|(new_gene, gene)| new_gene != gene)
+                .unwrap();
+            let end = new_chromosomes
+                .iter()
+                .zip(chromosomes.iter())
+                .rposition(|(new_gene, gene)| new_gene != gene)
+                .unwrap();
+
+            let mut segment = chromosomes[start..=end].to_vec();
+            segment.reverse();
+            assert_eq!(&new_chromosomes[start..=end], &segment[..]);
+        }
+    }
+
+    #[test]
+    fn inversion_mutation_two_genes() {
+        let mut mutation = InversionMutation::new(100.0);
+        assert_eq!(mutation.mutation(&vec![0_usize, 1]), vec![1, 0]);
+        assert_eq!(mutation.mutation(&vec![0_usize]), vec![0]);
+        assert_eq!(mutation.mutation(&Vec::<usize>::new()), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn inversion_mutation_zero_probability() {
+        let mut mutation = InversionMutation::new(0.0);
+        let chromosomes: Vec<usize> = (0..20).collect();
+        assert_eq!(mutation.mutation(&chromosomes), chromosomes);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inversion_mutation_invalid_probability() {
+        InversionMutation::new(-1.0);
+    }
+
+    #[test]
+    fn scramble_mutation_segment() {
+        let mut mutation = ScrambleMutation::new(100.0);
+        mutation.set_seed(1);
+
+        let chromosomes: Vec<usize> = (0..20).collect();
+        let mut changed = false;
+        for _ in 0..100 {
+            let mut new_chromosomes = chromosomes.clone();
+            mutation.scramble(&mut new_chromosomes, 5, 14);
+
+            assert_eq!(&new_chromosomes[..5], &chromosomes[..5]);
+            assert_eq!(&new_chromosomes[15..], &chromosomes[15..]);
+
+            let mut segment = new_chromosomes[5..15].to_vec();
+            changed |= segment != chromosomes[5..15];
+            segment.sort();
+            assert_eq!(&segment[..], &chromosomes[5..15]);
+        }
+
+        assert!(changed);
+    }
+
+    #[test]
+    fn scramble_mutation_valid() {
+        let mut mutation = ScrambleMutation::new(100.0);
+        mutation.set_seed(1);
+
+        let mut chromosomes: Vec<usize> = (0..20).collect();
+        for _ in 0..100 {
+            chromosomes = mutation.mutation(&chromosomes);
+            assert_eq!(chromosomes.len(), 20);
+            assert!(is_permutation(&chromosomes));
+        }
+    }
+
+    #[test]
+    fn scramble_mutation_small() {
+        let mut mutation = ScrambleMutation::new(100.0);
+        assert_eq!(mutation.mutation(&vec![0_usize]), vec![0]);
+        assert_eq!(mutation.mutation(&Vec::<usize>::new()), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn scramble_mutation_zero_probability() {
+        let mut mutation = ScrambleMutation::new(0.0);
+        let chromosomes: Vec<usize> = (0..20).collect();
+        assert_eq!(mutation.mutation(&chromosomes), chromosomes);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scramble_mutation_invalid_probability() {
+        ScrambleMutation::new(100.5);
+    }
 }
@@ -1,14 +1,17 @@
 //! The module with pairing algorithm traits. The pairing algorithm selects individuals for crossing.
 
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
 use crate::Agent;
+use crate::tools::compare_values;
 use crate::genetic:: {Pairing, Population};
 
 /// Pairing algorithm which select random individuals for crossing.
 pub struct RandomPairing {
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl<T> Pairing<T> for RandomPairing {
@@ -31,9 +34,14 @@ impl<T> Pairing<T> for RandomPairing {
 impl RandomPairing {
     /// Constructor.
     pub fn new() -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self { random }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 /// Algorithm of tournament.
@@ -43,7 +51,7 @@ pub struct Tournament {
     families_count: usize,
     partners_count: usize,
     rounds_count: usize,
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl Tournament {
@@ -52,7 +60,7 @@ impl Tournament {
     /// # Parameters
     /// * `families_count` - families count for crossing.
     pub fn new(families_count: usize) -> Self {
-        let random = rand::thread_rng();
+        let random = StdRng::from_entropy();
         Self {
             families_count,
             partners_count: 2,
@@ -61,6 +69,11 @@ impl Tournament {
         }
     }
 
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
     /// Set partners count for every family. Tthe default is 2.
     pub fn partners_count<'a>(mut self, count: usize) -> Self {
         self.partners_count = count;
@@ -103,3 +116,478 @@ impl<T> Pairing<T> for Tournament {
         pairs
     }
 }
+
+/// Roulette wheel (fitness proportionate) pairing. The probability of individual selection is
+/// proportional to 1 / (goal - min_goal + epsilon), where `goal` - value of the goal function
+/// for the individual, `min_goal` - the least value of the goal function in the population.
+/// The individuals with NaN or infinite value of the goal function are never selected.
+/// If the population does not contain individuals with finite value of the goal function,
+/// the individuals are selected at random.
+///
+/// The selection depends on the goal function scale. The `epsilon` value limits the probability
+/// of the best individual selection.
+pub struct RoulettePairing {
+    families_count: usize,
+    partners_count: usize,
+    epsilon: f64,
+    random: StdRng,
+}
+
+impl RoulettePairing {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `families_count` - families count for crossing.
+    pub fn new(families_count: usize) -> Self {
+        let random = StdRng::from_entropy();
+        Self {
+            families_count,
+            partners_count: 2,
+            epsilon: 1.0,
+            random,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Set partners count for every family. The default is 2.
+    pub fn partners_count(mut self, count: usize) -> Self {
+        self.partners_count = count;
+        self
+    }
+
+    /// Set the `epsilon` value in the selection probability. Must be greater than 0.
+    /// The default is 1.
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0.0);
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl<T> Pairing<T> for RoulettePairing {
+    fn get_pairs(&mut self, population: &Population<T>) -> Vec<Vec<usize>> {
+        let cumulative_weights = get_cumulative_weights(&get_roulette_weights(
+            population,
+            self.epsilon,
+        ));
+
+        let mut pairs: Vec<Vec<usize>> = Vec::with_capacity(self.families_count);
+        for _ in 0..self.families_count {
+            let family = (0..self.partners_count)
+                .map(|_| select_by_weights(&cumulative_weights, &mut self.random))
+                .collect();
+            pairs.push(family);
+        }
+
+        pairs
+    }
+}
+
+/// Rank-based pairing (linear ranking). The individuals are sorted by value of the goal function
+/// and the probability of individual selection depends linearly on its rank:
+/// p_i = (sp - (2 * sp - 2) * i / (N - 1)) / N,
+/// where `i` - rank of the individual (0 for the best individual), `N` - population size,
+/// `sp` - selection pressure in the range [1; 2]. The best individual is selected with
+/// probability `sp / N`, the worst individual with probability `(2 - sp) / N`.
+/// The individuals with NaN value of the goal function are ranked as the worst.
+///
+/// The selection does not depend on the goal function scale unlike `RoulettePairing`.
+pub struct RankPairing {
+    families_count: usize,
+    partners_count: usize,
+    selection_pressure: f64,
+    random: StdRng,
+}
+
+impl RankPairing {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `families_count` - families count for crossing.
+    /// * `selection_pressure` - value in the range [1; 2]. The individuals are selected
+    ///   uniformly if the value is 1. The worst individual is never selected if the value is 2.
+    pub fn new(families_count: usize, selection_pressure: f64) -> Self {
+        assert!((1.0..=2.0).contains(&selection_pressure));
+        let random = StdRng::from_entropy();
+        Self {
+            families_count,
+            partners_count: 2,
+            selection_pressure,
+            random,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Set partners count for every family. The default is 2.
+    pub fn partners_count(mut self, count: usize) -> Self {
+        self.partners_count = count;
+        self
+    }
+}
+
+impl<T> Pairing<T> for RankPairing {
+    fn get_pairs(&mut self, population: &Population<T>) -> Vec<Vec<usize>> {
+        let cumulative_weights = get_cumulative_weights(&get_rank_weights(
+            population,
+            self.selection_pressure,
+        ));
+
+        let mut pairs: Vec<Vec<usize>> = Vec::with_capacity(self.families_count);
+        for _ in 0..self.families_count {
+            let family = (0..self.partners_count)
+                .map(|_| select_by_weights(&cumulative_weights, &mut self.random))
+                .collect();
+            pairs.push(family);
+        }
+
+        pairs
+    }
+}
+
+/// Returns the weights of the individuals for the linear ranking selection.
+fn get_rank_weights<T>(population: &Population<T>, selection_pressure: f64) -> Vec<f64> {
+    let count = population.len();
+    if count == 1 {
+        return vec![1.0];
+    }
+
+    let mut indexes: Vec<usize> = (0..count).collect();
+    indexes.sort_by(|&a, &b| compare_values(population[a].get_goal(), population[b].get_goal()));
+
+    let mut weights = vec![0.0; count];
+    for (rank, index) in indexes.iter().enumerate() {
+        weights[*index] = selection_pressure
+            - (2.0 * selection_pressure - 2.0) * rank as f64 / (count - 1) as f64;
+    }
+
+    weights
+}
+
+/// Stochastic universal sampling (SUS). The weights of the individuals are the same as for
+/// `RoulettePairing`. The algorithm uses single random number and evenly spaced pointers around
+/// the roulette wheel to select all parents, so the selection variance is less than for
+/// the repeated roulette wheel selection. The selected individuals are shuffled before
+/// distribution into families.
+pub struct StochasticUniversalSampling {
+    families_count: usize,
+    partners_count: usize,
+    epsilon: f64,
+    random: StdRng,
+}
+
+impl StochasticUniversalSampling {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `families_count` - families count for crossing.
+    pub fn new(families_count: usize) -> Self {
+        let random = StdRng::from_entropy();
+        Self {
+            families_count,
+            partners_count: 2,
+            epsilon: 1.0,
+            random,
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Set partners count for every family. The default is 2.
+    pub fn partners_count(mut self, count: usize) -> Self {
+        self.partners_count = count;
+        self
+    }
+
+    /// Set the `epsilon` value in the selection probability (see `RoulettePairing`).
+    /// Must be greater than 0. The default is 1.
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0.0);
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl<T> Pairing<T> for StochasticUniversalSampling {
+    fn get_pairs(&mut self, population: &Population<T>) -> Vec<Vec<usize>> {
+        let selection_count = self.families_count * self.partners_count;
+        if selection_count == 0 {
+            return vec![vec![]; self.families_count];
+        }
+
+        let cumulative_weights = get_cumulative_weights(&get_roulette_weights(
+            population,
+            self.epsilon,
+        ));
+        let total = *cumulative_weights.last().unwrap();
+        let step = total / selection_count as f64;
+        let start = Uniform::new(0.0, step).sample(&mut self.random);
+
+        let mut selected: Vec<usize> = (0..selection_count)
+            .map(|n| find_by_cumulative_weight(&cumulative_weights, start + step * n as f64))
+            .collect();
+        selected.shuffle(&mut self.random);
+
+        selected
+            .chunks(self.partners_count)
+            .map(|family| family.to_vec())
+            .collect()
+    }
+}
+
+/// Returns the weights of the individuals for the roulette wheel selection.
+/// The weights are zero for the individuals with NaN or infinite value of the goal function.
+/// All weights are equal to 1 if there are no individuals with finite value of the goal function.
+fn get_roulette_weights<T>(population: &Population<T>, epsilon: f64) -> Vec<f64> {
+    let min_goal = (0..population.len())
+        .map(|n| population[n].get_goal())
+        .filter(|goal| goal.is_finite())
+        .fold(None, |min: Option<f64>, goal| match min {
+            Some(min) if min <= goal => Some(min),
+            _ => Some(goal),
+        });
+
+    match min_goal {
+        None => vec![1.0; population.len()],
+        Some(min_goal) => (0..population.len())
+            .map(|n| {
+                let goal = population[n].get_goal();
+                if goal.is_finite() {
+                    1.0 / (goal - min_goal + epsilon)
+                } else {
+                    0.0
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Returns the cumulative sums of the weights.
+fn get_cumulative_weights(weights: &[f64]) -> Vec<f64> {
+    weights
+        .iter()
+        .scan(0.0, |sum, weight| {
+            *sum += weight;
+            Some(*sum)
+        })
+        .collect()
+}
+
+/// Returns index of the random item. The probability of the item selection is proportional to
+/// its weight. `cumulative_weights` - the cumulative sums of the weights.
+fn select_by_weights(cumulative_weights: &[f64], random: &mut StdRng) -> usize {
+    let total = *cumulative_weights.last().unwrap();
+    let value = Uniform::new(0.0, total).sample(random);
+    find_by_cumulative_weight(cumulative_weights, value)
+}
+
+/// Returns index of the first item with the cumulative weight greater than `value`.
+fn find_by_cumulative_weight(cumulative_weights: &[f64], value: f64) -> usize {
+    cumulative_weights
+        .iter()
+        .position(|weight| *weight > value)
+        .unwrap_or(cumulative_weights.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    /// Create population with the given values of goal function.
+    fn create_population(goal_values: &[f64]) -> Population<'static, Vec<f64>> {
+        let mut population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        population.append(goal_values.iter().map(|value| vec![*value]).collect());
+        population
+    }
+
+    /// Returns how many times every individual was selected.
+    fn get_selection_counts(pairs: &[Vec<usize>], population_size: usize) -> Vec<usize> {
+        let mut counts = vec![0; population_size];
+        for family in pairs {
+            for index in family {
+                counts[*index] += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn roulette_pairing() {
+        let population = create_population(&[0.0, 1.0, 3.0]);
+        let mut pairing = RoulettePairing::new(3000);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert_eq!(pairs.len(), 3000);
+        assert!(pairs.iter().all(|family| family.len() == 2));
+
+        // The weights are 1, 1/2 and 1/4
+        let counts = get_selection_counts(&pairs, 3);
+        let total = 6000.0;
+        assert!((counts[0] as f64 / total - 4.0 / 7.0).abs() < 0.03);
+        assert!((counts[1] as f64 / total - 2.0 / 7.0).abs() < 0.03);
+        assert!((counts[2] as f64 / total - 1.0 / 7.0).abs() < 0.03);
+    }
+
+    #[test]
+    fn roulette_pairing_equal() {
+        let population = create_population(&[5.0; 4]);
+        let mut pairing = RoulettePairing::new(2000).partners_count(3).epsilon(1e-10);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert!(pairs.iter().all(|family| family.len() == 3));
+
+        let counts = get_selection_counts(&pairs, 4);
+        for count in counts {
+            assert!((count as f64 / 6000.0 - 0.25).abs() < 0.03);
+        }
+    }
+
+    #[test]
+    fn roulette_pairing_nan() {
+        let population = create_population(&[f64::NAN, 1.0, f64::INFINITY, 2.0]);
+        let mut pairing = RoulettePairing::new(100);
+        pairing.set_seed(1);
+
+        let counts = get_selection_counts(&pairing.get_pairs(&population), 4);
+        assert_eq!(counts[0], 0);
+        assert_eq!(counts[2], 0);
+        assert!(counts[1] > counts[3]);
+        assert!(counts[3] > 0);
+    }
+
+    #[test]
+    fn roulette_pairing_all_nan() {
+        let population = create_population(&[f64::NAN, f64::NAN, f64::INFINITY]);
+        let mut pairing = RoulettePairing::new(100);
+        pairing.set_seed(1);
+
+        let counts = get_selection_counts(&pairing.get_pairs(&population), 3);
+        assert!(counts.iter().all(|count| *count > 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn roulette_pairing_invalid_epsilon() {
+        RoulettePairing::new(10).epsilon(0.0);
+    }
+
+    #[test]
+    fn rank_pairing() {
+        // Ranks: 2, 0, 3, 1
+        let population = create_population(&[3.0, -100.0, 1000.0, 2.0]);
+        let mut pairing = RankPairing::new(3000, 2.0);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert_eq!(pairs.len(), 3000);
+        assert!(pairs.iter().all(|family| family.len() == 2));
+
+        // The weights are 2, 4/3, 2/3 and 0 for ranks
+        let counts = get_selection_counts(&pairs, 4);
+        let total = 6000.0;
+        assert!((counts[1] as f64 / total - 2.0 / 4.0).abs() < 0.03);
+        assert!((counts[3] as f64 / total - 4.0 / 12.0).abs() < 0.03);
+        assert!((counts[0] as f64 / total - 2.0 / 12.0).abs() < 0.03);
+        assert_eq!(counts[2], 0);
+    }
+
+    #[test]
+    fn rank_pairing_uniform() {
+        let population = create_population(&[3.0, -100.0, 1000.0, 2.0]);
+        let mut pairing = RankPairing::new(2000, 1.0).partners_count(3);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert!(pairs.iter().all(|family| family.len() == 3));
+
+        let counts = get_selection_counts(&pairs, 4);
+        for count in counts {
+            assert!((count as f64 / 6000.0 - 0.25).abs() < 0.03);
+        }
+    }
+
+    #[test]
+    fn rank_pairing_nan() {
+        let population = create_population(&[f64::NAN, 1.0, 2.0]);
+        let mut pairing = RankPairing::new(100, 2.0);
+        pairing.set_seed(1);
+
+        let counts = get_selection_counts(&pairing.get_pairs(&population), 3);
+        assert_eq!(counts[0], 0);
+        assert!(counts[1] > counts[2]);
+    }
+
+    #[test]
+    fn rank_pairing_single() {
+        let population = create_population(&[1.0]);
+        let mut pairing = RankPairing::new(2, 2.0);
+
+        assert_eq!(pairing.get_pairs(&population), vec![vec![0, 0], vec![0, 0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rank_pairing_invalid_pressure() {
+        RankPairing::new(10, 2.5);
+    }
+
+    #[test]
+    fn sus_pairing() {
+        let population = create_population(&[0.0, 1.0, 3.0]);
+        let mut pairing = StochasticUniversalSampling::new(7);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert_eq!(pairs.len(), 7);
+        assert!(pairs.iter().all(|family| family.len() == 2));
+
+        // The weights are 1, 1/2 and 1/4, so the selection counts are exact
+        let counts = get_selection_counts(&pairs, 3);
+        assert_eq!(counts, vec![8, 4, 2]);
+    }
+
+    #[test]
+    fn sus_pairing_equal() {
+        let population = create_population(&[5.0; 4]);
+        let mut pairing = StochasticUniversalSampling::new(4).partners_count(3);
+        pairing.set_seed(1);
+
+        let pairs = pairing.get_pairs(&population);
+        assert!(pairs.iter().all(|family| family.len() == 3));
+        assert_eq!(get_selection_counts(&pairs, 4), vec![3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn sus_pairing_nan() {
+        let population = create_population(&[f64::NAN, 1.0, f64::INFINITY, 1.0]);
+        let mut pairing = StochasticUniversalSampling::new(10);
+        pairing.set_seed(1);
+
+        let counts = get_selection_counts(&pairing.get_pairs(&population), 4);
+        assert_eq!(counts, vec![0, 10, 0, 10]);
+    }
+
+    #[test]
+    fn sus_pairing_empty() {
+        let population = create_population(&[1.0, 2.0]);
+        let mut pairing = StochasticUniversalSampling::new(0);
+        assert!(pairing.get_pairs(&population).is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+//! The module with `PopulationSizeController` trait implementations.
+
+pub mod vec_float;
+
+use crate::genetic::{Individual, PopulationSizeController};
+
+/// The function to calculate diversity of the population.
+type DiversityFunction<'a, T> = Box<dyn Fn(&[Individual<T>]) -> f64 + 'a>;
+
+/// Returns standard deviation of the fitness of individuals. The individuals with NaN or infinite
+/// fitness are ignored. Returns 0 if the population contains less than two such individuals.
+pub fn get_fitness_deviation<T>(population: &[Individual<T>]) -> f64 {
+    let values: Vec<f64> = population
+        .iter()
+        .map(|individual| individual.get_fitness())
+        .filter(|fitness| fitness.is_finite())
+        .collect();
+
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean) * (value - mean))
+        .sum::<f64>()
+        / count;
+
+    variance.sqrt()
+}
+
+/// The population size controller which changes the population size by diversity and improvement
+/// of the best fitness.
+///
+/// The population grows by `step` individuals per generation while the diversity is less than
+/// the threshold. The population shrinks by `step` individuals if the best fitness does not
+/// improve during `max_stagnant_generations` generations. The population size is always in
+/// the range [`min_size`; `max_size`].
+///
+/// The diversity is calculated by the function passed to the constructor, for example,
+/// `get_fitness_deviation` or `vec_float::get_average_distance`.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct DiversityBasedController<'a, T> {
+    min_size: usize,
+    max_size: usize,
+    diversity_threshold: f64,
+    diversity: DiversityFunction<'a, T>,
+    step: usize,
+    max_stagnant_generations: usize,
+
+    best_fitness: Option<f64>,
+    stagnant_generations: usize,
+}
+
+impl<'a, T> DiversityBasedController<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `min_size` - minimal population size. Must be greater than 0.
+    /// * `max_size` - maximal population size. Must be greater than or equal to `min_size`.
+    /// * `diversity_threshold` - the population grows if the diversity is less than the value.
+    /// * `diversity` - the function to calculate diversity of the population.
+    pub fn new(
+        min_size: usize,
+        max_size: usize,
+        diversity_threshold: f64,
+        diversity: DiversityFunction<'a, T>,
+    ) -> Self {
+        assert!(min_size > 0);
+        assert!(min_size <= max_size);
+        assert!(diversity_threshold >= 0.0);
+
+        Self {
+            min_size,
+            max_size,
+            diversity_threshold,
+            diversity,
+            step: 1,
+            max_stagnant_generations: 10,
+            best_fitness: None,
+            stagnant_generations: 0,
+        }
+    }
+
+    /// Set count of the individuals which are added or removed per generation. The default
+    /// value is 1.
+    pub fn set_step(&mut self, step: usize) {
+        assert!(step > 0);
+        self.step = step;
+    }
+
+    /// Set count of the generations without improvement of the best fitness before
+    /// the population shrinks. The default value is 10.
+    pub fn set_max_stagnant_generations(&mut self, max_stagnant_generations: usize) {
+        assert!(max_stagnant_generations > 0);
+        self.max_stagnant_generations = max_stagnant_generations;
+    }
+
+    fn update_stagnation(&mut self, current_population: &[Individual<T>]) {
+        let best_fitness = current_population
+            .iter()
+            .map(|individual| individual.get_fitness())
+            .filter(|fitness| !fitness.is_nan())
+            .fold(None, |best: Option<f64>, fitness| match best {
+                Some(value) if value <= fitness => Some(value),
+                _ => Some(fitness),
+            });
+
+        match (self.best_fitness, best_fitness) {
+            (Some(old_value), Some(new_value)) if new_value >= old_value => {
+                self.stagnant_generations += 1;
+            }
+            (_, Some(new_value)) => {
+                self.best_fitness = Some(new_value);
+                self.stagnant_generations = 0;
+            }
+            (_, None) => {
+                self.stagnant_generations += 1;
+            }
+        }
+    }
+}
+
+impl<'a, T> PopulationSizeController<T> for DiversityBasedController<'a, T> {
+    fn desired_size(&mut self, current_population: &[Individual<T>]) -> usize {
+        self.update_stagnation(current_population);
+        let size = current_population.len();
+
+        if size < self.min_size {
+            return self.min_size;
+        }
+
+        if size > self.max_size {
+            return self.max_size;
+        }
+
+        if size < self.max_size && (self.diversity)(current_population) < self.diversity_threshold {
+            return (size + self.step).min(self.max_size);
+        }
+
+        if size > self.min_size && self.stagnant_generations >= self.max_stagnant_generations {
+            self.stagnant_generations = 0;
+            return (size - self.step).max(self.min_size);
+        }
+
+        size
+    }
+
+    fn reset(&mut self) {
+        self.best_fitness = None;
+        self.stagnant_generations = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::Population;
+    use crate::GoalFromFunction;
+
+    fn first(x: &Vec<f64>) -> f64 {
+        x[0]
+    }
+
+    fn create_individuals(chromosomes: Vec<Vec<f64>>) -> Vec<Individual<Vec<f64>>> {
+        let mut population = Population::new(Box::new(GoalFromFunction::new(first)));
+        population.append(chromosomes);
+        population.individuals
+    }
+
+    fn create_controller(
+        min_size: usize,
+        max_size: usize,
+        diversity_threshold: f64,
+    ) -> DiversityBasedController<'static, Vec<f64>> {
+        DiversityBasedController::new(
+            min_size,
+            max_size,
+            diversity_threshold,
+            Box::new(get_fitness_deviation),
+        )
+    }
+
+    #[test]
+    fn fitness_deviation_empty() {
+        let individuals = create_individuals(vec![]);
+        assert_eq!(get_fitness_deviation(&individuals), 0.0);
+    }
+
+    #[test]
+    fn fitness_deviation_single() {
+        let individuals = create_individuals(vec![vec![5.0]]);
+        assert_eq!(get_fitness_deviation(&individuals), 0.0);
+    }
+
+    #[test]
+    fn fitness_deviation() {
+        let individuals = create_individuals(vec![
+            vec![2.0],
+            vec![4.0],
+            vec![4.0],
+            vec![4.0],
+            vec![5.0],
+            vec![5.0],
+            vec![7.0],
+            vec![9.0],
+        ]);
+        assert!((get_fitness_deviation(&individuals) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn fitness_deviation_nan() {
+        let individuals = create_individuals(vec![vec![1.0], vec![f64::NAN], vec![3.0]]);
+        assert!((get_fitness_deviation(&individuals) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn grow_low_diversity() {
+        let mut controller = create_controller(2, 10, 1.0);
+        let individuals = create_individuals(vec![vec![1.0], vec![1.1], vec![1.2]]);
+        assert_eq!(controller.desired_size(&individuals), 4);
+    }
+
+    #[test]
+    fn grow_step() {
+        let mut controller = create_controller(2, 10, 1.0);
+        controller.set_step(5);
+        let individuals = create_individuals(vec![vec![1.0]; 3]);
+        assert_eq!(controller.desired_size(&individuals), 8);
+
+        let individuals = create_individuals(vec![vec![1.0]; 8]);
+        assert_eq!(controller.desired_size(&individuals), 10);
+    }
+
+    #[test]
+    fn keep_high_diversity() {
+        let mut controller = create_controller(2, 10, 1.0);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0], vec![20.0]]);
+        assert_eq!(controller.desired_size(&individuals), 3);
+    }
+
+    #[test]
+    fn limits() {
+        let mut controller = create_controller(3, 5, 1.0);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0]]);
+        assert_eq!(controller.desired_size(&individuals), 3);
+
+        let individuals = create_individuals(vec![vec![0.0]; 8]);
+        assert_eq!(controller.desired_size(&individuals), 5);
+    }
+
+    #[test]
+    fn shrink_stagnation() {
+        let mut controller = create_controller(2, 10, 1.0);
+        controller.set_max_stagnant_generations(3);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0], vec![20.0], vec![30.0]]);
+
+        assert_eq!(controller.desired_size(&individuals), 4);
+        assert_eq!(controller.desired_size(&individuals), 4);
+        assert_eq!(controller.desired_size(&individuals), 4);
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 4);
+    }
+
+    #[test]
+    fn improvement_resets_stagnation() {
+        let mut controller = create_controller(2, 10, 1.0);
+        controller.set_max_stagnant_generations(2);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0], vec![20.0]]);
+        let better = create_individuals(vec![vec![-1.0], vec![10.0], vec![20.0]]);
+
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&better), 3);
+        assert_eq!(controller.desired_size(&better), 3);
+        assert_eq!(controller.desired_size(&better), 2);
+    }
+
+    #[test]
+    fn no_shrink_min_size() {
+        let mut controller = create_controller(3, 10, 1.0);
+        controller.set_max_stagnant_generations(1);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0], vec![20.0]]);
+
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 3);
+    }
+
+    #[test]
+    fn reset() {
+        let mut controller = create_controller(2, 10, 1.0);
+        controller.set_max_stagnant_generations(2);
+        let individuals = create_individuals(vec![vec![0.0], vec![10.0], vec![20.0]]);
+
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 3);
+        controller.reset();
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 3);
+        assert_eq!(controller.desired_size(&individuals), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_min_size() {
+        create_controller(0, 10, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_max_size() {
+        create_controller(10, 5, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_threshold() {
+        create_controller(2, 10, -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_step() {
+        create_controller(2, 10, 1.0).set_step(0);
+    }
+}
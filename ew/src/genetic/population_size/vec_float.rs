@@ -0,0 +1,77 @@
+//! The module with functions for population size controllers for type chromosomes of
+//! Vec<Float>.
+
+use num::Float;
+
+use crate::genetic::Individual;
+
+/// Returns average Euclidean distance between all pairs of individuals. Returns 0 if
+/// the population contains less than two individuals.
+///
+/// `G` - type of gene.
+pub fn get_average_distance<G: Float>(population: &[Individual<Vec<G>>]) -> f64 {
+    let count = population.len();
+    if count < 2 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for i in 0..count - 1 {
+        for j in i + 1..count {
+            let chromosomes_1 = population[i].get_chromosomes();
+            let chromosomes_2 = population[j].get_chromosomes();
+            assert_eq!(chromosomes_1.len(), chromosomes_2.len());
+
+            let distance_square = chromosomes_1
+                .iter()
+                .zip(chromosomes_2.iter())
+                .fold(G::zero(), |acc, (&x1, &x2)| acc + (x1 - x2) * (x1 - x2));
+            sum += distance_square.sqrt().to_f64().unwrap();
+        }
+    }
+
+    let pairs_count = (count * (count - 1) / 2) as f64;
+    sum / pairs_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::Population;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    fn create_individuals(chromosomes: Vec<Vec<f64>>) -> Vec<Individual<Vec<f64>>> {
+        let mut population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        population.append(chromosomes);
+        population.individuals
+    }
+
+    #[test]
+    fn average_distance_empty() {
+        let individuals = create_individuals(vec![]);
+        assert_eq!(get_average_distance(&individuals), 0.0);
+    }
+
+    #[test]
+    fn average_distance_single() {
+        let individuals = create_individuals(vec![vec![1.0, 2.0]]);
+        assert_eq!(get_average_distance(&individuals), 0.0);
+    }
+
+    #[test]
+    fn average_distance_equal() {
+        let individuals = create_individuals(vec![vec![1.0, 2.0]; 5]);
+        assert_eq!(get_average_distance(&individuals), 0.0);
+    }
+
+    #[test]
+    fn average_distance() {
+        // Distances: 5, 10, 5
+        let individuals = create_individuals(vec![vec![0.0, 0.0], vec![3.0, 4.0], vec![6.0, 8.0]]);
+        assert!((get_average_distance(&individuals) - 20.0 / 3.0).abs() < 1e-10);
+    }
+}
@@ -1,3 +1,90 @@
 //! The module with PreBirth trait implementations.
 
+pub mod permutation;
 pub mod vec_float;
+pub mod vec_int;
+
+use crate::genetic::{Population, PreBirth};
+
+/// The function which returns true if the chromosomes satisfy the constraint.
+type ConstraintFunction<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+/// Remove the new chromosomes if they do not satisfy the constraint. The constraint function
+/// returns true for the feasible chromosomes and false otherwise.
+///
+/// Several constraints can be used together by several `ConstraintChecker` in the pre-births
+/// list of the optimizer. In this case, the chromosomes must satisfy all constraints.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct ConstraintChecker<'a, T> {
+    constraint: ConstraintFunction<'a, T>,
+}
+
+impl<'a, T> ConstraintChecker<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `constraint` - the function which returns true for the feasible chromosomes.
+    pub fn new(constraint: Box<dyn Fn(&T) -> bool + 'a>) -> Self {
+        Self { constraint }
+    }
+}
+
+impl<'a, T> PreBirth<T> for ConstraintChecker<'a, T> {
+    fn pre_birth(&mut self, _population: &Population<T>, new_chromosomes: &mut Vec<T>) {
+        new_chromosomes.retain(|chromosomes| (self.constraint)(chromosomes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    fn create_chromosomes() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![0.9, 0.9],
+            vec![-0.5, 0.5],
+            vec![0.0, -2.0],
+        ]
+    }
+
+    #[test]
+    fn constraint_checker_circle() {
+        let population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        let radius = 1.0;
+        let mut checker = ConstraintChecker::new(Box::new(|x: &Vec<f64>| {
+            x[0] * x[0] + x[1] * x[1] <= radius * radius
+        }));
+
+        let mut chromosomes = create_chromosomes();
+        checker.pre_birth(&population, &mut chromosomes);
+        assert_eq!(
+            chromosomes,
+            vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![-0.5, 0.5]]
+        );
+    }
+
+    #[test]
+    fn constraint_checker_several() {
+        let population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        let mut checkers: Vec<Box<dyn PreBirth<Vec<f64>>>> = vec![
+            Box::new(ConstraintChecker::new(Box::new(|x: &Vec<f64>| {
+                x[0] * x[0] + x[1] * x[1] <= 1.0
+            }))),
+            Box::new(ConstraintChecker::new(Box::new(|x: &Vec<f64>| x[0] >= 0.0))),
+        ];
+
+        let mut chromosomes = create_chromosomes();
+        for checker in &mut checkers {
+            checker.pre_birth(&population, &mut chromosomes);
+        }
+        assert_eq!(chromosomes, vec![vec![0.0, 0.0], vec![1.0, 0.0]]);
+    }
+}
@@ -0,0 +1,89 @@
+//! The module with PreBirth trait implementations for chromosomes which are permutations of
+//! the integers [0; n).
+
+use crate::genetic::{Population, PreBirth};
+
+/// Returns true if `chromosomes` is a permutation of the integers 0, 1, ..., n - 1, where n is
+/// length of `chromosomes` (every integer is present exactly once).
+pub fn is_permutation(chromosomes: &[usize]) -> bool {
+    let mut present = vec![false; chromosomes.len()];
+    for &gene in chromosomes {
+        if gene >= present.len() || present[gene] {
+            return false;
+        }
+        present[gene] = true;
+    }
+
+    true
+}
+
+/// Kill individuals if theirs chromosomes are not valid permutations: the chromosomes length
+/// differs from the expected length, or the chromosomes contains duplicate or missing values.
+pub struct ValidPermutationChecker {
+    n: usize,
+}
+
+impl ValidPermutationChecker {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `n` - length of the permutations.
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl PreBirth<Vec<usize>> for ValidPermutationChecker {
+    fn pre_birth(
+        &mut self,
+        _population: &Population<Vec<usize>>,
+        new_chromosomes: &mut Vec<Vec<usize>>,
+    ) {
+        new_chromosomes
+            .retain(|chromosomes| chromosomes.len() == self.n && is_permutation(chromosomes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn first(x: &Vec<usize>) -> f64 {
+        x[0] as f64
+    }
+
+    #[test]
+    fn is_permutation_values() {
+        assert!(is_permutation(&[]));
+        assert!(is_permutation(&[0]));
+        assert!(is_permutation(&[2, 0, 1]));
+        assert!(is_permutation(&[0, 1, 2, 3]));
+
+        assert!(!is_permutation(&[1]));
+        assert!(!is_permutation(&[0, 0]));
+        assert!(!is_permutation(&[2, 0, 0]));
+        assert!(!is_permutation(&[0, 1, 3]));
+    }
+
+    #[test]
+    fn valid_permutation_checker() {
+        let population = Population::new(Box::new(GoalFromFunction::new(first)));
+        let mut checker = ValidPermutationChecker::new(3);
+
+        let mut chromosomes = vec![
+            vec![0, 1, 2],
+            vec![0, 1],
+            vec![2, 1, 0],
+            vec![1, 1, 0],
+            vec![0, 1, 3],
+            vec![0, 1, 2, 3],
+            vec![1, 2, 0],
+        ];
+        checker.pre_birth(&population, &mut chromosomes);
+        assert_eq!(
+            chromosomes,
+            vec![vec![0, 1, 2], vec![2, 1, 0], vec![1, 2, 0]]
+        );
+    }
+}
@@ -42,3 +42,203 @@ impl<G: Float> CheckChromoInterval<G> {
         true
     }
 }
+
+/// Move the genes which do not lie in the specified intervals to the interval boundaries:
+/// the gene less than the interval minimum is replaced by the minimum, the gene greater than
+/// the interval maximum is replaced by the maximum. The chromosomes with NaN genes are removed.
+///
+/// `G` - type of gene.
+pub struct RepairToBoundary<G: Float> {
+    intervals: Vec<(G, G)>,
+}
+
+impl<G: Float> RepairToBoundary<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - allowed interval for every gene. Count of the genes and count of the
+    ///   interval must be equal.
+    pub fn new(intervals: Vec<(G, G)>) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+        Self { intervals }
+    }
+}
+
+impl<G: Float> PreBirth<Vec<G>> for RepairToBoundary<G> {
+    fn pre_birth(&mut self, _population: &Population<Vec<G>>, new_chromosomes: &mut Vec<Vec<G>>) {
+        new_chromosomes.retain(|chromosomes| chromosomes.iter().all(|chromo| !chromo.is_nan()));
+
+        for chromosomes in new_chromosomes.iter_mut() {
+            assert_eq!(chromosomes.len(), self.intervals.len());
+
+            for (chromo, interval) in chromosomes.iter_mut().zip(self.intervals.iter()) {
+                if *chromo < interval.0 {
+                    *chromo = interval.0;
+                } else if *chromo > interval.1 {
+                    *chromo = interval.1;
+                }
+            }
+        }
+    }
+}
+
+/// Wrap the genes which do not lie in the specified intervals for the periodic search space
+/// (angles, for example). The gene `x` is replaced by min + (x - min) mod (max - min),
+/// so the genes lie in the range [min; max) after the repair. The chromosomes with NaN or
+/// infinite genes are removed.
+///
+/// `G` - type of gene.
+pub struct WrapAroundRepair<G: Float> {
+    intervals: Vec<(G, G)>,
+}
+
+impl<G: Float> WrapAroundRepair<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - the period for every gene. Count of the genes and count of the
+    ///   interval must be equal.
+    pub fn new(intervals: Vec<(G, G)>) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 < interval.1);
+        }
+        Self { intervals }
+    }
+}
+
+impl<G: Float> PreBirth<Vec<G>> for WrapAroundRepair<G> {
+    fn pre_birth(&mut self, _population: &Population<Vec<G>>, new_chromosomes: &mut Vec<Vec<G>>) {
+        new_chromosomes.retain(|chromosomes| chromosomes.iter().all(|chromo| chromo.is_finite()));
+
+        for chromosomes in new_chromosomes.iter_mut() {
+            assert_eq!(chromosomes.len(), self.intervals.len());
+
+            for (chromo, interval) in chromosomes.iter_mut().zip(self.intervals.iter()) {
+                if *chromo < interval.0 || *chromo >= interval.1 {
+                    let width = interval.1 - interval.0;
+                    let offset = ((*chromo - interval.0) % width + width) % width;
+
+                    // The rounding may give the offset equal to the width
+                    *chromo = if offset < width {
+                        interval.0 + offset
+                    } else {
+                        interval.0
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    fn create_population() -> Population<'static, Vec<f64>> {
+        Population::new(Box::new(GoalFromFunction::new(sum)))
+    }
+
+    #[test]
+    fn repair_to_boundary() {
+        let population = create_population();
+        let mut repair = RepairToBoundary::new(vec![(-1.0, 1.0), (0.0, 10.0)]);
+
+        let mut chromosomes = vec![
+            vec![0.5, 5.0],
+            vec![-2.0, 11.0],
+            vec![100.0, -0.1],
+            vec![f64::NEG_INFINITY, f64::INFINITY],
+            vec![-1.0, 10.0],
+        ];
+        repair.pre_birth(&population, &mut chromosomes);
+
+        assert_eq!(
+            chromosomes,
+            vec![
+                vec![0.5, 5.0],
+                vec![-1.0, 10.0],
+                vec![1.0, 0.0],
+                vec![-1.0, 10.0],
+                vec![-1.0, 10.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn repair_to_boundary_nan() {
+        let population = create_population();
+        let mut repair = RepairToBoundary::new(vec![(-1.0, 1.0), (0.0, 10.0)]);
+
+        let mut chromosomes = vec![vec![f64::NAN, 5.0], vec![0.0, 5.0]];
+        repair.pre_birth(&population, &mut chromosomes);
+        assert_eq!(chromosomes, vec![vec![0.0, 5.0]]);
+    }
+
+    #[test]
+    fn wrap_around_repair() {
+        let population = create_population();
+        let mut repair = WrapAroundRepair::new(vec![(-1.0, 1.0), (0.0, 10.0)]);
+
+        let mut chromosomes = vec![
+            vec![0.5, 5.0],
+            vec![1.5, 12.0],
+            vec![-1.5, -3.0],
+            vec![1.0, 10.0],
+            vec![-1.0, 0.0],
+        ];
+        repair.pre_birth(&population, &mut chromosomes);
+
+        assert_eq!(
+            chromosomes,
+            vec![
+                vec![0.5, 5.0],
+                vec![-0.5, 2.0],
+                vec![0.5, 7.0],
+                vec![-1.0, 0.0],
+                vec![-1.0, 0.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_around_repair_far() {
+        let population = create_population();
+        let mut repair = WrapAroundRepair::new(vec![(0.0, 360.0)]);
+
+        let mut chromosomes = vec![
+            vec![360.0 * 1000.0 + 90.0],
+            vec![-360.0 * 1000.0 - 90.0],
+            vec![360.0 * 7.0],
+        ];
+        repair.pre_birth(&population, &mut chromosomes);
+
+        let expected = [90.0, 270.0, 0.0];
+        for (chromo, expected_chromo) in chromosomes.iter().zip(expected.iter()) {
+            assert!((chromo[0] - expected_chromo).abs() < 1e-6);
+            assert!(chromo[0] >= 0.0 && chromo[0] < 360.0);
+        }
+    }
+
+    #[test]
+    fn wrap_around_repair_not_finite() {
+        let population = create_population();
+        let mut repair = WrapAroundRepair::new(vec![(0.0, 1.0)]);
+
+        let mut chromosomes = vec![vec![f64::NAN], vec![f64::INFINITY], vec![0.5]];
+        repair.pre_birth(&population, &mut chromosomes);
+        assert_eq!(chromosomes, vec![vec![0.5]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrap_around_repair_invalid_interval() {
+        WrapAroundRepair::new(vec![(1.0_f64, 1.0)]);
+    }
+}
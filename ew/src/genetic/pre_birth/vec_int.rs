@@ -0,0 +1,85 @@
+//! The module with PreBirth trait implementations for chromosomes of Vec<G> type, where G is
+//! an integer type.
+
+use num::PrimInt;
+
+use crate::genetic::{Population, PreBirth};
+
+/// Kill individuals if theirs gene does not lie in the specified intervals.
+///
+/// `G` - type of gene.
+pub struct CheckChromoInterval<G: PrimInt> {
+    intervals: Vec<(G, G)>,
+}
+
+impl<G: PrimInt> CheckChromoInterval<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `intervals` - allowed interval for every gene (the boundaries are included). Count of
+    ///   the genes and count of the interval must be equal.
+    pub fn new(intervals: Vec<(G, G)>) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+        Self { intervals }
+    }
+
+    fn check_chromo(&self, chromosomes: &[G]) -> bool {
+        assert_eq!(chromosomes.len(), self.intervals.len());
+
+        chromosomes
+            .iter()
+            .zip(self.intervals.iter())
+            .all(|(chromo, interval)| *chromo >= interval.0 && *chromo <= interval.1)
+    }
+}
+
+impl<G: PrimInt> PreBirth<Vec<G>> for CheckChromoInterval<G> {
+    fn pre_birth(&mut self, _population: &Population<Vec<G>>, new_chromosomes: &mut Vec<Vec<G>>) {
+        new_chromosomes.retain(|chromosomes| self.check_chromo(chromosomes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<i64>) -> f64 {
+        x.iter().sum::<i64>() as f64
+    }
+
+    #[test]
+    fn check_chromo_interval() {
+        let population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        let mut checker = CheckChromoInterval::new(vec![(-1, 1), (0, 10)]);
+
+        let mut chromosomes = vec![
+            vec![0, 5],
+            vec![-2, 5],
+            vec![1, 11],
+            vec![-1, 0],
+            vec![1, 10],
+            vec![0, -1],
+        ];
+        checker.pre_birth(&population, &mut chromosomes);
+        assert_eq!(chromosomes, vec![vec![0, 5], vec![-1, 0], vec![1, 10]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_chromo_interval_invalid_interval() {
+        CheckChromoInterval::new(vec![(1_i64, 0_i64)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_chromo_interval_invalid_length() {
+        let population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        let mut checker = CheckChromoInterval::new(vec![(-1, 1), (0, 10)]);
+
+        let mut chromosomes = vec![vec![0]];
+        checker.pre_birth(&population, &mut chromosomes);
+    }
+}
@@ -52,26 +52,109 @@ impl<T: Clone> Selection<T> for LimitPopulation {
     }
 }
 
+/// Elitism. The selection protects the `elite_count` best alive individuals, so the next
+/// selections can't kill them and the best solution is never lost. The selection must be applied
+/// before other selections (after `KillFitnessNaN`). The individuals with NaN value of the goal
+/// function are not protected.
+///
+/// The protected individuals are counted by `LimitPopulation` as the alive individuals,
+/// so the other individuals are killed to limit the population size. If `elite_count` is greater
+/// than the population limit, the population will contain `elite_count` individuals after
+/// the selection.
+///
+/// The protection is removed after the selection stage of every generation.
+pub struct ElitismSelection {
+    elite_count: usize,
+}
+
+impl ElitismSelection {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `elite_count` - count of the best individuals which go into the next generation.
+    pub fn new(elite_count: usize) -> Self {
+        Self { elite_count }
+    }
+}
+
+impl<T: Clone> Selection<T> for ElitismSelection {
+    fn kill(&mut self, population: &mut Population<T>) {
+        let mut candidates: Vec<usize> = (0..population.len())
+            .filter(|&n| population[n].is_alive() && !population[n].get_fitness().is_nan())
+            .collect();
+
+        candidates.sort_by(|&a, &b| {
+            population[a]
+                .get_fitness()
+                .partial_cmp(&population[b].get_fitness())
+                .unwrap()
+        });
+
+        for &n in candidates.iter().take(self.elite_count) {
+            population[n].protect();
+        }
+    }
+}
+
+/// Kill individuals which are older than the max age. The age of the individual is difference
+/// between the current generation number and the birth generation of the individual
+/// (`Individual::get_birth_generation()`). The children have the age 0 in the selection stage,
+/// so the selection with `max_age` equal to 0 kills all parents.
+///
+/// The selection prevents the old dominant individuals from blocking exploration, so the best
+/// solution may be lost. Use `ElitismSelection` before the selection to keep the best
+/// individuals regardless of their age.
+pub struct AgeBasedSelection {
+    max_age: usize,
+}
+
+impl AgeBasedSelection {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `max_age` - the max count of generations the individual can live.
+    pub fn new(max_age: usize) -> Self {
+        Self { max_age }
+    }
+}
+
+impl<T: Clone> Selection<T> for AgeBasedSelection {
+    fn kill(&mut self, population: &mut Population<T>) {
+        let generation = population.get_iteration();
+        for individual in population.iter_mut() {
+            if generation - individual.get_birth_generation() > self.max_age {
+                individual.kill();
+            }
+        }
+    }
+}
+
 /// Function to kill worst individuals in population.
-/// `count` - how many individuals must be killed.
+/// `count` - how many individuals must be killed. The protected individuals are not killed.
 pub fn kill_worst<T: Clone>(population: &mut Population<T>, count: usize) {
+    // Indexes of individuals which can be killed
+    let candidates: Vec<usize> = (0..population.len())
+        .filter(|&n| population[n].is_alive() && !population[n].is_protected())
+        .collect();
+
+    if count == 0 || candidates.is_empty() {
+        return;
+    }
+
     // List of indexes of individuals in population to be kill
     let mut kill_list: Vec<usize> = Vec::with_capacity(count);
-    kill_list.push(0);
+    kill_list.push(candidates[0]);
 
     // Index of the items in kill_list with best fitness
     let mut best_index = 0;
     let mut best_fitness = population[kill_list[best_index]].get_fitness();
 
-    for n in 1..population.len() {
-        if !population[n].is_alive() {
-            continue;
-        }
-
+    for &n in &candidates[1..] {
         if kill_list.len() < count {
             kill_list.push(n);
             if population[n].get_fitness() < best_fitness {
                 best_index = kill_list.len() - 1;
+                best_fitness = population[n].get_fitness();
             }
         } else {
             if population[n].get_fitness() > best_fitness {
@@ -94,3 +177,125 @@ pub fn kill_worst<T: Clone>(population: &mut Population<T>, count: usize) {
         population[n].kill();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GoalFromFunction;
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    /// Create population with the given values of goal function.
+    fn create_population(goal_values: &[f64]) -> Population<'static, Vec<f64>> {
+        let mut population = Population::new(Box::new(GoalFromFunction::new(sum)));
+        population.append(goal_values.iter().map(|value| vec![*value]).collect());
+        population
+    }
+
+    /// Returns values of the goal function for the alive individuals.
+    fn get_alive_goals(population: &Population<Vec<f64>>) -> Vec<f64> {
+        population
+            .iter()
+            .filter(|individual| individual.is_alive())
+            .map(|individual| individual.get_fitness())
+            .collect()
+    }
+
+    #[test]
+    fn limit_population() {
+        let mut population = create_population(&[5.0, 1.0, 4.0, 2.0, 3.0]);
+        LimitPopulation::new(3).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn kill_worst_zero_count() {
+        let mut population = create_population(&[5.0, 1.0, 4.0]);
+        kill_worst(&mut population, 0);
+        assert_eq!(get_alive_goals(&population), vec![5.0, 1.0, 4.0]);
+    }
+
+    #[test]
+    fn kill_worst_better_second() {
+        // The second individual is better than the first one
+        let mut population = create_population(&[5.0, 1.0, 4.0]);
+        kill_worst(&mut population, 2);
+        assert_eq!(get_alive_goals(&population), vec![1.0]);
+    }
+
+    #[test]
+    fn kill_worst_first_dead() {
+        // The dead individual must not be counted
+        let mut population = create_population(&[5.0, 1.0, 4.0, 2.0, 3.0]);
+        population[0].kill();
+        kill_worst(&mut population, 2);
+        assert_eq!(get_alive_goals(&population), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn elitism_selection() {
+        let mut population = create_population(&[5.0, 1.0, 4.0, f64::NAN, 2.0, 3.0]);
+        ElitismSelection::new(2).kill(&mut population);
+
+        let protected: Vec<bool> = population.iter().map(|ind| ind.is_protected()).collect();
+        assert_eq!(protected, vec![false, true, false, false, true, false]);
+
+        // The protected individuals can't be killed
+        for individual in population.iter_mut() {
+            individual.kill();
+        }
+        assert_eq!(get_alive_goals(&population), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn elitism_selection_limit_population() {
+        let mut population = create_population(&[5.0, 1.0, 4.0, 2.0, 3.0]);
+        population[1].kill();
+
+        // The elites count toward the population limit
+        ElitismSelection::new(2).kill(&mut population);
+        LimitPopulation::new(3).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![4.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn elitism_selection_greater_limit() {
+        let mut population = create_population(&[5.0, 1.0, 4.0, 2.0, 3.0]);
+
+        ElitismSelection::new(3).kill(&mut population);
+        LimitPopulation::new(1).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn age_based_selection() {
+        let mut population = create_population(&[1.0, 2.0]);
+        population.next_iteration();
+        population.append(vec![vec![3.0]]);
+        population.next_iteration();
+        population.append(vec![vec![4.0]]);
+
+        let birth: Vec<usize> = population.iter().map(|ind| ind.get_birth_generation()).collect();
+        assert_eq!(birth, vec![0, 0, 1, 2]);
+
+        AgeBasedSelection::new(1).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![3.0, 4.0]);
+
+        AgeBasedSelection::new(0).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![4.0]);
+    }
+
+    #[test]
+    fn age_based_selection_elitism() {
+        let mut population = create_population(&[1.0, 2.0]);
+        population.next_iteration();
+        population.append(vec![vec![3.0]]);
+
+        // The elite individual survives regardless of its age
+        ElitismSelection::new(1).kill(&mut population);
+        AgeBasedSelection::new(0).kill(&mut population);
+        assert_eq!(get_alive_goals(&population), vec![1.0, 3.0]);
+    }
+}
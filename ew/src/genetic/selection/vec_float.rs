@@ -41,3 +41,180 @@ impl<G: Float> Selection<Vec<G>> for CheckChromoInterval<G> {
         }
     }
 }
+
+/// Fitness sharing selection for multimodal optimization. The selection kills the individuals
+/// with the worst shared fitness to limit the population size, so the population keeps several
+/// distinct solutions instead of converging to a single optimum.
+///
+/// The fitness of the individual is 1 / (goal - min_goal + epsilon) (as for
+/// `RoulettePairing`), where `goal` - value of the goal function for the individual,
+/// `min_goal` - the least value of the goal function in the population. The shared fitness of
+/// the individual `i` is fitness(i) / sum_j sh(d(i, j)), where `d` is Euclidean distance between
+/// chromosomes, sh(d) = 1 if d < sigma_share and 0 otherwise. The sum includes the individual
+/// itself.
+///
+/// The individuals with NaN or infinite value of the goal function are killed first.
+/// The protected individuals (see `ElitismSelection`) are not killed but are counted in the
+/// population size.
+///
+/// `G` - type of gene.
+pub struct NichingSelection<G: Float> {
+    sigma_share: G,
+    max_count: usize,
+    epsilon: f64,
+}
+
+impl<G: Float> NichingSelection<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `sigma_share` - the niche radius. Must be greater than 0.
+    /// * `max_count` - the max population size after the selection.
+    pub fn new(sigma_share: G, max_count: usize) -> Self {
+        assert!(sigma_share > G::zero());
+        Self {
+            sigma_share,
+            max_count,
+            epsilon: 1.0,
+        }
+    }
+
+    /// Set the `epsilon` value in the fitness. Must be greater than 0. The default is 1.
+    pub fn set_epsilon(&mut self, epsilon: f64) {
+        assert!(epsilon > 0.0);
+        self.epsilon = epsilon;
+    }
+
+    /// Returns count of the alive individuals closer than `sigma_share` to the chromosomes.
+    fn get_niche_count(&self, population: &Population<Vec<G>>, chromosomes: &[G]) -> usize {
+        population
+            .iter()
+            .filter(|individual| individual.is_alive())
+            .filter(|individual| {
+                let distance = individual
+                    .get_chromosomes()
+                    .iter()
+                    .zip(chromosomes.iter())
+                    .fold(G::zero(), |sum, (x, y)| sum + (*x - *y) * (*x - *y))
+                    .sqrt();
+                distance < self.sigma_share
+            })
+            .count()
+    }
+}
+
+impl<G: Float> Selection<Vec<G>> for NichingSelection<G> {
+    fn kill(&mut self, population: &mut Population<Vec<G>>) {
+        let alive_count = population.len_alive();
+        if alive_count <= self.max_count {
+            return;
+        }
+
+        let min_goal = population
+            .iter()
+            .filter(|individual| individual.is_alive())
+            .map(|individual| individual.get_fitness())
+            .filter(|goal| goal.is_finite())
+            .fold(f64::INFINITY, f64::min);
+
+        // The inverse shared fitness for the individuals which can be killed
+        let mut candidates: Vec<(usize, f64)> = (0..population.len())
+            .filter(|&n| population[n].is_alive() && !population[n].is_protected())
+            .map(|n| {
+                let goal = population[n].get_fitness();
+                let value = if goal.is_finite() {
+                    let niche_count =
+                        self.get_niche_count(population, population[n].get_chromosomes());
+                    (goal - min_goal + self.epsilon) * niche_count as f64
+                } else {
+                    f64::INFINITY
+                };
+                (n, value)
+            })
+            .collect();
+
+        // The worst individuals are first
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+        for (n, _) in candidates.iter().take(alive_count - self.max_count) {
+            population[*n].kill();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::selection::LimitPopulation;
+    use crate::GoalFromFunction;
+
+    fn sum_abs(x: &Vec<f64>) -> f64 {
+        x.iter().map(|x| x.abs()).sum()
+    }
+
+    fn create_population(chromosomes: Vec<Vec<f64>>) -> Population<'static, Vec<f64>> {
+        let mut population = Population::new(Box::new(GoalFromFunction::new(sum_abs)));
+        population.append(chromosomes);
+        population
+    }
+
+    fn get_alive_chromosomes(population: &Population<Vec<f64>>) -> Vec<Vec<f64>> {
+        population
+            .iter()
+            .filter(|individual| individual.is_alive())
+            .map(|individual| individual.get_chromosomes().clone())
+            .collect()
+    }
+
+    /// The cluster of good individuals near zero and single worse individual far away.
+    fn create_clustered_population() -> Population<'static, Vec<f64>> {
+        create_population(vec![
+            vec![0.0, 0.0],
+            vec![0.1, 0.0],
+            vec![0.0, 0.1],
+            vec![0.1, 0.1],
+            vec![3.0, 0.0],
+        ])
+    }
+
+    #[test]
+    fn niching_selection() {
+        let mut population = create_clustered_population();
+        NichingSelection::new(1.0, 3).kill(&mut population);
+
+        // The far individual survives
+        let alive = get_alive_chromosomes(&population);
+        assert_eq!(alive.len(), 3);
+        assert!(alive.contains(&vec![0.0, 0.0]));
+        assert!(alive.contains(&vec![3.0, 0.0]));
+    }
+
+    #[test]
+    fn niching_selection_compare_limit() {
+        let mut population = create_clustered_population();
+        LimitPopulation::new(3).kill(&mut population);
+
+        let alive = get_alive_chromosomes(&population);
+        assert!(!alive.contains(&vec![3.0, 0.0]));
+    }
+
+    #[test]
+    fn niching_selection_nan() {
+        let mut population = create_population(vec![vec![f64::NAN], vec![0.0], vec![5.0]]);
+        NichingSelection::new(1.0, 2).kill(&mut population);
+        assert_eq!(get_alive_chromosomes(&population), vec![vec![0.0], vec![5.0]]);
+    }
+
+    #[test]
+    fn niching_selection_small_population() {
+        let mut population = create_clustered_population();
+        NichingSelection::new(1.0, 5).kill(&mut population);
+        assert_eq!(population.len_alive(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn niching_selection_invalid_sigma() {
+        NichingSelection::new(0.0, 5);
+    }
+}
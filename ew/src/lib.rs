@@ -2,8 +2,19 @@
 //! The crate uses common traits for easy switch between algorithms.
 extern crate num;
 
+use std::marker::PhantomData;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod cmaes;
+pub mod de;
 pub mod genetic;
+pub mod multistart;
+pub mod neldermead;
+pub mod nsga2;
 pub mod particleswarm;
+pub mod sa;
 pub mod tools;
 
 type GoalValue = f64;
@@ -12,6 +23,30 @@ type GoalValue = f64;
 /// second item is current goal value
 type Solution<T> = (T, GoalValue);
 
+/// Direction of the optimization.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Search minimum of the goal function (`Optimizer::find_min`).
+    Minimize,
+
+    /// Search maximum of the goal function (`Optimizer::find_max`).
+    Maximize,
+}
+
+impl Direction {
+    /// Convert value of the goal function to the value which algorithm must minimize and back.
+    pub fn apply(self, value: GoalValue) -> GoalValue {
+        match self {
+            Direction::Minimize => value,
+            Direction::Maximize => -value,
+        }
+    }
+}
+
+/// The marker type for the optimizers builders. It means that a required component is not set yet.
+pub struct NotSet;
+
 /// Common Optimizer trait.
 ///
 /// `T` - type of a point in search space for goal function.
@@ -22,16 +57,85 @@ pub trait Optimizer<T> {
     /// `goal` - value of goal function. Returns `None` if an algoritm can't find minimum of a goal function.
     ///
     /// # Remarks
-    /// All algorithms with `Optimizer` must search minimum of a goal function.
+    /// The method searches minimum of a goal function. Use `find_max` to search maximum.
     fn find_min(&mut self) -> Option<Solution<T>>;
+
+    /// Run an algorithm to search maximum of a goal function.
+    ///
+    /// Returns `Some((x: &T, goal: GoalValue))`, where `x` - result of optimization,
+    /// `goal` - value of goal function. Returns `None` if an algoritm can't find maximum of a goal function.
+    ///
+    /// # Remarks
+    /// The algorithm minimizes the negated goal function, but loggers and stop checkers get
+    /// the original (not negated) values of the goal function. The stop checkers which compare
+    /// the values of the goal function use `AlgorithmState::get_direction`.
+    fn find_max(&mut self) -> Option<Solution<T>>;
+
+    /// Remove the current algorithm state (agents, best solution, iteration number etc)
+    /// and reset the stop checker, but keep all settings of the algorithm.
+    ///
+    /// `find_min` and `find_max` always start from scratch, so the method is useful
+    /// for manual iterating with `IterativeOptimizer::step`.
+    fn reset(&mut self);
+
+    /// Run an algorithm to search minimum of a goal function and call `callback` in the end
+    /// of every iteration. It is the lightweight alternative to `FnLogger` for simple monitoring.
+    ///
+    /// The callback gets the iteration number (starting from 1) and the current best solution
+    /// (point in the search space and value of the goal function) or `None` if the algorithm
+    /// has not found a valid solution yet.
+    /// Returns the same result as `find_min`. The loggers get the same calls as for `find_min`.
+    ///
+    /// # Remarks
+    /// The method is available for the algorithms which implement `IterativeOptimizer`.
+    /// The algorithm state is reset before the running.
+    fn find_min_with_callback<F>(&mut self, mut callback: F) -> Option<Solution<T>>
+    where
+        Self: IterativeOptimizer<T> + Sized,
+        F: FnMut(usize, Option<(&T, GoalValue)>),
+    {
+        self.reset();
+        self.next_iterations_with_callback(&mut callback)
+    }
 }
 
 /// The trait for iterative algorithms.
 ///
 /// `T` - type of a point in search space for goal function.
 pub trait IterativeOptimizer<T> {
-    /// The method can be called after algorithm initialization.
-    fn next_iterations(&mut self) -> Option<Solution<T>>;
+    /// Do iterations of the algorithm until the stop checker stops it.
+    /// By default it calls `next_iterations_with_callback` without a callback.
+    fn next_iterations(&mut self) -> Option<Solution<T>> {
+        self.next_iterations_with_callback(&mut |_, _| {})
+    }
+
+    /// Do iterations of the algorithm until the stop checker stops it and call `callback`
+    /// in the end of every iteration with the iteration number (starting from 1 for every call
+    /// of the method) and the current best solution (`None` if there is no valid solution yet).
+    /// If the algorithm was not initialized, the method initializes it like `step`.
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&T, GoalValue)>),
+    ) -> Option<Solution<T>>;
+
+    /// Do single iteration of the algorithm if the stop checker does not stop it.
+    ///
+    /// Returns the best solution after the iteration or `None` if the stop checker stopped
+    /// the algorithm. If the algorithm was not initialized, the first call initializes it
+    /// (searching for the minimum, unless `find_max` was called before).
+    /// The loggers are not notified about the algorithm finish.
+    fn step(&mut self) -> Option<Solution<T>>;
+}
+
+/// Call the iteration callback of `IterativeOptimizer::next_iterations_with_callback` with
+/// the best solution of the state.
+pub(crate) fn call_iteration_callback<T>(
+    callback: &mut dyn FnMut(usize, Option<(&T, GoalValue)>),
+    iteration: usize,
+    state: &dyn AlgorithmState<T>,
+) {
+    let best = state.get_best_solution();
+    callback(iteration, best.as_ref().map(|(x, goal)| (x, *goal)));
 }
 
 /// The trait for a struct with information about current algorithm state.
@@ -41,6 +145,18 @@ pub trait IterativeOptimizer<T> {
 pub trait AlgorithmState<T> {
     fn get_best_solution(&self) -> Option<Solution<T>>;
     fn get_iteration(&self) -> usize;
+
+    /// Returns direction of the optimization: `Direction::Maximize` after `find_max` and
+    /// `Direction::Minimize` otherwise.
+    fn get_direction(&self) -> Direction {
+        Direction::Minimize
+    }
+
+    /// Returns the points in the search space for all agents (individuals, particles etc).
+    /// Returns empty vector if algorithm does not use agents.
+    fn get_agents_parameters(&self) -> Vec<&T> {
+        vec![]
+    }
 }
 
 /// The trait for algotithms where use agents (genetic algorithm, partical swarm algorithm etc).
@@ -61,6 +177,10 @@ pub trait Agent<T> {
     fn get_parameter(&self) -> &T;
 
     /// Returns value of a goal function for current agent.
+    ///
+    /// The value is the value which the algorithm minimizes, so it is the negated value of
+    /// the goal function after `find_max`. Use `AlgorithmState::get_direction` and
+    /// `Direction::apply` to get the original value.
     fn get_goal(&self) -> GoalValue;
 }
 
@@ -70,20 +190,153 @@ pub trait Goal<T> {
     fn get(&mut self, x: &T) -> GoalValue;
 }
 
-/// Struct to convert (wrap) function to `Goal` trait.
-pub struct GoalFromFunction<T> {
-    function: fn(&T) -> GoalValue,
+/// The goal function may be passed to an optimizer by mutable reference to inspect its state
+/// after the algorithm running.
+impl<T, G: Goal<T> + ?Sized> Goal<T> for &mut G {
+    fn get(&mut self, x: &T) -> GoalValue {
+        (**self).get(x)
+    }
+}
+
+/// The trait for the goal function which may be calculated in several threads simultaneously.
+///
+/// The trait is available with the "parallel" feature.
+#[cfg(feature = "parallel")]
+pub trait SyncGoal<T>: Sync {
+    /// Must return value of goal function for the point in the search space (x).
+    fn get(&self, x: &T) -> GoalValue;
+}
+
+/// The function to calculate goal functions for the pairs (goal function, point)
+/// in several threads.
+#[cfg(feature = "parallel")]
+pub(crate) type ParallelCalcFunction<T> = fn(&[(&dyn SyncGoal<T>, &T)]) -> Vec<GoalValue>;
+
+/// Calculate goal functions for the pairs (goal function, point) in several threads.
+#[cfg(feature = "parallel")]
+pub(crate) fn calc_parallel<T: Sync>(tasks: &[(&dyn SyncGoal<T>, &T)]) -> Vec<GoalValue> {
+    tasks.par_iter().map(|(goal, x)| goal.get(x)).collect()
+}
+
+/// Struct to convert (wrap) function or closure to `Goal` trait.
+///
+/// `T` - type of a point in the search space for goal function.
+/// `F` - type of the function. The closures may capture variables from the surrounding scope
+/// (the configuration of the problem, for example).
+///
+/// # Examples
+///
+/// ```
+/// use ew::{Goal, GoalFromFunction};
+///
+/// let target = vec![1.0, 2.0];
+/// let mut goal = GoalFromFunction::new(|x: &Vec<f64>| {
+///     x.iter().zip(target.iter()).map(|(x_i, t_i)| (x_i - t_i).powi(2)).sum()
+/// });
+///
+/// assert_eq!(goal.get(&vec![1.0, 2.0]), 0.0);
+/// assert_eq!(goal.get(&vec![0.0, 0.0]), 5.0);
+/// ```
+pub struct GoalFromFunction<T, F = fn(&T) -> GoalValue> {
+    function: F,
+    phantom: PhantomData<fn(&T)>,
 }
 
-impl<T> GoalFromFunction<T> {
+impl<T, F: Fn(&T) -> GoalValue> GoalFromFunction<T, F> {
     /// Constructor.
-    pub fn new(function: fn(&T) -> GoalValue) -> Self {
-        Self { function }
+    ///
+    /// # Parameters
+    /// * `function` - the function or the closure which returns value of goal function for
+    ///   the point in the search space.
+    pub fn new(function: F) -> Self {
+        Self {
+            function,
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<T> Goal<T> for GoalFromFunction<T> {
+impl<T, F: Fn(&T) -> GoalValue> Goal<T> for GoalFromFunction<T, F> {
     fn get(&mut self, x: &T) -> GoalValue {
         (self.function)(x)
     }
 }
+
+impl<T, F: Clone> Clone for GoalFromFunction<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            function: self.function.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, F: Fn(&T) -> GoalValue + Sync> SyncGoal<T> for GoalFromFunction<T, F> {
+    fn get(&self, x: &T) -> GoalValue {
+        (self.function)(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Goal, GoalFromFunction};
+
+    fn sum(x: &Vec<f64>) -> f64 {
+        x.iter().sum()
+    }
+
+    #[test]
+    fn goal_from_function() {
+        let mut goal = GoalFromFunction::new(sum);
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn goal_from_function_pointer() {
+        let mut goal: GoalFromFunction<Vec<f64>> = GoalFromFunction::new(sum);
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn goal_from_closure() {
+        let weights = vec![1.0, 10.0];
+        let mut goal = GoalFromFunction::new(|x: &Vec<f64>| {
+            x.iter().zip(weights.iter()).map(|(x_i, w_i)| x_i * w_i).sum()
+        });
+
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 21.0);
+        assert_eq!(goal.get(&vec![0.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn goal_from_mutable_state() {
+        // The closure may read the state which is changed outside
+        let offset = std::cell::Cell::new(0.0);
+        let mut goal = GoalFromFunction::new(|x: &Vec<f64>| sum(x) + offset.get());
+        assert_eq!(goal.get(&vec![1.0]), 1.0);
+
+        offset.set(5.0);
+        assert_eq!(goal.get(&vec![1.0]), 6.0);
+    }
+
+    #[test]
+    fn goal_from_closure_boxed() {
+        let scale = 2.0;
+        let goal: Box<dyn Goal<Vec<f64>>> =
+            Box::new(GoalFromFunction::new(move |x: &Vec<f64>| scale * sum(x)));
+
+        let mut goal = goal;
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 6.0);
+    }
+
+    #[test]
+    fn goal_from_function_clone() {
+        let scale = 2.0;
+        let mut goal = GoalFromFunction::new(move |x: &Vec<f64>| scale * sum(x));
+        let mut goal_clone = goal.clone();
+
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 6.0);
+        assert_eq!(goal_clone.get(&vec![1.0, 2.0]), 6.0);
+    }
+}
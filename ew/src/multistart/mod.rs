@@ -0,0 +1,405 @@
+//! The module with the multi-start wrapper for any optimizer.
+//!
+//! `MultiStartOptimizer` creates and runs an optimizer several times, gathers the results of
+//! all runnings into `Statistics` and returns the best result. Some of the later runnings may
+//! start from the best solution found so far (warm start).
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::tools::statistics::Statistics;
+use crate::{Direction, Optimizer, Solution};
+
+/// The multi-start optimizer. The optimizer implements the `Optimizer` trait.
+///
+/// `T` - type of a point in the search space for goal function.
+/// `F` - function to create the optimizer for every running. The function gets the start point
+/// for the warm start or `None` for the ordinary running.
+///
+/// # Example
+/// ```
+/// use ew::multistart::MultiStartOptimizer;
+/// use ew::neldermead::NelderMeadOptimizer;
+/// use ew::tools::stopchecker::MaxIterations;
+/// use ew::{GoalFromFunction, Optimizer};
+///
+/// fn goal(x: &Vec<f64>) -> f64 {
+///     (x[0] - 1.0).powi(2)
+/// }
+///
+/// let mut optimizer = MultiStartOptimizer::new(
+///     |start: Option<&Vec<f64>>| -> Box<dyn Optimizer<Vec<f64>>> {
+///         Box::new(NelderMeadOptimizer::new(
+///             Box::new(GoalFromFunction::new(goal)),
+///             Box::new(MaxIterations::new(100)),
+///             start.cloned().unwrap_or_else(|| vec![10.0]),
+///             vec![1.0],
+///         ))
+///     },
+///     5,
+/// );
+///
+/// let (solution, goal_value) = optimizer.find_min().unwrap();
+/// assert!((solution[0] - 1.0).abs() < 1e-3);
+/// assert!(goal_value < 1e-6);
+/// assert_eq!(optimizer.get_statistics().get_run_count(), 5);
+/// ```
+pub struct MultiStartOptimizer<T, F> {
+    create_optimizer: F,
+    run_count: usize,
+    warm_start_fraction: f64,
+    statistics: Statistics<T>,
+}
+
+/// Returns true if the `new` solution is better than the `best` solution.
+/// The solutions with NaN value of the goal function are never better.
+fn is_better<T>(
+    new: &Option<Solution<T>>,
+    best: &Option<Solution<T>>,
+    direction: Direction,
+) -> bool {
+    match (new, best) {
+        (Some((_, new_value)), _) if new_value.is_nan() => false,
+        (Some(_), None) => true,
+        (Some((_, new_value)), Some((_, best_value))) => {
+            best_value.is_nan() || direction.apply(*new_value) < direction.apply(*best_value)
+        }
+        (None, _) => false,
+    }
+}
+
+impl<'a, T, F> MultiStartOptimizer<T, F>
+where
+    T: Clone,
+    F: Fn(Option<&T>) -> Box<dyn Optimizer<T> + 'a>,
+{
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `create_optimizer` - function to create the optimizer for every running.
+    /// * `run_count` - count of the runnings. Must be greater than 0.
+    pub fn new(create_optimizer: F, run_count: usize) -> Self {
+        assert!(run_count > 0);
+        Self {
+            create_optimizer,
+            run_count,
+            warm_start_fraction: 0.0,
+            statistics: Statistics::new(),
+        }
+    }
+
+    /// Set the fraction of the runnings which start from the best solution found so far.
+    /// The warm starts are the last runnings, the first running never uses the warm start.
+    /// The fraction must be in the range [0; 1]. The default value is 0.
+    pub fn set_warm_start_fraction(&mut self, warm_start_fraction: f64) {
+        assert!((0.0..=1.0).contains(&warm_start_fraction));
+        self.warm_start_fraction = warm_start_fraction;
+    }
+
+    /// Returns the results of all runnings of the last `find_min` or `find_max` call.
+    /// The statistics do not contain the convergence data (the convergence of every running is empty).
+    pub fn get_statistics(&self) -> &Statistics<T> {
+        &self.statistics
+    }
+
+    /// Returns count of the runnings with the warm start.
+    fn get_warm_start_count(&self) -> usize {
+        let count = (self.run_count as f64 * self.warm_start_fraction).round() as usize;
+        count.min(self.run_count - 1)
+    }
+
+    fn run_single(&self, start: Option<&T>, direction: Direction) -> Option<Solution<T>> {
+        let mut optimizer = (self.create_optimizer)(start);
+        match direction {
+            Direction::Minimize => optimizer.find_min(),
+            Direction::Maximize => optimizer.find_max(),
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<T>> {
+        self.statistics = Statistics::new();
+        let warm_start_count = self.get_warm_start_count();
+        let mut best: Option<Solution<T>> = None;
+
+        for n in 0..self.run_count {
+            let start = if n >= self.run_count - warm_start_count {
+                best.as_ref().map(|(point, _)| point)
+            } else {
+                None
+            };
+
+            let result = self.run_single(start, direction);
+            if is_better(&result, &best, direction) {
+                best = result.clone();
+            }
+            self.statistics.add_run_result(result, direction);
+        }
+
+        best
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, T, F> MultiStartOptimizer<T, F>
+where
+    T: Clone + Send + Sync,
+    F: Fn(Option<&T>) -> Box<dyn Optimizer<T> + 'a> + Sync,
+{
+    /// Run the optimizer in several threads to search the minimum of the goal function.
+    /// The runnings with the warm start begin after all other runnings and start from the best
+    /// solution found by the other runnings.
+    ///
+    /// The method is available with the "parallel" feature.
+    pub fn find_min_parallel(&mut self) -> Option<Solution<T>> {
+        self.run_parallel(Direction::Minimize)
+    }
+
+    /// Run the optimizer in several threads to search the maximum of the goal function.
+    /// The runnings with the warm start begin after all other runnings and start from the best
+    /// solution found by the other runnings.
+    ///
+    /// The method is available with the "parallel" feature.
+    pub fn find_max_parallel(&mut self) -> Option<Solution<T>> {
+        self.run_parallel(Direction::Maximize)
+    }
+
+    fn run_batch_parallel(
+        &self,
+        count: usize,
+        start: Option<&T>,
+        direction: Direction,
+    ) -> Vec<Option<Solution<T>>> {
+        (0..count)
+            .into_par_iter()
+            .map(|_| self.run_single(start, direction))
+            .collect()
+    }
+
+    fn run_parallel(&mut self, direction: Direction) -> Option<Solution<T>> {
+        self.statistics = Statistics::new();
+        let warm_start_count = self.get_warm_start_count();
+        let mut best: Option<Solution<T>> = None;
+
+        let results = self.run_batch_parallel(self.run_count - warm_start_count, None, direction);
+        for result in results {
+            if is_better(&result, &best, direction) {
+                best = result.clone();
+            }
+            self.statistics.add_run_result(result, direction);
+        }
+
+        let start = best.as_ref().map(|(point, _)| point.clone());
+        let results = self.run_batch_parallel(warm_start_count, start.as_ref(), direction);
+        for result in results {
+            if is_better(&result, &best, direction) {
+                best = result.clone();
+            }
+            self.statistics.add_run_result(result, direction);
+        }
+
+        best
+    }
+}
+
+impl<'a, T, F> Optimizer<T> for MultiStartOptimizer<T, F>
+where
+    T: Clone,
+    F: Fn(Option<&T>) -> Box<dyn Optimizer<T> + 'a>,
+{
+    fn find_min(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove the statistics of the previous runnings.
+    fn reset(&mut self) {
+        self.statistics = Statistics::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// The optimizer returns the predefined results one by one.
+    struct FakeOptimizer {
+        result: Option<Solution<f64>>,
+    }
+
+    impl Optimizer<f64> for FakeOptimizer {
+        fn find_min(&mut self) -> Option<Solution<f64>> {
+            self.result
+        }
+
+        fn find_max(&mut self) -> Option<Solution<f64>> {
+            self.result
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn is_better_solution() {
+        let none: Option<Solution<f64>> = None;
+        assert!(is_better(&Some((0.0, 1.0)), &none, Direction::Minimize));
+        assert!(!is_better(&none, &Some((0.0, 1.0)), Direction::Minimize));
+        assert!(is_better(
+            &Some((0.0, 1.0)),
+            &Some((0.0, 2.0)),
+            Direction::Minimize
+        ));
+        assert!(!is_better(
+            &Some((0.0, 2.0)),
+            &Some((0.0, 1.0)),
+            Direction::Minimize
+        ));
+        assert!(is_better(
+            &Some((0.0, 2.0)),
+            &Some((0.0, 1.0)),
+            Direction::Maximize
+        ));
+        assert!(!is_better(
+            &Some((0.0, f64::NAN)),
+            &none,
+            Direction::Minimize
+        ));
+        assert!(is_better(
+            &Some((0.0, 1.0)),
+            &Some((0.0, f64::NAN)),
+            Direction::Minimize
+        ));
+    }
+
+    #[test]
+    fn find_min_best() {
+        let results = vec![Some((1.0, 3.0)), None, Some((2.0, 1.0)), Some((3.0, 2.0))];
+        let counter = RefCell::new(0);
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                let result = results[*counter.borrow()];
+                *counter.borrow_mut() += 1;
+                Box::new(FakeOptimizer { result })
+            },
+            4,
+        );
+
+        assert_eq!(optimizer.find_min(), Some((2.0, 1.0)));
+        assert_eq!(optimizer.get_statistics().get_results(), &results);
+    }
+
+    #[test]
+    fn find_max_best() {
+        let results = vec![Some((1.0, 3.0)), Some((2.0, 1.0)), Some((3.0, 2.0))];
+        let counter = RefCell::new(0);
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                let result = results[*counter.borrow()];
+                *counter.borrow_mut() += 1;
+                Box::new(FakeOptimizer { result })
+            },
+            3,
+        );
+
+        assert_eq!(optimizer.find_max(), Some((1.0, 3.0)));
+    }
+
+    #[test]
+    fn find_min_failed() {
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                Box::new(FakeOptimizer { result: None })
+            },
+            3,
+        );
+
+        assert_eq!(optimizer.find_min(), None);
+        assert_eq!(optimizer.get_statistics().get_run_count(), 3);
+    }
+
+    #[test]
+    fn warm_start() {
+        let starts = RefCell::new(vec![]);
+        let counter = RefCell::new(0.0);
+        let mut optimizer = MultiStartOptimizer::new(
+            |start: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                starts.borrow_mut().push(start.cloned());
+                *counter.borrow_mut() += 1.0;
+                let value = *counter.borrow();
+                Box::new(FakeOptimizer {
+                    result: Some((value, 10.0 - value)),
+                })
+            },
+            5,
+        );
+        optimizer.set_warm_start_fraction(0.4);
+
+        assert_eq!(optimizer.find_min(), Some((5.0, 5.0)));
+        assert_eq!(
+            *starts.borrow(),
+            vec![None, None, None, Some(3.0), Some(4.0)]
+        );
+    }
+
+    #[test]
+    fn warm_start_count() {
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                Box::new(FakeOptimizer { result: None })
+            },
+            10,
+        );
+        assert_eq!(optimizer.get_warm_start_count(), 0);
+
+        optimizer.set_warm_start_fraction(0.25);
+        assert_eq!(optimizer.get_warm_start_count(), 3);
+
+        optimizer.set_warm_start_fraction(1.0);
+        assert_eq!(optimizer.get_warm_start_count(), 9);
+    }
+
+    #[test]
+    fn reset() {
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                Box::new(FakeOptimizer {
+                    result: Some((1.0, 1.0)),
+                })
+            },
+            3,
+        );
+
+        optimizer.find_min();
+        assert_eq!(optimizer.get_statistics().get_run_count(), 3);
+
+        optimizer.reset();
+        assert_eq!(optimizer.get_statistics().get_run_count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_run_count() {
+        MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                Box::new(FakeOptimizer { result: None })
+            },
+            0,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_warm_start_fraction() {
+        let mut optimizer = MultiStartOptimizer::new(
+            |_: Option<&f64>| -> Box<dyn Optimizer<f64>> {
+                Box::new(FakeOptimizer { result: None })
+            },
+            3,
+        );
+        optimizer.set_warm_start_fraction(1.5);
+    }
+}
@@ -0,0 +1,560 @@
+//! The module with the Nelder-Mead simplex algorithm for the derivative-free local optimization.
+//!
+//! Nelder, J. A.; Mead, R. (1965). "A simplex method for function minimization". The Computer
+//! Journal, 7(4), pp.308-313.
+//!
+//! The algorithm keeps the simplex of n + 1 vertices in the n-dimensional search space. On every
+//! iteration the worst vertex is replaced by the reflected, expanded or contracted point, or all
+//! vertices except the best one are shrunk to the best vertex.
+//!
+//! # Terms
+//! * `alpha` - reflection coefficient (default 1).
+//! * `beta` - contraction coefficient (default 0.5).
+//! * `gamma` - expansion coefficient (default 2).
+//! * `delta` - shrink coefficient (default 0.5).
+
+use std::cmp::Ordering;
+
+use num::Float;
+
+use crate::tools::compare_values;
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::{
+    call_iteration_callback, AlgorithmState, Direction, Goal, GoalValue, IterativeOptimizer,
+    Optimizer, Solution,
+};
+
+type Coordinate<T> = Vec<T>;
+
+/// The vertex of the simplex.
+#[derive(Debug, Clone)]
+pub struct Vertex<T> {
+    coordinates: Coordinate<T>,
+
+    /// The value which the algorithm minimizes.
+    value: GoalValue,
+}
+
+impl<T> Vertex<T> {
+    /// Returns the coordinates of the vertex.
+    pub fn get_coordinates(&self) -> &Coordinate<T> {
+        &self.coordinates
+    }
+
+    /// Returns the value which the algorithm minimizes (the negated goal value for `find_max`).
+    pub fn get_value(&self) -> GoalValue {
+        self.value
+    }
+}
+
+/// The simplex. The state of the Nelder-Mead algorithm.
+pub struct Simplex<T> {
+    /// The vertices sorted by the value (the best vertex is first).
+    vertices: Vec<Vertex<T>>,
+    iteration: usize,
+    direction: Direction,
+}
+
+impl<T: Float> Simplex<T> {
+    fn new() -> Self {
+        Self {
+            vertices: vec![],
+            iteration: 0,
+            direction: Direction::Minimize,
+        }
+    }
+
+    /// Returns the vertices sorted from the best to the worst.
+    pub fn get_vertices(&self) -> &[Vertex<T>] {
+        &self.vertices
+    }
+
+    /// Returns the largest distance between the best vertex and the other vertices.
+    pub fn get_size(&self) -> f64 {
+        match self.vertices.first() {
+            Some(best) => self
+                .vertices
+                .iter()
+                .map(|vertex| {
+                    vertex
+                        .coordinates
+                        .iter()
+                        .zip(best.coordinates.iter())
+                        .map(|(x, x_best)| (*x - *x_best).to_f64().unwrap().powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .fold(0.0, f64::max),
+            None => 0.0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.vertices.clear();
+        self.iteration = 0;
+    }
+
+    fn sort(&mut self) {
+        self.vertices
+            .sort_by(|a, b| compare_values(a.value, b.value));
+    }
+
+    /// Returns the centroid of all vertices except the worst.
+    fn get_centroid(&self) -> Coordinate<T> {
+        let count = self.vertices.len() - 1;
+        let mut centroid = vec![T::zero(); self.vertices[0].coordinates.len()];
+        for vertex in &self.vertices[..count] {
+            for (c, x) in centroid.iter_mut().zip(vertex.coordinates.iter()) {
+                *c = *c + *x;
+            }
+        }
+
+        let count = T::from(count).unwrap();
+        centroid.iter().map(|c| *c / count).collect()
+    }
+}
+
+impl<T: Float> AlgorithmState<Coordinate<T>> for Simplex<T> {
+    fn get_best_solution(&self) -> Option<Solution<Coordinate<T>>> {
+        self.vertices.first().map(|vertex| {
+            (
+                vertex.coordinates.clone(),
+                self.direction.apply(vertex.value),
+            )
+        })
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&Coordinate<T>> {
+        self.vertices
+            .iter()
+            .map(|vertex| &vertex.coordinates)
+            .collect()
+    }
+}
+
+/// Returns `from + coeff * (to - from)`.
+fn get_point_on_line<T: Float>(from: &[T], to: &[T], coeff: f64) -> Coordinate<T> {
+    let coeff = T::from(coeff).unwrap();
+    from.iter()
+        .zip(to.iter())
+        .map(|(a, b)| *a + coeff * (*b - *a))
+        .collect()
+}
+
+/// The Nelder-Mead optimizer. The optimizer implements the `Optimizer` trait.
+///
+/// `T` - type of the coordinates in the search space (f32, f64).
+pub struct NelderMeadOptimizer<'a, T> {
+    goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+    stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+    loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>,
+
+    /// The vertices of the initial simplex.
+    initial_vertices: Vec<Coordinate<T>>,
+
+    alpha: f64,
+    beta: f64,
+    gamma: f64,
+    delta: f64,
+
+    simplex: Simplex<T>,
+
+    /// True if the algorithm was initialized.
+    initialized: bool,
+}
+
+impl<'a, T: Float + 'a> NelderMeadOptimizer<'a, T> {
+    /// Constructor. The initial simplex is created from the initial point: the vertex i + 1 is
+    /// the initial point with the coordinate i shifted by `steps[i]`.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop criterion.
+    /// * `initial_point` - the first vertex of the simplex.
+    /// * `steps` - the shifts of the coordinates for the other vertices. The length must be equal
+    ///   to the length of `initial_point`, all steps must not be zero.
+    pub fn new(
+        goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+        stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+        initial_point: Coordinate<T>,
+        steps: Coordinate<T>,
+    ) -> Self {
+        assert!(!initial_point.is_empty());
+        assert_eq!(initial_point.len(), steps.len());
+        assert!(steps.iter().all(|step| !step.is_zero()));
+
+        let mut vertices = vec![initial_point.clone()];
+        for (n, step) in steps.iter().enumerate() {
+            let mut vertex = initial_point.clone();
+            vertex[n] = vertex[n] + *step;
+            vertices.push(vertex);
+        }
+
+        Self::from_vertices(goal, stop_checker, vertices)
+    }
+
+    /// Constructor with the user-provided initial simplex.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop criterion.
+    /// * `vertices` - the vertices of the initial simplex. For the n-dimensional search space
+    ///   the simplex must contain n + 1 vertices.
+    pub fn from_vertices(
+        goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+        stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+        vertices: Vec<Coordinate<T>>,
+    ) -> Self {
+        assert!(vertices.len() >= 2);
+        let dimension = vertices[0].len();
+        assert_eq!(vertices.len(), dimension + 1);
+        assert!(vertices.iter().all(|vertex| vertex.len() == dimension));
+
+        Self {
+            goal,
+            stop_checker,
+            loggers: vec![],
+            initial_vertices: vertices,
+            alpha: 1.0,
+            beta: 0.5,
+            gamma: 2.0,
+            delta: 0.5,
+            simplex: Simplex::new(),
+            initialized: false,
+        }
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Set the coefficients of the algorithm.
+    ///
+    /// # Parameters
+    /// * `alpha` - reflection coefficient. Must be greater than 0.
+    /// * `beta` - contraction coefficient. Must be in the range (0; 1).
+    /// * `gamma` - expansion coefficient. Must be greater than 1 and greater than `alpha`.
+    /// * `delta` - shrink coefficient. Must be in the range (0; 1).
+    pub fn set_coefficients(&mut self, alpha: f64, beta: f64, gamma: f64, delta: f64) {
+        assert!(alpha > 0.0);
+        assert!(beta > 0.0 && beta < 1.0);
+        assert!(gamma > 1.0 && gamma > alpha);
+        assert!(delta > 0.0 && delta < 1.0);
+
+        self.alpha = alpha;
+        self.beta = beta;
+        self.gamma = gamma;
+        self.delta = delta;
+    }
+
+    /// Returns the current simplex.
+    pub fn get_simplex(&self) -> &Simplex<T> {
+        &self.simplex
+    }
+
+    fn create_vertex(&mut self, coordinates: Coordinate<T>) -> Vertex<T> {
+        let value = self.simplex.direction.apply(self.goal.get(&coordinates));
+        Vertex { coordinates, value }
+    }
+
+    fn init(&mut self, direction: Direction) {
+        self.simplex.reset();
+        self.simplex.direction = direction;
+
+        for coordinates in self.initial_vertices.clone() {
+            let vertex = self.create_vertex(coordinates);
+            self.simplex.vertices.push(vertex);
+        }
+
+        self.simplex.sort();
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.simplex);
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<Coordinate<T>>> {
+        self.init(direction);
+        self.next_iterations()
+    }
+
+    /// Shrink all vertices to the best vertex.
+    fn shrink(&mut self) {
+        let best = self.simplex.vertices[0].coordinates.clone();
+        for n in 1..self.simplex.vertices.len() {
+            let coordinates =
+                get_point_on_line(&best, &self.simplex.vertices[n].coordinates, self.delta);
+            self.simplex.vertices[n] = self.create_vertex(coordinates);
+        }
+    }
+
+    fn do_iteration(&mut self) {
+        let worst_index = self.simplex.vertices.len() - 1;
+        let best_value = self.simplex.vertices[0].value;
+        let second_worst_value = self.simplex.vertices[worst_index - 1].value;
+        let worst = self.simplex.vertices[worst_index].clone();
+        let centroid = self.simplex.get_centroid();
+
+        let reflected = self.create_vertex(get_point_on_line(
+            &centroid,
+            &worst.coordinates,
+            -self.alpha,
+        ));
+
+        let new_vertex = if compare_values(reflected.value, best_value) == Ordering::Less {
+            let expanded = self.create_vertex(get_point_on_line(
+                &centroid,
+                &reflected.coordinates,
+                self.gamma,
+            ));
+            if compare_values(expanded.value, reflected.value) == Ordering::Less {
+                Some(expanded)
+            } else {
+                Some(reflected)
+            }
+        } else if compare_values(reflected.value, second_worst_value) == Ordering::Less {
+            Some(reflected)
+        } else if compare_values(reflected.value, worst.value) == Ordering::Less {
+            // Outside contraction
+            let contracted = self.create_vertex(get_point_on_line(
+                &centroid,
+                &reflected.coordinates,
+                self.beta,
+            ));
+            if compare_values(contracted.value, reflected.value) != Ordering::Greater {
+                Some(contracted)
+            } else {
+                None
+            }
+        } else {
+            // Inside contraction
+            let contracted =
+                self.create_vertex(get_point_on_line(&centroid, &worst.coordinates, self.beta));
+            if compare_values(contracted.value, worst.value) == Ordering::Less {
+                Some(contracted)
+            } else {
+                None
+            }
+        };
+
+        match new_vertex {
+            Some(vertex) => self.simplex.vertices[worst_index] = vertex,
+            None => self.shrink(),
+        }
+
+        self.simplex.sort();
+        self.simplex.iteration += 1;
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.simplex);
+        }
+    }
+}
+
+impl<'a, T: Float + 'a> Optimizer<Coordinate<T>> for NelderMeadOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<Solution<Coordinate<T>>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<Coordinate<T>>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove the simplex and go to iteration 0.
+    fn reset(&mut self) {
+        self.simplex.reset();
+        self.simplex.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+impl<'a, T: Float + 'a> IterativeOptimizer<Coordinate<T>> for NelderMeadOptimizer<'a, T> {
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&Coordinate<T>, GoalValue)>),
+    ) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.simplex.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.simplex);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.simplex) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.simplex);
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.simplex);
+        }
+
+        self.simplex.get_best_solution()
+    }
+
+    fn step(&mut self) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.simplex.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.simplex) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.simplex.get_best_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::stopchecker::MaxIterations;
+    use crate::GoalFromFunction;
+
+    fn sum_sqr(x: &Vec<f64>) -> f64 {
+        x.iter().map(|xi| xi * xi).sum()
+    }
+
+    fn create_optimizer(iterations: usize) -> NelderMeadOptimizer<'static, f64> {
+        NelderMeadOptimizer::new(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(iterations)),
+            vec![1.0, 2.0],
+            vec![0.5, 0.5],
+        )
+    }
+
+    #[test]
+    fn point_on_line() {
+        assert_eq!(
+            get_point_on_line(&[1.0, 1.0], &[3.0, 0.0], -1.0),
+            vec![-1.0, 2.0]
+        );
+        assert_eq!(
+            get_point_on_line(&[1.0, 1.0], &[3.0, 0.0], 0.5),
+            vec![2.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn init_from_steps() {
+        let mut optimizer = create_optimizer(0);
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+        assert_eq!(solution, vec![1.0, 2.0]);
+        assert_eq!(goal_value, 5.0);
+
+        let vertices: Vec<&Vec<f64>> = optimizer.get_simplex().get_agents_parameters();
+        assert_eq!(
+            vertices,
+            vec![&vec![1.0, 2.0], &vec![1.5, 2.0], &vec![1.0, 2.5]]
+        );
+    }
+
+    #[test]
+    fn centroid() {
+        let mut optimizer = create_optimizer(0);
+        optimizer.find_min();
+        assert_eq!(optimizer.get_simplex().get_centroid(), vec![1.25, 2.0]);
+    }
+
+    #[test]
+    fn reflection() {
+        let mut optimizer = NelderMeadOptimizer::from_vertices(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(1)),
+            vec![vec![2.0, 1.0], vec![1.0, 2.0], vec![2.0, 2.0]],
+        );
+
+        // Reflected point [1.0, 1.0] is better than the best vertex,
+        // expanded point [0.5, 0.5] is better than the reflected point.
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+        assert_eq!(solution, vec![0.5, 0.5]);
+        assert_eq!(goal_value, 0.5);
+    }
+
+    #[test]
+    fn shrink() {
+        let mut optimizer = create_optimizer(0);
+        optimizer.find_min();
+        optimizer.shrink();
+        optimizer.simplex.sort();
+        let vertices: Vec<&Vec<f64>> = optimizer.get_simplex().get_agents_parameters();
+        assert_eq!(
+            vertices,
+            vec![&vec![1.0, 2.0], &vec![1.25, 2.0], &vec![1.0, 2.25]]
+        );
+    }
+
+    #[test]
+    fn simplex_size() {
+        let mut optimizer = create_optimizer(0);
+        optimizer.find_min();
+        assert_eq!(optimizer.get_simplex().get_size(), 0.5);
+
+        let mut optimizer = create_optimizer(200);
+        optimizer.find_min();
+        assert!(optimizer.get_simplex().get_size() < 1e-6);
+    }
+
+    #[test]
+    fn find_max() {
+        let mut optimizer = NelderMeadOptimizer::new(
+            Box::new(GoalFromFunction::new(|x: &Vec<f64>| -sum_sqr(x))),
+            Box::new(MaxIterations::new(200)),
+            vec![1.0, 2.0],
+            vec![0.5, 0.5],
+        );
+
+        let (solution, goal_value) = optimizer.find_max().unwrap();
+        assert!(goal_value <= 0.0);
+        assert!(goal_value > -1e-8);
+        assert!(solution.iter().all(|x| x.abs() < 1e-4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_vertices_count() {
+        NelderMeadOptimizer::from_vertices(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(1)),
+            vec![vec![2.0, 1.0], vec![1.0, 2.0]],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_zero_step() {
+        NelderMeadOptimizer::new(
+            Box::new(GoalFromFunction::new(sum_sqr)),
+            Box::new(MaxIterations::new(1)),
+            vec![1.0, 2.0],
+            vec![0.5, 0.0],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_coefficients() {
+        create_optimizer(1).set_coefficients(1.0, 0.5, 0.9, 0.5);
+    }
+}
@@ -0,0 +1,737 @@
+//! The module with the NSGA-II algorithm for the multi-objective optimization.
+//!
+//! Deb, K.; Pratap, A.; Agarwal, S.; Meyarivan, T. (2002). "A fast and elitist multiobjective
+//! genetic algorithm: NSGA-II". IEEE Transactions on Evolutionary Computation, 6(2), pp.182-197.
+//!
+//! On every iteration the algorithm creates the children by the crowded tournament selection,
+//! the cross and the mutation. The parents and the children are sorted into the non-dominated
+//! fronts. The next generation is filled by the best fronts, the last front is truncated by
+//! the crowding distance.
+//!
+//! All objectives are minimized.
+//!
+//! # Terms
+//! * "Objectives" - the values of the goal functions for the point in the search space.
+//! * "Dominance" - the solution `a` dominates the solution `b` if `a` is not worse than `b` in
+//!   all objectives and `a` is better than `b` in at least one objective.
+//! * "Rank" - the number of the non-dominated front of the individual. The individuals of
+//!   the Pareto front have rank 0.
+//! * "Crowding distance" - the estimation of the density of the solutions around
+//!   the individual in the objective space.
+
+use std::cmp::Ordering;
+use std::f64;
+use std::marker::PhantomData;
+
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::genetic::{Creator, Cross, Mutation};
+use crate::tools::logging::Logger;
+use crate::tools::comparable_value;
+use crate::tools::stopchecker::StopChecker;
+use crate::{AlgorithmState, Solution};
+
+pub use crate::tools::statistics::{dominates, ParetoFront};
+
+/// The trait for the goal function with several objectives.
+pub trait MultiObjectiveGoal<T> {
+    /// Must return the values of all objectives for the point in the search space (x).
+    fn get(&mut self, x: &T) -> Vec<f64>;
+}
+
+/// The goal function may be passed to an optimizer by mutable reference to inspect its state
+/// after the algorithm running.
+impl<T, G: MultiObjectiveGoal<T> + ?Sized> MultiObjectiveGoal<T> for &mut G {
+    fn get(&mut self, x: &T) -> Vec<f64> {
+        (**self).get(x)
+    }
+}
+
+/// Struct to convert (wrap) function or closure to `MultiObjectiveGoal` trait.
+///
+/// `T` - type of a point in the search space for goal function.
+/// `F` - type of the function.
+pub struct MultiObjectiveGoalFromFunction<T, F = fn(&T) -> Vec<f64>> {
+    function: F,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, F: Fn(&T) -> Vec<f64>> MultiObjectiveGoalFromFunction<T, F> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `function` - the function or the closure which returns the values of all objectives
+    ///   for the point in the search space.
+    pub fn new(function: F) -> Self {
+        Self {
+            function,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F: Fn(&T) -> Vec<f64>> MultiObjectiveGoal<T> for MultiObjectiveGoalFromFunction<T, F> {
+    fn get(&mut self, x: &T) -> Vec<f64> {
+        (self.function)(x)
+    }
+}
+
+/// The fast non-dominated sorting. Returns the indexes of the `objectives` items for every
+/// front. The first front is the Pareto front.
+pub fn non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let count = objectives.len();
+
+    // The indexes of the solutions which are dominated by the solution
+    let mut dominated: Vec<Vec<usize>> = vec![vec![]; count];
+
+    // The count of the solutions which dominate the solution
+    let mut domination_count = vec![0_usize; count];
+
+    let mut fronts: Vec<Vec<usize>> = vec![];
+    let mut current_front: Vec<usize> = vec![];
+
+    for p in 0..count {
+        for q in p + 1..count {
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated[p].push(q);
+                domination_count[q] += 1;
+            } else if dominates(&objectives[q], &objectives[p]) {
+                dominated[q].push(p);
+                domination_count[p] += 1;
+            }
+        }
+    }
+
+    for (p, &dominated_by) in domination_count.iter().enumerate() {
+        if dominated_by == 0 {
+            current_front.push(p);
+        }
+    }
+
+    while !current_front.is_empty() {
+        let mut next_front = vec![];
+        for &p in &current_front {
+            for &q in &dominated[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next_front.push(q);
+                }
+            }
+        }
+
+        fronts.push(current_front);
+        current_front = next_front;
+    }
+
+    fronts
+}
+
+/// Returns the crowding distance for every solution of the `front`. `front` contains
+/// the indexes of the `objectives` items. The boundary solutions for every objective have
+/// the infinite distance.
+pub fn crowding_distance(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let count = front.len();
+    let mut distances = vec![0.0; count];
+    if count == 0 {
+        return distances;
+    }
+
+    let objectives_count = objectives[front[0]].len();
+    let mut order: Vec<usize> = (0..count).collect();
+
+    // The values of every objective for the solutions of the front
+    let columns: Vec<Vec<f64>> = (0..objectives_count)
+        .map(|m| {
+            front
+                .iter()
+                .map(|&index| comparable_value(objectives[index][m]))
+                .collect()
+        })
+        .collect();
+
+    for values in &columns {
+        order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap());
+
+        distances[order[0]] = f64::INFINITY;
+        distances[order[count - 1]] = f64::INFINITY;
+
+        let range = values[order[count - 1]] - values[order[0]];
+        if range == 0.0 || !range.is_finite() {
+            continue;
+        }
+
+        for n in 1..count - 1 {
+            distances[order[n]] += (values[order[n + 1]] - values[order[n - 1]]) / range;
+        }
+    }
+
+    distances
+}
+
+/// Single point (agent) in the search space for NSGA-II.
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+#[derive(Debug, Clone)]
+pub struct Nsga2Individual<T> {
+    chromosomes: T,
+    objectives: Vec<f64>,
+    rank: usize,
+    crowding_distance: f64,
+}
+
+impl<T> Nsga2Individual<T> {
+    /// Returns the point in the search space.
+    pub fn get_chromosomes(&self) -> &T {
+        &self.chromosomes
+    }
+
+    /// Returns the values of the objectives.
+    pub fn get_objectives(&self) -> &[f64] {
+        &self.objectives
+    }
+
+    /// Returns the number of the non-dominated front of the individual (0 for the Pareto front).
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the crowding distance of the individual in its front.
+    pub fn get_crowding_distance(&self) -> f64 {
+        self.crowding_distance
+    }
+}
+
+/// The crowded comparison. The individual with the lower rank is better. If the ranks are
+/// equal, the individual with the greater crowding distance is better.
+/// Returns `Ordering::Less` if `a` is better than `b`.
+pub fn crowded_compare<T>(a: &Nsga2Individual<T>, b: &Nsga2Individual<T>) -> Ordering {
+    match a.rank.cmp(&b.rank) {
+        Ordering::Equal => b
+            .crowding_distance
+            .partial_cmp(&a.crowding_distance)
+            .unwrap_or(Ordering::Equal),
+        ordering => ordering,
+    }
+}
+
+/// The population of NSGA-II.
+///
+/// The population implements `AlgorithmState` to use the stop checkers and the loggers.
+/// There is no single best solution in the multi-objective optimization, so
+/// `get_best_solution()` returns `None`. Use the stop checkers which do not depend on
+/// the goal function value (`MaxIterations`, `WallTimeLimit`, for example).
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct Nsga2Population<T> {
+    individuals: Vec<Nsga2Individual<T>>,
+    iteration: usize,
+}
+
+impl<T: Clone> Nsga2Population<T> {
+    fn new() -> Self {
+        Self {
+            individuals: vec![],
+            iteration: 0,
+        }
+    }
+
+    /// Returns all individuals.
+    pub fn get_individuals(&self) -> &[Nsga2Individual<T>] {
+        &self.individuals
+    }
+
+    /// Returns count of the individuals.
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    /// Returns true if the population does not contain individuals.
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+
+    /// Returns the non-dominated individuals (with rank 0) of the population.
+    pub fn get_pareto_front(&self) -> ParetoFront<T> {
+        let mut front = ParetoFront::new();
+        for individual in self.individuals.iter().filter(|item| item.rank == 0) {
+            front.insert(
+                individual.chromosomes.clone(),
+                individual.objectives.clone(),
+            );
+        }
+
+        front
+    }
+
+    fn reset(&mut self) {
+        self.individuals.clear();
+        self.iteration = 0;
+    }
+
+    /// Sort the individuals into the fronts and select `size` best individuals.
+    fn select(&mut self, size: usize) {
+        let objectives: Vec<Vec<f64>> = self
+            .individuals
+            .iter()
+            .map(|individual| individual.objectives.clone())
+            .collect();
+        let fronts = non_dominated_sort(&objectives);
+
+        let mut individuals: Vec<Option<Nsga2Individual<T>>> =
+            self.individuals.drain(..).map(Some).collect();
+        let mut selected = Vec::with_capacity(size);
+
+        for (rank, front) in fronts.iter().enumerate() {
+            if selected.len() >= size {
+                break;
+            }
+
+            let distances = crowding_distance(&objectives, front);
+            let mut front_individuals: Vec<Nsga2Individual<T>> = front
+                .iter()
+                .zip(distances)
+                .map(|(&index, distance)| {
+                    let mut individual = individuals[index].take().unwrap();
+                    individual.rank = rank;
+                    individual.crowding_distance = distance;
+                    individual
+                })
+                .collect();
+
+            if selected.len() + front_individuals.len() > size {
+                front_individuals.sort_by(crowded_compare);
+                front_individuals.truncate(size - selected.len());
+            }
+
+            selected.append(&mut front_individuals);
+        }
+
+        self.individuals = selected;
+    }
+}
+
+impl<T: Clone> AlgorithmState<T> for Nsga2Population<T> {
+    fn get_best_solution(&self) -> Option<Solution<T>> {
+        None
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&T> {
+        self.individuals
+            .iter()
+            .map(|individual| &individual.chromosomes)
+            .collect()
+    }
+}
+
+/// The NSGA-II optimizer.
+///
+/// The optimizer uses `Creator`, `Cross` and `Mutation` of the genetic algorithm. The population
+/// size is equal to the count of the chromosomes returned by the creator. The parents for
+/// the cross are selected by the binary tournament with the crowded comparison.
+///
+/// The optimizer does not implement the `Optimizer` trait, because the result of
+/// the multi-objective optimization is the set of solutions (the Pareto front).
+///
+/// `T` - type of a point in the search space for goal function (chromosomes).
+pub struct Nsga2Optimizer<'a, T> {
+    goal: Box<dyn MultiObjectiveGoal<T> + 'a>,
+    stop_checker: Box<dyn StopChecker<T> + 'a>,
+    creator: Box<dyn Creator<T> + 'a>,
+    cross: Box<dyn Cross<T> + 'a>,
+    mutation: Box<dyn Mutation<T> + 'a>,
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+    population: Nsga2Population<T>,
+    population_size: usize,
+    random: StdRng,
+
+    /// True if the first generation was created.
+    initialized: bool,
+}
+
+impl<'a, T: Clone> Nsga2Optimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function with several objectives.
+    /// * `stop_checker` - the stop criterion. The population does not have the best solution,
+    ///   so the stop checkers should not depend on the goal function value.
+    /// * `creator` - the creator of the first generation.
+    /// * `cross` - the cross algorithm. The cross gets two parents.
+    /// * `mutation` - the mutation algorithm.
+    pub fn new(
+        goal: Box<dyn MultiObjectiveGoal<T> + 'a>,
+        stop_checker: Box<dyn StopChecker<T> + 'a>,
+        creator: Box<dyn Creator<T> + 'a>,
+        cross: Box<dyn Cross<T> + 'a>,
+        mutation: Box<dyn Mutation<T> + 'a>,
+    ) -> Self {
+        Self {
+            goal,
+            stop_checker,
+            creator,
+            cross,
+            mutation,
+            loggers: vec![],
+            population: Nsga2Population::new(),
+            population_size: 0,
+            random: StdRng::from_entropy(),
+            initialized: false,
+        }
+    }
+
+    /// Set seed for the random numbers generator of the tournament selection to get
+    /// reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<T> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<T> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Returns the current population.
+    pub fn get_population(&self) -> &Nsga2Population<T> {
+        &self.population
+    }
+
+    /// Create the first generation and run the algorithm. Returns the solutions of the Pareto
+    /// front and their objectives.
+    pub fn find_pareto_front(&mut self) -> Vec<(T, Vec<f64>)> {
+        self.init();
+        self.next_iterations()
+    }
+
+    /// Continue the algorithm running until the stop checker stops it. Returns the solutions
+    /// of the Pareto front and their objectives.
+    pub fn next_iterations(&mut self) -> Vec<(T, Vec<f64>)> {
+        if !self.initialized {
+            self.init();
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.population);
+        }
+
+        while !self.stop_checker.can_stop(&self.population) {
+            self.do_iteration();
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.population);
+        }
+
+        self.population.get_pareto_front().into_solutions()
+    }
+
+    /// Create single new generation. Returns the solutions of the Pareto front or `None` if
+    /// the algorithm is stopped.
+    pub fn step(&mut self) -> Option<Vec<(T, Vec<f64>)>> {
+        if !self.initialized {
+            self.init();
+        }
+
+        if self.stop_checker.can_stop(&self.population) {
+            return None;
+        }
+
+        self.do_iteration();
+        Some(self.population.get_pareto_front().into_solutions())
+    }
+
+    /// Remove all individuals, reset the stop checker and go to generation 0.
+    pub fn reset(&mut self) {
+        self.population.reset();
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+
+    fn create_individual(&mut self, chromosomes: T) -> Nsga2Individual<T> {
+        let objectives = self.goal.get(&chromosomes);
+        Nsga2Individual {
+            chromosomes,
+            objectives,
+            rank: 0,
+            crowding_distance: 0.0,
+        }
+    }
+
+    fn init(&mut self) {
+        self.population.reset();
+
+        let chromosomes_list = self.creator.create();
+        assert!(!chromosomes_list.is_empty());
+        self.population_size = chromosomes_list.len();
+
+        for chromosomes in chromosomes_list {
+            let individual = self.create_individual(chromosomes);
+            self.population.individuals.push(individual);
+        }
+
+        self.population.select(self.population_size);
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.population);
+        }
+    }
+
+    /// Returns the index of the winner of the binary tournament.
+    fn tournament(&mut self) -> usize {
+        let between = Uniform::new(0, self.population.len());
+        let index_1 = between.sample(&mut self.random);
+        let index_2 = between.sample(&mut self.random);
+
+        let individual_1 = &self.population.individuals[index_1];
+        let individual_2 = &self.population.individuals[index_2];
+        if crowded_compare(individual_2, individual_1) == Ordering::Less {
+            index_2
+        } else {
+            index_1
+        }
+    }
+
+    fn do_iteration(&mut self) {
+        self.mutation.set_generation(self.population.iteration);
+
+        let mut children: Vec<T> = Vec::with_capacity(self.population_size);
+        while children.len() < self.population_size {
+            let index_1 = self.tournament();
+            let index_2 = self.tournament();
+            let parents = [
+                &self.population.individuals[index_1].chromosomes,
+                &self.population.individuals[index_2].chromosomes,
+            ];
+
+            for child in self.cross.cross(&parents) {
+                children.push(self.mutation.mutation(&child));
+            }
+        }
+
+        children.truncate(self.population_size);
+        for chromosomes in children {
+            let individual = self.create_individual(chromosomes);
+            self.population.individuals.push(individual);
+        }
+
+        self.population.select(self.population_size);
+        self.population.iteration += 1;
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.population);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetic::creation::vec_float::RandomCreator;
+    use crate::genetic::cross::SimulatedBinaryCrossover;
+    use crate::genetic::mutation::{PolynomialMutation, VecMutation};
+    use crate::tools::stopchecker::MaxIterations;
+
+    /// Schaffer's problem: f1 = x^2, f2 = (x - 2)^2. The Pareto set is [0; 2].
+    fn schaffer(x: &Vec<f64>) -> Vec<f64> {
+        vec![x[0] * x[0], (x[0] - 2.0) * (x[0] - 2.0)]
+    }
+
+    fn create_optimizer(iterations: usize) -> Nsga2Optimizer<'static, Vec<f64>> {
+        let intervals = vec![(-10.0, 10.0)];
+        let mut creator = RandomCreator::new(40, intervals.clone());
+        creator.set_seed(42);
+
+        let mut cross = SimulatedBinaryCrossover::new(15.0);
+        cross.set_seed(42);
+
+        let mut single_mutation = PolynomialMutation::new(20.0, intervals);
+        single_mutation.set_seed(42);
+        let mut mutation = VecMutation::new(100.0, Box::new(single_mutation));
+        mutation.set_seed(42);
+
+        let mut optimizer = Nsga2Optimizer::new(
+            Box::new(MultiObjectiveGoalFromFunction::new(schaffer)),
+            Box::new(MaxIterations::new(iterations)),
+            Box::new(creator),
+            Box::new(cross),
+            Box::new(mutation),
+        );
+        optimizer.set_seed(42);
+        optimizer
+    }
+
+    #[test]
+    fn non_dominated_sort_fronts() {
+        let objectives = vec![
+            vec![1.0, 5.0],
+            vec![2.0, 2.0],
+            vec![3.0, 3.0],
+            vec![5.0, 1.0],
+            vec![4.0, 4.0],
+            vec![6.0, 6.0],
+        ];
+
+        let fronts = non_dominated_sort(&objectives);
+        assert_eq!(fronts, vec![vec![0, 1, 3], vec![2], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn non_dominated_sort_empty() {
+        assert!(non_dominated_sort(&[]).is_empty());
+    }
+
+    #[test]
+    fn non_dominated_sort_equal() {
+        let objectives = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+        assert_eq!(non_dominated_sort(&objectives), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn crowding_distance_front() {
+        let objectives = vec![
+            vec![0.0, 4.0],
+            vec![1.0, 3.0],
+            vec![100.0, 100.0],
+            vec![3.0, 1.0],
+            vec![4.0, 0.0],
+        ];
+
+        let distances = crowding_distance(&objectives, &[0, 1, 3, 4]);
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[3], f64::INFINITY);
+
+        // (3 - 0) / 4 + (4 - 1) / 4
+        assert!((distances[1] - 1.5).abs() < 1e-12);
+        assert!((distances[2] - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn crowding_distance_small() {
+        let objectives = vec![vec![0.0, 4.0], vec![1.0, 3.0]];
+        assert_eq!(
+            crowding_distance(&objectives, &[0, 1]),
+            vec![f64::INFINITY, f64::INFINITY]
+        );
+        assert!(crowding_distance(&objectives, &[]).is_empty());
+    }
+
+    #[test]
+    fn crowded_comparison() {
+        let create = |rank, crowding_distance| Nsga2Individual {
+            chromosomes: 0,
+            objectives: vec![],
+            rank,
+            crowding_distance,
+        };
+
+        assert_eq!(
+            crowded_compare(&create(0, 1.0), &create(1, 5.0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            crowded_compare(&create(1, 1.0), &create(1, 5.0)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            crowded_compare(&create(1, f64::INFINITY), &create(1, 5.0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            crowded_compare(&create(1, 5.0), &create(1, 5.0)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn select_population() {
+        let mut population = Nsga2Population::new();
+        for (n, objectives) in vec![
+            vec![1.0, 5.0],
+            vec![6.0, 6.0],
+            vec![2.0, 2.0],
+            vec![3.0, 3.0],
+            vec![5.0, 1.0],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            population.individuals.push(Nsga2Individual {
+                chromosomes: n,
+                objectives,
+                rank: 0,
+                crowding_distance: 0.0,
+            });
+        }
+
+        population.select(4);
+        let chromosomes: Vec<usize> = population
+            .get_individuals()
+            .iter()
+            .map(|individual| individual.chromosomes)
+            .collect();
+        let ranks: Vec<usize> = population
+            .get_individuals()
+            .iter()
+            .map(|individual| individual.rank)
+            .collect();
+        assert_eq!(chromosomes, vec![0, 2, 4, 3]);
+        assert_eq!(ranks, vec![0, 0, 0, 1]);
+
+        // The middle point of the first front has the least crowding distance
+        population.select(2);
+        let mut chromosomes: Vec<usize> = population
+            .get_individuals()
+            .iter()
+            .map(|individual| individual.chromosomes)
+            .collect();
+        chromosomes.sort();
+        assert_eq!(chromosomes, vec![0, 4]);
+    }
+
+    #[test]
+    fn schaffer_front() {
+        let mut optimizer = create_optimizer(50);
+        let front = optimizer.find_pareto_front();
+
+        assert!(front.len() > 20);
+        for (x, objectives) in &front {
+            assert!(x[0] > -0.01 && x[0] < 2.01);
+            assert_eq!(objectives, &schaffer(x));
+        }
+
+        assert_eq!(optimizer.get_population().len(), 40);
+        assert_eq!(optimizer.get_population().get_iteration(), 50);
+    }
+
+    #[test]
+    fn seed() {
+        let front_1 = create_optimizer(10).find_pareto_front();
+        let front_2 = create_optimizer(10).find_pareto_front();
+        assert_eq!(front_1, front_2);
+    }
+
+    #[test]
+    fn step() {
+        let mut optimizer = create_optimizer(3);
+        assert!(optimizer.step().is_some());
+        assert!(optimizer.step().is_some());
+        assert!(optimizer.step().is_some());
+        assert!(optimizer.step().is_none());
+        assert_eq!(optimizer.get_population().get_iteration(), 3);
+
+        optimizer.reset();
+        assert!(optimizer.get_population().is_empty());
+        assert!(optimizer.step().is_some());
+        assert_eq!(optimizer.get_population().get_iteration(), 1);
+    }
+}
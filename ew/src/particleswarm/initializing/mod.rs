@@ -1,6 +1,8 @@
 use num::{NumCast, Zero};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
-use crate::tools::RandomVectorCreator;
+use crate::tools::{latin_hypercube, RandomVectorCreator};
 use crate::particleswarm::{CoordinatesInitializer, VelocityInitializer};
 
 /// The struct to initialize particles coordinates with random value from given intervals.
@@ -25,6 +27,11 @@ impl<T> RandomCoordinatesInitializer<T> {
             vector_creator: RandomVectorCreator::new(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.vector_creator.set_seed(seed);
+    }
 }
 
 impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for RandomCoordinatesInitializer<T> {
@@ -35,8 +42,128 @@ impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for RandomCoordinatesIni
     }
 }
 
-/// The struct to initialze particles velocity with random velocity
+/// The struct to initialize particles coordinates with the Latin hypercube sampling.
+/// Every interval is divided into `particles_count` equal strata. Every stratum of every
+/// dimension contains the coordinate of exactly one particle. The coordinate inside the stratum
+/// is random.
+pub struct LatinHypercubeInitializer<T> {
+    // Intervals for every dimension. Size of the vector must be equal to dimension.
+    // The first value in tuple is minimum value, the second value is maximum value.
+    intervals: Vec<(T, T)>,
+    particles_count: usize,
+    random: StdRng,
+}
+
+impl<T: NumCast + PartialOrd> LatinHypercubeInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `particles_count` - how many particles do you need to create.
+    pub fn new(intervals: Vec<(T, T)>, particles_count: usize) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 < interval.1);
+        }
+
+        Self {
+            intervals,
+            particles_count,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for LatinHypercubeInitializer<T> {
+    fn get_coordinates(&mut self) -> Vec<Vec<T>> {
+        latin_hypercube(&self.intervals, self.particles_count, &mut self.random)
+    }
+}
+
+/// The struct to initialize particles coordinates on the regular grid.
+/// Every interval contains `points_per_dimension` evenly spaced points (including the interval
+/// boundaries), the particles are placed in all combinations of the points. So the count of the
+/// particles is `points_per_dimension ^ dimension` (see `get_particles_count`). The velocity
+/// initializer must create the same count of the velocities.
+///
+/// If `points_per_dimension` is 1, the particle is placed in the center of the interval.
+pub struct GridInitializer<T> {
+    // Intervals for every dimension. Size of the vector must be equal to dimension.
+    // The first value in tuple is minimum value, the second value is maximum value.
+    intervals: Vec<(T, T)>,
+    points_per_dimension: usize,
+}
+
+impl<T: NumCast + PartialOrd> GridInitializer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `points_per_dimension` - count of the points for every dimension. Must be greater than 0.
+    pub fn new(intervals: Vec<(T, T)>, points_per_dimension: usize) -> Self {
+        assert!(points_per_dimension > 0);
+        for interval in &intervals {
+            assert!(interval.0 <= interval.1);
+        }
+
+        Self {
+            intervals,
+            points_per_dimension,
+        }
+    }
+
+    /// Returns count of the particles which will be created.
+    pub fn get_particles_count(&self) -> usize {
+        self.points_per_dimension.pow(self.intervals.len() as u32)
+    }
+
+    /// Returns coordinate of the point with index `index` for the interval.
+    fn get_point(&self, (min, max): &(T, T), index: usize) -> T {
+        let min = min.to_f64().unwrap();
+        let max = max.to_f64().unwrap();
+
+        let value = if self.points_per_dimension == 1 {
+            (min + max) / 2.0
+        } else {
+            min + (max - min) * index as f64 / (self.points_per_dimension - 1) as f64
+        };
+
+        T::from(value).unwrap()
+    }
+}
+
+impl<T: NumCast + PartialOrd> CoordinatesInitializer<T> for GridInitializer<T> {
+    fn get_coordinates(&mut self) -> Vec<Vec<T>> {
+        (0..self.get_particles_count())
+            .map(|particle_number| {
+                // The last dimension changes faster
+                let mut rest = particle_number;
+                let mut coordinates: Vec<T> = self
+                    .intervals
+                    .iter()
+                    .rev()
+                    .map(|interval| {
+                        let index = rest % self.points_per_dimension;
+                        rest /= self.points_per_dimension;
+                        self.get_point(interval, index)
+                    })
+                    .collect();
+                coordinates.reverse();
+                coordinates
+            })
+            .collect()
+    }
+}
+
+/// The struct to initialize particles velocity with random value from given intervals.
+/// Every velocity projection is distributed uniformly in its interval.
 pub struct RandomVelocityInitializer<T> {
+    // Intervals for every dimension. Size of the vector must be equal to dimension.
+    // The first value in tuple is minimum value, the second value is maximum value.
     intervals: Vec<(T, T)>,
     particles_count: usize,
     vector_creator: RandomVectorCreator,
@@ -46,7 +173,7 @@ impl<T> RandomVelocityInitializer<T> {
     /// Constructor.
     ///
     /// # Parameters
-    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum velocity, the second value is maximum seed.
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum velocity, the second value is maximum velocity.
     /// `particles_count` - how many particles do you need to create.
     pub fn new(intervals: Vec<(T, T)>, particles_count: usize) -> Self {
         Self {
@@ -55,6 +182,33 @@ impl<T> RandomVelocityInitializer<T> {
             vector_creator: RandomVectorCreator::new(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.vector_creator.set_seed(seed);
+    }
+}
+
+impl<T: NumCast> RandomVelocityInitializer<T> {
+    /// Constructor with the velocity intervals `(-|max - min| / 2, |max - min| / 2)`
+    /// for every coordinates interval `(min, max)`.
+    ///
+    /// # Parameters
+    /// `coordinates_intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `particles_count` - how many particles do you need to create.
+    pub fn from_coordinates_intervals(
+        coordinates_intervals: &[(T, T)],
+        particles_count: usize,
+    ) -> Self {
+        let intervals = coordinates_intervals
+            .iter()
+            .map(|(min, max)| {
+                let half_width = (max.to_f64().unwrap() - min.to_f64().unwrap()).abs() / 2.0;
+                (T::from(-half_width).unwrap(), T::from(half_width).unwrap())
+            })
+            .collect();
+        Self::new(intervals, particles_count)
+    }
 }
 
 impl<T: NumCast + PartialOrd> VelocityInitializer<T> for RandomVelocityInitializer<T> {
@@ -84,3 +238,152 @@ impl<T: NumCast + PartialOrd + Zero + Clone> VelocityInitializer<T> for ZeroVelo
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns count of the coordinates in every stratum for the dimension.
+    fn get_strata_count(
+        coordinates: &[Vec<f64>],
+        dimension: usize,
+        interval: (f64, f64),
+        strata_count: usize,
+    ) -> Vec<usize> {
+        let mut result = vec![0; strata_count];
+        let stratum_width = (interval.1 - interval.0) / strata_count as f64;
+        for point in coordinates {
+            let stratum = ((point[dimension] - interval.0) / stratum_width).floor() as usize;
+            result[stratum.min(strata_count - 1)] += 1;
+        }
+
+        result
+    }
+
+    #[test]
+    fn latin_hypercube_strata() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 10.0), (100.0, 101.0)];
+        let particles_count = 20;
+        let mut initializer = LatinHypercubeInitializer::new(intervals.clone(), particles_count);
+
+        for _ in 0..10 {
+            let coordinates = initializer.get_coordinates();
+            assert_eq!(coordinates.len(), particles_count);
+
+            for (dimension, interval) in intervals.iter().enumerate() {
+                let strata = get_strata_count(&coordinates, dimension, *interval, particles_count);
+                assert!(strata.iter().all(|&count| count == 1));
+            }
+        }
+    }
+
+    #[test]
+    fn latin_hypercube_empty() {
+        let intervals: Vec<(f64, f64)> = vec![(0.0, 1.0)];
+        let mut initializer = LatinHypercubeInitializer::new(intervals, 0);
+        assert!(initializer.get_coordinates().is_empty());
+    }
+
+    #[test]
+    fn latin_hypercube_seed() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 10.0)];
+        let mut initializer_1 = LatinHypercubeInitializer::new(intervals.clone(), 10);
+        let mut initializer_2 = LatinHypercubeInitializer::new(intervals.clone(), 10);
+        initializer_1.set_seed(42);
+        initializer_2.set_seed(42);
+
+        let coordinates_1: Vec<Vec<f64>> = initializer_1.get_coordinates();
+        let coordinates_2: Vec<Vec<f64>> = initializer_2.get_coordinates();
+        assert_eq!(coordinates_1, coordinates_2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn latin_hypercube_invalid_intervals() {
+        LatinHypercubeInitializer::new(vec![(1.0, 0.0)], 10);
+    }
+
+    #[test]
+    fn grid_2d() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 10.0)];
+        let mut initializer = GridInitializer::new(intervals, 3);
+        assert_eq!(initializer.get_particles_count(), 9);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        assert_eq!(
+            coordinates,
+            vec![
+                vec![0.0, -10.0],
+                vec![0.0, 0.0],
+                vec![0.0, 10.0],
+                vec![0.5, -10.0],
+                vec![0.5, 0.0],
+                vec![0.5, 10.0],
+                vec![1.0, -10.0],
+                vec![1.0, 0.0],
+                vec![1.0, 10.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_single_point() {
+        let intervals = vec![(0.0, 1.0), (-10.0, 20.0)];
+        let mut initializer = GridInitializer::new(intervals, 1);
+        assert_eq!(initializer.get_particles_count(), 1);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        assert_eq!(coordinates, vec![vec![0.5, 5.0]]);
+    }
+
+    #[test]
+    fn grid_count() {
+        let intervals = vec![(0.0, 1.0); 4];
+        let mut initializer = GridInitializer::new(intervals, 5);
+        assert_eq!(initializer.get_particles_count(), 625);
+
+        let coordinates: Vec<Vec<f64>> = initializer.get_coordinates();
+        assert_eq!(coordinates.len(), 625);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_zero_points() {
+        GridInitializer::new(vec![(0.0, 1.0)], 0);
+    }
+
+    #[test]
+    fn random_velocity() {
+        let intervals = vec![(-1.0, 1.0), (0.0, 10.0)];
+        let mut initializer = RandomVelocityInitializer::new(intervals, 50);
+
+        let velocity: Vec<Vec<f64>> = initializer.get_velocity();
+        assert_eq!(velocity.len(), 50);
+        for particle_velocity in velocity {
+            assert!(particle_velocity[0] >= -1.0 && particle_velocity[0] <= 1.0);
+            assert!(particle_velocity[1] >= 0.0 && particle_velocity[1] <= 10.0);
+        }
+    }
+
+    #[test]
+    fn random_velocity_seed() {
+        let intervals = vec![(-1.0, 1.0), (0.0, 10.0)];
+        let mut initializer_1 = RandomVelocityInitializer::new(intervals.clone(), 10);
+        let mut initializer_2 = RandomVelocityInitializer::new(intervals, 10);
+        initializer_1.set_seed(42);
+        initializer_2.set_seed(42);
+
+        let velocity_1: Vec<Vec<f64>> = initializer_1.get_velocity();
+        let velocity_2: Vec<Vec<f64>> = initializer_2.get_velocity();
+        assert_eq!(velocity_1, velocity_2);
+    }
+
+    #[test]
+    fn random_velocity_from_coordinates_intervals() {
+        let coordinates_intervals = vec![(0.0, 4.0), (-10.0, 10.0)];
+        let initializer =
+            RandomVelocityInitializer::from_coordinates_intervals(&coordinates_intervals, 10);
+
+        assert_eq!(initializer.intervals, vec![(-2.0, 2.0), (-10.0, 10.0)]);
+    }
+}
@@ -1,20 +1,83 @@
 pub mod initializing;
 pub mod postmove;
 pub mod postvelocitycalc;
+pub mod resizer;
+pub mod topology;
 pub mod velocitycalc;
 
 use std::cmp::Ordering;
 use std::f64;
+#[cfg(feature = "checkpoint")]
+use std::io;
+#[cfg(feature = "checkpoint")]
+use std::path::Path;
 
 use num::Float;
 
 use crate::tools::logging::Logger;
 use crate::tools::stopchecker::StopChecker;
-use crate::{Agent, AgentsState, AlgorithmState, Goal, IterativeOptimizer, Optimizer, Solution};
+use crate::{
+    call_iteration_callback, Agent, AgentsState, AlgorithmState, Direction, Goal, GoalValue,
+    IterativeOptimizer, NotSet, Optimizer, Solution,
+};
+
+#[cfg(feature = "parallel")]
+use crate::{calc_parallel, ParallelCalcFunction, SyncGoal};
+
+#[cfg(feature = "checkpoint")]
+use crate::tools::checkpoint;
 
 type Velocity<T> = Vec<T>;
 type Coordinate<T> = Vec<T>;
 
+type GoalBox<'a, T> = Box<dyn Goal<Coordinate<T>> + 'a>;
+type StopCheckerBox<'a, T> = Box<dyn StopChecker<Coordinate<T>> + 'a>;
+type CoordinatesInitializerBox<'a, T> = Box<dyn CoordinatesInitializer<T> + 'a>;
+type VelocityInitializerBox<'a, T> = Box<dyn VelocityInitializer<T> + 'a>;
+type VelocityCalculatorBox<'a, T> = Box<dyn VelocityCalculator<T> + 'a>;
+
+#[cfg(feature = "parallel")]
+type SyncGoalBox<'a, T> = Box<dyn SyncGoal<Coordinate<T>> + 'a>;
+
+/// The goal function of the optimizer.
+enum GoalEvaluator<'a, T> {
+    /// The goal function is calculated for the particles one by one.
+    Sequential(GoalBox<'a, T>),
+
+    /// The goal function is calculated for the particles in several threads.
+    #[cfg(feature = "parallel")]
+    Parallel(SyncGoalBox<'a, T>, ParallelCalcFunction<Coordinate<T>>),
+}
+
+impl<'a, T> GoalEvaluator<'a, T> {
+    #[cfg(feature = "parallel")]
+    fn new_parallel(goal: SyncGoalBox<'a, T>) -> Self
+    where
+        T: Send + Sync + 'a,
+    {
+        GoalEvaluator::Parallel(goal, calc_parallel::<Coordinate<T>>)
+    }
+
+    /// Calculate goal function for all points in `coordinates_list`.
+    fn calc(&mut self, coordinates_list: &[Coordinate<T>]) -> Vec<GoalValue> {
+        match self {
+            GoalEvaluator::Sequential(goal) => coordinates_list
+                .iter()
+                .map(|coordinates| goal.get(coordinates))
+                .collect(),
+            #[cfg(feature = "parallel")]
+            GoalEvaluator::Parallel(goal, calc_function) => {
+                let goal = goal.as_ref();
+                let tasks: Vec<(&dyn SyncGoal<Coordinate<T>>, &Coordinate<T>)> = coordinates_list
+                    .iter()
+                    .map(|coordinates| (goal, coordinates))
+                    .collect();
+                calc_function(&tasks)
+            }
+        }
+    }
+}
+
 /// The trait to create initial particles swarm.
 ///
 /// `T` - type of a point in the search space for goal function.
@@ -37,20 +100,117 @@ pub trait VelocityInitializer<T> {
 pub trait PostMove<T> {
     /// The method may modify coordinates list before calculate goal function
     fn post_move(&mut self, coordinates: &mut Coordinate<T>);
+
+    /// The method may modify coordinates list and velocity of the particle before calculate
+    /// goal function. The optimizer calls this method. By default it calls `post_move`.
+    fn post_move_with_velocity(
+        &mut self,
+        coordinates: &mut Coordinate<T>,
+        _velocity: &mut Velocity<T>,
+    ) {
+        self.post_move(coordinates);
+    }
+
+    /// The method may modify coordinates list and velocity of the particle with index
+    /// `particle_index` before calculate goal function. The swarm contains the particle state
+    /// before the move. The optimizer calls the method on every iteration (but not for the swarm
+    /// initialization). By default it calls `post_move_with_velocity`.
+    fn post_move_particle(
+        &mut self,
+        _swarm: &Swarm<T>,
+        _particle_index: usize,
+        coordinates: &mut Coordinate<T>,
+        velocity: &mut Velocity<T>,
+    ) {
+        self.post_move_with_velocity(coordinates, velocity);
+    }
+
+    /// The optimizer calls the method after the particles with the indices `removed_indices`
+    /// were removed from the swarm (by `SwarmResizer` or `ParticleSwarmOptimizer::remove_particle`).
+    /// The indices are sorted and unique. The indices of the following particles are decreased,
+    /// so the post moves which store the state for every particle must remove the state of the
+    /// removed particles. By default it does nothing.
+    fn particles_removed(&mut self, _removed_indices: &[usize]) {}
 }
 
 /// The trait to calculate new velocity vector for every particle
 pub trait VelocityCalculator<T> {
     fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Velocity<T>;
+
+    /// Calculate new velocity of the particle with index `particle_index`.
+    /// The optimizer calls this method. By default it calls `calc_new_velocity`.
+    fn calc_particle_velocity(&mut self, swarm: &Swarm<T>, particle_index: usize) -> Velocity<T> {
+        self.calc_new_velocity(swarm, &swarm.particles[particle_index])
+    }
 }
 
+/// The trait to correct velocity of the particle after the velocity calculation.
 pub trait PostVelocityCalc<T> {
     fn correct_velocity(&mut self, velocity: Velocity<T>) -> Velocity<T>;
+
+    /// The method may correct velocity of the particle with index `particle_index`.
+    /// The swarm contains the particle state before the move. The optimizer calls this method.
+    /// By default it calls `correct_velocity`.
+    fn correct_particle_velocity(
+        &mut self,
+        _swarm: &Swarm<T>,
+        _particle_index: usize,
+        velocity: Velocity<T>,
+    ) -> Velocity<T> {
+        self.correct_velocity(velocity)
+    }
+}
+
+/// The trait to change count of the particles during the optimization (adaptive swarm size).
+///
+/// `T` - type of a point in the search space for goal function.
+pub trait SwarmResizer<T> {
+    /// The optimizer calls the method in the end of every iteration (before the loggers).
+    /// The method may add new particles and remove the particles with `changes`.
+    /// The particles are removed before the adding. The removing changes the indices of the
+    /// following particles, so the index-based topologies get the other neighbours for them.
+    fn resize(&mut self, swarm: &Swarm<T>, changes: &mut SwarmChanges<T>);
+}
+
+/// The particles which will be added to the swarm or removed from the swarm by `SwarmResizer`.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct SwarmChanges<T> {
+    added: Vec<(Coordinate<T>, Velocity<T>)>,
+    removed: Vec<usize>,
+}
+
+impl<T> SwarmChanges<T> {
+    fn new() -> Self {
+        Self {
+            added: vec![],
+            removed: vec![],
+        }
+    }
+
+    /// Add new particle with the coordinates and the velocity.
+    /// The post moves are applied for the particle before the goal function calculating.
+    pub fn add_particle(&mut self, coordinates: Coordinate<T>, velocity: Velocity<T>) {
+        self.added.push((coordinates, velocity));
+    }
+
+    /// Remove the particle with the index in the swarm which was passed to `SwarmResizer`.
+    /// The indices outside the swarm are ignored. The last particle is not removed
+    /// if no particles are added.
+    pub fn remove_particle(&mut self, index: usize) {
+        self.removed.push(index);
+    }
+
+    /// Returns true if there are no particles to add or remove.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
 }
 
 /// Struct for single point (agent) in the search space
 ///
 /// `T` - type of a point in the search space for goal function.
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct Particle<T> {
     /// Point in the search space.
     coordinates: Coordinate<T>,
@@ -59,18 +219,21 @@ pub struct Particle<T> {
     velocity: Velocity<T>,
 
     /// Value of function in the current coordinates.
+    #[cfg_attr(feature = "checkpoint", serde(with = "crate::tools::checkpoint::goal_value"))]
     value: f64,
 
     /// Best coordinates for this particle
     best_personal_coordinates: Coordinate<T>,
 
     /// Best value for this particle
+    #[cfg_attr(feature = "checkpoint", serde(with = "crate::tools::checkpoint::goal_value"))]
     best_personal_value: f64,
 
     /// Worst coordinates for this particle
     worst_personal_coordinates: Coordinate<T>,
 
     /// Worst value for this particle
+    #[cfg_attr(feature = "checkpoint", serde(with = "crate::tools::checkpoint::goal_value"))]
     worst_personal_value: f64,
 }
 
@@ -95,6 +258,33 @@ impl<T> Agent<Coordinate<T>> for Particle<T> {
     }
 }
 
+impl<T> Particle<T> {
+    /// Returns the current point in the search space.
+    pub fn get_coordinates(&self) -> &Coordinate<T> {
+        &self.coordinates
+    }
+
+    /// Returns the current velocity of the particle.
+    pub fn get_velocity(&self) -> &Velocity<T> {
+        &self.velocity
+    }
+
+    /// Returns value of the goal function in the current point.
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the best point found by the particle.
+    pub fn get_best_personal_coordinates(&self) -> &Coordinate<T> {
+        &self.best_personal_coordinates
+    }
+
+    /// Returns value of the goal function in the best point found by the particle.
+    pub fn get_best_personal_value(&self) -> f64 {
+        self.best_personal_value
+    }
+}
+
 impl<T: Clone> Particle<T> {
     /// Return value of the goal function.
     fn new(coordinates: Coordinate<T>, velocity: Velocity<T>, value: f64) -> Self {
@@ -134,6 +324,7 @@ impl<T: Clone> Particle<T> {
 /// Stores all particles.
 ///
 /// `T` - type of a point in the search space for goal function.
+#[cfg_attr(feature = "checkpoint", derive(serde::Serialize, serde::Deserialize))]
 pub struct Swarm<T> {
     particles: Vec<Particle<T>>,
 
@@ -144,6 +335,9 @@ pub struct Swarm<T> {
     worst_particle: Option<Particle<T>>,
 
     iteration: usize,
+
+    /// Direction of the optimization. The values of particles are stored for minimization.
+    direction: Direction,
 }
 
 impl<T: Clone> Swarm<T> {
@@ -153,6 +347,7 @@ impl<T: Clone> Swarm<T> {
             best_particle: None,
             worst_particle: None,
             iteration: 0,
+            direction: Direction::Minimize,
         }
     }
 
@@ -161,6 +356,20 @@ impl<T: Clone> Swarm<T> {
         self.particles.len()
     }
 
+    /// Returns all particles of the swarm.
+    pub fn get_particles(&self) -> &[Particle<T>] {
+        &self.particles
+    }
+
+    /// Returns index of the particle in the swarm or `None` if the `particle` is not a reference
+    /// to the particle from the swarm (for example, a reference to a clone of the particle).
+    /// The method checks all particles of the swarm.
+    pub fn get_particle_index(&self, particle: &Particle<T>) -> Option<usize> {
+        self.particles
+            .iter()
+            .position(|current| std::ptr::eq(current, particle))
+    }
+
     /// Remove all particles and go to iteration 0.
     fn reset(&mut self) {
         self.particles.clear();
@@ -173,6 +382,19 @@ impl<T: Clone> Swarm<T> {
         self.iteration += 1;
     }
 
+    fn add_particles(&mut self, particles: Vec<Particle<T>>) {
+        self.particles.extend(particles);
+        self.update_best_particle();
+        self.update_worst_particle();
+    }
+
+    /// Remove the particles with the indices. The indices must be sorted and unique.
+    fn remove_particles(&mut self, indices: &[usize]) {
+        for &index in indices.iter().rev() {
+            self.particles.remove(index);
+        }
+    }
+
     fn replace_particles(&mut self, particles: Vec<Particle<T>>) {
         self.particles = particles;
         self.best_particle = Self::find_best_particle(&self.particles);
@@ -247,37 +469,68 @@ impl<T: Clone> Swarm<T> {
 }
 
 pub struct ParticleSwarmOptimizer<'a, T> {
-    goal: Box<dyn Goal<Coordinate<T>> + 'a>,
+    /// The goal function which is calculated sequentially or in several threads.
+    evaluator: GoalEvaluator<'a, T>,
+
     stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
     coordinates_initializer: Box<dyn CoordinatesInitializer<T> + 'a>,
     velocity_initializer: Box<dyn VelocityInitializer<T> + 'a>,
     velocity_calculator: Box<dyn VelocityCalculator<T> + 'a>,
     post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T> + 'a>>,
     post_move: Vec<Box<dyn PostMove<T> + 'a>>,
+    swarm_resizer: Box<dyn SwarmResizer<T> + 'a>,
     loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>,
     swarm: Swarm<T>,
+
+    /// True if the swarm was created.
+    initialized: bool,
 }
 
 impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
+    /// Create the builder for the optimizer.
+    pub fn builder() -> ParticleSwarmOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet>
+    {
+        ParticleSwarmOptimizerBuilder::new()
+    }
+
     pub fn new(
         goal: Box<dyn Goal<Coordinate<T>> + 'a>,
         stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
         coordinates_initializer: Box<dyn CoordinatesInitializer<T> + 'a>,
         velocity_initializer: Box<dyn VelocityInitializer<T> + 'a>,
         velocity_calculator: Box<dyn VelocityCalculator<T> + 'a>,
+    ) -> Self {
+        Self::with_evaluator(
+            GoalEvaluator::Sequential(goal),
+            stop_checker,
+            coordinates_initializer,
+            velocity_initializer,
+            velocity_calculator,
+        )
+    }
+
+    /// Create the optimizer with the sequential or parallel goal function.
+    fn with_evaluator(
+        evaluator: GoalEvaluator<'a, T>,
+        stop_checker: Box<dyn StopChecker<Coordinate<T>> + 'a>,
+        coordinates_initializer: Box<dyn CoordinatesInitializer<T> + 'a>,
+        velocity_initializer: Box<dyn VelocityInitializer<T> + 'a>,
+        velocity_calculator: Box<dyn VelocityCalculator<T> + 'a>,
     ) -> Self {
         let swarm = Swarm::new();
 
         ParticleSwarmOptimizer {
-            goal,
+            evaluator,
             stop_checker,
             coordinates_initializer,
             velocity_initializer,
             velocity_calculator,
             post_velocity_calc: vec![],
             post_move: vec![],
+            swarm_resizer: Box::new(resizer::NoOpSwarmResizer::new()),
             loggers: vec![],
             swarm,
+            initialized: false,
         }
     }
 
@@ -285,114 +538,554 @@ impl<'a, T: Clone + Float> ParticleSwarmOptimizer<'a, T> {
         self.stop_checker = stop_checker;
     }
 
+    /// Returns all particles of the swarm.
+    ///
+    /// The values of particles are the values which the algorithm minimizes,
+    /// so the values are the negated values of the goal function after `find_max`.
+    pub fn get_particles(&self) -> &[Particle<T>] {
+        &self.swarm.particles
+    }
+
     pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>) {
         self.loggers = loggers;
     }
 
-    pub fn set_post_moves(&mut self, post_move: Vec<Box<dyn PostMove<T>>>) {
+    pub fn set_post_moves(&mut self, post_move: Vec<Box<dyn PostMove<T> + 'a>>) {
         self.post_move = post_move;
     }
 
-    pub fn set_post_velocity_calc(&mut self, post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T>>>) {
+    pub fn set_post_velocity_calc(
+        &mut self,
+        post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T> + 'a>>,
+    ) {
         self.post_velocity_calc = post_velocity_calc;
     }
 
-    fn renew_swarm(&mut self) {
-        let mut coordinates = self.coordinates_initializer.get_coordinates();
-        let velocity = self.velocity_initializer.get_velocity();
+    /// Set the struct to change count of the particles during the optimization.
+    /// The count of the particles is not changed by default (`NoOpSwarmResizer`).
+    pub fn set_swarm_resizer(&mut self, swarm_resizer: Box<dyn SwarmResizer<T> + 'a>) {
+        self.swarm_resizer = swarm_resizer;
+    }
+
+    /// Add new particle to the swarm. The post moves are applied for the particle and the goal
+    /// function is calculated for it. The method may be used between the calls of
+    /// `IterativeOptimizer::step`.
+    pub fn add_particle(&mut self, coordinates: Coordinate<T>, velocity: Velocity<T>) {
+        self.add_particles(vec![(coordinates, velocity)]);
+    }
+
+    /// Remove the particle with the index from the swarm. The best solution is not changed.
+    /// The indices of the following particles are decreased. The method does nothing if
+    /// the index is outside the swarm or the swarm contains the single particle.
+    pub fn remove_particle(&mut self, index: usize) {
+        self.remove_particles(vec![index], 1);
+    }
+
+    /// Calculate goal function for all points in `coordinates_list`.
+    fn calc_goal(&mut self, coordinates_list: &[Coordinate<T>]) -> Vec<GoalValue> {
+        let direction = self.swarm.direction;
+        self.evaluator
+            .calc(coordinates_list)
+            .into_iter()
+            .map(|value| direction.apply(value))
+            .collect()
+    }
+
+    /// Create the particles with the coordinates and the velocity after the post moves.
+    fn create_particles(
+        &mut self,
+        mut coordinates: Vec<Coordinate<T>>,
+        mut velocity: Vec<Velocity<T>>,
+    ) -> Vec<Particle<T>> {
         assert!(coordinates.len() == velocity.len());
 
-        for mut current_coordinates in &mut coordinates {
-            self.post_move
-                .iter_mut()
-                .for_each(|post_move| post_move.post_move(&mut current_coordinates));
+        for (current_coordinates, current_velocity) in coordinates.iter_mut().zip(velocity.iter_mut()) {
+            self.post_move.iter_mut().for_each(|post_move| {
+                post_move.post_move_with_velocity(current_coordinates, current_velocity)
+            });
         }
 
-        let particles: Vec<Particle<T>> = coordinates
-            .iter()
-            .zip(velocity.iter())
-            .map(|cs| {
-                let particle_coordinate = cs.0.clone();
-                let particle_velocity = cs.1.clone();
-                let particle_value = self.goal.get(cs.0);
-                Particle::new(particle_coordinate, particle_velocity, particle_value)
+        let values = self.calc_goal(&coordinates);
+
+        coordinates
+            .into_iter()
+            .zip(velocity)
+            .zip(values)
+            .map(|((particle_coordinates, particle_velocity), particle_value)| {
+                Particle::new(particle_coordinates, particle_velocity, particle_value)
             })
-            .collect();
+            .collect()
+    }
+
+    fn renew_swarm(&mut self) {
+        let coordinates = self.coordinates_initializer.get_coordinates();
+        let velocity = self.velocity_initializer.get_velocity();
+        let particles = self.create_particles(coordinates, velocity);
 
         self.swarm.reset();
         self.swarm.replace_particles(particles);
     }
-}
 
-impl<'a, T: Clone + Float> Optimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
-    fn find_min(&mut self) -> Option<(Coordinate<T>, f64)> {
+    fn add_particles(&mut self, new_particles: Vec<(Coordinate<T>, Velocity<T>)>) {
+        let (coordinates, velocity) = new_particles.into_iter().unzip();
+        let particles = self.create_particles(coordinates, velocity);
+        self.swarm.add_particles(particles);
+    }
+
+    /// Remove the particles with the indices and notify the post moves. The indices may be
+    /// repeated, the indices outside the swarm are ignored. At least `min_count` particles are
+    /// kept in the swarm.
+    fn remove_particles(&mut self, mut indices: Vec<usize>, min_count: usize) {
+        let count = self.swarm.len();
+        indices.retain(|&index| index < count);
+        indices.sort_unstable();
+        indices.dedup();
+        indices.truncate(count.saturating_sub(min_count));
+        if indices.is_empty() {
+            return;
+        }
+
+        self.swarm.remove_particles(&indices);
+        self.post_move
+            .iter_mut()
+            .for_each(|post_move| post_move.particles_removed(&indices));
+    }
+
+    /// Add and remove the particles with `SwarmResizer`.
+    fn resize_swarm(&mut self) {
+        let mut changes = SwarmChanges::new();
+        self.swarm_resizer.resize(&self.swarm, &mut changes);
+        if changes.is_empty() {
+            return;
+        }
+
+        // The swarm must not be empty after the resizing
+        let min_count = if changes.added.is_empty() { 1 } else { 0 };
+        self.remove_particles(changes.removed, min_count);
+        if !changes.added.is_empty() {
+            self.add_particles(changes.added);
+        }
+    }
+
+    /// Create the swarm.
+    fn init(&mut self, direction: Direction) {
+        self.swarm.direction = direction;
         self.renew_swarm();
+        self.stop_checker.reset();
+        self.initialized = true;
 
         for logger in &mut self.loggers {
             logger.start(&self.swarm);
         }
+    }
 
+    /// Create the swarm and run the algorithm.
+    fn run(&mut self, direction: Direction) -> Option<Solution<Coordinate<T>>> {
+        self.init(direction);
         self.next_iterations()
     }
-}
 
-impl<'a, T: Clone + Float> IterativeOptimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
-    /// Main algorithm steps is here
-    fn next_iterations(&mut self) -> Option<Solution<Coordinate<T>>> {
+    /// Move all particles once.
+    fn do_iteration(&mut self) {
+        let mut coordinates_list = Vec::with_capacity(self.swarm.particles.len());
+
+        for n in 0..self.swarm.particles.len() {
+            // Calculate new velocity
+            let mut new_velocity = self.velocity_calculator.calc_particle_velocity(&self.swarm, n);
+
+            // Correct new velocity
+            for post_velocity_calc in &mut self.post_velocity_calc {
+                new_velocity =
+                    post_velocity_calc.correct_particle_velocity(&self.swarm, n, new_velocity);
+            }
+
+            // Calculate new coordinates
+            let mut new_coordinates: Coordinate<T> = self.swarm.particles[n]
+                .coordinates
+                .iter()
+                .zip(new_velocity.iter())
+                .map(|(coord, velocity)| *coord + *velocity)
+                .collect();
+
+            // Correct coordinates
+            let swarm = &self.swarm;
+            self.post_move.iter_mut().for_each(|post_move| {
+                post_move.post_move_particle(swarm, n, &mut new_coordinates, &mut new_velocity)
+            });
+
+            self.swarm.particles[n].set_velocity(new_velocity);
+            coordinates_list.push(new_coordinates);
+        }
+
+        // Calculate new values for the particles
+        let values = self.calc_goal(&coordinates_list);
+
+        for (particle, (new_coordinates, new_value)) in self
+            .swarm
+            .particles
+            .iter_mut()
+            .zip(coordinates_list.into_iter().zip(values))
+        {
+            particle.move_to(new_coordinates, new_value);
+        }
+
+        self.swarm.update_best_particle();
+        self.swarm.update_worst_particle();
+        self.swarm.next_iteration();
+        self.resize_swarm();
+
         for logger in &mut self.loggers {
-            logger.resume(&self.swarm);
+            logger.next_iteration(&self.swarm);
         }
+    }
+}
 
-        while !self.stop_checker.can_stop(&self.swarm) {
-            for n in 0..self.swarm.particles.len() {
-                // Calculate new velocity
-                let mut new_velocity = self
-                    .velocity_calculator
-                    .calc_new_velocity(&self.swarm, &self.swarm.particles[n]);
-
-                // Correct new velocity
-                for post_velocity_calc in &mut self.post_velocity_calc {
-                    new_velocity = post_velocity_calc.correct_velocity(new_velocity);
-                }
+#[cfg(feature = "checkpoint")]
+impl<'a, T> ParticleSwarmOptimizer<'a, T>
+where
+    T: Clone + Float + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Save the state of the algorithm to the JSON file: the particles (coordinates, velocities,
+    /// personal best and worst points), the best and the worst particles, the iteration number
+    /// and the direction of the optimization.
+    ///
+    /// The method is available with the "checkpoint" feature.
+    pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        checkpoint::save(path, &self.swarm)
+    }
 
-                self.swarm.particles[n].set_velocity(new_velocity);
+    /// Replace the state of the algorithm with the state saved by `save_checkpoint`.
+    /// The optimization may be continued by `IterativeOptimizer::next_iterations` or
+    /// `IterativeOptimizer::step` after the loading.
+    ///
+    /// The goal function, the stop checker and the other parts of the algorithm are not saved
+    /// in the checkpoint, so the optimizer must be created with the same parts as the saved one.
+    /// Internal state of the parts (for example, random generators) is not restored.
+    ///
+    /// The method is available with the "checkpoint" feature.
+    pub fn load_checkpoint<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let swarm: Swarm<T> = checkpoint::load(path)?;
+        if swarm.particles.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the checkpoint does not contain particles",
+            ));
+        }
 
-                // Calculate new coordinates
-                let mut new_coordinates: Coordinate<T> = self.swarm.particles[n]
-                    .coordinates
-                    .iter()
-                    .zip(self.swarm.particles[n].velocity.iter())
-                    .map(|(coord, velocity)| *coord + *velocity)
-                    .collect();
+        self.swarm = swarm;
+        self.initialized = true;
+        Ok(())
+    }
+}
 
-                // Correct coordinates
-                self.post_move
-                    .iter_mut()
-                    .for_each(|post_move| post_move.post_move(&mut new_coordinates));
+/// The builder for `ParticleSwarmOptimizer`.
+///
+/// The `build()` method is available only after the goal function, the stop checker,
+/// the coordinates initializer, the velocity initializer and the velocity calculator are set.
+/// Post moves, post velocity calculators, the swarm resizer and loggers are optional.
+///
+/// ```compile_fail
+/// use ew::particleswarm::ParticleSwarmOptimizer;
+/// use ew::tools::stopchecker;
+///
+/// // The goal function and other required components are not set.
+/// let optimizer = ParticleSwarmOptimizer::<f32>::builder()
+///     .stop_checker(Box::new(stopchecker::MaxIterations::new(10)))
+///     .build();
+/// ```
+pub struct ParticleSwarmOptimizerBuilder<'a, T, G, S, CI, VI, VC> {
+    goal: G,
+    stop_checker: S,
+    coordinates_initializer: CI,
+    velocity_initializer: VI,
+    velocity_calculator: VC,
+    post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T> + 'a>>,
+    post_move: Vec<Box<dyn PostMove<T> + 'a>>,
+    swarm_resizer: Option<Box<dyn SwarmResizer<T> + 'a>>,
+    loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>,
+}
 
-                // Calculate new value for the particle
-                let new_value = self.goal.get(&new_coordinates);
+impl<'a, T> ParticleSwarmOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet> {
+    /// Constructor. All required components are not set.
+    pub fn new() -> Self {
+        ParticleSwarmOptimizerBuilder {
+            goal: NotSet,
+            stop_checker: NotSet,
+            coordinates_initializer: NotSet,
+            velocity_initializer: NotSet,
+            velocity_calculator: NotSet,
+            post_velocity_calc: vec![],
+            post_move: vec![],
+            swarm_resizer: None,
+            loggers: vec![],
+        }
+    }
+}
 
-                self.swarm.particles[n].move_to(new_coordinates, new_value);
-            }
+impl<'a, T> Default for ParticleSwarmOptimizerBuilder<'a, T, NotSet, NotSet, NotSet, NotSet, NotSet> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-            self.swarm.update_best_particle();
-            self.swarm.update_worst_particle();
-            self.swarm.next_iteration();
+impl<'a, T, G, S, CI, VI, VC> ParticleSwarmOptimizerBuilder<'a, T, G, S, CI, VI, VC> {
+    /// Set the goal function which is calculated for the particles one by one.
+    pub fn goal(
+        self,
+        goal: GoalBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, GoalBox<'a, T>, S, CI, VI, VC> {
+        self.set_goal(goal)
+    }
 
-            for logger in &mut self.loggers {
-                logger.next_iteration(&self.swarm);
-            }
+    /// Set the goal function which is calculated for the particles in several threads.
+    /// The method is an alternative to `goal`: the optimizer has single goal function which is
+    /// used for all calculations, including the creation of the swarm.
+    /// Use `GoalCalcStatisticsArc` to count the goal function calls.
+    ///
+    /// The method is available with the "parallel" feature.
+    #[cfg(feature = "parallel")]
+    pub fn parallel_goal(
+        self,
+        goal: SyncGoalBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, SyncGoalBox<'a, T>, S, CI, VI, VC> {
+        self.set_goal(goal)
+    }
+
+    fn set_goal<NG>(self, goal: NG) -> ParticleSwarmOptimizerBuilder<'a, T, NG, S, CI, VI, VC> {
+        ParticleSwarmOptimizerBuilder {
+            goal,
+            stop_checker: self.stop_checker,
+            coordinates_initializer: self.coordinates_initializer,
+            velocity_initializer: self.velocity_initializer,
+            velocity_calculator: self.velocity_calculator,
+            post_velocity_calc: self.post_velocity_calc,
+            post_move: self.post_move,
+            swarm_resizer: self.swarm_resizer,
+            loggers: self.loggers,
+        }
+    }
+
+    pub fn stop_checker(
+        self,
+        stop_checker: StopCheckerBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, G, StopCheckerBox<'a, T>, CI, VI, VC>
+    {
+        ParticleSwarmOptimizerBuilder {
+            goal: self.goal,
+            stop_checker,
+            coordinates_initializer: self.coordinates_initializer,
+            velocity_initializer: self.velocity_initializer,
+            velocity_calculator: self.velocity_calculator,
+            post_velocity_calc: self.post_velocity_calc,
+            post_move: self.post_move,
+            swarm_resizer: self.swarm_resizer,
+            loggers: self.loggers,
+        }
+    }
+
+    pub fn coordinates_initializer(
+        self,
+        coordinates_initializer: CoordinatesInitializerBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, G, S, CoordinatesInitializerBox<'a, T>, VI, VC>
+    {
+        ParticleSwarmOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            coordinates_initializer,
+            velocity_initializer: self.velocity_initializer,
+            velocity_calculator: self.velocity_calculator,
+            post_velocity_calc: self.post_velocity_calc,
+            post_move: self.post_move,
+            swarm_resizer: self.swarm_resizer,
+            loggers: self.loggers,
+        }
+    }
+
+    pub fn velocity_initializer(
+        self,
+        velocity_initializer: VelocityInitializerBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, G, S, CI, VelocityInitializerBox<'a, T>, VC>
+    {
+        ParticleSwarmOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            coordinates_initializer: self.coordinates_initializer,
+            velocity_initializer,
+            velocity_calculator: self.velocity_calculator,
+            post_velocity_calc: self.post_velocity_calc,
+            post_move: self.post_move,
+            swarm_resizer: self.swarm_resizer,
+            loggers: self.loggers,
+        }
+    }
+
+    pub fn velocity_calculator(
+        self,
+        velocity_calculator: VelocityCalculatorBox<'a, T>,
+    ) -> ParticleSwarmOptimizerBuilder<'a, T, G, S, CI, VI, VelocityCalculatorBox<'a, T>>
+    {
+        ParticleSwarmOptimizerBuilder {
+            goal: self.goal,
+            stop_checker: self.stop_checker,
+            coordinates_initializer: self.coordinates_initializer,
+            velocity_initializer: self.velocity_initializer,
+            velocity_calculator,
+            post_velocity_calc: self.post_velocity_calc,
+            post_move: self.post_move,
+            swarm_resizer: self.swarm_resizer,
+            loggers: self.loggers,
+        }
+    }
+
+    pub fn post_moves(mut self, post_move: Vec<Box<dyn PostMove<T> + 'a>>) -> Self {
+        self.post_move = post_move;
+        self
+    }
+
+    pub fn post_velocity_calc(
+        mut self,
+        post_velocity_calc: Vec<Box<dyn PostVelocityCalc<T> + 'a>>,
+    ) -> Self {
+        self.post_velocity_calc = post_velocity_calc;
+        self
+    }
+
+    pub fn swarm_resizer(mut self, swarm_resizer: Box<dyn SwarmResizer<T> + 'a>) -> Self {
+        self.swarm_resizer = Some(swarm_resizer);
+        self
+    }
+
+    pub fn loggers(mut self, loggers: Vec<Box<dyn Logger<Coordinate<T>> + 'a>>) -> Self {
+        self.loggers = loggers;
+        self
+    }
+}
+
+impl<'a, T: Clone + Float>
+    ParticleSwarmOptimizerBuilder<
+        'a,
+        T,
+        GoalBox<'a, T>,
+        StopCheckerBox<'a, T>,
+        CoordinatesInitializerBox<'a, T>,
+        VelocityInitializerBox<'a, T>,
+        VelocityCalculatorBox<'a, T>,
+    >
+{
+    /// Create the optimizer from the components.
+    pub fn build(self) -> ParticleSwarmOptimizer<'a, T> {
+        self.build_with_evaluator(GoalEvaluator::Sequential)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, T: Clone + Float + Send + Sync + 'a>
+    ParticleSwarmOptimizerBuilder<
+        'a,
+        T,
+        SyncGoalBox<'a, T>,
+        StopCheckerBox<'a, T>,
+        CoordinatesInitializerBox<'a, T>,
+        VelocityInitializerBox<'a, T>,
+        VelocityCalculatorBox<'a, T>,
+    >
+{
+    /// Create the optimizer which calculates the goal function in several threads.
+    pub fn build(self) -> ParticleSwarmOptimizer<'a, T> {
+        self.build_with_evaluator(GoalEvaluator::new_parallel)
+    }
+}
+
+impl<'a, T: Clone + Float, G>
+    ParticleSwarmOptimizerBuilder<
+        'a,
+        T,
+        G,
+        StopCheckerBox<'a, T>,
+        CoordinatesInitializerBox<'a, T>,
+        VelocityInitializerBox<'a, T>,
+        VelocityCalculatorBox<'a, T>,
+    >
+{
+    fn build_with_evaluator<F>(self, create_evaluator: F) -> ParticleSwarmOptimizer<'a, T>
+    where
+        F: FnOnce(G) -> GoalEvaluator<'a, T>,
+    {
+        let mut optimizer = ParticleSwarmOptimizer::with_evaluator(
+            create_evaluator(self.goal),
+            self.stop_checker,
+            self.coordinates_initializer,
+            self.velocity_initializer,
+            self.velocity_calculator,
+        );
+        optimizer.set_post_velocity_calc(self.post_velocity_calc);
+        optimizer.set_post_moves(self.post_move);
+        if let Some(swarm_resizer) = self.swarm_resizer {
+            optimizer.set_swarm_resizer(swarm_resizer);
+        }
+        optimizer.set_loggers(self.loggers);
+        optimizer
+    }
+}
+
+impl<'a, T: Clone + Float> Optimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<(Coordinate<T>, f64)> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<(Coordinate<T>, f64)> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove all particles and go to iteration 0.
+    fn reset(&mut self) {
+        self.swarm.reset();
+        self.swarm.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+impl<'a, T: Clone + Float> IterativeOptimizer<Coordinate<T>> for ParticleSwarmOptimizer<'a, T> {
+    /// Main algorithm steps is here
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&Coordinate<T>, GoalValue)>),
+    ) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.swarm.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.swarm);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.swarm) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.swarm);
         }
 
         for logger in &mut self.loggers {
             logger.finish(&self.swarm);
         }
 
-        match &self.swarm.best_particle {
-            None => None,
-            Some(particle) => Some((particle.coordinates.clone(), particle.value)),
+        self.swarm.get_best_solution()
+    }
+
+    /// Move all particles once.
+    fn step(&mut self) -> Option<Solution<Coordinate<T>>> {
+        if !self.initialized {
+            self.init(self.swarm.direction);
         }
+
+        if self.stop_checker.can_stop(&self.swarm) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.swarm.get_best_solution()
     }
 }
 
@@ -400,13 +1093,27 @@ impl<T: Clone> AlgorithmState<Coordinate<T>> for Swarm<T> {
     fn get_best_solution(&self) -> Option<(Coordinate<T>, f64)> {
         match &self.best_particle {
             None => None,
-            Some(particle) => Some((particle.coordinates.clone(), particle.value)),
+            Some(particle) => Some((
+                particle.coordinates.clone(),
+                self.direction.apply(particle.value),
+            )),
         }
     }
 
     fn get_iteration(&self) -> usize {
         self.iteration
     }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&Coordinate<T>> {
+        self.particles
+            .iter()
+            .map(|particle| &particle.coordinates)
+            .collect()
+    }
 }
 
 impl<T: Clone> AgentsState<Coordinate<T>> for Swarm<T> {
@@ -445,6 +1152,22 @@ fn compare_floats(x: f64, y: f64) -> Ordering {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn goal_evaluator_parallel() {
+        let goal = |x: &Vec<f64>| x.iter().map(|x_i| x_i * x_i).sum();
+        let coordinates_list = vec![vec![1.0, 2.0], vec![3.0, 0.0], vec![f64::NAN, 1.0]];
+
+        let mut evaluator =
+            GoalEvaluator::new_parallel(Box::new(crate::GoalFromFunction::new(goal)));
+        let goals = evaluator.calc(&coordinates_list);
+
+        assert_eq!(goals.len(), 3);
+        assert_eq!(goals[0], 5.0);
+        assert_eq!(goals[1], 9.0);
+        assert!(goals[2].is_nan());
+    }
+
     #[test]
     fn test_compare_floats() {
         assert_eq!(
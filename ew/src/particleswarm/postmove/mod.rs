@@ -1,9 +1,11 @@
-use crate::particleswarm::PostMove;
+use crate::particleswarm::{velocitycalc::sigmoid, PostMove, Swarm};
+use crate::tools::get_standard_normal;
 
 use num::Float;
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 /// The struct to limit the coordinates of particle.
 pub struct MoveToBoundary<T> {
@@ -44,7 +46,7 @@ impl<T: Float> PostMove<T> for MoveToBoundary<T> {
 pub struct RandomTeleport<T: Float + SampleUniform> {
     intervals: Vec<(T, T)>,
     probability: f32,
-    random: ThreadRng,
+    random: StdRng,
     random_intervals: Vec<Uniform<T>>,
 }
 
@@ -64,10 +66,15 @@ impl<T: Float + SampleUniform> RandomTeleport<T> {
         Self {
             intervals,
             probability,
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
             random_intervals,
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<T: Float + SampleUniform> PostMove<T> for RandomTeleport<T> {
@@ -84,9 +91,452 @@ impl<T: Float + SampleUniform> PostMove<T> for RandomTeleport<T> {
     }
 }
 
+/// The struct to reflect the particle from the boundaries of the search space.
+/// The coordinate which is out of the interval is mirrored with respect to the crossed boundary
+/// (several times if necessary) and the velocity projection is negated for every reflection.
+pub struct ReflectBoundary<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Float> ReflectBoundary<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    pub fn new(intervals: Vec<(T, T)>) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 < interval.1);
+        }
+
+        Self { intervals }
+    }
+
+    /// Reflect the coordinate into the interval.
+    /// Returns the new coordinate and true if the count of the reflections is odd.
+    fn reflect(coordinate: T, (min, max): (T, T)) -> (T, bool) {
+        if coordinate >= min && coordinate <= max {
+            return (coordinate, false);
+        }
+
+        let width = max - min;
+        let two = T::from(2.0).unwrap();
+
+        // The distance from the opposite boundary
+        let distance = if coordinate < min {
+            max - coordinate
+        } else {
+            coordinate - min
+        };
+
+        let reflections = (distance / width).floor();
+        let remainder = distance - reflections * width;
+        let odd = reflections % two == T::one();
+
+        let new_coordinate = match (coordinate < min, odd) {
+            (true, true) => min + remainder,
+            (true, false) => max - remainder,
+            (false, true) => max - remainder,
+            (false, false) => min + remainder,
+        };
+
+        (new_coordinate, odd)
+    }
+}
+
+impl<T: Float> PostMove<T> for ReflectBoundary<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), self.intervals.len());
+
+        for (coordinate, interval) in coordinates.iter_mut().zip(self.intervals.iter()) {
+            if !coordinate.is_finite() {
+                *coordinate = interval.0;
+            }
+
+            *coordinate = Self::reflect(*coordinate, *interval).0;
+        }
+    }
+
+    fn post_move_with_velocity(&mut self, coordinates: &mut Vec<T>, velocity: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), self.intervals.len());
+        assert_eq!(velocity.len(), self.intervals.len());
+
+        for ((coordinate, velocity_item), interval) in coordinates
+            .iter_mut()
+            .zip(velocity.iter_mut())
+            .zip(self.intervals.iter())
+        {
+            if !coordinate.is_finite() {
+                *coordinate = interval.0;
+                *velocity_item = T::zero();
+            }
+
+            let (new_coordinate, odd) = Self::reflect(*coordinate, *interval);
+            *coordinate = new_coordinate;
+            if odd {
+                *velocity_item = -*velocity_item;
+            }
+        }
+    }
+}
+
+/// The struct to stop the particle on the boundaries of the search space.
+/// The coordinate which is out of the interval is moved to the boundary and the velocity
+/// projection for this dimension is set to zero. The velocity projections for other dimensions
+/// are not changed.
+pub struct AbsorbingBoundary<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> AbsorbingBoundary<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    pub fn new(intervals: Vec<(T, T)>) -> Self {
+        Self { intervals }
+    }
+}
+
+impl<T: Float> PostMove<T> for AbsorbingBoundary<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        let mut velocity = vec![T::zero(); coordinates.len()];
+        self.post_move_with_velocity(coordinates, &mut velocity);
+    }
+
+    fn post_move_with_velocity(&mut self, coordinates: &mut Vec<T>, velocity: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), self.intervals.len());
+        assert_eq!(velocity.len(), self.intervals.len());
+
+        for ((coordinate, velocity_item), (min, max)) in coordinates
+            .iter_mut()
+            .zip(velocity.iter_mut())
+            .zip(self.intervals.iter())
+        {
+            if !coordinate.is_finite() || *coordinate < *min {
+                *coordinate = *min;
+                *velocity_item = T::zero();
+            } else if *coordinate > *max {
+                *coordinate = *max;
+                *velocity_item = T::zero();
+            }
+        }
+    }
+}
+
+/// The struct for the periodic boundaries of the search space. The particle which leaves
+/// the interval through one boundary returns through the opposite boundary
+/// (the coordinate `max + d` becomes `min + d`). The velocity is not changed.
+/// The maximum coordinate is equivalent to the minimum coordinate, so the coordinates
+/// are in the range [min, max).
+pub struct ToroidalBoundary<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Float> ToroidalBoundary<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    pub fn new(intervals: Vec<(T, T)>) -> Self {
+        for interval in &intervals {
+            assert!(interval.0 < interval.1);
+        }
+
+        Self { intervals }
+    }
+}
+
+impl<T: Float> PostMove<T> for ToroidalBoundary<T> {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        assert_eq!(coordinates.len(), self.intervals.len());
+
+        for (coordinate, (min, max)) in coordinates.iter_mut().zip(self.intervals.iter()) {
+            if !coordinate.is_finite() {
+                *coordinate = *min;
+                continue;
+            }
+
+            let width = *max - *min;
+            let mut offset = (*coordinate - *min) % width;
+            if offset < T::zero() {
+                offset = offset + width;
+            }
+
+            *coordinate = *min + offset;
+
+            // Rounding error may give the max value
+            if *coordinate >= *max {
+                *coordinate = *min;
+            }
+        }
+    }
+}
+
+/// The struct to add the Gaussian noise to the particle coordinates. The noise helps particles
+/// to escape the local optima.
+/// The struct does not check the intervals, so it should be used with `MoveToBoundary` or
+/// other post move which limits the coordinates.
+pub struct GaussianPerturbation {
+    sigma: f64,
+    probability: f64,
+    random: StdRng,
+}
+
+impl GaussianPerturbation {
+    /// Constructor. The noise is added to every particle after every move.
+    ///
+    /// # Parameters
+    /// `sigma` - standard deviation of the noise. Must be greater or equal to 0.
+    pub fn new(sigma: f64) -> Self {
+        assert!(sigma >= 0.0);
+        Self {
+            sigma,
+            probability: 1.0,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set probability to add the noise to the particle. Must be in the range [0, 1].
+    /// The default value is 1.
+    pub fn set_probability(&mut self, probability: f64) {
+        assert!((0.0..=1.0).contains(&probability));
+        self.probability = probability;
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<T: Float> PostMove<T> for GaussianPerturbation {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        if self.probability < 1.0 {
+            let rnd = Uniform::new(0.0, 1.0).sample(&mut self.random);
+            if rnd >= self.probability {
+                return;
+            }
+        }
+
+        for coordinate in coordinates.iter_mut() {
+            let noise = self.sigma * get_standard_normal(&mut self.random);
+            *coordinate = *coordinate + T::from(noise).unwrap();
+        }
+    }
+}
+
+/// The struct to move the particle to random position if the personal best value of the particle
+/// was not improved during `max_stagnant_iterations` iterations. The velocity of the particle
+/// may be set to zero too (see `set_reset_velocity`). The personal best position of the particle
+/// is not changed.
+///
+/// The struct works only during the optimization (with the `post_move_particle` method),
+/// the `post_move` method does not change the coordinates.
+pub struct StagnationReset<T: Float + SampleUniform> {
+    intervals: Vec<(T, T)>,
+    max_stagnant_iterations: usize,
+    reset_velocity: bool,
+    random: StdRng,
+    random_intervals: Vec<Uniform<T>>,
+
+    /// The last personal best value and count of the iterations without improvement
+    /// for every particle.
+    stagnation: Vec<(f64, usize)>,
+}
+
+impl<T: Float + SampleUniform> StagnationReset<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `intervals` - vector of tuples. Size of the vector must be equal to dimension. The first value in tuple is minimum coordinate, the second value is maximum coordinate.
+    /// `max_stagnant_iterations` - count of the iterations without improvement before
+    /// the particle moving. Must be greater than 0.
+    pub fn new(intervals: Vec<(T, T)>, max_stagnant_iterations: usize) -> Self {
+        assert!(max_stagnant_iterations > 0);
+        let random_intervals = intervals
+            .iter()
+            .map(|(min, max)| Uniform::new_inclusive(min, max))
+            .collect();
+        Self {
+            intervals,
+            max_stagnant_iterations,
+            reset_velocity: false,
+            random: StdRng::from_entropy(),
+            random_intervals,
+            stagnation: vec![],
+        }
+    }
+
+    /// Set the velocity of the moved particle to zero if `reset_velocity` is true.
+    /// The velocity is not changed by default.
+    pub fn set_reset_velocity(&mut self, reset_velocity: bool) {
+        self.reset_velocity = reset_velocity;
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<T: Float + SampleUniform> PostMove<T> for StagnationReset<T> {
+    fn post_move(&mut self, _coordinates: &mut Vec<T>) {}
+
+    fn post_move_particle(
+        &mut self,
+        swarm: &Swarm<T>,
+        particle_index: usize,
+        coordinates: &mut Vec<T>,
+        velocity: &mut Vec<T>,
+    ) {
+        assert_eq!(coordinates.len(), self.intervals.len());
+
+        if self.stagnation.len() < swarm.len() {
+            self.stagnation.resize(swarm.len(), (f64::INFINITY, 0));
+        }
+
+        let best_value = swarm.particles[particle_index].best_personal_value;
+        let (last_best_value, stagnant_iterations) = &mut self.stagnation[particle_index];
+
+        // The new optimization is started or the particle is improved
+        if swarm.iteration == 0 || best_value < *last_best_value {
+            *last_best_value = best_value;
+            *stagnant_iterations = 0;
+            return;
+        }
+
+        *stagnant_iterations += 1;
+        if *stagnant_iterations >= self.max_stagnant_iterations {
+            *stagnant_iterations = 0;
+
+            for (coordinate, random_interval) in
+                coordinates.iter_mut().zip(self.random_intervals.iter())
+            {
+                *coordinate = random_interval.sample(&mut self.random);
+            }
+
+            if self.reset_velocity {
+                velocity.iter_mut().for_each(|item| *item = T::zero());
+            }
+        }
+    }
+
+    fn particles_removed(&mut self, removed_indices: &[usize]) {
+        for &index in removed_indices.iter().rev() {
+            if index < self.stagnation.len() {
+                self.stagnation.remove(index);
+            }
+        }
+    }
+}
+
+/// The struct to solve the mixed-integer problems. The coordinates with the given indices are
+/// rounded to the nearest integer after every move. The other coordinates are not changed.
+/// Use the struct after the boundary post moves (for example, `MoveToBoundary`) with the integer
+/// boundaries to keep the rounded coordinates inside the search space.
+pub struct MixedIntegerPostMove {
+    integer_dimensions: Vec<usize>,
+}
+
+impl MixedIntegerPostMove {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `integer_dimensions` - indices of the coordinates which must be integer. The indices must be unique.
+    pub fn new(integer_dimensions: Vec<usize>) -> Self {
+        for (i, dimension) in integer_dimensions.iter().enumerate() {
+            assert!(!integer_dimensions[i + 1..].contains(dimension));
+        }
+
+        Self { integer_dimensions }
+    }
+}
+
+impl<T: Float> PostMove<T> for MixedIntegerPostMove {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        for &dimension in &self.integer_dimensions {
+            assert!(dimension < coordinates.len());
+            coordinates[dimension] = coordinates[dimension].round();
+        }
+    }
+}
+
+/// The struct to move the particles of the binary particle swarm (see
+/// `velocitycalc::BinaryVelocityCalculator`). Every coordinate is 0 or 1. After the move every
+/// coordinate is set to 1 with the probability `sigmoid(v_i)` and to 0 otherwise, where `v_i` is
+/// the velocity projection. The goal function receives the coordinates as the floating point
+/// values 0 and 1.
+///
+/// The initial coordinates (for example, from `initializing::RandomCoordinatesInitializer` with
+/// the intervals (0, 1)) are rounded to the nearest bit.
+pub struct BinaryPostMove {
+    random: StdRng,
+}
+
+impl BinaryPostMove {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self {
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl Default for BinaryPostMove {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float> PostMove<T> for BinaryPostMove {
+    fn post_move(&mut self, coordinates: &mut Vec<T>) {
+        let half = T::from(0.5).unwrap();
+        for coordinate in coordinates.iter_mut() {
+            *coordinate = if *coordinate >= half {
+                T::one()
+            } else {
+                T::zero()
+            };
+        }
+    }
+
+    fn post_move_particle(
+        &mut self,
+        _swarm: &Swarm<T>,
+        _particle_index: usize,
+        coordinates: &mut Vec<T>,
+        velocity: &mut Vec<T>,
+    ) {
+        assert_eq!(coordinates.len(), velocity.len());
+
+        let between = Uniform::new(0.0_f64, 1.0_f64);
+        for (coordinate, velocity_item) in coordinates.iter_mut().zip(velocity.iter()) {
+            let probability = sigmoid(velocity_item.to_f64().unwrap());
+            *coordinate = if between.sample(&mut self.random) < probability {
+                T::one()
+            } else {
+                T::zero()
+            };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::particleswarm::{postmove::MoveToBoundary, PostMove};
+    use crate::particleswarm::{
+        postmove::{
+            AbsorbingBoundary, BinaryPostMove, GaussianPerturbation, MixedIntegerPostMove,
+            MoveToBoundary, ReflectBoundary, StagnationReset, ToroidalBoundary,
+        },
+        resizer::DiversityBasedResizer,
+        velocitycalc::sigmoid,
+        Particle, PostMove, Swarm, SwarmChanges, SwarmResizer,
+    };
     use num::abs;
 
     #[test]
@@ -143,4 +593,477 @@ mod tests {
         assert!(abs(coordinates[1] - 3.0_f32) < 1e-6);
         assert!(abs(coordinates[2] - 6.0_f32) < 1e-6);
     }
+
+    #[test]
+    fn reflect_inside_interval() {
+        let mut postmove = ReflectBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![3.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![3.0]);
+        assert_eq!(velocity, vec![1.0]);
+    }
+
+    #[test]
+    fn reflect_single() {
+        let mut postmove = ReflectBoundary::new(vec![(0.0_f64, 10.0_f64), (0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![12.0, -3.0];
+        let mut velocity = vec![5.0, -4.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert!(abs(coordinates[0] - 8.0) < 1e-10);
+        assert!(abs(coordinates[1] - 3.0) < 1e-10);
+        assert_eq!(velocity, vec![-5.0, 4.0]);
+    }
+
+    #[test]
+    fn reflect_several() {
+        let mut postmove = ReflectBoundary::new(vec![(0.0_f64, 10.0_f64), (0.0_f64, 10.0_f64)]);
+
+        // Two reflections: 25 -> -5 -> 5
+        // Three reflections: -27 -> 27 -> -7 -> 7
+        let mut coordinates = vec![25.0, -27.0];
+        let mut velocity = vec![20.0, -30.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert!(abs(coordinates[0] - 5.0) < 1e-10);
+        assert!(abs(coordinates[1] - 7.0) < 1e-10);
+        assert_eq!(velocity, vec![20.0, 30.0]);
+    }
+
+    #[test]
+    fn reflect_remains_inside() {
+        let intervals = vec![(-1.0_f64, 2.0_f64)];
+        let mut postmove = ReflectBoundary::new(intervals);
+        let mut coordinates = vec![0.0];
+        let mut velocity = vec![0.7];
+
+        for _ in 0..1000 {
+            coordinates[0] += velocity[0];
+            postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+            assert!(coordinates[0] >= -1.0);
+            assert!(coordinates[0] <= 2.0);
+            assert_eq!(abs(velocity[0]), 0.7);
+        }
+    }
+
+    #[test]
+    fn reflect_without_velocity() {
+        let mut postmove = ReflectBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![-2.0];
+        postmove.post_move(&mut coordinates);
+
+        assert!(abs(coordinates[0] - 2.0) < 1e-10);
+    }
+
+    #[test]
+    fn absorbing_inside_interval() {
+        let mut postmove = AbsorbingBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![3.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![3.0]);
+        assert_eq!(velocity, vec![1.0]);
+    }
+
+    #[test]
+    fn absorbing_per_dimension() {
+        let intervals = vec![(0.0_f64, 10.0_f64), (0.0_f64, 10.0_f64), (0.0_f64, 10.0_f64)];
+        let mut postmove = AbsorbingBoundary::new(intervals);
+        let mut coordinates = vec![12.0, 5.0, -3.0];
+        let mut velocity = vec![5.0, 2.0, -4.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![10.0, 5.0, 0.0]);
+        assert_eq!(velocity, vec![0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn absorbing_without_velocity() {
+        let mut postmove = AbsorbingBoundary::new(vec![(0.0_f64, 10.0_f64), (0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![-2.0, f64::NAN];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn toroidal_inside_interval() {
+        let mut postmove = ToroidalBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![3.0];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![3.0]);
+    }
+
+    #[test]
+    fn toroidal_wrap() {
+        let intervals = vec![(-5.0_f64, 5.0_f64), (-5.0_f64, 5.0_f64), (-5.0_f64, 5.0_f64)];
+        let mut postmove = ToroidalBoundary::new(intervals);
+        let mut coordinates = vec![7.0, -8.0, 27.0];
+        let mut velocity = vec![1.0, 2.0, 3.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert!(abs(coordinates[0] + 3.0) < 1e-10);
+        assert!(abs(coordinates[1] - 2.0) < 1e-10);
+        assert!(abs(coordinates[2] + 3.0) < 1e-10);
+        assert_eq!(velocity, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn toroidal_max() {
+        let mut postmove = ToroidalBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![10.0];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.0]);
+    }
+
+    #[test]
+    fn toroidal_cycle() {
+        let mut postmove = ToroidalBoundary::new(vec![(0.0_f64, 10.0_f64)]);
+        let mut coordinates = vec![0.0];
+        let velocity = 3.0;
+        let mut visited = [false; 10];
+
+        for _ in 0..100 {
+            coordinates[0] += velocity;
+            postmove.post_move(&mut coordinates);
+
+            assert!(coordinates[0] >= 0.0);
+            assert!(coordinates[0] < 10.0);
+            visited[coordinates[0].round() as usize % 10] = true;
+        }
+
+        // The particle goes through the whole interval
+        assert!(visited.iter().all(|&item| item));
+    }
+
+    #[test]
+    fn gaussian_perturbation_statistics() {
+        let run_count = 10000;
+        let sigma = 0.5;
+        let mut postmove = GaussianPerturbation::new(sigma);
+        postmove.set_seed(42);
+
+        let values: Vec<f64> = (0..run_count)
+            .map(|_| {
+                let mut coordinates = vec![1.0_f64];
+                postmove.post_move(&mut coordinates);
+                coordinates[0]
+            })
+            .collect();
+
+        let mean = values.iter().sum::<f64>() / run_count as f64;
+        let variance =
+            values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (run_count - 1) as f64;
+
+        assert!(abs(mean - 1.0) < 0.05);
+        assert!(abs(variance.sqrt() - sigma) < 0.05);
+    }
+
+    #[test]
+    fn gaussian_perturbation_probability() {
+        let run_count = 10000;
+        let mut postmove = GaussianPerturbation::new(1.0);
+        postmove.set_probability(0.2);
+        postmove.set_seed(42);
+
+        let changed_count = (0..run_count)
+            .filter(|_| {
+                let mut coordinates = vec![1.0_f64, 2.0_f64];
+                postmove.post_move(&mut coordinates);
+                coordinates != vec![1.0, 2.0]
+            })
+            .count();
+
+        assert!(abs(changed_count as f64 / run_count as f64 - 0.2) < 0.02);
+    }
+
+    #[test]
+    fn gaussian_perturbation_zero_probability() {
+        let mut postmove = GaussianPerturbation::new(1.0);
+        postmove.set_probability(0.0);
+
+        let mut coordinates = vec![1.0_f64, 2.0_f64];
+        postmove.post_move(&mut coordinates);
+        assert_eq!(coordinates, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn gaussian_perturbation_with_boundary() {
+        let intervals = vec![(0.0_f64, 1.0_f64), (-1.0_f64, 1.0_f64)];
+        let mut post_moves: Vec<Box<dyn PostMove<f64>>> = vec![
+            Box::new(GaussianPerturbation::new(10.0)),
+            Box::new(MoveToBoundary::new(intervals.clone())),
+        ];
+
+        for _ in 0..1000 {
+            let mut coordinates = vec![0.5_f64, 0.0_f64];
+            for post_move in post_moves.iter_mut() {
+                post_move.post_move(&mut coordinates);
+            }
+
+            for (coordinate, (min, max)) in coordinates.iter().zip(intervals.iter()) {
+                assert!(coordinate >= min);
+                assert!(coordinate <= max);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gaussian_perturbation_invalid_sigma() {
+        GaussianPerturbation::new(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gaussian_perturbation_invalid_probability() {
+        GaussianPerturbation::new(1.0).set_probability(1.5);
+    }
+
+    fn create_swarm(iteration: usize, best_personal_value: f64) -> Swarm<f64> {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![100.0], vec![1.0], best_personal_value),
+            Particle::new(vec![100.0], vec![1.0], 0.0),
+        ]);
+        swarm.iteration = iteration;
+        swarm
+    }
+
+    #[test]
+    fn stagnation_reset() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 3);
+
+        // The personal best value is not changed
+        for iteration in 0..3 {
+            let swarm = create_swarm(iteration, 10.0);
+            let mut coordinates = vec![100.0];
+            let mut velocity = vec![1.0];
+            postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+            assert_eq!(coordinates, vec![100.0]);
+        }
+
+        let swarm = create_swarm(3, 10.0);
+        let mut coordinates = vec![100.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+        assert!(coordinates[0] >= 0.0);
+        assert!(coordinates[0] <= 1.0);
+        assert_eq!(velocity, vec![1.0]);
+
+        // The stagnation counter is reset after the moving
+        let swarm = create_swarm(4, 10.0);
+        let mut coordinates = vec![100.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+        assert_eq!(coordinates, vec![100.0]);
+    }
+
+    #[test]
+    fn stagnation_reset_improvement() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 2);
+
+        for iteration in 0..10 {
+            // The personal best value is improved on every iteration
+            let swarm = create_swarm(iteration, 10.0 - iteration as f64);
+            let mut coordinates = vec![100.0];
+            let mut velocity = vec![1.0];
+            postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+            assert_eq!(coordinates, vec![100.0]);
+        }
+    }
+
+    #[test]
+    fn stagnation_reset_velocity() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 1);
+        postmove.set_reset_velocity(true);
+
+        let mut coordinates = vec![100.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_particle(&create_swarm(0, 10.0), 1, &mut coordinates, &mut velocity);
+        postmove.post_move_particle(&create_swarm(1, 10.0), 1, &mut coordinates, &mut velocity);
+
+        assert!(coordinates[0] <= 1.0);
+        assert_eq!(velocity, vec![0.0]);
+    }
+
+    #[test]
+    fn stagnation_reset_new_run() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 2);
+
+        let mut coordinates = vec![100.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_particle(&create_swarm(0, 10.0), 0, &mut coordinates, &mut velocity);
+        postmove.post_move_particle(&create_swarm(1, 10.0), 0, &mut coordinates, &mut velocity);
+
+        // The new optimization with the worse values
+        postmove.post_move_particle(&create_swarm(0, 20.0), 0, &mut coordinates, &mut velocity);
+        postmove.post_move_particle(&create_swarm(1, 20.0), 0, &mut coordinates, &mut velocity);
+        assert_eq!(coordinates, vec![100.0]);
+    }
+
+    #[test]
+    fn stagnation_reset_particles_removed() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 2);
+
+        let mut coordinates = vec![100.0];
+        let mut velocity = vec![1.0];
+        postmove.post_move_particle(&create_swarm(0, 10.0), 0, &mut coordinates, &mut velocity);
+        postmove.post_move_particle(&create_swarm(0, 10.0), 1, &mut coordinates, &mut velocity);
+        postmove.post_move_particle(&create_swarm(1, 10.0), 1, &mut coordinates, &mut velocity);
+
+        // The stagnant particle gets the index 0
+        postmove.particles_removed(&[0]);
+        let mut swarm = create_swarm(2, 10.0);
+        swarm.remove_particles(&[0]);
+        postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+        assert!(coordinates[0] <= 1.0);
+    }
+
+    #[test]
+    fn stagnation_reset_diversity_resizer() {
+        let intervals = vec![(0.0_f64, 1.0_f64)];
+        let mut postmove = StagnationReset::new(intervals, 3);
+        let mut resizer = DiversityBasedResizer::new(1, 10, 0.0);
+        resizer.set_max_stagnant_iterations(2);
+
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![100.0], vec![1.0], 1.0),
+            Particle::new(vec![100.0], vec![1.0], 9.0),
+            Particle::new(vec![100.0], vec![1.0], 8.0),
+        ]);
+
+        for iteration in 0..4 {
+            swarm.iteration = iteration;
+            for n in 0..swarm.len() {
+                let mut coordinates = vec![100.0];
+                let mut velocity = vec![1.0];
+                postmove.post_move_particle(&swarm, n, &mut coordinates, &mut velocity);
+
+                // The particles are moved after 3 stagnant iterations regardless of the removing
+                // of the worst particle on the iteration 2.
+                assert_eq!(coordinates[0] <= 1.0, iteration == 3);
+            }
+
+            // Apply the changes as the optimizer does
+            let mut changes = SwarmChanges::new();
+            resizer.resize(&swarm, &mut changes);
+            swarm.remove_particles(&changes.removed);
+            postmove.particles_removed(&changes.removed);
+        }
+
+        assert_eq!(swarm.len(), 2);
+        assert_eq!(swarm.particles[1].best_personal_value, 8.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn stagnation_reset_invalid_iterations() {
+        StagnationReset::new(vec![(0.0_f64, 1.0_f64)], 0);
+    }
+
+    #[test]
+    fn mixed_integer_round() {
+        let mut postmove = MixedIntegerPostMove::new(vec![0, 2]);
+        let mut coordinates = vec![1.4_f64, 2.6, -3.6, 4.5];
+        let mut velocity = vec![1.0, 2.0, 3.0, 4.0];
+        postmove.post_move_with_velocity(&mut coordinates, &mut velocity);
+
+        assert_eq!(coordinates, vec![1.0, 2.6, -4.0, 4.5]);
+        assert_eq!(velocity, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn mixed_integer_empty() {
+        let mut postmove = MixedIntegerPostMove::new(vec![]);
+        let mut coordinates = vec![1.4_f32, 2.6_f32];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![1.4_f32, 2.6_f32]);
+    }
+
+    #[test]
+    fn mixed_integer_with_boundary() {
+        let intervals = vec![(-2.0_f64, 2.0_f64); 3];
+        let mut boundary = MoveToBoundary::new(intervals);
+        let mut postmove = MixedIntegerPostMove::new(vec![0, 1, 2]);
+
+        for i in 0..100 {
+            let value = (i as f64 - 50.0) * 0.137;
+            let mut coordinates = vec![value, -value, value * 0.5];
+            boundary.post_move(&mut coordinates);
+            postmove.post_move(&mut coordinates);
+
+            for coordinate in &coordinates {
+                assert_eq!(coordinate.fract(), 0.0);
+                assert!(*coordinate >= -2.0);
+                assert!(*coordinate <= 2.0);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_integer_duplicate_dimensions() {
+        MixedIntegerPostMove::new(vec![0, 1, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_integer_invalid_dimension() {
+        let mut postmove = MixedIntegerPostMove::new(vec![2]);
+        let mut coordinates = vec![1.4_f64, 2.6];
+        postmove.post_move(&mut coordinates);
+    }
+
+    #[test]
+    fn binary_round() {
+        let mut postmove = BinaryPostMove::new();
+        let mut coordinates = vec![0.2_f64, 0.5, 0.9, -3.0, 5.0];
+        postmove.post_move(&mut coordinates);
+
+        assert_eq!(coordinates, vec![0.0, 1.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn binary_probability() {
+        let mut postmove = BinaryPostMove::new();
+        postmove.set_seed(42);
+
+        let swarm = Swarm::new();
+        let velocity_values = vec![-4.0_f64, 0.0, 2.0];
+        let count = 10000;
+        let mut ones = vec![0; velocity_values.len()];
+        for _ in 0..count {
+            // The current coordinates do not affect the new coordinates
+            let mut coordinates = vec![1.0, 0.0, 1.0];
+            let mut velocity = velocity_values.clone();
+            postmove.post_move_particle(&swarm, 0, &mut coordinates, &mut velocity);
+
+            assert_eq!(velocity, velocity_values);
+            for (i, coordinate) in coordinates.iter().enumerate() {
+                assert!(*coordinate == 0.0 || *coordinate == 1.0);
+                if *coordinate == 1.0 {
+                    ones[i] += 1;
+                }
+            }
+        }
+
+        for (i, velocity) in velocity_values.iter().enumerate() {
+            let frequency = ones[i] as f64 / count as f64;
+            assert!(abs(frequency - sigmoid(*velocity)) < 0.02);
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use num::Float;
 
-use crate::particleswarm::PostVelocityCalc;
+use crate::particleswarm::{PostVelocityCalc, Swarm};
 
 /// The trait to restrict value for every dimension of velocity
 pub struct MaxVelocityDimensions<T> {
@@ -46,27 +46,157 @@ impl<T> MaxVelocityAbs<T> {
 
 impl<T: Float> PostVelocityCalc<T> for MaxVelocityAbs<T> {
     fn correct_velocity(&mut self, velocity: Vec<T>) -> Vec<T> {
-        let current_velocity_abs = velocity
+        limit_velocity_abs(velocity, self.max_velocity)
+    }
+}
+
+/// Decrease the absolute velocity to `max_velocity` if the absolute velocity is greater.
+fn limit_velocity_abs<T: Float>(velocity: Vec<T>, max_velocity: T) -> Vec<T> {
+    let current_velocity_abs = velocity
+        .iter()
+        .fold(T::zero(), |acc, vi| acc + (*vi) * (*vi))
+        .sqrt();
+    if current_velocity_abs > max_velocity {
+        let new_velocity = velocity
             .iter()
-            .fold(T::zero(), |acc, vi| acc + (*vi) * (*vi))
-            .sqrt();
-        if current_velocity_abs > self.max_velocity {
-            let new_velocity = velocity
-                .iter()
-                .map(|vi| (*vi) * self.max_velocity / current_velocity_abs)
-                .collect();
-            new_velocity
+            .map(|vi| (*vi) * max_velocity / current_velocity_abs)
+            .collect();
+        new_velocity
+    } else {
+        velocity
+    }
+}
+
+/// The struct to restrict absolute velocity. The max velocity decreases from `v_max_initial`
+/// to `v_max_final` during `max_iterations` iterations (linearly or exponentially) and
+/// does not change after that.
+///
+/// Linear decreasing:
+/// v_max(t) = v_max_initial - (v_max_initial - v_max_final) * t / max_iterations
+///
+/// Exponential decreasing:
+/// v_max(t) = v_max_initial * (v_max_final / v_max_initial) ^ (t / max_iterations)
+///
+/// `t` - iteration number.
+pub struct AdaptiveMaxVelocity<T> {
+    v_max_initial: T,
+    v_max_final: T,
+    max_iterations: usize,
+    exponential: bool,
+
+    /// The last iteration number which the optimizer passed.
+    iteration: usize,
+}
+
+impl<T: Float> AdaptiveMaxVelocity<T> {
+    /// Constructor for the linear decreasing of the max velocity.
+    ///
+    /// # Parameters
+    /// `v_max_initial` - the max velocity on the first iteration.
+    /// `v_max_final` - the max velocity after `max_iterations` iterations. Must be in the range
+    /// [0, v_max_initial].
+    /// `max_iterations` - must be greater than 0.
+    pub fn new(v_max_initial: T, v_max_final: T, max_iterations: usize) -> Self {
+        assert!(v_max_final >= T::zero());
+        assert!(v_max_final <= v_max_initial);
+        assert!(max_iterations > 0);
+
+        Self {
+            v_max_initial,
+            v_max_final,
+            max_iterations,
+            exponential: false,
+            iteration: 0,
+        }
+    }
+
+    /// Constructor for the exponential decreasing of the max velocity.
+    ///
+    /// # Parameters
+    /// `v_max_initial` - the max velocity on the first iteration.
+    /// `v_max_final` - the max velocity after `max_iterations` iterations. Must be in the range
+    /// (0, v_max_initial].
+    /// `max_iterations` - must be greater than 0.
+    pub fn new_exponential(v_max_initial: T, v_max_final: T, max_iterations: usize) -> Self {
+        assert!(v_max_final > T::zero());
+
+        let mut result = Self::new(v_max_initial, v_max_final, max_iterations);
+        result.exponential = true;
+        result
+    }
+
+    /// Returns the max velocity for the iteration.
+    pub fn get_max_velocity(&self, iteration: usize) -> T {
+        let ratio = T::from(iteration.min(self.max_iterations)).unwrap()
+            / T::from(self.max_iterations).unwrap();
+
+        if self.exponential {
+            self.v_max_initial * (self.v_max_final / self.v_max_initial).powf(ratio)
         } else {
-            velocity
+            self.v_max_initial - (self.v_max_initial - self.v_max_final) * ratio
         }
     }
 }
 
+impl<T: Float> PostVelocityCalc<T> for AdaptiveMaxVelocity<T> {
+    /// Restrict the velocity with the max velocity for the last iteration which
+    /// the optimizer passed.
+    fn correct_velocity(&mut self, velocity: Vec<T>) -> Vec<T> {
+        limit_velocity_abs(velocity, self.get_max_velocity(self.iteration))
+    }
+
+    fn correct_particle_velocity(
+        &mut self,
+        swarm: &Swarm<T>,
+        _particle_index: usize,
+        velocity: Vec<T>,
+    ) -> Vec<T> {
+        self.iteration = swarm.iteration;
+        self.correct_velocity(velocity)
+    }
+}
+
+/// The struct to reflect the velocity projections which exceed the max velocity.
+/// The direction of the velocity projection is reversed and the absolute value
+/// of the projection becomes equal to the max velocity:
+/// v_i = -sign(v_i) * max_velocity if |v_i| > max_velocity
+pub struct VelocityClampingWithReflection<T> {
+    max_velocity: T,
+}
+
+impl<T: Float> VelocityClampingWithReflection<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// `max_velocity` - max absolute value for every velocity projection. Must be greater than 0.
+    pub fn new(max_velocity: T) -> Self {
+        assert!(max_velocity > T::zero());
+        Self { max_velocity }
+    }
+}
+
+impl<T: Float> PostVelocityCalc<T> for VelocityClampingWithReflection<T> {
+    fn correct_velocity(&mut self, velocity: Vec<T>) -> Vec<T> {
+        velocity
+            .into_iter()
+            .map(|v| {
+                if v.abs() > self.max_velocity {
+                    -v.signum() * self.max_velocity
+                } else {
+                    v
+                }
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use num::abs;
-    use super::{MaxVelocityAbs, MaxVelocityDimensions};
-    use crate::particleswarm::PostVelocityCalc;
+    use super::{
+        AdaptiveMaxVelocity, MaxVelocityAbs, MaxVelocityDimensions, VelocityClampingWithReflection,
+    };
+    use crate::particleswarm::{Particle, PostVelocityCalc, Swarm};
 
     #[test]
     fn test_max_velocity_dimensions_empty() {
@@ -76,7 +206,7 @@ mod tests {
         let mut post_velocity = MaxVelocityDimensions::new(max_velocity);
         let new_velocity = post_velocity.correct_velocity(velocity);
 
-        assert_eq!(new_velocity, vec![]);
+        assert_eq!(new_velocity, Vec::<f32>::new());
     }
 
     #[test]
@@ -119,7 +249,7 @@ mod tests {
         let mut post_velocity = MaxVelocityAbs::new(max_velocity);
         let new_velocity = post_velocity.correct_velocity(velocity);
 
-        assert_eq!(new_velocity, vec![]);
+        assert_eq!(new_velocity, Vec::<f32>::new());
     }
 
     #[test]
@@ -145,4 +275,98 @@ mod tests {
 
         assert!(abs(new_velocity_abs - max_velocity) < 1e-3);
     }
+
+    fn velocity_abs(velocity: &[f64]) -> f64 {
+        velocity.iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn adaptive_max_velocity_linear() {
+        let post_velocity = AdaptiveMaxVelocity::new(10.0_f64, 2.0_f64, 4);
+
+        assert!(abs(post_velocity.get_max_velocity(0) - 10.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(1) - 8.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(4) - 2.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(100) - 2.0) < 1e-10);
+    }
+
+    #[test]
+    fn adaptive_max_velocity_exponential() {
+        let post_velocity = AdaptiveMaxVelocity::new_exponential(16.0_f64, 1.0_f64, 4);
+
+        assert!(abs(post_velocity.get_max_velocity(0) - 16.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(1) - 8.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(2) - 4.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(4) - 1.0) < 1e-10);
+        assert!(abs(post_velocity.get_max_velocity(10) - 1.0) < 1e-10);
+    }
+
+    #[test]
+    fn adaptive_max_velocity_swarm() {
+        let mut post_velocity = AdaptiveMaxVelocity::new(10.0_f64, 2.0_f64, 4);
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![Particle::new(vec![0.0, 0.0], vec![0.0, 0.0], 0.0)]);
+
+        let new_velocity = post_velocity.correct_particle_velocity(&swarm, 0, vec![30.0, 40.0]);
+        assert!(abs(velocity_abs(&new_velocity) - 10.0) < 1e-10);
+
+        swarm.iteration = 2;
+        let new_velocity = post_velocity.correct_particle_velocity(&swarm, 0, vec![30.0, 40.0]);
+        assert!(abs(velocity_abs(&new_velocity) - 6.0) < 1e-10);
+
+        let new_velocity = post_velocity.correct_particle_velocity(&swarm, 0, vec![3.0, 4.0]);
+        assert_eq!(new_velocity, vec![3.0, 4.0]);
+
+        // The max velocity for the last iteration is used
+        let new_velocity = post_velocity.correct_velocity(vec![30.0, 40.0]);
+        assert!(abs(velocity_abs(&new_velocity) - 6.0) < 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adaptive_max_velocity_invalid_final() {
+        AdaptiveMaxVelocity::new(1.0_f64, 2.0_f64, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adaptive_max_velocity_exponential_zero() {
+        AdaptiveMaxVelocity::new_exponential(1.0_f64, 0.0_f64, 10);
+    }
+
+    #[test]
+    fn velocity_reflection_not_change() {
+        let mut post_velocity = VelocityClampingWithReflection::new(10.0_f64);
+        let new_velocity = post_velocity.correct_velocity(vec![5.0, -10.0, 0.0]);
+
+        assert_eq!(new_velocity, vec![5.0, -10.0, 0.0]);
+    }
+
+    #[test]
+    fn velocity_reflection() {
+        let mut post_velocity = VelocityClampingWithReflection::new(10.0_f64);
+        let new_velocity = post_velocity.correct_velocity(vec![15.0, -25.0, 3.0]);
+
+        assert_eq!(new_velocity, vec![-10.0, 10.0, 3.0]);
+    }
+
+    #[test]
+    fn velocity_reflection_magnitude() {
+        let max_velocity = 10.0_f64;
+        let mut post_velocity = VelocityClampingWithReflection::new(max_velocity);
+        let new_velocity = post_velocity.correct_velocity(vec![100.0]);
+
+        assert!(abs(new_velocity[0].abs() - max_velocity) < 1e-10);
+        assert!(new_velocity[0] < 0.0);
+
+        // The velocity on the boundary is not changed
+        let new_velocity = post_velocity.correct_velocity(new_velocity);
+        assert!(abs(new_velocity[0] + max_velocity) < 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn velocity_reflection_invalid_max() {
+        VelocityClampingWithReflection::new(0.0_f64);
+    }
 }
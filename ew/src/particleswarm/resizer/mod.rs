@@ -0,0 +1,369 @@
+//! The module with the structs to change count of the particles during the optimization.
+//! The structs implement the `SwarmResizer` trait.
+
+use crate::particleswarm::{Swarm, SwarmChanges, SwarmResizer};
+use crate::AlgorithmState;
+
+use num::Float;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Calculate diversity of the swarm as the average Euclidean distance from the particles to
+/// the center of the swarm. Returns 0 for the empty swarm.
+pub fn get_diversity<T: Float>(swarm: &Swarm<T>) -> f64 {
+    let count = swarm.len();
+    if count == 0 {
+        return 0.0;
+    }
+
+    let dimension = swarm.particles[0].coordinates.len();
+    let mut center = vec![0.0; dimension];
+    for particle in &swarm.particles {
+        for (center_item, x) in center.iter_mut().zip(particle.coordinates.iter()) {
+            *center_item += x.to_f64().unwrap();
+        }
+    }
+    center.iter_mut().for_each(|item| *item /= count as f64);
+
+    let sum = swarm.particles.iter().fold(0.0, |acc, particle| {
+        let distance_sqr = particle
+            .coordinates
+            .iter()
+            .zip(center.iter())
+            .fold(0.0, |acc, (x, center_item)| {
+                acc + (x.to_f64().unwrap() - center_item).powi(2)
+            });
+        acc + distance_sqr.sqrt()
+    });
+
+    sum / count as f64
+}
+
+/// The struct does not change count of the particles. The optimizer uses it by default.
+pub struct NoOpSwarmResizer;
+
+impl NoOpSwarmResizer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for NoOpSwarmResizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SwarmResizer<T> for NoOpSwarmResizer {
+    fn resize(&mut self, _swarm: &Swarm<T>, _changes: &mut SwarmChanges<T>) {}
+}
+
+/// The struct changes count of the particles depending on the swarm diversity
+/// (see `get_diversity`).
+///
+/// If the diversity is less than the threshold, a new particle is added to the swarm on every
+/// iteration. The new particle has zero velocity and random coordinates inside the area
+/// which the swarm covered on the first iteration of the algorithm.
+/// If the diversity is not less than the threshold, but the best solution is not improved during
+/// the several iterations (see `set_max_stagnant_iterations`), the particle with the worst
+/// personal best value is removed from the swarm.
+/// Count of the particles is kept in the range [`min_particles`; `max_particles`].
+pub struct DiversityBasedResizer<T: Float + SampleUniform> {
+    min_particles: usize,
+    max_particles: usize,
+    diversity_threshold: f64,
+    max_stagnant_iterations: usize,
+    random: StdRng,
+
+    /// The area for the new particles. The area is calculated on the first iteration.
+    random_intervals: Vec<Uniform<T>>,
+    last_iteration: usize,
+    last_best_value: Option<f64>,
+    stagnant_iterations: usize,
+}
+
+impl<T: Float + SampleUniform> DiversityBasedResizer<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `min_particles` - minimal count of the particles. Must be greater than 0.
+    /// * `max_particles` - maximal count of the particles. Must not be less than `min_particles`.
+    /// * `diversity_threshold` - the particles are added if the diversity of the swarm is less
+    ///   than the threshold. Must not be negative.
+    pub fn new(min_particles: usize, max_particles: usize, diversity_threshold: f64) -> Self {
+        assert!(min_particles > 0);
+        assert!(min_particles <= max_particles);
+        assert!(diversity_threshold >= 0.0);
+
+        Self {
+            min_particles,
+            max_particles,
+            diversity_threshold,
+            max_stagnant_iterations: 10,
+            random: StdRng::from_entropy(),
+            random_intervals: vec![],
+            last_iteration: 0,
+            last_best_value: None,
+            stagnant_iterations: 0,
+        }
+    }
+
+    /// Set count of the iterations without improvement of the best solution before
+    /// the particle removing. Must be greater than 0. The default value is 10.
+    pub fn set_max_stagnant_iterations(&mut self, max_stagnant_iterations: usize) {
+        assert!(max_stagnant_iterations > 0);
+        self.max_stagnant_iterations = max_stagnant_iterations;
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    /// Start new running of the algorithm. Remember the area which the swarm covers.
+    fn start(&mut self, swarm: &Swarm<T>) {
+        let dimension = swarm.particles[0].coordinates.len();
+        self.random_intervals = (0..dimension)
+            .map(|i| {
+                let (min, max) = swarm.particles.iter().fold(
+                    (T::infinity(), T::neg_infinity()),
+                    |(min, max), particle| {
+                        (
+                            min.min(particle.coordinates[i]),
+                            max.max(particle.coordinates[i]),
+                        )
+                    },
+                );
+                Uniform::new_inclusive(min, max)
+            })
+            .collect();
+        self.last_best_value = None;
+        self.stagnant_iterations = 0;
+    }
+
+    fn update_stagnation(&mut self, swarm: &Swarm<T>) {
+        // The best solution is changed only if it is improved
+        let best_value = swarm.get_best_solution().map(|(_, value)| value);
+        if best_value != self.last_best_value {
+            self.last_best_value = best_value;
+            self.stagnant_iterations = 0;
+        } else {
+            self.stagnant_iterations += 1;
+        }
+    }
+
+    fn create_coordinates(&mut self) -> Vec<T> {
+        let random = &mut self.random;
+        self.random_intervals
+            .iter()
+            .map(|interval| interval.sample(random))
+            .collect()
+    }
+
+    /// Returns index of the particle with the worst personal best value.
+    fn get_worst_particle_index(swarm: &Swarm<T>) -> usize {
+        let mut worst_index = 0;
+        for (n, particle) in swarm.particles.iter().enumerate() {
+            let worst_value = swarm.particles[worst_index].best_personal_value;
+            if particle.best_personal_value.is_nan() || particle.best_personal_value > worst_value {
+                worst_index = n;
+            }
+        }
+
+        worst_index
+    }
+}
+
+impl<T: Float + SampleUniform> SwarmResizer<T> for DiversityBasedResizer<T> {
+    fn resize(&mut self, swarm: &Swarm<T>, changes: &mut SwarmChanges<T>) {
+        if swarm.len() == 0 {
+            return;
+        }
+
+        if swarm.iteration <= self.last_iteration || self.random_intervals.is_empty() {
+            self.start(swarm);
+        }
+        self.last_iteration = swarm.iteration;
+        self.update_stagnation(swarm);
+
+        let dimension = self.random_intervals.len();
+        let count = swarm.len();
+        let add = count < self.min_particles
+            || (count < self.max_particles && get_diversity(swarm) < self.diversity_threshold);
+        let remove = count > self.max_particles
+            || (count > self.min_particles
+                && self.stagnant_iterations >= self.max_stagnant_iterations);
+
+        if add {
+            let coordinates = self.create_coordinates();
+            changes.add_particle(coordinates, vec![T::zero(); dimension]);
+        } else if remove {
+            changes.remove_particle(Self::get_worst_particle_index(swarm));
+            self.stagnant_iterations = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::particleswarm::Particle;
+
+    fn create_swarm(coordinates: Vec<Vec<f64>>, values: Vec<f64>, iteration: usize) -> Swarm<f64> {
+        let dimension = coordinates[0].len();
+        let particles = coordinates
+            .into_iter()
+            .zip(values)
+            .map(|(x, value)| Particle::new(x, vec![0.0; dimension], value))
+            .collect();
+
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(particles);
+        swarm.iteration = iteration;
+        swarm
+    }
+
+    fn resize(resizer: &mut dyn SwarmResizer<f64>, swarm: &Swarm<f64>) -> SwarmChanges<f64> {
+        let mut changes = SwarmChanges::new();
+        resizer.resize(swarm, &mut changes);
+        changes
+    }
+
+    #[test]
+    fn diversity_empty() {
+        let swarm: Swarm<f64> = Swarm::new();
+        assert_eq!(get_diversity(&swarm), 0.0);
+    }
+
+    #[test]
+    fn diversity_single() {
+        let swarm = create_swarm(vec![vec![1.0, 2.0]], vec![0.0], 0);
+        assert_eq!(get_diversity(&swarm), 0.0);
+    }
+
+    #[test]
+    fn diversity_several() {
+        let swarm = create_swarm(
+            vec![
+                vec![0.0, 0.0],
+                vec![2.0, 0.0],
+                vec![0.0, 2.0],
+                vec![2.0, 2.0],
+            ],
+            vec![0.0; 4],
+            0,
+        );
+        assert!((get_diversity(&swarm) - 2.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn noop_resizer() {
+        let swarm = create_swarm(vec![vec![1.0], vec![1.0]], vec![0.0, 0.0], 1);
+        let mut resizer = NoOpSwarmResizer::new();
+        assert!(resize(&mut resizer, &swarm).is_empty());
+    }
+
+    #[test]
+    fn diversity_based_resizer_add() {
+        let mut resizer = DiversityBasedResizer::new(2, 10, 1.0);
+        resizer.set_seed(1);
+
+        // The area for new particles is [0; 4] x [-1; 1]
+        let swarm = create_swarm(
+            vec![vec![0.0, -1.0], vec![4.0, 1.0], vec![2.0, 0.0]],
+            vec![1.0, 2.0, 3.0],
+            1,
+        );
+        assert!(resize(&mut resizer, &swarm).is_empty());
+
+        // Low diversity
+        let swarm = create_swarm(
+            vec![vec![0.0, 0.0], vec![0.1, 0.0], vec![0.0, 0.1]],
+            vec![1.0, 2.0, 3.0],
+            2,
+        );
+        let changes = resize(&mut resizer, &swarm);
+        assert!(changes.removed.is_empty());
+        assert_eq!(changes.added.len(), 1);
+
+        let (coordinates, velocity) = &changes.added[0];
+        assert!(coordinates[0] >= 0.0 && coordinates[0] <= 4.0);
+        assert!(coordinates[1] >= -1.0 && coordinates[1] <= 1.0);
+        assert_eq!(velocity, &vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn diversity_based_resizer_max_particles() {
+        let mut resizer = DiversityBasedResizer::new(1, 3, 1.0);
+        let swarm = create_swarm(
+            vec![vec![0.0], vec![0.1], vec![0.2]],
+            vec![1.0, 2.0, 3.0],
+            1,
+        );
+        assert!(resize(&mut resizer, &swarm).added.is_empty());
+    }
+
+    #[test]
+    fn diversity_based_resizer_min_particles() {
+        let mut resizer = DiversityBasedResizer::new(3, 5, 0.0);
+        let swarm = create_swarm(vec![vec![0.0], vec![10.0]], vec![1.0, 2.0], 1);
+        assert_eq!(resize(&mut resizer, &swarm).added.len(), 1);
+    }
+
+    #[test]
+    fn diversity_based_resizer_remove() {
+        let mut resizer = DiversityBasedResizer::new(2, 10, 1.0);
+        resizer.set_max_stagnant_iterations(2);
+
+        let coordinates = vec![vec![0.0], vec![10.0], vec![20.0]];
+        let swarm = create_swarm(coordinates.clone(), vec![1.0, 5.0, 2.0], 1);
+        assert!(resize(&mut resizer, &swarm).is_empty());
+
+        let swarm = create_swarm(coordinates.clone(), vec![1.0, 5.0, 2.0], 2);
+        assert!(resize(&mut resizer, &swarm).is_empty());
+
+        // The best solution is not improved during 2 iterations
+        let swarm = create_swarm(coordinates.clone(), vec![1.0, 5.0, 2.0], 3);
+        let changes = resize(&mut resizer, &swarm);
+        assert!(changes.added.is_empty());
+        assert_eq!(changes.removed, vec![1]);
+
+        // The stagnation counter is reset after removing
+        let swarm = create_swarm(vec![vec![0.0], vec![20.0]], vec![1.0, 2.0], 4);
+        assert!(resize(&mut resizer, &swarm).is_empty());
+
+        // min_particles
+        let swarm = create_swarm(vec![vec![0.0], vec![20.0]], vec![1.0, 2.0], 10);
+        assert!(resize(&mut resizer, &swarm).is_empty());
+    }
+
+    #[test]
+    fn diversity_based_resizer_improvement() {
+        let mut resizer = DiversityBasedResizer::new(1, 10, 1.0);
+        resizer.set_max_stagnant_iterations(2);
+
+        let coordinates = vec![vec![0.0], vec![10.0], vec![20.0]];
+        for (iteration, best_value) in [3.0, 3.0, 2.0, 2.0, 1.0].iter().enumerate() {
+            let swarm = create_swarm(
+                coordinates.clone(),
+                vec![*best_value, 5.0, 6.0],
+                iteration + 1,
+            );
+            assert!(resize(&mut resizer, &swarm).is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn diversity_based_resizer_invalid_min_max() {
+        DiversityBasedResizer::<f64>::new(5, 3, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn diversity_based_resizer_invalid_min() {
+        DiversityBasedResizer::<f64>::new(0, 3, 1.0);
+    }
+}
@@ -0,0 +1,253 @@
+//! The module with the particles neighborhood topologies for particle swarm optimization.
+//! The topologies implement the `Topology` trait.
+
+/// The trait to get the neighbors of the particle.
+pub trait Topology {
+    /// Returns indexes of the neighbors for the particle with the index `particle_index`.
+    /// The result may contain `particle_index` if the particle is a neighbor of itself.
+    ///
+    /// # Parameters
+    /// * `particle_index` - index of the particle in the swarm.
+    /// * `total_particles` - count of the particles in the swarm.
+    fn neighbors(&self, particle_index: usize, total_particles: usize) -> Vec<usize>;
+}
+
+/// All particles are neighbors (including the particle itself).
+pub struct GlobalTopology;
+
+impl GlobalTopology {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GlobalTopology {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Topology for GlobalTopology {
+    fn neighbors(&self, particle_index: usize, total_particles: usize) -> Vec<usize> {
+        assert!(particle_index < total_particles);
+        (0..total_particles).collect()
+    }
+}
+
+/// The particles are placed on the ring by their indexes. The neighbors are `k` particles
+/// on the left, `k` particles on the right and the particle itself.
+pub struct RingTopology {
+    k: usize,
+}
+
+impl RingTopology {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `k` - count of the neighbors on every side of the particle. Must be greater than 0.
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0);
+        Self { k }
+    }
+}
+
+impl Topology for RingTopology {
+    fn neighbors(&self, particle_index: usize, total_particles: usize) -> Vec<usize> {
+        assert!(particle_index < total_particles);
+
+        // All particles are neighbors for the small swarm
+        if 2 * self.k + 1 >= total_particles {
+            return (0..total_particles).collect();
+        }
+
+        (0..=2 * self.k)
+            .map(|n| (particle_index + total_particles - self.k + n) % total_particles)
+            .collect()
+    }
+}
+
+/// The star (wheel) topology. The central particle is a neighbor of all particles,
+/// the other particles are neighbors of the central particle and of themselves only.
+/// The information passes between particles through the central particle.
+pub struct StarTopology {
+    center: usize,
+}
+
+impl StarTopology {
+    /// Constructor. The particle with index 0 is the central particle.
+    pub fn new() -> Self {
+        Self { center: 0 }
+    }
+
+    /// Constructor with the given index of the central particle.
+    pub fn with_center(center: usize) -> Self {
+        Self { center }
+    }
+}
+
+impl Default for StarTopology {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Topology for StarTopology {
+    fn neighbors(&self, particle_index: usize, total_particles: usize) -> Vec<usize> {
+        assert!(particle_index < total_particles);
+        assert!(self.center < total_particles);
+
+        if particle_index == self.center {
+            (0..total_particles).collect()
+        } else {
+            vec![self.center, particle_index]
+        }
+    }
+}
+
+/// The Von Neumann topology. The particles are placed on the grid `width` x `height` by rows
+/// (the particle with index `i` is in the row `i / width` and the column `i % width`).
+/// The grid is wrapped to the torus. The neighbors are the particle itself and the particles
+/// on the left, on the right, above and below.
+pub struct VonNeumannTopology {
+    width: usize,
+    height: usize,
+}
+
+impl VonNeumannTopology {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `width` - count of the columns. Must be greater than 0.
+    /// * `height` - count of the rows. Must be greater than 0.
+    ///
+    /// The count of particles in the swarm must be equal to `width * height`.
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0);
+        assert!(height > 0);
+        Self { width, height }
+    }
+}
+
+impl Topology for VonNeumannTopology {
+    fn neighbors(&self, particle_index: usize, total_particles: usize) -> Vec<usize> {
+        assert!(particle_index < total_particles);
+        assert_eq!(total_particles, self.width * self.height);
+
+        let row = particle_index / self.width;
+        let column = particle_index % self.width;
+        let index = |row: usize, column: usize| row * self.width + column;
+
+        let candidates = [
+            particle_index,
+            index(row, (column + self.width - 1) % self.width),
+            index(row, (column + 1) % self.width),
+            index((row + self.height - 1) % self.height, column),
+            index((row + 1) % self.height, column),
+        ];
+
+        // The neighbors may be repeated for the small grid
+        let mut result = Vec::with_capacity(candidates.len());
+        for candidate in candidates.iter() {
+            if !result.contains(candidate) {
+                result.push(*candidate);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_topology() {
+        let topology = GlobalTopology::new();
+        assert_eq!(topology.neighbors(0, 1), vec![0]);
+        assert_eq!(topology.neighbors(2, 4), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn ring_topology() {
+        let topology = RingTopology::new(1);
+        assert_eq!(topology.neighbors(0, 5), vec![4, 0, 1]);
+        assert_eq!(topology.neighbors(2, 5), vec![1, 2, 3]);
+        assert_eq!(topology.neighbors(4, 5), vec![3, 4, 0]);
+    }
+
+    #[test]
+    fn ring_topology_k2() {
+        let topology = RingTopology::new(2);
+        assert_eq!(topology.neighbors(0, 10), vec![8, 9, 0, 1, 2]);
+        assert_eq!(topology.neighbors(5, 10), vec![3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn ring_topology_small_swarm() {
+        let topology = RingTopology::new(2);
+        assert_eq!(topology.neighbors(1, 3), vec![0, 1, 2]);
+        assert_eq!(topology.neighbors(0, 5), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ring_topology_invalid_k() {
+        RingTopology::new(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn topology_invalid_index() {
+        GlobalTopology::new().neighbors(3, 3);
+    }
+    #[test]
+    fn star_topology() {
+        let topology = StarTopology::new();
+        assert_eq!(topology.neighbors(0, 4), vec![0, 1, 2, 3]);
+        assert_eq!(topology.neighbors(2, 4), vec![0, 2]);
+    }
+
+    #[test]
+    fn star_topology_center() {
+        let topology = StarTopology::with_center(2);
+        assert_eq!(topology.neighbors(2, 4), vec![0, 1, 2, 3]);
+        assert_eq!(topology.neighbors(0, 4), vec![2, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn star_topology_invalid_center() {
+        StarTopology::with_center(4).neighbors(0, 4);
+    }
+
+    #[test]
+    fn von_neumann_topology() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let topology = VonNeumannTopology::new(4, 3);
+        assert_eq!(topology.neighbors(5, 12), vec![5, 4, 6, 1, 9]);
+        assert_eq!(topology.neighbors(0, 12), vec![0, 3, 1, 8, 4]);
+        assert_eq!(topology.neighbors(11, 12), vec![11, 10, 8, 7, 3]);
+    }
+
+    #[test]
+    fn von_neumann_topology_small_grid() {
+        let topology = VonNeumannTopology::new(2, 2);
+        assert_eq!(topology.neighbors(0, 4), vec![0, 1, 2]);
+
+        let topology = VonNeumannTopology::new(3, 1);
+        assert_eq!(topology.neighbors(1, 3), vec![1, 0, 2]);
+
+        let topology = VonNeumannTopology::new(1, 1);
+        assert_eq!(topology.neighbors(0, 1), vec![0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn von_neumann_topology_invalid_size() {
+        VonNeumannTopology::new(4, 3).neighbors(0, 10);
+    }
+}
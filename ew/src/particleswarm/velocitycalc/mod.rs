@@ -1,9 +1,11 @@
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 use num::{Float, Num, NumCast};
 
-use crate::particleswarm::{Particle, VelocityCalculator, Swarm};
+use crate::particleswarm::{topology::Topology, Particle, VelocityCalculator, Swarm};
+use crate::tools::get_standard_normal;
 
 /// ClassicVelocityCalculator implements the equation from the article
 /// Kennedy, J.; Eberhart, R. (1995). "Particle Swarm Optimization".
@@ -15,21 +17,41 @@ use crate::particleswarm::{Particle, VelocityCalculator, Swarm};
 /// `x_i` - current coordinate,
 /// `phi_p`, `phi_g` - parameters,
 /// `r_p`, `r_g` - random values in (0, 1)
+///
+/// The equation does not use the inertia weight or the constriction coefficient, so the velocity
+/// may grow without limit. Usually the calculator is used with a velocity limit
+/// (for example, `postvelocitycalc::MaxVelocityAbs` or `postvelocitycalc::MaxVelocityDimensions`).
+/// The original article uses `phi_p` = `phi_g` = 2.
 pub struct ClassicVelocityCalculator<T> {
     phi_personal: T,
     phi_global: T,
 
-    random: ThreadRng,
+    random: StdRng,
 }
 
-impl<T> ClassicVelocityCalculator<T> {
+impl<T: Num + PartialOrd> ClassicVelocityCalculator<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `phi_personal` - the acceleration coefficient to the personal best coordinates (`phi_p`).
+    ///   Must be greater or equal to 0.
+    /// * `phi_global` - the acceleration coefficient to the global best coordinates (`phi_g`).
+    ///   Must be greater or equal to 0.
     pub fn new(phi_personal: T, phi_global: T) -> Self {
+        assert!(phi_personal >= T::zero());
+        assert!(phi_global >= T::zero());
+
         Self {
             phi_personal,
             phi_global,
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<T: NumCast + Num + Copy> VelocityCalculator<T> for ClassicVelocityCalculator<T> {
@@ -73,7 +95,7 @@ pub struct CanonicalVelocityCalculator<T> {
     phi_global: T,
     xi: T,
 
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl<T: Float> CanonicalVelocityCalculator<T> {
@@ -88,9 +110,14 @@ impl<T: Float> CanonicalVelocityCalculator<T> {
             phi_personal,
             phi_global,
             xi,
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<T: NumCast + Num + Copy> VelocityCalculator<T> for CanonicalVelocityCalculator<T> {
@@ -151,7 +178,7 @@ pub struct NegativeReinforcement<T> {
 
     xi: T,
 
-    random: ThreadRng,
+    random: StdRng,
 }
 impl<T> NegativeReinforcement<T> {
     pub fn new(
@@ -171,9 +198,14 @@ impl<T> NegativeReinforcement<T> {
             phi_worst_current,
             phi_worst_global,
             xi,
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<T: NumCast + Num + Copy> VelocityCalculator<T> for NegativeReinforcement<T> {
@@ -297,7 +329,7 @@ pub struct InertiaVelocityCalculator<'a, T> {
     phi_global: T,
     inertia: Box<dyn Inertia<T> + 'a>,
 
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl<'a, T> InertiaVelocityCalculator<'a, T> {
@@ -306,9 +338,14 @@ impl<'a, T> InertiaVelocityCalculator<'a, T> {
             phi_personal,
             phi_global,
             inertia,
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
 }
 
 impl<'a, T: NumCast + Num + Copy> VelocityCalculator<T> for InertiaVelocityCalculator<'a, T> {
@@ -335,3 +372,758 @@ impl<'a, T: NumCast + Num + Copy> VelocityCalculator<T> for InertiaVelocityCalcu
         new_velocity
     }
 }
+
+/// LinearInertiaVelocityCalculator implements the equation with the inertia coefficient w(t)
+/// which decreases linearly from `w_max` to `w_min` during `max_iterations` iterations.
+/// The inertia coefficient is equal to `w_min` after `max_iterations` iterations.
+/// v_i = w(t) * v_i + phi_personal * r_p * (p_i - x_i) + phi_global * r_g * (g_i - x_i)
+/// w(t) = w_max - (w_max - w_min) * t / max_iterations
+/// `v_i` - velocity projection for dimension i,
+/// `p_i` - personal best coordinate,
+/// `g_i` - global best coordinate,
+/// `x_i` - current coordinate,
+/// `phi_personal`, `phi_global` - parameters,
+/// `r_p`, `r_g` - random values in (0, 1),
+/// `t` - iteration number.
+pub struct LinearInertiaVelocityCalculator<T> {
+    calculator: InertiaVelocityCalculator<'static, T>,
+}
+
+impl<T: Float + 'static> LinearInertiaVelocityCalculator<T> {
+    pub fn new(phi_personal: T, phi_global: T, w_max: T, w_min: T, max_iterations: usize) -> Self {
+        assert!(w_max >= w_min);
+        assert!(max_iterations > 0);
+
+        let inertia = LinearInertia::new(w_min, w_max, max_iterations);
+        let calculator = InertiaVelocityCalculator::new(
+            phi_personal,
+            phi_global,
+            Box::new(LimitedInertia {
+                inertia,
+                max_iterations,
+            }),
+        );
+        Self { calculator }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.calculator.set_seed(seed);
+    }
+}
+
+impl<T: NumCast + Num + Copy> VelocityCalculator<T> for LinearInertiaVelocityCalculator<T> {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        self.calculator.calc_new_velocity(swarm, particle)
+    }
+}
+
+/// The inertia coefficient does not change after `max_iterations` iterations.
+struct LimitedInertia<T> {
+    inertia: LinearInertia<T>,
+    max_iterations: usize,
+}
+
+impl<T: Float> Inertia<T> for LimitedInertia<T> {
+    fn get(&mut self, iteration: usize) -> T {
+        self.inertia.get(iteration.min(self.max_iterations))
+    }
+}
+
+/// FIPSVelocityCalculator implements the Fully Informed Particle Swarm from the article
+/// Mendes, R.; Kennedy, J.; Neves, J. (2004). "The Fully Informed Particle Swarm: Simpler,
+/// Maybe Better". IEEE Transactions on Evolutionary Computation, 8(3), pp.204-210.
+/// v_i = xi * (v_i + sum_k(r_k * phi / |N| * (p_k_i - x_i)))
+/// `v_i` - velocity projection for dimension i,
+/// `N` - the particle neighbors (the neighbors are defined by the `Topology` trait),
+/// `p_k_i` - personal best coordinate of the neighbor `k`,
+/// `x_i` - current coordinate,
+/// `r_k` - random values in (0, 1),
+/// `xi` = 2 / (phi - 2 + sqrt(phi^2 - 4 * phi)),
+/// `phi` must be greater than 4
+///
+/// The neighbors are found by the particle index, so `calc_new_velocity` accepts only
+/// the references to the particles from the swarm. The optimizer passes the index
+/// with `calc_particle_velocity`.
+pub struct FIPSVelocityCalculator<'a, T> {
+    phi: T,
+    xi: T,
+    topology: Box<dyn Topology + 'a>,
+
+    random: StdRng,
+}
+
+impl<'a, T: Float> FIPSVelocityCalculator<'a, T> {
+    pub fn new(phi: T, topology: Box<dyn Topology + 'a>) -> Self {
+        let four = T::from(4.0).unwrap();
+        let two = T::from(2.0).unwrap();
+        assert!(phi > four);
+
+        let xi = two / (phi - two + (phi * phi - four * phi).sqrt());
+        Self {
+            phi,
+            xi,
+            topology,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<'a, T: NumCast + Num + Copy> VelocityCalculator<T> for FIPSVelocityCalculator<'a, T> {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let index = swarm
+            .get_particle_index(particle)
+            .expect("The particle must belong to the swarm");
+        self.calc_particle_velocity(swarm, index)
+    }
+
+    fn calc_particle_velocity(&mut self, swarm: &Swarm<T>, particle_index: usize) -> Vec<T> {
+        let particle = &swarm.particles[particle_index];
+        let dimension = particle.coordinates.len();
+        let neighbors = self.topology.neighbors(particle_index, swarm.len());
+        assert!(!neighbors.is_empty());
+
+        let weight = self.phi / T::from(neighbors.len()).unwrap();
+
+        let between = Uniform::new_inclusive(0.0_f32, 1.0_f32);
+        let mut new_velocity = Vec::with_capacity(dimension);
+        for i in 0..dimension {
+            let mut neighbors_sum = T::zero();
+            for &neighbor in &neighbors {
+                let r = T::from(between.sample(&mut self.random)).unwrap();
+                let best_coordinate = swarm.particles[neighbor].best_personal_coordinates[i];
+                neighbors_sum =
+                    neighbors_sum + r * weight * (best_coordinate - particle.coordinates[i]);
+            }
+
+            new_velocity.push(self.xi * (particle.velocity[i] + neighbors_sum));
+        }
+
+        new_velocity
+    }
+}
+
+/// LocalBestVelocityCalculator implements the local best (lbest) particle swarm with
+/// the constriction coefficient from the article
+/// Bratton, D.; Kennedy, J. (2007). "Defining a Standard for Particle Swarm Optimization".
+/// Proceedings of the 2007 IEEE Swarm Intelligence Symposium, pp.120-127.
+/// The best personal coordinates among the neighbors are used instead of the global best
+/// coordinates. The neighbors are defined by the `Topology` trait object.
+/// v_i = xi * (v_i + phi_p * r_p * (p_i - x_i) + phi_l * r_l * (l_i - x_i))
+/// `v_i` - velocity projection for dimension i,
+/// `p_i` - personal best coordinate,
+/// `l_i` - the best personal coordinate among the neighbors (local best coordinate),
+/// `x_i` - current coordinate,
+/// `phi_p`, `phi_l` - parameters,
+/// `r_p`, `r_l` - random values in (0, 1),
+/// `xi` = 2 / (phi - 2 + sqrt(phi^2 - 4 * phi)),
+/// `phi` = phi_p + phi_l must be greater than 4
+///
+/// The neighbors are found by the particle index, so `calc_new_velocity` accepts only
+/// the references to the particles from the swarm. The optimizer passes the index
+/// with `calc_particle_velocity`.
+pub struct LocalBestVelocityCalculator<'a, T> {
+    phi_personal: T,
+    phi_local: T,
+    xi: T,
+    topology: Box<dyn Topology + 'a>,
+
+    random: StdRng,
+}
+
+impl<'a, T: Float> LocalBestVelocityCalculator<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `phi_personal` - the acceleration coefficient to the personal best coordinates.
+    ///   Must be greater or equal to 0.
+    /// * `phi_local` - the acceleration coefficient to the local best coordinates.
+    ///   Must be greater or equal to 0.
+    /// * `topology` - the neighborhood of the particles.
+    ///
+    /// The usual values are `phi_personal` = `phi_local` = 2.05.
+    pub fn new(phi_personal: T, phi_local: T, topology: Box<dyn Topology + 'a>) -> Self {
+        let four = T::from(4.0).unwrap();
+        let two = T::from(2.0).unwrap();
+        assert!(phi_personal >= T::zero());
+        assert!(phi_local >= T::zero());
+
+        let phi = phi_personal + phi_local;
+        assert!(phi > four);
+
+        let xi = two / (phi - two + (phi * phi - four * phi).sqrt());
+        Self {
+            phi_personal,
+            phi_local,
+            xi,
+            topology,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+/// Returns index of the particle with the best personal value among `neighbors`.
+/// NaN values are worse than any other values.
+fn get_local_best_index<T>(swarm: &Swarm<T>, neighbors: &[usize]) -> usize {
+    let mut best_index = neighbors[0];
+    for &neighbor in &neighbors[1..] {
+        let best_value = swarm.particles[best_index].best_personal_value;
+        let value = swarm.particles[neighbor].best_personal_value;
+        if value < best_value || (best_value.is_nan() && !value.is_nan()) {
+            best_index = neighbor;
+        }
+    }
+
+    best_index
+}
+
+impl<'a, T: NumCast + Num + Copy> VelocityCalculator<T> for LocalBestVelocityCalculator<'a, T> {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let index = swarm
+            .get_particle_index(particle)
+            .expect("The particle must belong to the swarm");
+        self.calc_particle_velocity(swarm, index)
+    }
+
+    fn calc_particle_velocity(&mut self, swarm: &Swarm<T>, particle_index: usize) -> Vec<T> {
+        let particle = &swarm.particles[particle_index];
+        let dimension = particle.coordinates.len();
+        let neighbors = self.topology.neighbors(particle_index, swarm.len());
+        assert!(!neighbors.is_empty());
+
+        let local_best_index = get_local_best_index(swarm, &neighbors);
+        let local_best_solution = &swarm.particles[local_best_index].best_personal_coordinates;
+
+        let between = Uniform::new_inclusive(0.0_f32, 1.0_f32);
+        let mut new_velocity = Vec::with_capacity(dimension);
+        for (i, local_best) in local_best_solution.iter().enumerate() {
+            let r_personal = T::from(between.sample(&mut self.random)).unwrap();
+            let r_local = T::from(between.sample(&mut self.random)).unwrap();
+
+            let velocity_item = self.xi
+                * (particle.velocity[i]
+                    + self.phi_personal
+                        * r_personal
+                        * (particle.best_personal_coordinates[i] - particle.coordinates[i])
+                    + self.phi_local * r_local * (*local_best - particle.coordinates[i]));
+            new_velocity.push(velocity_item);
+        }
+
+        new_velocity
+    }
+}
+
+/// BareBonesVelocityCalculator implements the bare bones particle swarm from the article
+/// Kennedy, J. (2003). "Bare bones particle swarms". Proceedings of the 2003 IEEE Swarm
+/// Intelligence Symposium, pp.80-87.
+/// The new coordinates of the particle do not depend on the velocity. The new coordinates
+/// are sampled from the normal distribution:
+/// x_new_i ~ N((p_i + g_i) / 2, |p_i - g_i|)
+/// The calculator returns the velocity to move the particle to the new coordinates:
+/// v_i = x_new_i - x_i
+/// `p_i` - personal best coordinate,
+/// `g_i` - global best coordinate,
+/// `x_i` - current coordinate.
+pub struct BareBonesVelocityCalculator {
+    random: StdRng,
+}
+
+impl BareBonesVelocityCalculator {
+    pub fn new() -> Self {
+        Self {
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl Default for BareBonesVelocityCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NumCast + Num + Copy> VelocityCalculator<T> for BareBonesVelocityCalculator {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let global_best_particle = swarm.best_particle.as_ref().unwrap();
+        let global_best_solution = &global_best_particle.coordinates;
+
+        particle
+            .coordinates
+            .iter()
+            .zip(particle.best_personal_coordinates.iter())
+            .zip(global_best_solution.iter())
+            .map(|((coordinate, personal_best), global_best)| {
+                let personal_best = personal_best.to_f64().unwrap();
+                let global_best = global_best.to_f64().unwrap();
+
+                let mean = (personal_best + global_best) / 2.0;
+                let sigma = (personal_best - global_best).abs();
+                let new_coordinate = mean + sigma * get_standard_normal(&mut self.random);
+
+                T::from(new_coordinate).unwrap() - *coordinate
+            })
+            .collect()
+    }
+}
+
+/// Returns the value of the logistic function 1 / (1 + exp(-x)).
+/// The binary particle swarm uses the function to convert the velocity to the probability.
+pub fn sigmoid<T: Float>(x: T) -> T {
+    T::one() / (T::one() + (-x).exp())
+}
+
+/// BinaryVelocityCalculator implements the velocity equation for the binary particle swarm from
+/// the article
+/// Kennedy, J.; Eberhart, R. (1997). "A discrete binary version of the particle swarm algorithm".
+/// Proceedings of IEEE International Conference on Systems, Man, and Cybernetics, pp.4104-4108.
+/// The coordinates of the particles are 0 or 1 and the velocity is the tendency of the bit
+/// to be equal to 1. The probability to set the bit is `sigmoid(v_i)`, so the calculator should
+/// be used with `postmove::BinaryPostMove`, which samples the new coordinates.
+/// v_i = v_i + phi_p * r_p * (p_i - x_i) + phi_g * r_g * (g_i - x_i)
+/// `v_i` - velocity projection for dimension i, it is limited by the interval [-v_max, v_max],
+/// `p_i` - personal best coordinate,
+/// `g_i` - global best coordinate,
+/// `x_i` - current coordinate,
+/// `phi_p`, `phi_g` - parameters,
+/// `r_p`, `r_g` - random values in (0, 1)
+///
+/// The velocity limit does not allow the probability to saturate, so the bits may change even
+/// if the swarm has converged. The article uses `phi_p` = `phi_g` = 2 and `v_max` = 4.
+pub struct BinaryVelocityCalculator<T> {
+    phi_personal: T,
+    phi_global: T,
+    max_velocity: T,
+
+    random: StdRng,
+}
+
+impl<T: Float> BinaryVelocityCalculator<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `phi_personal` - the acceleration coefficient to the personal best coordinates (`phi_p`).
+    ///   Must be greater or equal to 0.
+    /// * `phi_global` - the acceleration coefficient to the global best coordinates (`phi_g`).
+    ///   Must be greater or equal to 0.
+    /// * `max_velocity` - the maximum absolute value of the velocity projection (`v_max`).
+    ///   Must be greater than 0.
+    pub fn new(phi_personal: T, phi_global: T, max_velocity: T) -> Self {
+        assert!(phi_personal >= T::zero());
+        assert!(phi_global >= T::zero());
+        assert!(max_velocity > T::zero());
+
+        Self {
+            phi_personal,
+            phi_global,
+            max_velocity,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<T: Float> VelocityCalculator<T> for BinaryVelocityCalculator<T> {
+    fn calc_new_velocity(&mut self, swarm: &Swarm<T>, particle: &Particle<T>) -> Vec<T> {
+        let global_best_particle = swarm.best_particle.as_ref().unwrap();
+        let global_best_solution = &global_best_particle.coordinates;
+
+        let between = Uniform::new_inclusive(0.0_f32, 1.0_f32);
+        let mut new_velocity = Vec::with_capacity(particle.coordinates.len());
+        for (i, global_best) in global_best_solution.iter().enumerate() {
+            let r_personal = T::from(between.sample(&mut self.random)).unwrap();
+            let r_global = T::from(between.sample(&mut self.random)).unwrap();
+
+            let velocity_item = particle.velocity[i]
+                + self.phi_personal
+                    * r_personal
+                    * (particle.best_personal_coordinates[i] - particle.coordinates[i])
+                + self.phi_global * r_global * (*global_best - particle.coordinates[i]);
+            new_velocity.push(
+                velocity_item
+                    .max(-self.max_velocity)
+                    .min(self.max_velocity),
+            );
+        }
+
+        new_velocity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::particleswarm::topology::{GlobalTopology, RingTopology};
+
+    /// Create the swarm with the single particle.
+    /// The particle best personal coordinates and the swarm best coordinates are equal
+    /// to the current coordinates.
+    fn create_swarm(coordinates: Vec<f64>, velocity: Vec<f64>) -> Swarm<f64> {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![Particle::new(coordinates, velocity, 0.0)]);
+        swarm
+    }
+
+    #[test]
+    fn classic_velocity_at_best() {
+        // The particle is at the best point, so the velocity does not change
+        let swarm = create_swarm(vec![1.0, 2.0], vec![10.0, -10.0]);
+        let mut calculator = ClassicVelocityCalculator::new(2.0, 2.0);
+
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+        assert_eq!(velocity, vec![10.0, -10.0]);
+    }
+
+    #[test]
+    fn classic_velocity_bounds() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 0.0),
+            Particle::new(vec![4.0], vec![1.0], 1.0),
+        ]);
+
+        // The particle 1 moves to the worse point, so its personal best remains 4.0
+        swarm.particles[1].move_to(vec![10.0], 10.0);
+
+        let mut calculator = ClassicVelocityCalculator::new(2.0, 3.0);
+        calculator.set_seed(42);
+
+        // v = 1 + 2 * r_p * (4 - 10) + 3 * r_g * (0 - 10)
+        let mut min_velocity = f64::INFINITY;
+        let mut max_velocity = f64::NEG_INFINITY;
+        for _ in 0..1000 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1])[0];
+            assert!(velocity <= 1.0);
+            assert!(velocity >= 1.0 - 12.0 - 30.0);
+            min_velocity = min_velocity.min(velocity);
+            max_velocity = max_velocity.max(velocity);
+        }
+
+        assert!(min_velocity < -35.0);
+        assert!(max_velocity > -5.0);
+    }
+
+    #[test]
+    fn classic_velocity_zero_phi() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 0.0),
+            Particle::new(vec![4.0], vec![1.0], 1.0),
+        ]);
+
+        let mut calculator = ClassicVelocityCalculator::new(0.0, 0.0);
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+        assert_eq!(velocity, vec![1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn classic_invalid_phi() {
+        ClassicVelocityCalculator::new(2.0, -1.0);
+    }
+
+    #[test]
+    fn linear_inertia_velocity() {
+        let mut swarm = create_swarm(vec![1.0, 2.0], vec![10.0, -10.0]);
+        let mut calculator = LinearInertiaVelocityCalculator::new(1.0, 1.0, 0.9, 0.4, 10);
+
+        // The particle is at the best point, so only the inertia term remains
+        let particle = swarm.particles[0].clone();
+        let velocity = calculator.calc_new_velocity(&swarm, &particle);
+        assert!((velocity[0] - 9.0).abs() < 1e-10);
+        assert!((velocity[1] + 9.0).abs() < 1e-10);
+
+        swarm.iteration = 5;
+        let velocity = calculator.calc_new_velocity(&swarm, &particle);
+        assert!((velocity[0] - 6.5).abs() < 1e-10);
+
+        swarm.iteration = 10;
+        let velocity = calculator.calc_new_velocity(&swarm, &particle);
+        assert!((velocity[0] - 4.0).abs() < 1e-10);
+
+        // The inertia coefficient does not decrease after max_iterations
+        swarm.iteration = 100;
+        let velocity = calculator.calc_new_velocity(&swarm, &particle);
+        assert!((velocity[0] - 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn linear_inertia_invalid_w() {
+        LinearInertiaVelocityCalculator::new(1.0, 1.0, 0.4, 0.9, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn linear_inertia_invalid_max_iterations() {
+        LinearInertiaVelocityCalculator::new(1.0, 1.0, 0.9, 0.4, 0);
+    }
+
+    #[test]
+    fn fips_xi() {
+        let calculator = FIPSVelocityCalculator::new(4.1, Box::new(GlobalTopology::new()));
+        assert!((calculator.xi - 0.7298).abs() < 1e-4);
+    }
+
+    #[test]
+    fn fips_velocity_single_particle() {
+        let swarm = create_swarm(vec![1.0, 2.0], vec![10.0, -10.0]);
+        let mut calculator = FIPSVelocityCalculator::new(4.1, Box::new(GlobalTopology::new()));
+        let xi = calculator.xi;
+
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+        assert!((velocity[0] - xi * 10.0).abs() < 1e-10);
+        assert!((velocity[1] + xi * 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn fips_velocity_neighbors() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 0.0),
+            Particle::new(vec![10.0], vec![0.0], 0.0),
+            Particle::new(vec![-10.0], vec![0.0], 0.0),
+            Particle::new(vec![100.0], vec![0.0], 0.0),
+        ]);
+        let phi = 4.1;
+
+        // The neighbors of the particle 1 are 0, 1 and 2, so the particle 3 does not influence
+        let mut calculator = FIPSVelocityCalculator::new(phi, Box::new(RingTopology::new(1)));
+        calculator.set_seed(42);
+        let xi = calculator.xi;
+        let max_velocity = xi * phi / 3.0 * 30.0;
+
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+            assert!(velocity[0] <= 0.0);
+            assert!(velocity[0] >= -max_velocity);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fips_particle_not_in_swarm() {
+        let swarm = create_swarm(vec![1.0], vec![1.0]);
+        let particle = swarm.particles[0].clone();
+        let mut calculator = FIPSVelocityCalculator::new(4.1, Box::new(GlobalTopology::new()));
+        calculator.calc_new_velocity(&swarm, &particle);
+    }
+
+    #[test]
+    fn fips_velocity_particle_index() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 0.0),
+            Particle::new(vec![10.0], vec![0.0], 0.0),
+            Particle::new(vec![-10.0], vec![0.0], 0.0),
+            Particle::new(vec![100.0], vec![0.0], 0.0),
+        ]);
+
+        let mut calculator_1 = FIPSVelocityCalculator::new(4.1, Box::new(RingTopology::new(1)));
+        let mut calculator_2 = FIPSVelocityCalculator::new(4.1, Box::new(RingTopology::new(1)));
+        calculator_1.set_seed(42);
+        calculator_2.set_seed(42);
+
+        // The index and the reference to the particle give the same velocity
+        for n in 0..swarm.len() {
+            assert_eq!(
+                calculator_1.calc_particle_velocity(&swarm, n),
+                calculator_2.calc_new_velocity(&swarm, &swarm.particles[n])
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fips_invalid_phi() {
+        FIPSVelocityCalculator::<f64>::new(4.0, Box::new(GlobalTopology::new()));
+    }
+
+    #[test]
+    fn bare_bones_same_best() {
+        // The personal best and the global best are equal, so sigma is 0
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![Particle::new(vec![1.0, 2.0], vec![10.0, 20.0], 0.0)]);
+        swarm.particles[0].move_to(vec![5.0, 5.0], 10.0);
+
+        let mut calculator = BareBonesVelocityCalculator::new();
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+
+        assert_eq!(velocity, vec![-4.0, -3.0]);
+    }
+
+    #[test]
+    fn bare_bones_distribution() {
+        let run_count = 10000;
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 0.0),
+            Particle::new(vec![4.0], vec![0.0], 1.0),
+        ]);
+
+        let mut calculator = BareBonesVelocityCalculator::new();
+        calculator.set_seed(42);
+
+        // p = 4, g = 0, so the new coordinates are sampled from N(2, 4)
+        let new_coordinates: Vec<f64> = (0..run_count)
+            .map(|_| {
+                let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+                swarm.particles[1].coordinates[0] + velocity[0]
+            })
+            .collect();
+
+        let mean = new_coordinates.iter().sum::<f64>() / run_count as f64;
+        let variance = new_coordinates.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+            / (run_count - 1) as f64;
+
+        assert!((mean - 2.0).abs() < 0.2);
+        assert!((variance.sqrt() - 4.0).abs() < 0.2);
+    }
+    #[test]
+    fn local_best_index() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 5.0),
+            Particle::new(vec![1.0], vec![0.0], f64::NAN),
+            Particle::new(vec![2.0], vec![0.0], 3.0),
+            Particle::new(vec![3.0], vec![0.0], 1.0),
+        ]);
+
+        assert_eq!(get_local_best_index(&swarm, &[0, 1, 2]), 2);
+        assert_eq!(get_local_best_index(&swarm, &[1, 0]), 0);
+        assert_eq!(get_local_best_index(&swarm, &[1]), 1);
+        assert_eq!(get_local_best_index(&swarm, &[0, 1, 2, 3]), 3);
+    }
+
+    #[test]
+    fn local_best_velocity_neighbors() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 1.0),
+            Particle::new(vec![10.0], vec![0.0], 2.0),
+            Particle::new(vec![20.0], vec![0.0], 3.0),
+            Particle::new(vec![100.0], vec![0.0], 0.0),
+        ]);
+
+        // The neighbors of the particle 1 are 0, 1 and 2, so the local best is the particle 0
+        // and the global best particle 3 does not influence
+        let mut calculator =
+            LocalBestVelocityCalculator::new(2.05, 2.05, Box::new(RingTopology::new(1)));
+        calculator.set_seed(42);
+        let max_velocity = calculator.xi * 2.05 * 10.0;
+
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+            assert!(velocity[0] <= 0.0);
+            assert!(velocity[0] >= -max_velocity);
+        }
+    }
+
+    #[test]
+    fn local_best_velocity_at_best() {
+        let swarm = create_swarm(vec![1.0, 2.0], vec![10.0, -10.0]);
+        let mut calculator =
+            LocalBestVelocityCalculator::new(2.05, 2.05, Box::new(GlobalTopology::new()));
+        assert!((calculator.xi - 0.7298).abs() < 1e-4);
+        let xi = calculator.xi;
+
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+        assert!((velocity[0] - xi * 10.0).abs() < 1e-10);
+        assert!((velocity[1] + xi * 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn local_best_velocity_particle_index() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![0.0], vec![0.0], 1.0),
+            Particle::new(vec![10.0], vec![0.0], 2.0),
+            Particle::new(vec![20.0], vec![0.0], 3.0),
+            Particle::new(vec![100.0], vec![0.0], 0.0),
+        ]);
+
+        let mut calculator_1 =
+            LocalBestVelocityCalculator::new(2.05, 2.05, Box::new(RingTopology::new(1)));
+        let mut calculator_2 =
+            LocalBestVelocityCalculator::new(2.05, 2.05, Box::new(RingTopology::new(1)));
+        calculator_1.set_seed(42);
+        calculator_2.set_seed(42);
+
+        // The index and the reference to the particle give the same velocity
+        for n in 0..swarm.len() {
+            assert_eq!(
+                calculator_1.calc_particle_velocity(&swarm, n),
+                calculator_2.calc_new_velocity(&swarm, &swarm.particles[n])
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn local_best_invalid_phi() {
+        LocalBestVelocityCalculator::<f64>::new(2.0, 2.0, Box::new(GlobalTopology::new()));
+    }
+
+    #[test]
+    fn sigmoid_values() {
+        assert!((sigmoid(0.0_f64) - 0.5).abs() < 1e-10);
+        assert!((sigmoid(4.0_f64) - 0.982_013_790_037_908_5).abs() < 1e-10);
+        assert!((sigmoid(-4.0_f64) + sigmoid(4.0_f64) - 1.0).abs() < 1e-10);
+        assert!(sigmoid(-1000.0_f64) >= 0.0);
+        assert!(sigmoid(1000.0_f64) <= 1.0);
+    }
+
+    #[test]
+    fn binary_velocity_at_best() {
+        let swarm = create_swarm(vec![1.0, 0.0], vec![2.0, -3.0]);
+        let mut calculator = BinaryVelocityCalculator::new(2.0, 2.0, 4.0);
+
+        let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[0]);
+        assert_eq!(velocity, vec![2.0, -3.0]);
+    }
+
+    #[test]
+    fn binary_velocity_limit() {
+        let mut swarm = Swarm::new();
+        swarm.replace_particles(vec![
+            Particle::new(vec![1.0, 0.0], vec![0.0, 0.0], 0.0),
+            Particle::new(vec![0.0, 1.0], vec![3.5, -3.5], 1.0),
+        ]);
+
+        let mut calculator = BinaryVelocityCalculator::new(2.0, 2.0, 4.0);
+        calculator.set_seed(42);
+
+        // The global best pulls the first bit to 1 and the second bit to 0
+        for _ in 0..100 {
+            let velocity = calculator.calc_new_velocity(&swarm, &swarm.particles[1]);
+            assert!(velocity[0] >= 3.5);
+            assert!(velocity[0] <= 4.0);
+            assert!(velocity[1] <= -3.5);
+            assert!(velocity[1] >= -4.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn binary_invalid_max_velocity() {
+        BinaryVelocityCalculator::new(2.0, 2.0, 0.0);
+    }
+}
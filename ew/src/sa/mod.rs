@@ -0,0 +1,519 @@
+//! The module with the simulated annealing algorithm.
+//!
+//! Kirkpatrick, S.; Gelatt Jr, C. D.; Vecchi, M. P. (1983). "Optimization by Simulated
+//! Annealing". Science, 220(4598), pp.671-680.
+//!
+//! On every iteration the algorithm proposes the new point near the current point by
+//! `NeighborGenerator`. The new point is accepted by the Metropolis criterion: the better point
+//! is always accepted, the worse point is accepted with probability exp(-delta / temperature),
+//! where `delta` - increase of the goal function. The temperature decreases according to
+//! `CoolingSchedule`.
+
+use num::Float;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::tools::get_standard_normal;
+use crate::tools::logging::Logger;
+use crate::tools::stopchecker::StopChecker;
+use crate::{
+    call_iteration_callback, AlgorithmState, Direction, Goal, GoalValue, IterativeOptimizer,
+    Optimizer, Solution,
+};
+
+/// The trait for the temperature decreasing law.
+pub trait CoolingSchedule {
+    /// Returns the temperature for the iteration.
+    fn get_temperature(&mut self, iteration: usize) -> f64;
+}
+
+/// The trait to propose the new point near the current point.
+///
+/// `T` - type of a point in the search space for goal function.
+pub trait NeighborGenerator<T> {
+    /// Returns the new point near the `current` point. The generator may use the current
+    /// `temperature` to change the step size.
+    fn get_neighbor(&mut self, current: &T, temperature: f64) -> T;
+}
+
+/// Linear cooling. The temperature decreases linearly from the initial temperature to the final
+/// temperature during `iterations` iterations and does not change after.
+pub struct LinearCooling {
+    initial_temperature: f64,
+    final_temperature: f64,
+    iterations: usize,
+}
+
+impl LinearCooling {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `initial_temperature` - the temperature on the iteration 0. Must be greater or equal
+    ///   to the final temperature.
+    /// * `final_temperature` - the temperature after `iterations` iterations. Must be greater or
+    ///   equal to 0.
+    /// * `iterations` - iterations count to reach the final temperature. Must be greater than 0.
+    pub fn new(initial_temperature: f64, final_temperature: f64, iterations: usize) -> Self {
+        assert!(final_temperature >= 0.0);
+        assert!(initial_temperature >= final_temperature);
+        assert!(iterations > 0);
+        Self {
+            initial_temperature,
+            final_temperature,
+            iterations,
+        }
+    }
+}
+
+impl CoolingSchedule for LinearCooling {
+    fn get_temperature(&mut self, iteration: usize) -> f64 {
+        let ratio = iteration.min(self.iterations) as f64 / self.iterations as f64;
+        self.initial_temperature - (self.initial_temperature - self.final_temperature) * ratio
+    }
+}
+
+/// Exponential (geometric) cooling: T_k = T_0 * alpha ^ k.
+pub struct ExponentialCooling {
+    initial_temperature: f64,
+    alpha: f64,
+}
+
+impl ExponentialCooling {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `initial_temperature` - the temperature on the iteration 0. Must be greater or equal
+    ///   to 0.
+    /// * `alpha` - the cooling factor. Must be in the range (0; 1). The usual values are in
+    ///   the range [0.8; 0.999].
+    pub fn new(initial_temperature: f64, alpha: f64) -> Self {
+        assert!(initial_temperature >= 0.0);
+        assert!(alpha > 0.0 && alpha < 1.0);
+        Self {
+            initial_temperature,
+            alpha,
+        }
+    }
+}
+
+impl CoolingSchedule for ExponentialCooling {
+    fn get_temperature(&mut self, iteration: usize) -> f64 {
+        self.initial_temperature * self.alpha.powf(iteration as f64)
+    }
+}
+
+/// Boltzmann (logarithmic) cooling: T_k = T_0 * ln(2) / ln(k + 2).
+/// The temperature decreases very slowly.
+pub struct BoltzmannCooling {
+    initial_temperature: f64,
+}
+
+impl BoltzmannCooling {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `initial_temperature` - the temperature on the iteration 0. Must be greater or equal
+    ///   to 0.
+    pub fn new(initial_temperature: f64) -> Self {
+        assert!(initial_temperature >= 0.0);
+        Self {
+            initial_temperature,
+        }
+    }
+}
+
+impl CoolingSchedule for BoltzmannCooling {
+    fn get_temperature(&mut self, iteration: usize) -> f64 {
+        self.initial_temperature * 2.0_f64.ln() / (iteration as f64 + 2.0).ln()
+    }
+}
+
+/// The neighbor generator for the points of type Vec<G>. The generator adds the normal
+/// distributed noise N(0, sigma^2) to every coordinate.
+pub struct GaussianNeighbor {
+    sigma: f64,
+    random: StdRng,
+}
+
+impl GaussianNeighbor {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `sigma` - standard deviation of the noise. Must be greater than 0.
+    pub fn new(sigma: f64) -> Self {
+        assert!(sigma > 0.0);
+        Self {
+            sigma,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<G: Float> NeighborGenerator<Vec<G>> for GaussianNeighbor {
+    fn get_neighbor(&mut self, current: &Vec<G>, _temperature: f64) -> Vec<G> {
+        current
+            .iter()
+            .map(|x| *x + G::from(self.sigma * get_standard_normal(&mut self.random)).unwrap())
+            .collect()
+    }
+}
+
+/// Returns true if the new point must be accepted by the Metropolis criterion.
+/// `delta` - increase of the value which the algorithm minimizes, `probability` - random value in
+/// the range [0; 1).
+fn metropolis(delta: f64, temperature: f64, probability: f64) -> bool {
+    if delta.is_nan() {
+        false
+    } else if delta <= 0.0 {
+        true
+    } else if temperature <= 0.0 {
+        false
+    } else {
+        probability < (-delta / temperature).exp()
+    }
+}
+
+/// The state of the simulated annealing algorithm.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct SaState<T> {
+    /// The current point and the value which the algorithm minimizes.
+    current: Option<(T, GoalValue)>,
+
+    /// The best point and the value which the algorithm minimizes.
+    best: Option<(T, GoalValue)>,
+
+    temperature: f64,
+    iteration: usize,
+    accepted_count: usize,
+    direction: Direction,
+}
+
+impl<T: Clone> SaState<T> {
+    fn new() -> Self {
+        Self {
+            current: None,
+            best: None,
+            temperature: 0.0,
+            iteration: 0,
+            accepted_count: 0,
+            direction: Direction::Minimize,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.current = None;
+        self.best = None;
+        self.temperature = 0.0;
+        self.iteration = 0;
+        self.accepted_count = 0;
+    }
+
+    /// Returns the current point and the value of the goal function for it.
+    pub fn get_current_solution(&self) -> Option<Solution<T>> {
+        self.current
+            .as_ref()
+            .map(|(point, value)| (point.clone(), self.direction.apply(*value)))
+    }
+
+    /// Returns the current temperature.
+    pub fn get_temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Returns count of the accepted points since the algorithm start.
+    pub fn get_accepted_count(&self) -> usize {
+        self.accepted_count
+    }
+}
+
+impl<T: Clone> AlgorithmState<T> for SaState<T> {
+    fn get_best_solution(&self) -> Option<Solution<T>> {
+        self.best
+            .as_ref()
+            .map(|(point, value)| (point.clone(), self.direction.apply(*value)))
+    }
+
+    fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn get_agents_parameters(&self) -> Vec<&T> {
+        match &self.current {
+            Some((point, _)) => vec![point],
+            None => vec![],
+        }
+    }
+}
+
+/// The simulated annealing optimizer. The optimizer implements the `Optimizer` trait.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct SimulatedAnnealingOptimizer<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+    stop_checker: Box<dyn StopChecker<T> + 'a>,
+    neighbor_generator: Box<dyn NeighborGenerator<T> + 'a>,
+    cooling_schedule: Box<dyn CoolingSchedule + 'a>,
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+    initial_point: T,
+    state: SaState<T>,
+    random: StdRng,
+
+    /// True if the algorithm was initialized.
+    initialized: bool,
+}
+
+impl<'a, T: Clone> SimulatedAnnealingOptimizer<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function.
+    /// * `stop_checker` - the stop criterion.
+    /// * `initial_point` - the start point of the search.
+    /// * `neighbor_generator` - the generator of the new points.
+    /// * `cooling_schedule` - the temperature decreasing law.
+    pub fn new(
+        goal: Box<dyn Goal<T> + 'a>,
+        stop_checker: Box<dyn StopChecker<T> + 'a>,
+        initial_point: T,
+        neighbor_generator: Box<dyn NeighborGenerator<T> + 'a>,
+        cooling_schedule: Box<dyn CoolingSchedule + 'a>,
+    ) -> Self {
+        Self {
+            goal,
+            stop_checker,
+            neighbor_generator,
+            cooling_schedule,
+            loggers: vec![],
+            initial_point,
+            state: SaState::new(),
+            random: StdRng::from_entropy(),
+            initialized: false,
+        }
+    }
+
+    /// Set seed for the random numbers generator of the Metropolis criterion to get
+    /// reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn set_stop_checker(&mut self, stop_checker: Box<dyn StopChecker<T> + 'a>) {
+        self.stop_checker = stop_checker;
+    }
+
+    pub fn set_loggers(&mut self, loggers: Vec<Box<dyn Logger<T> + 'a>>) {
+        self.loggers = loggers;
+    }
+
+    /// Set the start point for the next runs.
+    pub fn set_initial_point(&mut self, initial_point: T) {
+        self.initial_point = initial_point;
+    }
+
+    /// Returns the current state of the algorithm.
+    pub fn get_state(&self) -> &SaState<T> {
+        &self.state
+    }
+
+    fn init(&mut self, direction: Direction) {
+        self.state.reset();
+        self.state.direction = direction;
+
+        let value = direction.apply(self.goal.get(&self.initial_point));
+        self.state.current = Some((self.initial_point.clone(), value));
+        self.state.best = Some((self.initial_point.clone(), value));
+        self.state.temperature = self.cooling_schedule.get_temperature(0);
+        self.stop_checker.reset();
+        self.initialized = true;
+
+        for logger in &mut self.loggers {
+            logger.start(&self.state);
+        }
+    }
+
+    fn run(&mut self, direction: Direction) -> Option<Solution<T>> {
+        self.init(direction);
+        self.next_iterations()
+    }
+
+    /// Propose single new point.
+    fn do_iteration(&mut self) {
+        let (current_point, current_value) = self.state.current.clone().unwrap();
+        let temperature = self.state.temperature;
+
+        let new_point = self
+            .neighbor_generator
+            .get_neighbor(&current_point, temperature);
+        let new_value = self.state.direction.apply(self.goal.get(&new_point));
+
+        let probability = Uniform::new(0.0, 1.0).sample(&mut self.random);
+        if metropolis(new_value - current_value, temperature, probability)
+            || (current_value.is_nan() && !new_value.is_nan())
+        {
+            let best_value = self.state.best.as_ref().unwrap().1;
+            if new_value < best_value || best_value.is_nan() {
+                self.state.best = Some((new_point.clone(), new_value));
+            }
+
+            self.state.current = Some((new_point, new_value));
+            self.state.accepted_count += 1;
+        }
+
+        self.state.iteration += 1;
+        self.state.temperature = self.cooling_schedule.get_temperature(self.state.iteration);
+
+        for logger in &mut self.loggers {
+            logger.next_iteration(&self.state);
+        }
+    }
+}
+
+impl<'a, T: Clone> Optimizer<T> for SimulatedAnnealingOptimizer<'a, T> {
+    fn find_min(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Minimize)
+    }
+
+    fn find_max(&mut self) -> Option<Solution<T>> {
+        self.run(Direction::Maximize)
+    }
+
+    /// Remove the current and the best points and go to iteration 0.
+    fn reset(&mut self) {
+        self.state.reset();
+        self.state.direction = Direction::Minimize;
+        self.stop_checker.reset();
+        self.initialized = false;
+    }
+}
+
+impl<'a, T: Clone> IterativeOptimizer<T> for SimulatedAnnealingOptimizer<'a, T> {
+    fn next_iterations_with_callback(
+        &mut self,
+        callback: &mut dyn FnMut(usize, Option<(&T, GoalValue)>),
+    ) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.state.direction);
+        }
+
+        for logger in &mut self.loggers {
+            logger.resume(&self.state);
+        }
+
+        let mut iteration = 0;
+        while !self.stop_checker.can_stop(&self.state) {
+            self.do_iteration();
+            iteration += 1;
+            call_iteration_callback(callback, iteration, &self.state);
+        }
+
+        for logger in &mut self.loggers {
+            logger.finish(&self.state);
+        }
+
+        self.state.get_best_solution()
+    }
+
+    fn step(&mut self) -> Option<Solution<T>> {
+        if !self.initialized {
+            self.init(self.state.direction);
+        }
+
+        if self.stop_checker.can_stop(&self.state) {
+            return None;
+        }
+
+        self.do_iteration();
+        self.state.get_best_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_cooling() {
+        let mut cooling = LinearCooling::new(100.0, 10.0, 10);
+        assert_eq!(cooling.get_temperature(0), 100.0);
+        assert_eq!(cooling.get_temperature(5), 55.0);
+        assert_eq!(cooling.get_temperature(10), 10.0);
+        assert_eq!(cooling.get_temperature(20), 10.0);
+    }
+
+    #[test]
+    fn exponential_cooling() {
+        let mut cooling = ExponentialCooling::new(100.0, 0.5);
+        assert_eq!(cooling.get_temperature(0), 100.0);
+        assert_eq!(cooling.get_temperature(1), 50.0);
+        assert_eq!(cooling.get_temperature(3), 12.5);
+    }
+
+    #[test]
+    fn boltzmann_cooling() {
+        let mut cooling = BoltzmannCooling::new(100.0);
+        assert!((cooling.get_temperature(0) - 100.0).abs() < 1e-10);
+        assert!((cooling.get_temperature(2) - 50.0).abs() < 1e-10);
+
+        let mut prev_temperature = cooling.get_temperature(0);
+        for iteration in 1..100 {
+            let temperature = cooling.get_temperature(iteration);
+            assert!(temperature < prev_temperature);
+            assert!(temperature > 0.0);
+            prev_temperature = temperature;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponential_cooling_invalid_alpha() {
+        ExponentialCooling::new(100.0, 1.0);
+    }
+
+    #[test]
+    fn gaussian_neighbor() {
+        let mut generator = GaussianNeighbor::new(2.0);
+        generator.set_seed(1);
+
+        let run_count = 10000;
+        let current = vec![1.0_f64, -1.0];
+        let mut sum_sqr = vec![0.0, 0.0];
+        for _ in 0..run_count {
+            let neighbor = generator.get_neighbor(&current, 1.0);
+            for n in 0..2 {
+                sum_sqr[n] += (neighbor[n] - current[n]).powi(2);
+            }
+        }
+
+        for value in sum_sqr {
+            let deviation = (value / run_count as f64).sqrt();
+            assert!((deviation - 2.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn metropolis_criterion() {
+        // The better points are always accepted
+        assert!(metropolis(-1.0, 0.0, 0.99));
+        assert!(metropolis(0.0, 1.0, 0.99));
+
+        // The worse points are not accepted with zero temperature
+        assert!(!metropolis(1.0, 0.0, 0.0));
+
+        // exp(-1) = 0.368
+        assert!(metropolis(1.0, 1.0, 0.3));
+        assert!(!metropolis(1.0, 1.0, 0.4));
+
+        assert!(!metropolis(f64::NAN, 1.0, 0.0));
+    }
+}
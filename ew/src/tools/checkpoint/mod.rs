@@ -0,0 +1,138 @@
+//! The functions to save the optimizers state to the JSON files (checkpoints) and to load it.
+//!
+//! The module is available with the "checkpoint" feature.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Write the `state` to the file as JSON. The file is created or truncated.
+pub(crate) fn save<S: Serialize, P: AsRef<Path>>(path: P, state: &S) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut writer, state)?;
+    writer.flush()
+}
+
+/// Read the state saved by `save`.
+pub(crate) fn load<D: DeserializeOwned, P: AsRef<Path>>(path: P) -> io::Result<D> {
+    let reader = BufReader::new(File::open(path)?);
+    let state = serde_json::from_reader(reader)?;
+    Ok(state)
+}
+
+/// The serialization of the values of the goal function for `#[serde(with = "...")]`.
+/// JSON does not support infinity and NaN, so these values are stored as the strings
+/// "inf", "-inf" and "NaN".
+pub(crate) mod goal_value {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum StoredValue {
+        Number(f64),
+        Text(String),
+    }
+
+    pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        let stored = if value.is_finite() {
+            StoredValue::Number(*value)
+        } else if value.is_nan() {
+            StoredValue::Text("NaN".to_string())
+        } else if *value > 0.0 {
+            StoredValue::Text("inf".to_string())
+        } else {
+            StoredValue::Text("-inf".to_string())
+        };
+
+        stored.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        match StoredValue::deserialize(deserializer)? {
+            StoredValue::Number(value) => Ok(value),
+            StoredValue::Text(text) => match text.as_str() {
+                "NaN" => Ok(std::f64::NAN),
+                "inf" => Ok(std::f64::INFINITY),
+                "-inf" => Ok(std::f64::NEG_INFINITY),
+                _ => Err(D::Error::custom(format!("invalid goal value: {}", text))),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, serde::Deserialize)]
+    struct State {
+        #[serde(with = "goal_value")]
+        value: f64,
+        items: Vec<f64>,
+    }
+
+    fn get_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "ew-checkpoint-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn save_load() {
+        let path = get_path("save_load");
+        let state = State {
+            value: 1.5,
+            items: vec![1.0, -2.0],
+        };
+        save(&path, &state).unwrap();
+
+        let loaded: State = load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.value, 1.5);
+        assert_eq!(loaded.items, vec![1.0, -2.0]);
+    }
+
+    #[test]
+    fn save_load_not_finite() {
+        let path = get_path("save_load_not_finite");
+        for &value in &[std::f64::INFINITY, std::f64::NEG_INFINITY, std::f64::NAN] {
+            let state = State {
+                value,
+                items: vec![],
+            };
+            save(&path, &state).unwrap();
+
+            let loaded: State = load(&path).unwrap();
+            assert_eq!(loaded.value.is_nan(), value.is_nan());
+            if !value.is_nan() {
+                assert_eq!(loaded.value, value);
+            }
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_invalid_value() {
+        let path = get_path("load_invalid_value");
+        std::fs::write(&path, r#"{"value": "xxx", "items": []}"#).unwrap();
+
+        let result: io::Result<State> = load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let result: io::Result<State> = load(get_path("load_missing_file"));
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::NotFound);
+    }
+}
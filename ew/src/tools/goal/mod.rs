@@ -0,0 +1,676 @@
+//! The module with the wrappers for goal functions. The wrappers implements the `Goal` trait.
+
+use std::collections::{BTreeMap, HashMap};
+use std::f64;
+use std::hash::Hash;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::tools::get_standard_normal;
+use crate::{Goal, GoalValue};
+
+/// The goal function wrapper which stores the calculated values of the wrapped goal function.
+/// The stored value is returned if the goal function is calculated for the same point again.
+/// The wrapped goal function must be deterministic.
+///
+/// The cache size is limited. The least recently used value is removed if the cache is full.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct GoalCached<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+    capacity: usize,
+
+    /// Key - point in the search space, value - (goal function value, last usage time).
+    cache: HashMap<T, (GoalValue, usize)>,
+
+    /// Key - last usage time, value - point in the search space.
+    usage: BTreeMap<usize, T>,
+
+    time: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl<'a, T: Hash + Eq + Clone> GoalCached<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the wrapped goal function.
+    /// * `capacity` - max count of the stored values. `capacity` must be greater than 0.
+    pub fn new(goal: Box<dyn Goal<T> + 'a>, capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            goal,
+            capacity,
+            cache: HashMap::new(),
+            usage: BTreeMap::new(),
+            time: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns count of the goal function calls when the value was found in the cache.
+    pub fn get_hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns count of the goal function calls when the wrapped goal function was calculated.
+    pub fn get_misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Returns ratio of the cache hits to the total calls count or 0.0 if the goal function
+    /// was not called.
+    pub fn get_hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+
+    /// Returns count of the stored values.
+    pub fn get_cache_size(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Remove all stored values and reset the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.usage.clear();
+        self.time = 0;
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    fn remove_least_recently_used(&mut self) {
+        let oldest_time = *self.usage.keys().next().unwrap();
+        let x = self.usage.remove(&oldest_time).unwrap();
+        self.cache.remove(&x);
+    }
+}
+
+impl<'a, T: Hash + Eq + Clone> Goal<T> for GoalCached<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        self.time += 1;
+
+        if let Some((value, last_time)) = self.cache.get_mut(x) {
+            self.hits += 1;
+            let old_time = *last_time;
+            *last_time = self.time;
+
+            let key = self.usage.remove(&old_time).unwrap();
+            self.usage.insert(self.time, key);
+            return *value;
+        }
+
+        self.misses += 1;
+        let value = self.goal.get(x);
+
+        if self.cache.len() == self.capacity {
+            self.remove_least_recently_used();
+        }
+
+        self.cache.insert(x.clone(), (value, self.time));
+        self.usage.insert(self.time, x.clone());
+        value
+    }
+}
+
+/// The constraint function for `GoalWithPenalty`.
+type ConstraintFunction<'a, T> = Box<dyn Fn(&T) -> f64 + 'a>;
+
+/// The goal function wrapper for the constrained optimization with the exterior penalty method.
+///
+/// The goal function is calculated as `goal(x) + penalty_coefficient * sum(violation_i(x)^2)`,
+/// where `violation_i(x) = max(0, g_i(x))` for the inequality constraints `g_i(x) <= 0`
+/// and `violation_i(x) = h_i(x)` for the equality constraints `h_i(x) = 0`.
+///
+/// The penalty coefficient may be increased during optimization (see `set_adaptive`).
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct GoalWithPenalty<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+
+    /// The functions return the constraints violation (0.0 if the constraint is satisfied).
+    violations: Vec<ConstraintFunction<'a, T>>,
+    penalty_coefficient: f64,
+
+    /// (growth factor, calls count between the penalty coefficient increasings)
+    adaptive: Option<(f64, usize)>,
+
+    /// Calls count after the last penalty coefficient increasing.
+    call_count: usize,
+}
+
+impl<'a, T: 'a> GoalWithPenalty<'a, T> {
+    /// Constructor without constraints. Use `add_inequality` and `add_equality`
+    /// to add constraints.
+    ///
+    /// # Parameters
+    /// * `goal` - the wrapped goal function.
+    /// * `penalty_coefficient` - the multiplier for the penalty. Must be greater than 0.
+    pub fn new(goal: Box<dyn Goal<T> + 'a>, penalty_coefficient: f64) -> Self {
+        assert!(penalty_coefficient > 0.0);
+
+        Self {
+            goal,
+            violations: vec![],
+            penalty_coefficient,
+            adaptive: None,
+            call_count: 0,
+        }
+    }
+
+    /// Constructor with the inequality constraints `g_i(x) <= 0`.
+    pub fn with_inequalities(
+        goal: Box<dyn Goal<T> + 'a>,
+        constraints: Vec<ConstraintFunction<'a, T>>,
+        penalty_coefficient: f64,
+    ) -> Self {
+        let mut result = Self::new(goal, penalty_coefficient);
+        for constraint in constraints {
+            result.add_inequality(constraint);
+        }
+
+        result
+    }
+
+    /// Constructor with the equality constraints `h_i(x) = 0`.
+    pub fn with_equalities(
+        goal: Box<dyn Goal<T> + 'a>,
+        constraints: Vec<ConstraintFunction<'a, T>>,
+        penalty_coefficient: f64,
+    ) -> Self {
+        let mut result = Self::new(goal, penalty_coefficient);
+        for constraint in constraints {
+            result.add_equality(constraint);
+        }
+
+        result
+    }
+
+    /// Add the inequality constraint `constraint(x) <= 0`.
+    pub fn add_inequality(&mut self, constraint: ConstraintFunction<'a, T>) {
+        self.violations.push(Box::new(move |x| constraint(x).max(0.0)));
+    }
+
+    /// Add the equality constraint `constraint(x) = 0`.
+    pub fn add_equality(&mut self, constraint: ConstraintFunction<'a, T>) {
+        self.violations.push(constraint);
+    }
+
+    /// Enable the adaptive penalty coefficient. The penalty coefficient is multiplied
+    /// by `growth_factor` after every `period` goal function calls.
+    ///
+    /// The goal function landscape is changed when the penalty coefficient is increased,
+    /// so the values of the goal function calculated earlier become out of date.
+    ///
+    /// # Parameters
+    /// * `growth_factor` - must be greater or equal to 1.
+    /// * `period` - must be greater than 0.
+    pub fn set_adaptive(&mut self, growth_factor: f64, period: usize) {
+        assert!(growth_factor >= 1.0);
+        assert!(period > 0);
+        self.adaptive = Some((growth_factor, period));
+    }
+
+    /// Returns the current penalty coefficient.
+    pub fn get_penalty_coefficient(&self) -> f64 {
+        self.penalty_coefficient
+    }
+
+    /// Returns the penalty (without the multiplier) for the point `x`.
+    /// Returns 0.0 if all constraints are satisfied.
+    pub fn get_penalty(&self, x: &T) -> f64 {
+        self.violations
+            .iter()
+            .map(|violation| violation(x).powi(2))
+            .sum()
+    }
+}
+
+impl<'a, T> Goal<T> for GoalWithPenalty<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let value = self.goal.get(x) + self.penalty_coefficient * self.get_penalty(x);
+
+        if let Some((growth_factor, period)) = self.adaptive {
+            self.call_count += 1;
+            if self.call_count == period {
+                self.penalty_coefficient *= growth_factor;
+                self.call_count = 0;
+            }
+        }
+
+        value
+    }
+}
+
+/// The goal function wrapper which adds the Gaussian noise to the wrapped goal function values.
+/// The wrapper is useful to test robustness of the algorithms for the noisy goal functions.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct GoalWithNoise<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+    sigma: f64,
+    random: StdRng,
+}
+
+impl<'a, T> GoalWithNoise<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the wrapped goal function.
+    /// * `sigma` - the standard deviation of the noise. Must be greater or equal to 0.
+    pub fn new(goal: Box<dyn Goal<T> + 'a>, sigma: f64) -> Self {
+        assert!(sigma >= 0.0);
+
+        Self {
+            goal,
+            sigma,
+            random: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<'a, T> Goal<T> for GoalWithNoise<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let noise = self.sigma * get_standard_normal(&mut self.random);
+        self.goal.get(x) + noise
+    }
+}
+
+/// The goal function wrapper which calculates the wrapped goal function several times
+/// and returns the average value. The wrapper is used to decrease the influence of noise
+/// for the noisy goal functions.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct GoalAveragedSamples<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+    samples_count: usize,
+}
+
+impl<'a, T> GoalAveragedSamples<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the wrapped goal function.
+    /// * `samples_count` - how many times the wrapped goal function is calculated for every
+    ///   point. Must be greater than 0.
+    pub fn new(goal: Box<dyn Goal<T> + 'a>, samples_count: usize) -> Self {
+        assert!(samples_count > 0);
+        Self {
+            goal,
+            samples_count,
+        }
+    }
+}
+
+impl<'a, T> Goal<T> for GoalAveragedSamples<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let sum: GoalValue = (0..self.samples_count).map(|_| self.goal.get(x)).sum();
+        sum / self.samples_count as f64
+    }
+}
+
+/// The goal function wrapper which scales the wrapped goal function values to the range [0, 1].
+/// The range of the goal function is estimated by the minimum and maximum of all calculated
+/// values, so the scaling changes during the optimization.
+///
+/// The wrapper returns the raw values of the wrapped goal function until `min_samples`
+/// values are calculated. Returns 0.0 if all calculated values are equal.
+/// NaN values are returned as is and are not used to estimate the range.
+///
+/// `T` - type of a point in the search space for goal function.
+pub struct GoalNormalized<'a, T> {
+    goal: Box<dyn Goal<T> + 'a>,
+    min_samples: usize,
+    samples_count: usize,
+    min_value: GoalValue,
+    max_value: GoalValue,
+}
+
+impl<'a, T> GoalNormalized<'a, T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the wrapped goal function.
+    /// * `min_samples` - the calculated values count before the scaling is started.
+    pub fn new(goal: Box<dyn Goal<T> + 'a>, min_samples: usize) -> Self {
+        Self {
+            goal,
+            min_samples,
+            samples_count: 0,
+            min_value: f64::INFINITY,
+            max_value: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Returns the minimal and maximal calculated values of the wrapped goal function or `None`
+    /// if the goal function was not calculated.
+    pub fn get_range(&self) -> Option<(GoalValue, GoalValue)> {
+        if self.samples_count == 0 {
+            None
+        } else {
+            Some((self.min_value, self.max_value))
+        }
+    }
+
+    /// Returns count of the calculated values (except NaN).
+    pub fn get_samples_count(&self) -> usize {
+        self.samples_count
+    }
+}
+
+impl<'a, T> Goal<T> for GoalNormalized<'a, T> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        let value = self.goal.get(x);
+        if value.is_nan() {
+            return value;
+        }
+
+        self.samples_count += 1;
+        self.min_value = self.min_value.min(value);
+        self.max_value = self.max_value.max(value);
+
+        if self.samples_count < self.min_samples {
+            value
+        } else if self.max_value == self.min_value {
+            0.0
+        } else {
+            (value - self.min_value) / (self.max_value - self.min_value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{Goal, GoalFromFunction};
+
+    /// Goal function which counts the calls.
+    struct CountingGoal<'a> {
+        call_count: &'a Cell<usize>,
+    }
+
+    impl<'a> Goal<Vec<i32>> for CountingGoal<'a> {
+        fn get(&mut self, x: &Vec<i32>) -> GoalValue {
+            self.call_count.set(self.call_count.get() + 1);
+            x.iter().map(|item| (item * item) as f64).sum()
+        }
+    }
+
+    #[test]
+    fn cached_empty() {
+        let call_count = Cell::new(0);
+        let goal = GoalCached::new(Box::new(CountingGoal { call_count: &call_count }), 10);
+
+        assert_eq!(goal.get_hits(), 0);
+        assert_eq!(goal.get_misses(), 0);
+        assert_eq!(goal.get_hit_rate(), 0.0);
+        assert_eq!(goal.get_cache_size(), 0);
+    }
+
+    #[test]
+    fn cached_hit() {
+        let call_count = Cell::new(0);
+        let mut goal = GoalCached::new(Box::new(CountingGoal { call_count: &call_count }), 10);
+
+        assert_eq!(goal.get(&vec![1, 2]), 5.0);
+        assert_eq!(goal.get(&vec![1, 2]), 5.0);
+        assert_eq!(goal.get(&vec![3]), 9.0);
+        assert_eq!(goal.get(&vec![1, 2]), 5.0);
+
+        assert_eq!(call_count.get(), 2);
+        assert_eq!(goal.get_hits(), 2);
+        assert_eq!(goal.get_misses(), 2);
+        assert_eq!(goal.get_hit_rate(), 0.5);
+        assert_eq!(goal.get_cache_size(), 2);
+    }
+
+    #[test]
+    fn cached_lru_eviction() {
+        let call_count = Cell::new(0);
+        let mut goal = GoalCached::new(Box::new(CountingGoal { call_count: &call_count }), 2);
+
+        goal.get(&vec![1]);
+        goal.get(&vec![2]);
+
+        // [1] is used recently, so [2] must be removed
+        goal.get(&vec![1]);
+        goal.get(&vec![3]);
+        assert_eq!(goal.get_cache_size(), 2);
+        assert_eq!(call_count.get(), 3);
+
+        goal.get(&vec![1]);
+        assert_eq!(call_count.get(), 3);
+
+        goal.get(&vec![2]);
+        assert_eq!(call_count.get(), 4);
+        assert_eq!(goal.get_cache_size(), 2);
+    }
+
+    #[test]
+    fn cached_clear() {
+        let call_count = Cell::new(0);
+        let mut goal = GoalCached::new(Box::new(CountingGoal { call_count: &call_count }), 10);
+
+        goal.get(&vec![1]);
+        goal.get(&vec![1]);
+        goal.clear();
+
+        assert_eq!(goal.get_hits(), 0);
+        assert_eq!(goal.get_misses(), 0);
+        assert_eq!(goal.get_cache_size(), 0);
+
+        goal.get(&vec![1]);
+        assert_eq!(call_count.get(), 2);
+    }
+
+    #[test]
+    fn cached_by_reference() {
+        let call_count = Cell::new(0);
+        let mut counting_goal = CountingGoal { call_count: &call_count };
+        let mut goal = GoalCached::new(Box::new(&mut counting_goal), 10);
+
+        goal.get(&vec![1]);
+        goal.get(&vec![1]);
+        assert_eq!(call_count.get(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cached_zero_capacity() {
+        let call_count = Cell::new(0);
+        GoalCached::new(Box::new(CountingGoal { call_count: &call_count }), 0);
+    }
+
+    fn sum(x: &Vec<f64>) -> GoalValue {
+        x.iter().sum()
+    }
+
+    #[test]
+    fn penalty_without_constraints() {
+        let mut goal = GoalWithPenalty::new(Box::new(GoalFromFunction::new(sum)), 10.0);
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn penalty_inequality() {
+        // x[0] <= 1
+        let mut goal = GoalWithPenalty::with_inequalities(
+            Box::new(GoalFromFunction::new(sum)),
+            vec![Box::new(|x: &Vec<f64>| x[0] - 1.0)],
+            10.0,
+        );
+
+        assert_eq!(goal.get(&vec![0.5, 2.0]), 2.5);
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+        assert_eq!(goal.get(&vec![3.0, 2.0]), 5.0 + 10.0 * 4.0);
+    }
+
+    #[test]
+    fn penalty_equality() {
+        // x[0] = 1
+        let mut goal = GoalWithPenalty::with_equalities(
+            Box::new(GoalFromFunction::new(sum)),
+            vec![Box::new(|x: &Vec<f64>| x[0] - 1.0)],
+            10.0,
+        );
+
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+        assert_eq!(goal.get(&vec![3.0, 2.0]), 5.0 + 10.0 * 4.0);
+        assert_eq!(goal.get(&vec![-1.0, 2.0]), 1.0 + 10.0 * 4.0);
+    }
+
+    #[test]
+    fn penalty_several_constraints() {
+        let mut goal = GoalWithPenalty::new(Box::new(GoalFromFunction::new(sum)), 2.0);
+        goal.add_inequality(Box::new(|x: &Vec<f64>| x[0] - 1.0));
+        goal.add_equality(Box::new(|x: &Vec<f64>| x[1]));
+
+        assert_eq!(goal.get_penalty(&vec![0.0, 0.0]), 0.0);
+        assert_eq!(goal.get_penalty(&vec![3.0, 1.0]), 5.0);
+        assert_eq!(goal.get(&vec![3.0, 1.0]), 4.0 + 2.0 * 5.0);
+    }
+
+    #[test]
+    fn penalty_adaptive() {
+        let mut goal = GoalWithPenalty::new(Box::new(GoalFromFunction::new(sum)), 1.0);
+        goal.set_adaptive(2.0, 2);
+
+        goal.get(&vec![1.0]);
+        assert_eq!(goal.get_penalty_coefficient(), 1.0);
+
+        goal.get(&vec![1.0]);
+        assert_eq!(goal.get_penalty_coefficient(), 2.0);
+
+        goal.get(&vec![1.0]);
+        goal.get(&vec![1.0]);
+        assert_eq!(goal.get_penalty_coefficient(), 4.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn penalty_invalid_coefficient() {
+        GoalWithPenalty::new(Box::new(GoalFromFunction::new(sum)), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn penalty_invalid_growth_factor() {
+        let mut goal = GoalWithPenalty::new(Box::new(GoalFromFunction::new(sum)), 1.0);
+        goal.set_adaptive(0.5, 10);
+    }
+
+    #[test]
+    fn noise_zero_sigma() {
+        let mut goal = GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), 0.0);
+        assert_eq!(goal.get(&vec![1.0, 2.0]), 3.0);
+    }
+
+    #[test]
+    fn noise_statistics() {
+        let run_count = 10000;
+        let sigma = 2.0;
+        let mut goal = GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), sigma);
+        goal.set_seed(42);
+
+        let values: Vec<GoalValue> = (0..run_count).map(|_| goal.get(&vec![1.0, 2.0])).collect();
+        let mean = values.iter().sum::<f64>() / run_count as f64;
+        let variance =
+            values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (run_count - 1) as f64;
+
+        assert!((mean - 3.0).abs() < 0.1);
+        assert!((variance.sqrt() - sigma).abs() < 0.1);
+    }
+
+    #[test]
+    fn noise_seed() {
+        let mut goal_1 = GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), 1.0);
+        let mut goal_2 = GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), 1.0);
+        goal_1.set_seed(42);
+        goal_2.set_seed(42);
+
+        for _ in 0..10 {
+            assert_eq!(goal_1.get(&vec![1.0]), goal_2.get(&vec![1.0]));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn noise_invalid_sigma() {
+        GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), -1.0);
+    }
+
+    #[test]
+    fn averaged_samples() {
+        let call_count = Cell::new(0);
+        let mut goal =
+            GoalAveragedSamples::new(Box::new(CountingGoal { call_count: &call_count }), 5);
+
+        assert_eq!(goal.get(&vec![1, 2]), 5.0);
+        assert_eq!(call_count.get(), 5);
+    }
+
+    #[test]
+    fn averaged_samples_noise() {
+        let mut noisy_goal = GoalWithNoise::new(Box::new(GoalFromFunction::new(sum)), 1.0);
+        noisy_goal.set_seed(42);
+        let mut goal = GoalAveragedSamples::new(Box::new(noisy_goal), 10000);
+
+        assert!((goal.get(&vec![1.0, 2.0]) - 3.0).abs() < 0.05);
+    }
+
+    #[test]
+    #[should_panic]
+    fn averaged_samples_zero_count() {
+        GoalAveragedSamples::new(Box::new(GoalFromFunction::new(sum)), 0);
+    }
+
+    #[test]
+    fn normalized_cold_start() {
+        let mut goal = GoalNormalized::new(Box::new(GoalFromFunction::new(sum)), 3);
+        assert_eq!(goal.get_range(), None);
+
+        assert_eq!(goal.get(&vec![10.0]), 10.0);
+        assert_eq!(goal.get(&vec![20.0]), 20.0);
+        assert_eq!(goal.get_range(), Some((10.0, 20.0)));
+        assert_eq!(goal.get_samples_count(), 2);
+
+        assert_eq!(goal.get(&vec![15.0]), 0.5);
+    }
+
+    #[test]
+    fn normalized_range() {
+        let mut goal = GoalNormalized::new(Box::new(GoalFromFunction::new(sum)), 1);
+
+        assert_eq!(goal.get(&vec![10.0]), 0.0);
+        assert_eq!(goal.get(&vec![10.0]), 0.0);
+        assert_eq!(goal.get(&vec![20.0]), 1.0);
+        assert_eq!(goal.get(&vec![0.0]), 0.0);
+        assert_eq!(goal.get(&vec![15.0]), 0.75);
+        assert_eq!(goal.get_range(), Some((0.0, 20.0)));
+    }
+
+    #[test]
+    fn normalized_nan() {
+        let mut goal = GoalNormalized::new(Box::new(GoalFromFunction::new(sum)), 1);
+
+        goal.get(&vec![0.0]);
+        assert!(goal.get(&vec![f64::NAN]).is_nan());
+        assert_eq!(goal.get(&vec![10.0]), 1.0);
+        assert_eq!(goal.get_samples_count(), 2);
+    }
+}
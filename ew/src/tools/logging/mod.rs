@@ -10,7 +10,7 @@ use crate::AlgorithmState;
 ///
 /// `T` - type of a point in the search space for goal function.
 pub trait Logger<T> {
-    /// Will be called after algorithm initializing.
+    /// Will be called after algorithm initializing (before the first iteration).
     fn start(&mut self, _state: &dyn AlgorithmState<T>) {}
 
     /// Will be called before run algorithm (possibly after result algorithm after pause).
@@ -19,7 +19,9 @@ pub trait Logger<T> {
     /// Will be called in the end of iteration.
     fn next_iteration(&mut self, _state: &dyn AlgorithmState<T>) {}
 
-    /// Will be called when algorithm will be stopped.
+    /// Will be called when algorithm will be stopped (after the last iteration).
+    /// The result of the algorithm is available by `state.get_best_solution()`.
+    /// The loggers which write to `io::Write` flush the writer in the method.
     fn finish(&mut self, _state: &dyn AlgorithmState<T>) {}
 
     // fn as_any(&self) -> &dyn Any;
@@ -55,6 +57,73 @@ impl<'a, T: Display> Logger<Vec<T>> for VerboseLogger<'a> {
             writeln!(&mut self.writer, "{}", result).unwrap();
         }
     }
+
+    fn finish(&mut self, _state: &dyn AlgorithmState<Vec<T>>) {
+        self.writer.flush().unwrap();
+    }
+}
+
+/// The logger prints out current solution and goal function only if the value of goal function
+/// for the best solution improved by more than `delta` since the last printed line
+/// (decreased after `find_min` and increased after `find_max`).
+/// The first found solution is always printed out.
+pub struct BestImprovementLogger<'a> {
+    writer: &'a mut dyn io::Write,
+    precision: usize,
+    delta: f64,
+    /// The last printed goal value converted for minimization.
+    last_goal: Option<f64>,
+}
+
+impl<'a> BestImprovementLogger<'a> {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// * `precision` - count of the digits after comma for float numbers.
+    /// * `delta` - minimal improvement of the goal function to print out new line.
+    ///   If `delta` is 0, then any improvement is printed out.
+    pub fn new(writer: &'a mut dyn io::Write, precision: usize, delta: f64) -> Self {
+        assert!(delta >= 0.0);
+        Self {
+            writer,
+            precision,
+            delta,
+            last_goal: None,
+        }
+    }
+}
+
+impl<'a, T: Display> Logger<Vec<T>> for BestImprovementLogger<'a> {
+    fn start(&mut self, _state: &dyn AlgorithmState<Vec<T>>) {
+        self.last_goal = None;
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<Vec<T>>) {
+        if let Some((solution, goal)) = state.get_best_solution() {
+            let minimized_goal = state.get_direction().apply(goal);
+            if let Some(last_goal) = self.last_goal {
+                if last_goal - minimized_goal <= self.delta {
+                    return;
+                }
+            }
+
+            self.last_goal = Some(minimized_goal);
+
+            let mut result = String::new();
+            result = result + &format!("{:<8}", state.get_iteration());
+
+            for x in solution {
+                result = result + &format!("  {:<20.*}", self.precision, x);
+            }
+            result = result + &format!("  {:20.*}", self.precision, goal);
+
+            writeln!(&mut self.writer, "{}", result).unwrap();
+        }
+    }
+
+    fn finish(&mut self, _state: &dyn AlgorithmState<Vec<T>>) {
+        self.writer.flush().unwrap();
+    }
 }
 
 /// The logger print out to stdout best result and value of goal function after end of genetic algorithm running.
@@ -98,6 +167,7 @@ impl<'a, T: Display> Logger<Vec<T>> for ResultOnlyLogger<'a> {
             state.get_iteration()
         )
         .unwrap();
+        self.writer.flush().unwrap();
     }
 }
 
@@ -127,5 +197,431 @@ impl<'a, T: Display> Logger<Vec<T>> for TimeLogger<'a> {
         let time_ms = duration.as_secs() * 1000 + duration.subsec_millis() as u64;
 
         writeln!(&mut self.writer, "Time elapsed: {} ms", time_ms).unwrap();
+        self.writer.flush().unwrap();
+    }
+}
+
+/// The logger calls the function (closure) in the end of every iteration.
+///
+/// The function gets the iteration number and the current best solution
+/// (point in the search space and value of the goal function) if it exists.
+pub struct FnLogger<'a, T> {
+    function: Box<LogFunction<'a, T>>,
+}
+
+type LogFunction<'a, T> = dyn FnMut(usize, Option<(&T, f64)>) + 'a;
+
+impl<'a, T> FnLogger<'a, T> {
+    /// Constructor
+    pub fn new(function: impl FnMut(usize, Option<(&T, f64)>) + 'a) -> Self {
+        Self {
+            function: Box::new(function),
+        }
+    }
+}
+
+impl<'a, T> Logger<T> for FnLogger<'a, T> {
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        let best_solution = state.get_best_solution();
+        let best_solution = best_solution
+            .as_ref()
+            .map(|(solution, goal)| (solution, *goal));
+
+        (self.function)(state.get_iteration(), best_solution);
+    }
+}
+
+/// The logger passes all calls to the inner loggers.
+/// The logger is useful to use several loggers as single one.
+pub struct TeeLogger<'a, T> {
+    loggers: Vec<Box<dyn Logger<T> + 'a>>,
+}
+
+impl<'a, T> TeeLogger<'a, T> {
+    /// Constructor
+    pub fn new(loggers: Vec<Box<dyn Logger<T> + 'a>>) -> Self {
+        Self { loggers }
+    }
+}
+
+impl<'a, T> Logger<T> for TeeLogger<'a, T> {
+    fn start(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.start(state);
+        }
+    }
+
+    fn resume(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.resume(state);
+        }
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.next_iteration(state);
+        }
+    }
+
+    fn finish(&mut self, state: &dyn AlgorithmState<T>) {
+        for logger in &mut self.loggers {
+            logger.finish(state);
+        }
+    }
+}
+
+/// The logger writes one CSV row per iteration: iteration number, value of the goal function for
+/// the best solution and time elapsed from the algorithm start in milliseconds.
+/// The header row is written before the first row.
+/// The value of the goal function is "N/A" if the best solution is not found yet.
+pub struct CsvLogger<'a> {
+    writer: &'a mut dyn io::Write,
+    start_time: Option<time::Instant>,
+    header_written: bool,
+}
+
+impl<'a> CsvLogger<'a> {
+    /// Constructor
+    pub fn new(writer: &'a mut dyn io::Write) -> Self {
+        Self {
+            writer,
+            start_time: None,
+            header_written: false,
+        }
+    }
+}
+
+impl<'a, T> Logger<T> for CsvLogger<'a> {
+    fn start(&mut self, _state: &dyn AlgorithmState<T>) {
+        self.start_time = Some(time::Instant::now());
+    }
+
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        if !self.header_written {
+            writeln!(&mut self.writer, "iteration,goal_value,elapsed_ms").unwrap();
+            self.header_written = true;
+        }
+
+        let start_time = *self.start_time.get_or_insert_with(time::Instant::now);
+        let elapsed_ms = start_time.elapsed().as_millis();
+
+        let goal = match state.get_best_solution() {
+            Some((_, goal)) => goal.to_string(),
+            None => "N/A".to_string(),
+        };
+
+        writeln!(
+            &mut self.writer,
+            "{},{},{}",
+            state.get_iteration(),
+            goal,
+            elapsed_ms
+        )
+        .unwrap();
+    }
+
+    fn finish(&mut self, _state: &dyn AlgorithmState<T>) {
+        self.writer.flush().unwrap();
+    }
+}
+
+/// The logger writes one JSON object per line (NDJSON format) in the end of every iteration.
+/// The object contains iteration number ("iteration"), value of the goal function for the best
+/// solution ("goal"), the best solution ("solution") and the Unix time in milliseconds
+/// ("timestamp_ms"). "goal" and "solution" are null if the best solution is not found yet.
+///
+/// The logger is available with the "json" feature.
+#[cfg(feature = "json")]
+pub struct JsonLogger<'a> {
+    writer: &'a mut dyn io::Write,
+}
+
+#[cfg(feature = "json")]
+impl<'a> JsonLogger<'a> {
+    /// Constructor
+    pub fn new(writer: &'a mut dyn io::Write) -> Self {
+        Self { writer }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, T: serde::Serialize> Logger<T> for JsonLogger<'a> {
+    fn next_iteration(&mut self, state: &dyn AlgorithmState<T>) {
+        let timestamp_ms = time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+
+        let (solution, goal) = match state.get_best_solution() {
+            Some((solution, goal)) => (Some(solution), Some(goal)),
+            None => (None, None),
+        };
+
+        let record = serde_json::json!({
+            "iteration": state.get_iteration(),
+            "goal": goal,
+            "solution": solution,
+            "timestamp_ms": timestamp_ms as u64,
+        });
+
+        serde_json::to_writer(&mut self.writer, &record).unwrap();
+        writeln!(&mut self.writer).unwrap();
+    }
+
+    fn finish(&mut self, _state: &dyn AlgorithmState<T>) {
+        self.writer.flush().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Solution};
+
+    /// The algorithm state for tests.
+    struct TestState {
+        best_solution: Option<Solution<Vec<f64>>>,
+        iteration: usize,
+        direction: Direction,
+    }
+
+    impl TestState {
+        fn new(best_goal: Option<f64>, iteration: usize) -> Self {
+            Self {
+                best_solution: best_goal.map(|goal| (vec![1.0, 2.0], goal)),
+                iteration,
+                direction: Direction::Minimize,
+            }
+        }
+
+        fn new_max(best_goal: Option<f64>, iteration: usize) -> Self {
+            Self {
+                direction: Direction::Maximize,
+                ..Self::new(best_goal, iteration)
+            }
+        }
+    }
+
+    impl AlgorithmState<Vec<f64>> for TestState {
+        fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+            self.best_solution.clone()
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+
+        fn get_direction(&self) -> Direction {
+            self.direction
+        }
+    }
+
+    /// The writer counts the calls of `flush()`.
+    #[derive(Default)]
+    struct FlushCountWriter {
+        data: Vec<u8>,
+        flush_count: usize,
+    }
+
+    impl io::Write for FlushCountWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.data.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flush_count += 1;
+            Ok(())
+        }
+    }
+
+    /// Call all methods of the logger and return count of `flush()` calls
+    /// before the `finish()` call and after it.
+    fn get_flush_count(
+        create_logger: &dyn Fn(&mut FlushCountWriter) -> Box<dyn Logger<Vec<f64>> + '_>,
+    ) -> (usize, usize) {
+        let mut writer = FlushCountWriter::default();
+        let mut logger = create_logger(&mut writer);
+        logger.start(&TestState::new(None, 0));
+        logger.resume(&TestState::new(None, 0));
+        logger.next_iteration(&TestState::new(Some(5.0), 1));
+        logger.next_iteration(&TestState::new(Some(4.0), 2));
+        drop(logger);
+        let before_finish = writer.flush_count;
+
+        let mut logger = create_logger(&mut writer);
+        logger.resume(&TestState::new(None, 0));
+        logger.finish(&TestState::new(Some(4.0), 2));
+        drop(logger);
+
+        (before_finish, writer.flush_count)
+    }
+
+    #[test]
+    fn fn_logger() {
+        let mut calls = vec![];
+        {
+            let mut logger = FnLogger::new(|iteration, best: Option<(&Vec<f64>, f64)>| {
+                calls.push((iteration, best.map(|(solution, goal)| (solution.clone(), goal))));
+            });
+
+            logger.start(&TestState::new(None, 0));
+            logger.next_iteration(&TestState::new(None, 1));
+            logger.next_iteration(&TestState::new(Some(5.0), 2));
+            logger.finish(&TestState::new(Some(5.0), 2));
+        }
+
+        assert_eq!(calls, vec![(1, None), (2, Some((vec![1.0, 2.0], 5.0)))]);
+    }
+
+    #[test]
+    fn csv_logger() {
+        let mut output = vec![];
+        {
+            let mut logger = CsvLogger::new(&mut output);
+            logger.start(&TestState::new(None, 0));
+            logger.next_iteration(&TestState::new(None, 1));
+            logger.next_iteration(&TestState::new(Some(5.5), 2));
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "iteration,goal_value,elapsed_ms");
+        assert!(lines[1].starts_with("1,N/A,"));
+        assert!(lines[2].starts_with("2,5.5,"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_logger() {
+        let mut output = vec![];
+        {
+            let mut logger = JsonLogger::new(&mut output);
+            logger.next_iteration(&TestState::new(None, 1));
+            logger.next_iteration(&TestState::new(Some(5.5), 2));
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let records: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0]["iteration"], 1);
+        assert!(records[0]["goal"].is_null());
+        assert!(records[0]["solution"].is_null());
+        assert!(records[0]["timestamp_ms"].is_u64());
+
+        assert_eq!(records[1]["iteration"], 2);
+        assert_eq!(records[1]["goal"], 5.5);
+        assert_eq!(records[1]["solution"], serde_json::json!([1.0, 2.0]));
+    }
+
+    #[test]
+    fn best_improvement_logger() {
+        let mut output = vec![];
+        {
+            let mut logger = BestImprovementLogger::new(&mut output, 1, 0.5);
+            logger.start(&TestState::new(None, 0));
+            logger.next_iteration(&TestState::new(None, 1));
+            logger.next_iteration(&TestState::new(Some(10.0), 2));
+            logger.next_iteration(&TestState::new(Some(10.0), 3));
+            logger.next_iteration(&TestState::new(Some(9.6), 4));
+            logger.next_iteration(&TestState::new(Some(9.0), 5));
+            logger.next_iteration(&TestState::new(Some(11.0), 6));
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let iterations: Vec<&str> = output
+            .lines()
+            .map(|line| line.split_whitespace().next().unwrap())
+            .collect();
+        assert_eq!(iterations, vec!["2", "5"]);
+    }
+
+    #[test]
+    fn best_improvement_logger_maximize() {
+        let mut output = vec![];
+        {
+            let mut logger = BestImprovementLogger::new(&mut output, 1, 0.5);
+            logger.start(&TestState::new_max(None, 0));
+            logger.next_iteration(&TestState::new_max(None, 1));
+            logger.next_iteration(&TestState::new_max(Some(10.0), 2));
+            logger.next_iteration(&TestState::new_max(Some(10.0), 3));
+            logger.next_iteration(&TestState::new_max(Some(10.4), 4));
+            logger.next_iteration(&TestState::new_max(Some(11.0), 5));
+            logger.next_iteration(&TestState::new_max(Some(9.0), 6));
+            logger.next_iteration(&TestState::new_max(Some(12.0), 7));
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<Vec<&str>> = output
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        let iterations: Vec<&str> = lines.iter().map(|line| line[0]).collect();
+        let goals: Vec<&str> = lines.iter().map(|line| line[3]).collect();
+        assert_eq!(iterations, vec!["2", "5", "7"]);
+        assert_eq!(goals, vec!["10.0", "11.0", "12.0"]);
+    }
+
+    #[test]
+    fn tee_logger() {
+        let mut iterations_1 = vec![];
+        let mut iterations_2 = vec![];
+        let mut output = vec![];
+        {
+            let mut logger = TeeLogger::new(vec![
+                Box::new(FnLogger::new(|iteration, _: Option<(&Vec<f64>, f64)>| {
+                    iterations_1.push(iteration)
+                })),
+                Box::new(FnLogger::new(|iteration, _: Option<(&Vec<f64>, f64)>| {
+                    iterations_2.push(iteration)
+                })),
+                Box::new(CsvLogger::new(&mut output)),
+            ]);
+
+            logger.start(&TestState::new(None, 0));
+            logger.resume(&TestState::new(None, 0));
+            logger.next_iteration(&TestState::new(None, 1));
+            logger.next_iteration(&TestState::new(Some(5.0), 2));
+            logger.finish(&TestState::new(Some(5.0), 2));
+        }
+
+        assert_eq!(iterations_1, vec![1, 2]);
+        assert_eq!(iterations_2, vec![1, 2]);
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 3);
+    }
+
+    #[test]
+    fn loggers_flush_on_finish() {
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(VerboseLogger::new(writer, 2))),
+            (0, 1)
+        );
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(BestImprovementLogger::new(writer, 2, 0.0))),
+            (0, 1)
+        );
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(ResultOnlyLogger::new(writer, 2))),
+            (0, 1)
+        );
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(TimeLogger::new(writer))),
+            (0, 1)
+        );
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(CsvLogger::new(writer))),
+            (0, 1)
+        );
+
+        #[cfg(feature = "json")]
+        assert_eq!(
+            get_flush_count(&|writer| Box::new(JsonLogger::new(writer))),
+            (0, 1)
+        );
     }
 }
@@ -1,25 +1,97 @@
+#[cfg(feature = "checkpoint")]
+pub(crate) mod checkpoint;
+pub mod goal;
 pub mod logging;
 pub mod stopchecker;
 pub mod statistics;
 
+use std::cmp::Ordering;
+
 use num::NumCast;
 use rand::distributions::{Distribution, Uniform};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// Returns the random value from the standard normal distribution (Box-Muller transform).
+pub(crate) fn get_standard_normal(random: &mut StdRng) -> f64 {
+    let between = Uniform::new(0.0, 1.0);
+    let u1: f64 = 1.0 - between.sample(random);
+    let u2: f64 = between.sample(random);
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Compare the values of the goal function so that NaN is worse than any other value.
+pub(crate) fn compare_values(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+/// Returns the value of the goal function (or the objective) for the comparison by `<` and `>`.
+/// NaN is replaced by the infinity, so it is worse than any other value except the infinity.
+pub(crate) fn comparable_value(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::INFINITY
+    } else {
+        value
+    }
+}
+
+/// Create `count` points with the Latin hypercube sampling. Every interval is divided into
+/// `count` equal strata. Every stratum of every dimension contains exactly one point.
+/// The point inside the stratum is random.
+pub(crate) fn latin_hypercube<T: NumCast>(
+    intervals: &[(T, T)],
+    count: usize,
+    random: &mut StdRng,
+) -> Vec<Vec<T>> {
+    let mut points: Vec<Vec<T>> = (0..count)
+        .map(|_| Vec::with_capacity(intervals.len()))
+        .collect();
+
+    let between = Uniform::new(0.0, 1.0);
+    for (min, max) in intervals {
+        let min = min.to_f64().unwrap();
+        let max = max.to_f64().unwrap();
+        let stratum_width = (max - min) / count as f64;
+
+        let mut strata: Vec<usize> = (0..count).collect();
+        strata.shuffle(random);
+
+        for (point, stratum) in points.iter_mut().zip(strata) {
+            let offset: f64 = between.sample(random);
+            let value = min + (stratum as f64 + offset) * stratum_width;
+            point.push(T::from(value).unwrap());
+        }
+    }
+
+    points
+}
 
 /// Creator to initialize vector with random values in given interval.
 /// `T` - vector items type
 pub struct RandomVectorCreator {
-    random: ThreadRng,
+    random: StdRng,
 }
 
 impl RandomVectorCreator {
     /// Constructor.
     pub fn new() -> Self {
         Self {
-            random: rand::thread_rng(),
+            random: StdRng::from_entropy(),
         }
     }
 
+    /// Set seed for the random numbers generator to get reproducible results.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+    }
+
     pub fn create_vec<T: NumCast + PartialOrd>(&mut self, intervals: &Vec<(T, T)>) -> Vec<T> {
         for interval in intervals {
             assert!(interval.0 < interval.1);
@@ -39,7 +111,26 @@ impl RandomVectorCreator {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::RandomVectorCreator;
+    use std::cmp::Ordering;
+
+    use crate::tools::{comparable_value, compare_values, RandomVectorCreator};
+
+    #[test]
+    fn compare_nan() {
+        assert_eq!(compare_values(1.0, 2.0), Ordering::Less);
+        assert_eq!(compare_values(2.0, 1.0), Ordering::Greater);
+        assert_eq!(compare_values(1.0, 1.0), Ordering::Equal);
+        assert_eq!(compare_values(f64::NAN, 2.0), Ordering::Greater);
+        assert_eq!(compare_values(2.0, f64::NAN), Ordering::Less);
+        assert_eq!(compare_values(f64::NAN, f64::NAN), Ordering::Equal);
+    }
+
+    #[test]
+    fn comparable_nan() {
+        assert_eq!(comparable_value(1.0), 1.0);
+        assert_eq!(comparable_value(-1.0), -1.0);
+        assert_eq!(comparable_value(f64::NAN), f64::INFINITY);
+    }
 
     #[test]
     fn test_empty() {
@@ -69,6 +160,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seed() {
+        let intervals = vec![(0.0, 1.0), (-1.0, 1.0), (100.0, 110.0)];
+        let mut creator_1 = RandomVectorCreator::new();
+        let mut creator_2 = RandomVectorCreator::new();
+        creator_1.set_seed(42);
+        creator_2.set_seed(42);
+
+        for _ in 0..10 {
+            let result_1: Vec<f64> = creator_1.create_vec(&intervals);
+            let result_2: Vec<f64> = creator_2.create_vec(&intervals);
+            assert_eq!(result_1, result_2);
+        }
+    }
+
     #[test]
     #[should_panic]
     fn invalid_intervals_01() {
@@ -1,8 +1,19 @@
 //! The module with the loggers ready for using. The loggers implements the `Logger` trait.
 
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
 use num::Float;
 
-use crate::{tools::logging::Logger, AlgorithmState, Goal, GoalValue, Solution};
+use crate::{
+    tools::{comparable_value, compare_values, logging::Logger},
+    AlgorithmState, Direction, Goal, GoalValue, Optimizer, Solution,
+};
+
+#[cfg(feature = "parallel")]
+use crate::SyncGoal;
 
 /// convergence[run number][iteration]
 type Convergence<T> = Vec<Vec<Option<Solution<T>>>>;
@@ -10,22 +21,78 @@ type Convergence<T> = Vec<Vec<Option<Solution<T>>>>;
 /// The `Statistics` struct stores solutions for every algorithm running and every algorithm
 /// iteration.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statistics<T> {
     /// The member stores final solution for every running. The index of vector is running number.
     results: Vec<Option<Solution<T>>>,
 
     /// The member stores current result for every algorithm running and every iteration. convergence[run number][iteration]
+    /// The vector is empty for the runnings added without the convergence data.
     convergence: Convergence<T>,
+
+    /// The direction of the optimization for the last added running.
+    #[cfg_attr(feature = "serde", serde(default = "default_direction"))]
+    direction: Direction,
+}
+
+#[cfg(feature = "serde")]
+fn default_direction() -> Direction {
+    Direction::Minimize
 }
 
 /// The `CallCountData` struct used to store call count of goal function.
+/// The call count may be changed by shared reference, so the `CallCountData` may be used
+/// by `GoalCalcStatistics` and `FunctionCallBudget` stop checker simultaneously.
+/// The struct does not implement `Sync`, use `GoalCalcStatisticsArc` to count calls of the goal
+/// function in several threads.
 #[derive(Debug, Clone)]
-pub struct CallCountData(Vec<usize>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallCountData(RefCell<Vec<usize>>);
+
+/// The confidence interval for the average value of goal function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfidenceInterval {
+    /// Lower bound of the interval.
+    pub lower: f64,
+
+    /// Upper bound of the interval.
+    pub upper: f64,
+
+    /// Confidence level in the range (0, 1). For example, 0.95.
+    pub confidence: f64,
+}
 
 /// The struct to calculate call count of goal function.
+///
+/// The struct borrows the goal function mutably, so it can not implement `Clone`.
+/// Use `GoalCalcStatisticsArc` to clone the goal function together with the call counter.
 pub struct GoalCalcStatistics<'a, T> {
     goal: &'a mut dyn Goal<T>,
-    call_count: &'a mut CallCountData,
+    call_count: &'a CallCountData,
+}
+
+/// The thread-safe struct to calculate call count of goal function.
+/// The call count is stored in `Arc<AtomicUsize>`, so the clones of the struct share the counter
+/// and may be sent to several threads (for parallel calculation of goal function).
+/// The call count may be moved to `CallCountData` by the `transfer_to` method.
+///
+/// `G` - type of the goal function. The struct implements `Goal<T>` if `G: Goal<T>` and
+/// `SyncGoal<T>` (with the "parallel" feature) if `G: SyncGoal<T>`.
+#[derive(Clone)]
+pub struct GoalCalcStatisticsArc<G> {
+    goal: G,
+    call_count: Arc<AtomicUsize>,
+}
+
+/// The set of the non-dominated solutions for the multi-objective optimization. All objectives
+/// are minimized. The front may gather the solutions of several runnings of any optimizer.
+///
+/// `T` - type of a point in the search space for goal function.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParetoFront<T> {
+    solutions: Vec<(T, Vec<f64>)>,
 }
 
 /// The trait contains methods for calculate statistics for Convergance<T>
@@ -41,6 +108,16 @@ pub trait StatFunctionsConvergence {
     /// self[run number][iteration]
     fn get_average_convergence(&self) -> Vec<Option<GoalValue>>;
     fn get_min_iterations(&self) -> usize;
+
+    /// Calculate the best (minimal) goal function found up to the iteration number
+    /// for all runnings ("best-so-far" curve).
+    /// Returns vector with: index - iteration, value - Option<GoalValue>.
+    /// Value is None if Solution is None for all runnings up to the iteration.
+    /// Length of result is maximal iterations count for all running.
+    /// NaN values of goal function are ignored.
+    /// # Params
+    /// self[run number][iteration]
+    fn get_best_so_far_trajectory(&self) -> Vec<Option<GoalValue>>;
 }
 
 /// The trait contains methods for calculate goal function statistics for Vec<Option<Solution<T>>>
@@ -53,6 +130,19 @@ pub trait StatFunctionsGoal {
     /// Calculate a standard deviation of goal function.
     /// Returns None if length of `self` less 2 or `self` contains `None` only.
     fn get_standard_deviation_goal(&self) -> Option<GoalValue>;
+
+    /// Calculate a median of goal function.
+    /// Returns None if `self` is empty or `self` contains `None` only.
+    fn get_median_goal(&self) -> Option<GoalValue> {
+        self.get_percentile_goal(0.5)
+    }
+
+    /// Calculate a percentile of goal function with linear interpolation between the nearest values.
+    /// NaN values of goal function are ignored.
+    /// Returns None if `self` is empty or `self` contains `None` only.
+    /// # Params
+    /// `p` - the percentile in the range [0, 1].
+    fn get_percentile_goal(&self, p: f64) -> Option<GoalValue>;
 }
 
 /// The trait contains methods for calculate solution statistics for Vec<Option<Solution<T>>>
@@ -104,41 +194,43 @@ pub fn get_predicate_success_vec_solution<T: Float>(
 
 impl CallCountData {
     pub fn new() -> Self {
-        Self(vec![])
+        Self(RefCell::new(vec![]))
     }
 
     /// This method will be called before new optimization running
-    pub fn next_run(&mut self) {
-        self.0.push(0);
+    pub fn next_run(&self) {
+        self.0.borrow_mut().push(0);
     }
 
-    pub fn increment(&mut self) {
-        if self.0.len() == 0 {
-            self.0.push(0);
-        }
-        let index = self.0.len() - 1;
-
-        self.0[index] += 1;
+    pub fn increment(&self) {
+        self.add(1);
     }
 
-    pub fn add(&mut self, n: usize) {
-        if self.0.len() == 0 {
-            self.0.push(0);
+    pub fn add(&self, n: usize) {
+        let mut data = self.0.borrow_mut();
+        if data.len() == 0 {
+            data.push(0);
         }
-        let index = self.0.len() - 1;
+        let index = data.len() - 1;
 
-        self.0[index] += n;
+        data[index] += n;
     }
 
     /// Get call count for every running
     pub fn get_call_count(&self) -> Vec<usize> {
-        self.0.clone()
+        self.0.borrow().clone()
+    }
+
+    /// Get call count for current (last) running
+    pub fn get_current_call_count(&self) -> usize {
+        self.0.borrow().last().cloned().unwrap_or(0)
     }
 
     /// Get average call count for all runnings
     pub fn get_average_call_count(&self) -> Option<f64> {
-        let sum: usize = self.0.iter().sum();
-        let count = self.0.len();
+        let data = self.0.borrow();
+        let sum: usize = data.iter().sum();
+        let count = data.len();
         if count == 0 {
             None
         } else {
@@ -146,8 +238,73 @@ impl CallCountData {
         }
     }
 
-    pub fn unite(&mut self, mut other: Self) {
-        self.0.append(&mut other.0);
+    /// Get minimal call count for all runnings
+    pub fn get_min_call_count(&self) -> Option<usize> {
+        self.0.borrow().iter().min().cloned()
+    }
+
+    /// Get maximal call count for all runnings
+    pub fn get_max_call_count(&self) -> Option<usize> {
+        self.0.borrow().iter().max().cloned()
+    }
+
+    /// Calculate a percentile of call count for all runnings with linear interpolation
+    /// between the nearest values.
+    /// Returns None if there are no runnings.
+    /// # Params
+    /// `p` - the percentile in the range [0, 1].
+    pub fn get_percentile_call_count(&self, p: f64) -> Option<f64> {
+        assert!((0.0..=1.0).contains(&p));
+
+        let mut data = self.get_call_count();
+        if data.is_empty() {
+            return None;
+        }
+
+        data.sort_unstable();
+
+        let position = p * ((data.len() - 1) as f64);
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        let fraction = position - (lower as f64);
+
+        Some((data[lower] as f64) + ((data[upper] - data[lower]) as f64) * fraction)
+    }
+
+    /// Split the range between minimal and maximal call count into `bins` intervals
+    /// of equal width and count the runnings for every interval.
+    /// Returns vector with `bins` items: (lower bound of the interval, runnings count).
+    /// The interval includes the lower bound and excludes the lower bound of the next interval.
+    /// Returns empty vector if there are no runnings.
+    /// # Params
+    /// `bins` - the intervals count. Must be greater than 0.
+    pub fn get_call_count_histogram(&self, bins: usize) -> Vec<(usize, usize)> {
+        assert!(bins > 0);
+
+        let data = self.0.borrow();
+        let (min, max) = match (data.iter().min(), data.iter().max()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => return vec![],
+        };
+
+        let range = max - min + 1;
+        let width = if range % bins == 0 {
+            range / bins
+        } else {
+            range / bins + 1
+        };
+
+        let mut histogram: Vec<(usize, usize)> = (0..bins).map(|i| (min + i * width, 0)).collect();
+        for call_count in data.iter() {
+            let index = (call_count - min) / width;
+            histogram[index].1 += 1;
+        }
+
+        histogram
+    }
+
+    pub fn unite(&mut self, other: Self) {
+        self.0.get_mut().append(&mut other.0.into_inner());
     }
 }
 
@@ -156,6 +313,7 @@ impl<T: Clone> Statistics<T> {
         Self {
             results: vec![],
             convergence: vec![],
+            direction: Direction::Minimize,
         }
     }
 
@@ -171,8 +329,101 @@ impl<T: Clone> Statistics<T> {
         &self.convergence
     }
 
+    /// Returns the direction of the optimization: `Direction::Maximize` if the runnings
+    /// were gathered after `find_max` and `Direction::Minimize` otherwise.
+    pub fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the best solution for all runnings: the solution with minimal value of goal
+    /// function after `find_min` and with maximal value after `find_max`.
+    /// Returns None if there are no found solutions. NaN values of goal function are ignored.
+    pub fn get_best_result(&self) -> Option<(&T, GoalValue)> {
+        let direction = self.direction;
+        self.get_valid_results().min_by(|(_, goal_1), (_, goal_2)| {
+            compare_values(direction.apply(*goal_1), direction.apply(*goal_2))
+        })
+    }
+
+    /// Returns the worst solution for all runnings: the solution with maximal value of goal
+    /// function after `find_min` and with minimal value after `find_max`.
+    /// Returns None if there are no found solutions. NaN values of goal function are ignored.
+    pub fn get_worst_result(&self) -> Option<(&T, GoalValue)> {
+        let direction = self.direction;
+        self.get_valid_results().max_by(|(_, goal_1), (_, goal_2)| {
+            compare_values(direction.apply(*goal_1), direction.apply(*goal_2))
+        })
+    }
+
+    /// Returns the iteration number (starting from 0) at which the overall best solution
+    /// (see `get_best_result`) was first found.
+    /// If several runnings found the best solution, the smallest iteration number is returned.
+    /// Returns None if there are no found solutions or the convergence data for the runnings
+    /// with the best solution were not saved.
+    pub fn get_iteration_of_best(&self) -> Option<usize> {
+        let (_, best_goal) = self.get_best_result()?;
+
+        self.results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| matches!(result, Some((_, goal)) if *goal == best_goal))
+            .filter_map(|(n, _)| self.convergence.get(n))
+            .filter_map(|run_convergence| {
+                run_convergence
+                    .iter()
+                    .position(|solution| matches!(solution, Some((_, goal)) if *goal == best_goal))
+            })
+            .min()
+    }
+
+    /// Returns new `Statistics` with the runnings where the solution was found and
+    /// `predicate` returns true for the solution.
+    /// # Params
+    /// `predicate` - function must return true for success solution and false otherwise.
+    /// For example, the function may be created by `get_predicate_success_goal` or
+    /// `get_predicate_success_vec_solution`.
+    pub fn filter_successful_runs<P>(&self, predicate: P) -> Statistics<T>
+    where
+        P: Fn(&Solution<T>) -> bool,
+    {
+        let mut result = Statistics::new();
+        result.direction = self.direction;
+
+        for (n, run_result) in self.results.iter().enumerate() {
+            if let Some(solution) = run_result {
+                if predicate(solution) {
+                    result.results.push(run_result.clone());
+                    result
+                        .convergence
+                        .push(self.convergence.get(n).cloned().unwrap_or_default());
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns iterator over found solutions where value of goal function is not NaN.
+    fn get_valid_results(&self) -> impl Iterator<Item = (&T, GoalValue)> {
+        self.results
+            .iter()
+            .filter_map(|result| result.as_ref())
+            .filter(|(_, goal)| !goal.is_nan())
+            .map(|(solution, goal)| (solution, *goal))
+    }
+
     fn add_result(&mut self, state: &dyn AlgorithmState<T>) {
         self.results.push(state.get_best_solution().clone());
+        self.direction = state.get_direction();
+    }
+
+    /// Add the final result of a running without the convergence data.
+    /// The empty convergence is added to keep `results` and `convergence` for the same running
+    /// at the same index.
+    pub(crate) fn add_run_result(&mut self, result: Option<Solution<T>>, direction: Direction) {
+        self.results.push(result);
+        self.convergence.push(vec![]);
+        self.direction = direction;
     }
 
     fn add_convergence(&mut self, state: &dyn AlgorithmState<T>) {
@@ -180,7 +431,11 @@ impl<T: Clone> Statistics<T> {
         self.convergence[run_index].push(state.get_best_solution().clone());
     }
 
+    /// Append the runnings of `other`. The direction of `other` is used if `other` is not empty.
     pub fn unite(&mut self, mut other: Self) {
+        if !other.results.is_empty() {
+            self.direction = other.direction;
+        }
         self.results.append(&mut other.results);
         self.convergence.append(&mut other.convergence);
     }
@@ -219,6 +474,31 @@ impl<T> StatFunctionsConvergence for Convergence<T> {
         result
     }
 
+    fn get_best_so_far_trajectory(&self) -> Vec<Option<GoalValue>> {
+        let max_iterations = self.iter().map(|run| run.len()).max().unwrap_or(0);
+        let mut result = Vec::with_capacity(max_iterations);
+        let mut best_goal: Option<GoalValue> = None;
+
+        for i in 0..max_iterations {
+            for run in self.iter().filter(|run| run.len() > i) {
+                if let Some((_, goal)) = &run[i] {
+                    let is_better = match best_goal {
+                        Some(best) => *goal < best,
+                        None => !goal.is_nan(),
+                    };
+
+                    if is_better {
+                        best_goal = Some(*goal);
+                    }
+                }
+            }
+
+            result.push(best_goal);
+        }
+
+        result
+    }
+
     fn get_min_iterations(&self) -> usize {
         if self.is_empty() {
             0
@@ -269,6 +549,30 @@ impl<T> StatFunctionsGoal for Vec<Option<Solution<T>>> {
             Some((sum / ((count - 1) as GoalValue)).sqrt())
         }
     }
+
+    fn get_percentile_goal(&self, p: f64) -> Option<GoalValue> {
+        assert!((0.0..=1.0).contains(&p));
+
+        let mut goals: Vec<GoalValue> = self
+            .iter()
+            .filter_map(|x| x.as_ref())
+            .map(|(_, goal)| *goal)
+            .filter(|goal| !goal.is_nan())
+            .collect();
+
+        if goals.is_empty() {
+            return None;
+        }
+
+        goals.sort_by(|goal_1, goal_2| goal_1.partial_cmp(goal_2).unwrap());
+
+        let position = p * ((goals.len() - 1) as f64);
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        let fraction = position - (lower as f64);
+
+        Some(goals[lower] + (goals[upper] - goals[lower]) * fraction)
+    }
 }
 
 impl<T: Float + std::fmt::Debug> StatFunctionsSolution<Vec<T>> for Vec<Option<Solution<Vec<T>>>> {
@@ -373,6 +677,88 @@ impl<T: Float + std::fmt::Debug> StatFunctionsSolution<Vec<T>> for Vec<Option<So
     }
 }
 
+/// Calculate the confidence interval for the average value of goal function for all runnings
+/// with normal approximation.
+/// Returns None if count of the found solutions less 2.
+/// # Params
+/// `confidence` - confidence level in the range (0, 1). For example, 0.95.
+pub fn goal_confidence_interval<T: Clone>(
+    stat: &Statistics<T>,
+    confidence: f64,
+) -> Option<ConfidenceInterval> {
+    assert!(confidence > 0.0 && confidence < 1.0);
+
+    let results = stat.get_results();
+    let count = results.iter().filter(|x| x.is_some()).count();
+
+    match (results.get_average_goal(), results.get_standard_deviation_goal()) {
+        (Some(average), Some(deviation)) if count >= 2 => {
+            let z = inverse_normal_cdf((1.0 + confidence) / 2.0);
+            let half_width = z * deviation / (count as f64).sqrt();
+            Some(ConfidenceInterval {
+                lower: average - half_width,
+                upper: average + half_width,
+                confidence,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Calculate the quantile function of the standard normal distribution.
+/// The Acklam's algorithm is used (relative error is less 1.15e-9).
+fn inverse_normal_cdf(p: f64) -> f64 {
+    assert!(p > 0.0 && p < 1.0);
+
+    let a = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    let b = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    let c = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    let d = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+
+    let p_low = 0.02425;
+    let p_high = 1.0 - p_low;
+
+    if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    } else if p <= p_high {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q
+            / (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    }
+}
+
 fn vectorize<T>(v1: &Vec<T>, v2: &Vec<T>, func: fn(&T, &T) -> T) -> Vec<T> {
     assert_eq!(v1.len(), v2.len());
     v1.iter().zip(v2.iter()).map(|(x, y)| func(x, y)).collect()
@@ -409,7 +795,7 @@ impl<'a, T: Clone> Logger<T> for StatisticsLogger<'a, T> {
 }
 
 impl<'a, T> GoalCalcStatistics<'a, T> {
-    pub fn new(goal: &'a mut dyn Goal<T>, call_count: &'a mut CallCountData) -> Self {
+    pub fn new(goal: &'a mut dyn Goal<T>, call_count: &'a CallCountData) -> Self {
         Self { goal, call_count }
     }
 }
@@ -421,9 +807,271 @@ impl<'a, T> Goal<T> for GoalCalcStatistics<'a, T> {
     }
 }
 
+impl<G> GoalCalcStatisticsArc<G> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `goal` - the goal function to calculate call count.
+    pub fn new(goal: G) -> Self {
+        Self {
+            goal,
+            call_count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Get call count of goal function for the struct and all its clones.
+    pub fn get_call_count(&self) -> usize {
+        self.call_count.load(Ordering::SeqCst)
+    }
+
+    /// Get the shared call counter.
+    pub fn get_counter(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.call_count)
+    }
+
+    /// Clone the goal function with new call counter, which is not shared with the struct.
+    /// The call count for the new struct is zero.
+    pub fn clone_with_new_counter(&self) -> Self
+    where
+        G: Clone,
+    {
+        Self::new(self.goal.clone())
+    }
+
+    /// Add the call count to current (last) running of `call_count` and reset the counter to zero,
+    /// so the struct may be used for the next running.
+    pub fn transfer_to(&self, call_count: &CallCountData) {
+        call_count.add(self.call_count.swap(0, Ordering::SeqCst));
+    }
+}
+
+impl<T, G: Goal<T>> Goal<T> for GoalCalcStatisticsArc<G> {
+    fn get(&mut self, x: &T) -> GoalValue {
+        self.call_count.fetch_add(1, Ordering::SeqCst);
+        self.goal.get(x)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T, G: SyncGoal<T>> SyncGoal<T> for GoalCalcStatisticsArc<G> {
+    fn get(&self, x: &T) -> GoalValue {
+        self.call_count.fetch_add(1, Ordering::SeqCst);
+        self.goal.get(x)
+    }
+}
+
+/// Returns true if the objectives `a` dominate the objectives `b` (for minimization).
+/// NaN values are worse than any other values.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    assert_eq!(a.len(), b.len());
+
+    let mut better = false;
+    for (&a_i, &b_i) in a.iter().zip(b.iter()) {
+        let a_i = comparable_value(a_i);
+        let b_i = comparable_value(b_i);
+        if a_i > b_i {
+            return false;
+        }
+
+        if a_i < b_i {
+            better = true;
+        }
+    }
+
+    better
+}
+
+impl<T> ParetoFront<T> {
+    /// Create the empty Pareto front.
+    pub fn new() -> Self {
+        Self { solutions: vec![] }
+    }
+
+    /// Add the solution if it is not dominated by the solutions of the front. The solutions
+    /// which are dominated by the new solution are removed. Returns true if the solution was
+    /// added.
+    pub fn insert(&mut self, solution: T, objectives: Vec<f64>) -> bool {
+        if self
+            .solutions
+            .iter()
+            .any(|(_, front_objectives)| dominates(front_objectives, &objectives))
+        {
+            return false;
+        }
+
+        self.solutions
+            .retain(|(_, front_objectives)| !dominates(&objectives, front_objectives));
+        self.solutions.push((solution, objectives));
+        true
+    }
+
+    /// Returns the solutions and their objectives.
+    pub fn solutions(&self) -> &[(T, Vec<f64>)] {
+        &self.solutions
+    }
+
+    /// Returns the solutions and their objectives, the front is consumed.
+    pub fn into_solutions(self) -> Vec<(T, Vec<f64>)> {
+        self.solutions
+    }
+
+    /// Returns the hypervolume of the front: the volume of the objective space which is
+    /// dominated by the solutions of the front and bounded by the reference point.
+    /// The solutions which are not less than the reference point in all objectives
+    /// (and the solutions with NaN objectives) do not contribute to the hypervolume.
+    ///
+    /// The hypervolume is calculated by the slicing along the objectives, the complexity is
+    /// O(n^d) for n solutions and d objectives.
+    ///
+    /// # Parameters
+    /// * `reference_point` - the point which is worse than the solutions in all objectives.
+    ///   The length must be equal to the count of objectives.
+    pub fn hypervolume(&self, reference_point: &[f64]) -> f64 {
+        assert!(!reference_point.is_empty());
+
+        let points: Vec<&[f64]> = self
+            .solutions
+            .iter()
+            .map(|(_, objectives)| {
+                assert_eq!(objectives.len(), reference_point.len());
+                objectives.as_slice()
+            })
+            .filter(|objectives| {
+                objectives
+                    .iter()
+                    .zip(reference_point.iter())
+                    .all(|(x, reference)| x < reference)
+            })
+            .collect();
+
+        get_hypervolume(points, reference_point)
+    }
+
+    /// Returns count of the solutions.
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Returns true if the front does not contain solutions.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+impl<T> Default for ParetoFront<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the hypervolume of the `points` by the slicing along the last objective.
+/// All points must be less than the reference point in all objectives.
+fn get_hypervolume(mut points: Vec<&[f64]>, reference_point: &[f64]) -> f64 {
+    let dimension = reference_point.len();
+    if points.is_empty() {
+        return 0.0;
+    }
+
+    if dimension == 1 {
+        let min = points
+            .iter()
+            .map(|point| point[0])
+            .fold(f64::INFINITY, f64::min);
+        return reference_point[0] - min;
+    }
+
+    let last = dimension - 1;
+    points.sort_by(|a, b| a[last].partial_cmp(&b[last]).unwrap());
+
+    let mut volume = 0.0;
+    for (n, point) in points.iter().enumerate() {
+        let upper = match points.get(n + 1) {
+            Some(next_point) => next_point[last],
+            None => reference_point[last],
+        };
+
+        let height = upper - point[last];
+        if height > 0.0 {
+            // The projection of the points below the slice
+            let slice: Vec<&[f64]> = points[..=n].iter().map(|point| &point[..last]).collect();
+            volume += height * get_hypervolume(slice, &reference_point[..last]);
+        }
+    }
+
+    volume
+}
+
+/// Run the optimization `run_count` times in `threads_count` threads and gather statistics.
+///
+/// Returns the statistics and the goal function call count for all runnings.
+/// # Params
+/// `create_goal` - function to create goal function. The function is called once for every thread.
+/// `create_optimizer` - function to create optimizer for every running. The function gets
+/// the goal function (which calculates call count) and the logger to gather statistics.
+/// The optimizer must use both.
+pub fn run_parallel<T, G, F>(
+    create_goal: G,
+    create_optimizer: F,
+    run_count: usize,
+    threads_count: usize,
+) -> (Statistics<T>, CallCountData)
+where
+    T: Clone + Send,
+    G: Fn() -> Box<dyn Goal<T>> + Sync,
+    F: for<'a> Fn(Box<dyn Goal<T> + 'a>, Box<dyn Logger<T> + 'a>) -> Box<dyn Optimizer<T> + 'a>
+        + Sync,
+{
+    assert!(threads_count > 0);
+
+    let mut full_statistics = Statistics::new();
+    let mut full_call_count = CallCountData::new();
+
+    let threads_results = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads_count)
+            .map(|n| {
+                // Distribute runnings between threads as evenly as possible
+                let thread_run_count =
+                    run_count / threads_count + usize::from(n < run_count % threads_count);
+                let create_goal = &create_goal;
+                let create_optimizer = &create_optimizer;
+
+                scope.spawn(move || {
+                    let mut statistics = Statistics::new();
+                    let call_count = CallCountData::new();
+                    let mut goal_object = create_goal();
+
+                    for _ in 0..thread_run_count {
+                        call_count.next_run();
+
+                        let goal = GoalCalcStatistics::new(goal_object.as_mut(), &call_count);
+                        let logger = StatisticsLogger::new(&mut statistics);
+                        let mut optimizer = create_optimizer(Box::new(goal), Box::new(logger));
+                        optimizer.find_min();
+                    }
+
+                    (statistics, call_count)
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    for (statistics, call_count) in threads_results {
+        full_statistics.unite(statistics);
+        full_call_count.unite(call_count);
+    }
+
+    (full_statistics, full_call_count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::GoalFromFunction;
 
     #[test]
     fn get_min_iterations_empty() {
@@ -477,6 +1125,57 @@ mod tests {
         assert_eq!(convergence.get_min_iterations(), 0);
     }
 
+    #[test]
+    fn get_best_so_far_trajectory_empty() {
+        let convergence: Convergence<f32> = vec![];
+        assert_eq!(convergence.get_best_so_far_trajectory(), vec![]);
+    }
+
+    #[test]
+    fn get_best_so_far_trajectory_single() {
+        let mut convergence: Convergence<f32> = vec![];
+        convergence.push(vec![
+            None,
+            Some((3_f32, 30_f64)),
+            Some((1_f32, 40_f64)),
+            Some((2_f32, 20_f64)),
+        ]);
+
+        let result = vec![None, Some(30_f64), Some(30_f64), Some(20_f64)];
+
+        assert_eq!(convergence.get_best_so_far_trajectory(), result);
+    }
+
+    #[test]
+    fn get_best_so_far_trajectory_several() {
+        let mut convergence: Convergence<f32> = vec![];
+        convergence.push(vec![Some((3_f32, 30_f64)), Some((1_f32, 5_f64))]);
+        convergence.push(vec![
+            Some((3_f32, 20_f64)),
+            Some((1_f32, 10_f64)),
+            Some((1_f32, 8_f64)),
+            Some((1_f32, 1_f64)),
+        ]);
+
+        let result = vec![Some(20_f64), Some(5_f64), Some(5_f64), Some(1_f64)];
+
+        assert_eq!(convergence.get_best_so_far_trajectory(), result);
+    }
+
+    #[test]
+    fn get_best_so_far_trajectory_nan() {
+        let mut convergence: Convergence<f32> = vec![];
+        convergence.push(vec![
+            Some((3_f32, f64::NAN)),
+            Some((1_f32, 10_f64)),
+            Some((1_f32, f64::NAN)),
+        ]);
+
+        let result = vec![None, Some(10_f64), Some(10_f64)];
+
+        assert_eq!(convergence.get_best_so_far_trajectory(), result);
+    }
+
     #[test]
     fn get_average_convergence_empty() {
         let convergence: Convergence<f32> = vec![];
@@ -854,13 +1553,13 @@ mod tests {
     #[test]
     fn call_count_data_average_empty() {
         let data = CallCountData::new();
-        assert_eq!(data.get_call_count(), vec![]);
+        assert_eq!(data.get_call_count(), Vec::<usize>::new());
         assert_eq!(data.get_average_call_count(), None);
     }
 
     #[test]
     fn call_count_data_average_single_zero() {
-        let mut data = CallCountData::new();
+        let data = CallCountData::new();
         data.next_run();
 
         assert_eq!(data.get_call_count(), vec![0]);
@@ -869,7 +1568,7 @@ mod tests {
 
     #[test]
     fn call_count_data_average_single_one() {
-        let mut data = CallCountData::new();
+        let data = CallCountData::new();
         data.next_run();
         data.increment();
 
@@ -879,7 +1578,7 @@ mod tests {
 
     #[test]
     fn call_count_data_average_single_two() {
-        let mut data = CallCountData::new();
+        let data = CallCountData::new();
         data.next_run();
         data.add(5);
 
@@ -889,7 +1588,7 @@ mod tests {
 
     #[test]
     fn call_count_data_average_several() {
-        let mut data = CallCountData::new();
+        let data = CallCountData::new();
         data.next_run();
         data.add(5);
         data.next_run();
@@ -899,6 +1598,179 @@ mod tests {
         assert!((data.get_average_call_count().unwrap() - 6.0) < 1e-5);
     }
 
+    #[test]
+    fn call_count_data_min_max_empty() {
+        let data = CallCountData::new();
+        assert_eq!(data.get_min_call_count(), None);
+        assert_eq!(data.get_max_call_count(), None);
+    }
+
+    #[test]
+    fn call_count_data_min_max_several() {
+        let data = CallCountData::new();
+        for n in &[5, 2, 7, 3] {
+            data.next_run();
+            data.add(*n);
+        }
+
+        assert_eq!(data.get_min_call_count(), Some(2));
+        assert_eq!(data.get_max_call_count(), Some(7));
+    }
+
+    #[test]
+    fn call_count_data_percentile_empty() {
+        let data = CallCountData::new();
+        assert_eq!(data.get_percentile_call_count(0.5), None);
+    }
+
+    #[test]
+    fn call_count_data_percentile_single() {
+        let data = CallCountData::new();
+        data.next_run();
+        data.add(4);
+
+        assert_eq!(data.get_percentile_call_count(0.0), Some(4.0));
+        assert_eq!(data.get_percentile_call_count(0.5), Some(4.0));
+        assert_eq!(data.get_percentile_call_count(1.0), Some(4.0));
+    }
+
+    #[test]
+    fn call_count_data_percentile_several() {
+        let data = CallCountData::new();
+        for n in &[40, 10, 30, 20] {
+            data.next_run();
+            data.add(*n);
+        }
+
+        assert_eq!(data.get_percentile_call_count(0.0), Some(10.0));
+        assert_eq!(data.get_percentile_call_count(1.0), Some(40.0));
+        assert!((data.get_percentile_call_count(0.5).unwrap() - 25.0).abs() < 1e-9);
+        assert!((data.get_percentile_call_count(0.25).unwrap() - 17.5).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn call_count_data_percentile_invalid() {
+        let data = CallCountData::new();
+        data.next_run();
+        data.get_percentile_call_count(1.5);
+    }
+
+    #[test]
+    fn call_count_data_histogram_empty() {
+        let data = CallCountData::new();
+        assert!(data.get_call_count_histogram(3).is_empty());
+    }
+
+    #[test]
+    fn call_count_data_histogram_equal() {
+        let data = CallCountData::new();
+        for _ in 0..3 {
+            data.next_run();
+            data.add(10);
+        }
+
+        assert_eq!(data.get_call_count_histogram(2), vec![(10, 3), (11, 0)]);
+    }
+
+    #[test]
+    fn call_count_data_histogram_several() {
+        let data = CallCountData::new();
+        for n in &[0, 1, 2, 5, 6, 9, 9] {
+            data.next_run();
+            data.add(*n);
+        }
+
+        assert_eq!(data.get_call_count_histogram(2), vec![(0, 3), (5, 4)]);
+        assert_eq!(
+            data.get_call_count_histogram(4),
+            vec![(0, 3), (3, 1), (6, 1), (9, 2)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn call_count_data_histogram_zero_bins() {
+        let data = CallCountData::new();
+        data.get_call_count_histogram(0);
+    }
+
+    #[test]
+    fn goal_calc_statistics_arc_single() {
+        let mut goal = GoalCalcStatisticsArc::new(GoalFromFunction::new(|x: &f64| x * 2.0));
+        assert_eq!(goal.get_call_count(), 0);
+
+        assert_eq!(Goal::get(&mut goal, &1.0), 2.0);
+        assert_eq!(Goal::get(&mut goal, &2.0), 4.0);
+        assert_eq!(goal.get_call_count(), 2);
+    }
+
+    #[test]
+    fn goal_calc_statistics_arc_threads() {
+        let goal = GoalCalcStatisticsArc::new(GoalFromFunction::new(|x: &f64| x * 2.0));
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                let mut goal_clone = goal.clone();
+                scope.spawn(move || {
+                    for _ in 0..25 {
+                        Goal::get(&mut goal_clone, &1.0);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(goal.get_call_count(), 100);
+        assert_eq!(goal.get_counter().load(Ordering::SeqCst), 100);
+    }
+
+    #[test]
+    fn goal_calc_statistics_arc_new_counter() {
+        let mut goal = GoalCalcStatisticsArc::new(GoalFromFunction::new(|x: &f64| x * 2.0));
+        Goal::get(&mut goal, &1.0);
+
+        let mut goal_clone = goal.clone_with_new_counter();
+        assert_eq!(goal_clone.get_call_count(), 0);
+
+        assert_eq!(Goal::get(&mut goal_clone, &2.0), 4.0);
+        Goal::get(&mut goal_clone, &2.0);
+        assert_eq!(goal_clone.get_call_count(), 2);
+        assert_eq!(goal.get_call_count(), 1);
+    }
+
+    #[test]
+    fn goal_calc_statistics_arc_transfer() {
+        let mut goal = GoalCalcStatisticsArc::new(GoalFromFunction::new(|x: &f64| x * 2.0));
+        let call_count = CallCountData::new();
+
+        call_count.next_run();
+        Goal::get(&mut goal, &1.0);
+        Goal::get(&mut goal, &1.0);
+        goal.transfer_to(&call_count);
+        assert_eq!(goal.get_call_count(), 0);
+
+        call_count.next_run();
+        Goal::get(&mut goal, &1.0);
+        goal.transfer_to(&call_count);
+
+        assert_eq!(call_count.get_call_count(), vec![2, 1]);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn goal_calc_statistics_arc_sync_goal() {
+        use rayon::prelude::*;
+
+        let goal = GoalCalcStatisticsArc::new(GoalFromFunction::new(|x: &f64| x * 2.0));
+        let values: Vec<GoalValue> = (0..10)
+            .into_par_iter()
+            .map(|x| SyncGoal::get(&goal, &(x as f64)))
+            .collect();
+
+        assert_eq!(values[3], 6.0);
+        assert_eq!(goal.get_call_count(), 10);
+    }
+
     #[test]
     fn statistics_convergence_unite_empty() {
         let mut stat_1: Statistics<f32> = Statistics::new();
@@ -1014,7 +1886,7 @@ mod tests {
 
         call_count_1.unite(call_count_2);
 
-        assert_eq!(call_count_1.0, valid_call_count_stat);
+        assert_eq!(*call_count_1.0.borrow(), valid_call_count_stat);
     }
 
     #[test]
@@ -1024,10 +1896,10 @@ mod tests {
 
         let valid_call_count_stat: Vec<usize> = vec![100];
 
-        call_count_1.0.push(100);
+        call_count_1.0.get_mut().push(100);
         call_count_1.unite(call_count_2);
 
-        assert_eq!(call_count_1.0, valid_call_count_stat);
+        assert_eq!(*call_count_1.0.borrow(), valid_call_count_stat);
     }
 
     #[test]
@@ -1037,10 +1909,10 @@ mod tests {
 
         let valid_call_count_stat: Vec<usize> = vec![100];
 
-        call_count_2.0.push(100);
+        call_count_2.0.get_mut().push(100);
         call_count_1.unite(call_count_2);
 
-        assert_eq!(call_count_1.0, valid_call_count_stat);
+        assert_eq!(*call_count_1.0.borrow(), valid_call_count_stat);
     }
 
     #[test]
@@ -1050,11 +1922,502 @@ mod tests {
 
         let valid_call_count_stat: Vec<usize> = vec![100, 200];
 
-        call_count_1.0.push(100);
-        call_count_2.0.push(200);
+        call_count_1.0.get_mut().push(100);
+        call_count_2.0.get_mut().push(200);
 
         call_count_1.unite(call_count_2);
 
-        assert_eq!(call_count_1.0, valid_call_count_stat);
+        assert_eq!(*call_count_1.0.borrow(), valid_call_count_stat);
+    }
+
+    #[test]
+    fn statistics_best_worst_result_empty() {
+        let stat: Statistics<f32> = Statistics::new();
+        assert_eq!(stat.get_best_result(), None);
+        assert_eq!(stat.get_worst_result(), None);
+    }
+
+    #[test]
+    fn statistics_best_worst_result_none() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(None);
+        stat.results.push(None);
+
+        assert_eq!(stat.get_best_result(), None);
+        assert_eq!(stat.get_worst_result(), None);
+    }
+
+    #[test]
+    fn statistics_best_worst_result_single() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((1.0_f32, 2.0)));
+
+        assert_eq!(stat.get_best_result(), Some((&1.0_f32, 2.0)));
+        assert_eq!(stat.get_worst_result(), Some((&1.0_f32, 2.0)));
+    }
+
+    #[test]
+    fn statistics_best_worst_result_several() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((1.0_f32, 2.0)));
+        stat.results.push(None);
+        stat.results.push(Some((3.0_f32, -1.0)));
+        stat.results.push(Some((4.0_f32, f64::NAN)));
+        stat.results.push(Some((5.0_f32, 10.0)));
+
+        assert_eq!(stat.get_best_result(), Some((&3.0_f32, -1.0)));
+        assert_eq!(stat.get_worst_result(), Some((&5.0_f32, 10.0)));
+    }
+
+    #[test]
+    fn statistics_best_worst_result_maximize() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.add_run_result(Some((1.0_f32, 2.0)), Direction::Maximize);
+        stat.add_run_result(None, Direction::Maximize);
+        stat.add_run_result(Some((3.0_f32, -1.0)), Direction::Maximize);
+        stat.add_run_result(Some((4.0_f32, f64::NAN)), Direction::Maximize);
+        stat.add_run_result(Some((5.0_f32, 10.0)), Direction::Maximize);
+
+        assert_eq!(stat.get_direction(), Direction::Maximize);
+        assert_eq!(stat.get_best_result(), Some((&5.0_f32, 10.0)));
+        assert_eq!(stat.get_worst_result(), Some((&3.0_f32, -1.0)));
+    }
+
+    /// The algorithm state with the given best solution after `find_max`.
+    struct MaximizeState {
+        best_solution: Option<Solution<f32>>,
+    }
+
+    impl AlgorithmState<f32> for MaximizeState {
+        fn get_best_solution(&self) -> Option<Solution<f32>> {
+            self.best_solution
+        }
+
+        fn get_iteration(&self) -> usize {
+            0
+        }
+
+        fn get_direction(&self) -> Direction {
+            Direction::Maximize
+        }
+    }
+
+    #[test]
+    fn statistics_logger_maximize() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        for goals in [vec![1.0, 3.0, 3.0], vec![2.0, 5.0, 6.0]].iter() {
+            let mut logger = StatisticsLogger::new(&mut stat);
+            let mut state = MaximizeState {
+                best_solution: None,
+            };
+            logger.start(&state);
+            for goal in goals {
+                state.best_solution = Some((*goal as f32, *goal));
+                logger.next_iteration(&state);
+            }
+            logger.finish(&state);
+        }
+
+        assert_eq!(stat.get_direction(), Direction::Maximize);
+        assert_eq!(stat.get_best_result(), Some((&6.0_f32, 6.0)));
+        assert_eq!(stat.get_worst_result(), Some((&3.0_f32, 3.0)));
+        assert_eq!(stat.get_iteration_of_best(), Some(2));
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_empty() {
+        let stat: Statistics<f32> = Statistics::new();
+        assert_eq!(stat.get_iteration_of_best(), None);
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_none() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(None);
+        stat.convergence.push(vec![None, None]);
+
+        assert_eq!(stat.get_iteration_of_best(), None);
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_without_convergence() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.add_run_result(Some((1.0_f32, 2.0)), Direction::Minimize);
+
+        assert_eq!(stat.get_iteration_of_best(), None);
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_unite_without_convergence() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.add_run_result(Some((1.0_f32, 5.0)), Direction::Minimize);
+
+        let mut stat_logger: Statistics<f32> = Statistics::new();
+        stat_logger.results.push(Some((2.0_f32, -1.0)));
+        stat_logger
+            .convergence
+            .push(vec![Some((3.0_f32, 0.0)), Some((2.0_f32, -1.0))]);
+
+        stat.unite(stat_logger);
+
+        assert_eq!(stat.get_run_count(), 2);
+        assert_eq!(stat.get_convergence().len(), 2);
+        assert_eq!(stat.get_iteration_of_best(), Some(1));
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_single() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((3.0_f32, 1.0)));
+        stat.convergence.push(vec![
+            None,
+            Some((1.0_f32, 5.0)),
+            Some((3.0_f32, 1.0)),
+            Some((3.0_f32, 1.0)),
+        ]);
+
+        assert_eq!(stat.get_iteration_of_best(), Some(2));
+    }
+
+    #[test]
+    fn statistics_iteration_of_best_several() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((1.0_f32, 2.0)));
+        stat.convergence.push(vec![Some((1.0_f32, 2.0))]);
+
+        stat.results.push(Some((3.0_f32, -1.0)));
+        stat.convergence.push(vec![
+            Some((1.0_f32, 2.0)),
+            Some((2.0_f32, 0.0)),
+            Some((2.0_f32, 0.0)),
+            Some((3.0_f32, -1.0)),
+        ]);
+
+        stat.results.push(Some((4.0_f32, f64::NAN)));
+        stat.convergence.push(vec![Some((4.0_f32, f64::NAN))]);
+
+        stat.results.push(Some((5.0_f32, -1.0)));
+        stat.convergence.push(vec![
+            Some((1.0_f32, 2.0)),
+            Some((5.0_f32, -1.0)),
+            Some((5.0_f32, -1.0)),
+        ]);
+
+        assert_eq!(stat.get_iteration_of_best(), Some(1));
+    }
+
+    #[test]
+    fn get_median_goal_empty() {
+        let results: Vec<Option<Solution<f32>>> = vec![];
+        assert_eq!(results.get_median_goal(), None);
+    }
+
+    #[test]
+    fn get_median_goal_none() {
+        let results: Vec<Option<Solution<f32>>> = vec![None, None];
+        assert_eq!(results.get_median_goal(), None);
+    }
+
+    #[test]
+    fn get_median_goal_single() {
+        let results: Vec<Option<Solution<f32>>> = vec![Some((1.0_f32, 2.0))];
+        assert_eq!(results.get_median_goal(), Some(2.0));
+    }
+
+    #[test]
+    fn get_median_goal_odd() {
+        let results: Vec<Option<Solution<f32>>> = vec![
+            Some((1.0_f32, 100.0)),
+            None,
+            Some((1.0_f32, 1.0)),
+            Some((1.0_f32, 3.0)),
+        ];
+        assert_eq!(results.get_median_goal(), Some(3.0));
+    }
+
+    #[test]
+    fn get_median_goal_even() {
+        let results: Vec<Option<Solution<f32>>> = vec![
+            Some((1.0_f32, 4.0)),
+            Some((1.0_f32, 1.0)),
+            Some((1.0_f32, 3.0)),
+            Some((1.0_f32, 100.0)),
+        ];
+        assert_eq!(results.get_median_goal(), Some(3.5));
+    }
+
+    #[test]
+    fn get_median_goal_nan() {
+        let results: Vec<Option<Solution<f32>>> = vec![
+            Some((1.0_f32, f64::NAN)),
+            Some((1.0_f32, 1.0)),
+            Some((1.0_f32, 3.0)),
+        ];
+        assert_eq!(results.get_median_goal(), Some(2.0));
+    }
+
+    #[test]
+    fn get_percentile_goal_bounds() {
+        let results: Vec<Option<Solution<f32>>> = vec![
+            Some((1.0_f32, 5.0)),
+            Some((1.0_f32, 1.0)),
+            Some((1.0_f32, 3.0)),
+        ];
+        assert_eq!(results.get_percentile_goal(0.0), Some(1.0));
+        assert_eq!(results.get_percentile_goal(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn get_percentile_goal_interpolation() {
+        let results: Vec<Option<Solution<f32>>> = vec![
+            Some((1.0_f32, 0.0)),
+            Some((1.0_f32, 10.0)),
+            Some((1.0_f32, 20.0)),
+            Some((1.0_f32, 30.0)),
+            Some((1.0_f32, 40.0)),
+        ];
+        assert_eq!(results.get_percentile_goal(0.25), Some(10.0));
+        assert_eq!(results.get_percentile_goal(0.9), Some(36.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_percentile_goal_invalid() {
+        let results: Vec<Option<Solution<f32>>> = vec![Some((1.0_f32, 0.0))];
+        results.get_percentile_goal(1.5);
+    }
+
+    #[test]
+    fn statistics_filter_successful_runs_empty() {
+        let stat: Statistics<f32> = Statistics::new();
+        let filtered = stat.filter_successful_runs(get_predicate_success_goal(0.0, 1.0));
+
+        assert_eq!(filtered.get_run_count(), 0);
+        assert!(filtered.get_convergence().is_empty());
+    }
+
+    #[test]
+    fn statistics_filter_successful_runs() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((1.0_f32, 0.5)));
+        stat.results.push(None);
+        stat.results.push(Some((2.0_f32, 10.0)));
+        stat.results.push(Some((3.0_f32, -0.5)));
+        stat.convergence.push(vec![Some((1.0_f32, 0.5))]);
+        stat.convergence.push(vec![None]);
+        stat.convergence.push(vec![Some((2.0_f32, 10.0))]);
+        stat.convergence.push(vec![Some((3.0_f32, 1.0)), Some((3.0_f32, -0.5))]);
+
+        let filtered = stat.filter_successful_runs(get_predicate_success_goal(0.0, 1.0));
+
+        assert_eq!(
+            filtered.get_results(),
+            &vec![Some((1.0_f32, 0.5)), Some((3.0_f32, -0.5))]
+        );
+        assert_eq!(
+            filtered.get_convergence(),
+            &vec![
+                vec![Some((1.0_f32, 0.5))],
+                vec![Some((3.0_f32, 1.0)), Some((3.0_f32, -0.5))],
+            ]
+        );
+    }
+
+    #[test]
+    fn statistics_filter_successful_runs_without_convergence() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.add_run_result(Some((1.0_f32, 0.5)), Direction::Minimize);
+
+        let mut stat_logger: Statistics<f32> = Statistics::new();
+        stat_logger.results.push(Some((2.0_f32, 10.0)));
+        stat_logger.results.push(Some((3.0_f32, -0.5)));
+        stat_logger.convergence.push(vec![Some((2.0_f32, 10.0))]);
+        stat_logger.convergence.push(vec![Some((3.0_f32, -0.5))]);
+
+        stat.unite(stat_logger);
+        // The convergence data are missing for the last running.
+        stat.results.push(Some((4.0_f32, 0.0)));
+
+        let filtered = stat.filter_successful_runs(get_predicate_success_goal(0.0, 1.0));
+
+        assert_eq!(
+            filtered.get_results(),
+            &vec![
+                Some((1.0_f32, 0.5)),
+                Some((3.0_f32, -0.5)),
+                Some((4.0_f32, 0.0))
+            ]
+        );
+        assert_eq!(
+            filtered.get_convergence(),
+            &vec![vec![], vec![Some((3.0_f32, -0.5))], vec![]]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn statistics_serde() {
+        let mut stat: Statistics<Vec<f32>> = Statistics::new();
+        stat.results.push(Some((vec![1.0_f32, 2.0], 0.5)));
+        stat.results.push(None);
+        stat.convergence.push(vec![None, Some((vec![1.0_f32, 2.0], 0.5))]);
+        stat.convergence.push(vec![None]);
+
+        let json = serde_json::to_string(&stat).unwrap();
+        let restored: Statistics<Vec<f32>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.get_results(), stat.get_results());
+        assert_eq!(restored.get_convergence(), stat.get_convergence());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn call_count_data_serde() {
+        let data = CallCountData::new();
+        data.next_run();
+        data.add(5);
+        data.next_run();
+        data.add(3);
+
+        let json = serde_json::to_string(&data).unwrap();
+        let restored: CallCountData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.get_call_count(), vec![5, 3]);
+    }
+
+    #[test]
+    fn inverse_normal_cdf_values() {
+        assert!(inverse_normal_cdf(0.5).abs() < 1e-9);
+        assert!((inverse_normal_cdf(0.975) - 1.959_963_985).abs() < 1e-8);
+        assert!((inverse_normal_cdf(0.025) + 1.959_963_985).abs() < 1e-8);
+        assert!((inverse_normal_cdf(0.995) - 2.575_829_304).abs() < 1e-8);
+        assert!((inverse_normal_cdf(0.001) + 3.090_232_306).abs() < 1e-8);
+    }
+
+    #[test]
+    fn goal_confidence_interval_empty() {
+        let stat: Statistics<f32> = Statistics::new();
+        assert_eq!(goal_confidence_interval(&stat, 0.95), None);
+    }
+
+    #[test]
+    fn goal_confidence_interval_single() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        stat.results.push(Some((1.0_f32, 1.0)));
+        stat.results.push(None);
+
+        assert_eq!(goal_confidence_interval(&stat, 0.95), None);
+    }
+
+    #[test]
+    fn goal_confidence_interval_several() {
+        let mut stat: Statistics<f32> = Statistics::new();
+        for goal in 1..=5 {
+            stat.results.push(Some((0.0_f32, goal as f64)));
+        }
+        stat.results.push(None);
+
+        let interval = goal_confidence_interval(&stat, 0.95).unwrap();
+
+        // Average is 3, standard deviation is sqrt(2.5)
+        let half_width = 1.959_963_985 * 2.5_f64.sqrt() / 5.0_f64.sqrt();
+        assert!((interval.lower - (3.0 - half_width)).abs() < 1e-8);
+        assert!((interval.upper - (3.0 + half_width)).abs() < 1e-8);
+        assert_eq!(interval.confidence, 0.95);
+    }
+
+    #[test]
+    #[should_panic]
+    fn goal_confidence_interval_invalid() {
+        let stat: Statistics<f32> = Statistics::new();
+        goal_confidence_interval(&stat, 1.0);
+    }
+
+    #[test]
+    fn dominates_simple() {
+        assert!(dominates(&[1.0, 1.0], &[2.0, 2.0]));
+        assert!(dominates(&[1.0, 2.0], &[2.0, 2.0]));
+        assert!(!dominates(&[2.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[1.0, 3.0], &[2.0, 2.0]));
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn dominates_nan() {
+        assert!(dominates(&[1.0, 2.0], &[f64::NAN, 2.0]));
+        assert!(!dominates(&[f64::NAN, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[f64::NAN, 2.0], &[f64::NAN, 2.0]));
+    }
+
+    #[test]
+    fn pareto_front_insert() {
+        let mut front = ParetoFront::new();
+        assert!(front.is_empty());
+
+        assert!(front.insert("a", vec![2.0, 2.0]));
+        assert!(front.insert("b", vec![1.0, 3.0]));
+        assert!(!front.insert("c", vec![3.0, 3.0]));
+        assert_eq!(front.len(), 2);
+
+        // The new solution dominates "a"
+        assert!(front.insert("d", vec![1.5, 1.5]));
+        let names: Vec<&str> = front.solutions().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["b", "d"]);
+    }
+
+    #[test]
+    fn pareto_front_hypervolume_2d() {
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![1.0, 3.0]);
+        front.insert(1, vec![3.0, 1.0]);
+        front.insert(2, vec![2.0, 2.0]);
+
+        assert!((front.hypervolume(&[4.0, 4.0]) - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn pareto_front_hypervolume_3d() {
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![0.0, 0.0, 0.0]);
+        assert!((front.hypervolume(&[1.0, 2.0, 3.0]) - 6.0).abs() < 1e-12);
+
+        // 2 * 2 * 1 + 1 * 1 * 2 - 1 * 1 * 1
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![0.0, 0.0, 1.0]);
+        front.insert(1, vec![1.0, 1.0, 0.0]);
+        assert!((front.hypervolume(&[2.0, 2.0, 2.0]) - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn pareto_front_hypervolume_single_objective() {
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![1.5]);
+        assert_eq!(front.hypervolume(&[4.0]), 2.5);
+    }
+
+    #[test]
+    fn pareto_front_hypervolume_outside_reference() {
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![5.0, 0.0]);
+        front.insert(1, vec![0.0, 4.0]);
+        front.insert(2, vec![f64::NAN, 1.0]);
+        assert_eq!(front.hypervolume(&[4.0, 4.0]), 0.0);
+
+        front.insert(3, vec![2.0, 2.0]);
+        assert_eq!(front.hypervolume(&[4.0, 4.0]), 4.0);
+    }
+
+    #[test]
+    fn pareto_front_hypervolume_empty() {
+        let front: ParetoFront<usize> = ParetoFront::new();
+        assert_eq!(front.hypervolume(&[1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pareto_front_hypervolume_invalid_reference() {
+        let mut front = ParetoFront::new();
+        front.insert(0, vec![1.0, 1.0]);
+        front.hypervolume(&[2.0, 2.0, 2.0]);
     }
 }
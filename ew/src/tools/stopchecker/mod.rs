@@ -1,68 +1,154 @@
 use std::f64;
+use std::fmt;
+use std::time::{Duration, Instant};
 
-use super::super::AlgorithmState;
+use num::Float;
+
+use super::super::{AlgorithmState, Direction};
+use super::statistics::CallCountData;
 
 /// The trait with break criterion optimization algorithm.
 ///
 /// `T` - type of a point in the search space for goal function.
+///
+/// All built-in stop checkers implement `Debug`. The trait does not require `Debug` for
+/// user-defined stop checkers, so `CompositeAny` and `CompositeAll` print only the count
+/// of the inner stop checkers.
 pub trait StopChecker<T> {
     /// The method must return true if algorithm must be stopped.
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool;
+
+    /// Reset the internal state of the stop checker (iteration counters, the last goal value etc)
+    /// to reuse it for new running of the algorithm.
+    /// The optimizers call the method during the initialization of the algorithm.
+    fn reset(&mut self) {}
+}
+
+/// The stop checker may be passed to an optimizer by mutable reference to inspect its state
+/// after the algorithm running.
+impl<T, S: StopChecker<T> + ?Sized> StopChecker<T> for &mut S {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        (**self).can_stop(state)
+    }
+
+    fn reset(&mut self) {
+        (**self).reset();
+    }
 }
 
 /// Stop the algorithm if ANY of stop checker returns true
-pub struct CompositeAny<T> {
-    stop_checkers: Vec<Box<dyn StopChecker<T>>>,
+pub struct CompositeAny<'a, T> {
+    stop_checkers: Vec<Box<dyn StopChecker<T> + 'a>>,
+    triggered_index: Option<usize>,
+    iteration: usize,
 }
 
-impl<T> CompositeAny<T> {
+impl<'a, T> CompositeAny<'a, T> {
     /// Constructor
-    pub fn new(stop_checkers: Vec<Box<dyn StopChecker<T>>>) -> Self {
+    pub fn new(stop_checkers: Vec<Box<dyn StopChecker<T> + 'a>>) -> Self {
         assert!(stop_checkers.len() != 0);
-        Self { stop_checkers }
+        Self {
+            stop_checkers,
+            triggered_index: None,
+            iteration: 0,
+        }
+    }
+
+    /// Returns index of the stop checker which returned true during the last call of
+    /// `can_stop()` or None if all stop checkers returned false.
+    /// The index is the position of the stop checker in the vector passed to the constructor.
+    pub fn triggered_index(&self) -> Option<usize> {
+        self.triggered_index
+    }
+
+    /// Returns the iteration number of the algorithm at the last call of `can_stop()`.
+    pub fn current_iteration(&self) -> usize {
+        self.iteration
     }
 }
 
-impl<T> StopChecker<T> for CompositeAny<T> {
+/// The inner stop checkers are not printed because `StopChecker` does not require `Debug`.
+impl<'a, T> fmt::Debug for CompositeAny<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompositeAny")
+            .field("stop_checkers_count", &self.stop_checkers.len())
+            .field("triggered_index", &self.triggered_index)
+            .field("iteration", &self.iteration)
+            .finish()
+    }
+}
+
+impl<'a, T> StopChecker<T> for CompositeAny<'a, T> {
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
-        for checker in &mut self.stop_checkers {
+        self.triggered_index = None;
+        self.iteration = state.get_iteration();
+        for (n, checker) in self.stop_checkers.iter_mut().enumerate() {
             if checker.can_stop(state) {
+                self.triggered_index = Some(n);
                 return true;
             }
         }
 
         false
     }
+
+    fn reset(&mut self) {
+        self.triggered_index = None;
+        self.iteration = 0;
+        for checker in &mut self.stop_checkers {
+            checker.reset();
+        }
+    }
 }
 
-/// Stop the algorithm if ALL stop checkers returns true
-pub struct CompositeAll<T> {
-    stop_checkers: Vec<Box<dyn StopChecker<T>>>,
+/// Stop the algorithm if ALL stop checkers returns true.
+/// All stop checkers are called on every iteration.
+pub struct CompositeAll<'a, T> {
+    stop_checkers: Vec<Box<dyn StopChecker<T> + 'a>>,
 }
 
-impl<T> CompositeAll<T> {
+impl<'a, T> CompositeAll<'a, T> {
     /// Constructor
-    pub fn new(stop_checkers: Vec<Box<dyn StopChecker<T>>>) -> Self {
+    pub fn new(stop_checkers: Vec<Box<dyn StopChecker<T> + 'a>>) -> Self {
         assert!(stop_checkers.len() != 0);
         Self { stop_checkers }
     }
 }
 
-impl<T> StopChecker<T> for CompositeAll<T> {
+/// The inner stop checkers are not printed because `StopChecker` does not require `Debug`.
+impl<'a, T> fmt::Debug for CompositeAll<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompositeAll")
+            .field("stop_checkers_count", &self.stop_checkers.len())
+            .finish()
+    }
+}
+
+impl<'a, T> StopChecker<T> for CompositeAll<'a, T> {
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        // Every stop checker must be called because it may update its state.
+        let mut result = true;
         for checker in &mut self.stop_checkers {
             if !checker.can_stop(state) {
-                return false;
+                result = false;
             }
         }
 
-        true
+        result
+    }
+
+    fn reset(&mut self) {
+        for checker in &mut self.stop_checkers {
+            checker.reset();
+        }
     }
 }
 
 /// The algorithm will be stopped after specified iteration.
+#[derive(Debug)]
 pub struct MaxIterations {
     max_iter: usize,
+    iteration: usize,
 }
 
 impl MaxIterations {
@@ -71,23 +157,40 @@ impl MaxIterations {
     /// # Parameters
     /// * `max_iter` - how many iterations will run the algorithm.
     pub fn new(max_iter: usize) -> Self {
-        MaxIterations { max_iter }
+        MaxIterations {
+            max_iter,
+            iteration: 0,
+        }
+    }
+
+    /// Returns the iteration number of the algorithm at the last call of `can_stop()`.
+    pub fn current_iteration(&self) -> usize {
+        self.iteration
     }
 }
 
 impl<T> StopChecker<T> for MaxIterations {
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
-        state.get_iteration() >= self.max_iter
+        self.iteration = state.get_iteration();
+        self.iteration >= self.max_iter
+    }
+
+    fn reset(&mut self) {
+        self.iteration = 0;
     }
 }
 
 /// The algorithm will be stopped if the best goal function does not change.
+#[derive(Debug)]
 pub struct GoalNotChange {
     max_iter: usize,
     delta: f64,
 
     old_goal: f64,
     change_iter: usize,
+
+    last_goal: Option<f64>,
+    last_iter: usize,
 }
 
 impl GoalNotChange {
@@ -104,8 +207,28 @@ impl GoalNotChange {
             delta,
             old_goal: f64::MAX,
             change_iter: 0,
+            last_goal: None,
+            last_iter: 0,
         }
     }
+
+    /// Returns how many iterations the value of goal function of the best solution did not change
+    /// at the last call of `can_stop()`.
+    pub fn stagnant_iterations(&self) -> usize {
+        self.last_iter - self.change_iter
+    }
+
+    /// Returns the iteration number of the algorithm at the last call of `can_stop()`
+    /// when the best solution was found.
+    pub fn current_iteration(&self) -> usize {
+        self.last_iter
+    }
+
+    /// Returns the value of goal function of the best solution at the last call of `can_stop()`
+    /// or None if the best solution was not found yet.
+    pub fn last_goal_value(&self) -> Option<f64> {
+        self.last_goal
+    }
 }
 
 impl<T> StopChecker<T> for GoalNotChange {
@@ -119,13 +242,96 @@ impl<T> StopChecker<T> for GoalNotChange {
                     self.change_iter = state.get_iteration();
                 }
 
-                (state.get_iteration() - self.change_iter) > self.max_iter
+                self.last_goal = Some(best_goal);
+                self.last_iter = state.get_iteration();
+
+                self.stagnant_iterations() > self.max_iter
             }
         }
     }
+
+    fn reset(&mut self) {
+        self.old_goal = f64::MAX;
+        self.change_iter = 0;
+        self.last_goal = None;
+        self.last_iter = 0;
+    }
+}
+
+/// The algorithm will be stopped if the best goal function does not improve relatively.
+///
+/// The relative improvement is `(prev - current) / |prev|`, where `prev` is the last value of
+/// the goal function which was considered as improvement, `current` - current value of the goal
+/// function. If `prev` is zero, the absolute improvement `prev - current` is used.
+/// For maximization the relative improvement is `(current - prev) / |prev|`.
+#[derive(Debug)]
+pub struct RelativeImprovement {
+    max_stagnant_iterations: usize,
+    relative_delta: f64,
+
+    best_goal: Option<f64>,
+    change_iter: usize,
+}
+
+impl RelativeImprovement {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `max_stagnant_iterations` - how many iterations the best solution may not improve.
+    /// * `relative_delta` - small value. The improvement is not considered if the relative
+    ///   improvement less of `relative_delta`.
+    pub fn new(max_stagnant_iterations: usize, relative_delta: f64) -> Self {
+        Self {
+            max_stagnant_iterations,
+            relative_delta,
+            best_goal: None,
+            change_iter: 0,
+        }
+    }
+
+    fn is_improvement(&self, goal: f64) -> bool {
+        match self.best_goal {
+            None => true,
+            Some(prev) => {
+                let improvement = if prev == 0.0 {
+                    prev - goal
+                } else {
+                    (prev - goal) / prev.abs()
+                };
+
+                improvement >= self.relative_delta
+            }
+        }
+    }
+}
+
+impl<T> StopChecker<T> for RelativeImprovement {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        match state.get_best_solution() {
+            None => false,
+            Some((_, best_goal)) => {
+                // The improvement is calculated for the value which the algorithm minimizes
+                let best_goal = state.get_direction().apply(best_goal);
+                if self.is_improvement(best_goal) {
+                    self.best_goal = Some(best_goal);
+                    self.change_iter = state.get_iteration();
+                }
+
+                (state.get_iteration() - self.change_iter) > self.max_stagnant_iterations
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.best_goal = None;
+        self.change_iter = 0;
+    }
 }
 
 /// Stop the algorithm if value of the goal function less of than threshold.
+/// For maximization the algorithm is stopped if value of the goal function greater of
+/// than threshold.
+#[derive(Debug)]
 pub struct Threshold {
     threshold: f64,
 }
@@ -134,7 +340,7 @@ impl Threshold {
     /// Constructor.
     ///
     /// # Parameters
-    /// * `threshold` - min value of the goal function
+    /// * `threshold` - min value of the goal function (max value for maximization).
     pub fn new(threshold: f64) -> Self {
         Self { threshold }
     }
@@ -144,7 +350,734 @@ impl<T> StopChecker<T> for Threshold {
     fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
         match state.get_best_solution() {
             None => false,
-            Some((_, goal)) => goal <= self.threshold,
+            Some((_, goal)) => match state.get_direction() {
+                Direction::Minimize => goal <= self.threshold,
+                Direction::Maximize => goal >= self.threshold,
+            },
+        }
+    }
+}
+
+/// Stop the algorithm after the specified wall-clock time duration.
+///
+/// The time is counted from the first call of `can_stop()` for the run of the algorithm
+/// (the call with iteration 0).
+#[derive(Debug)]
+pub struct WallTimeLimit {
+    duration: Duration,
+    start_time: Option<Instant>,
+}
+
+impl WallTimeLimit {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `duration` - max time duration of the algorithm running.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            start_time: None,
+        }
+    }
+}
+
+impl<T> StopChecker<T> for WallTimeLimit {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<T>) -> bool {
+        if self.start_time.is_none() || state.get_iteration() == 0 {
+            self.start_time = Some(Instant::now());
+        }
+
+        self.start_time.unwrap().elapsed() >= self.duration
+    }
+
+    fn reset(&mut self) {
+        self.start_time = None;
+    }
+}
+
+/// Stop the algorithm if the goal function was called the specified times or more.
+///
+/// The call count is taken from `CallCountData` which is filled by `GoalCalcStatistics`.
+#[derive(Debug)]
+pub struct FunctionCallBudget<'a> {
+    call_count: &'a CallCountData,
+    max_calls: usize,
+}
+
+impl<'a> FunctionCallBudget<'a> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `call_count` - reference to `CallCountData` which is used by `GoalCalcStatistics`.
+    /// * `max_calls` - max count of the goal function calls for single run of the algorithm.
+    pub fn new(call_count: &'a CallCountData, max_calls: usize) -> Self {
+        Self {
+            call_count,
+            max_calls,
+        }
+    }
+}
+
+impl<'a, T> StopChecker<T> for FunctionCallBudget<'a> {
+    fn can_stop(&mut self, _state: &dyn AlgorithmState<T>) -> bool {
+        self.call_count.get_current_call_count() >= self.max_calls
+    }
+}
+
+/// Stop the algorithm if the agents (particles, individuals) are gathered close to each other.
+///
+/// The standard deviation of agents coordinates is calculated for every dimension. The algorithm
+/// will be stopped if the standard deviation for all dimensions less than threshold.
+/// The agents coordinates are taken from `AlgorithmState::get_agents_parameters()`.
+#[derive(Debug)]
+pub struct PopulationConvergence {
+    threshold: f64,
+    min_iterations: usize,
+}
+
+impl PopulationConvergence {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// * `threshold` - max standard deviation of the agents coordinates to stop the algorithm.
+    /// * `min_iterations` - the algorithm will not be stopped before the iteration.
+    pub fn new(threshold: f64, min_iterations: usize) -> Self {
+        Self {
+            threshold,
+            min_iterations,
+        }
+    }
+}
+
+impl<G: Float> StopChecker<Vec<G>> for PopulationConvergence {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<Vec<G>>) -> bool {
+        if state.get_iteration() < self.min_iterations {
+            return false;
+        }
+
+        match get_max_standard_deviation(&state.get_agents_parameters()) {
+            None => false,
+            Some(deviation) => deviation < self.threshold,
+        }
+    }
+}
+
+/// Calculate standard deviation of the points for every dimension and return max of them.
+/// Returns None if count of the points less 2.
+fn get_max_standard_deviation<G: Float>(points: &[&Vec<G>]) -> Option<f64> {
+    let count = points.len();
+    if count < 2 {
+        return None;
+    }
+
+    let dimension = points[0].len();
+    let mut max_deviation = 0.0;
+    for i in 0..dimension {
+        let average = points
+            .iter()
+            .fold(0.0, |acc, point| acc + point[i].to_f64().unwrap())
+            / (count as f64);
+        let sum = points.iter().fold(0.0, |acc, point| {
+            let x = point[i].to_f64().unwrap();
+            acc + (x - average) * (x - average)
+        });
+
+        let deviation = (sum / ((count - 1) as f64)).sqrt();
+        // NaN deviation must not be lost
+        if deviation.is_nan() || deviation > max_deviation {
+            max_deviation = deviation;
+        }
+    }
+
+    Some(max_deviation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+    use std::thread;
+
+    /// The algorithm state for tests.
+    struct TestState {
+        best_solution: Option<Solution<f64>>,
+        iteration: usize,
+        direction: Direction,
+    }
+
+    impl TestState {
+        fn new(best_goal: Option<f64>, iteration: usize) -> Self {
+            Self {
+                best_solution: best_goal.map(|goal| (0.0, goal)),
+                iteration,
+                direction: Direction::Minimize,
+            }
+        }
+
+        fn new_max(best_goal: Option<f64>, iteration: usize) -> Self {
+            Self {
+                direction: Direction::Maximize,
+                ..Self::new(best_goal, iteration)
+            }
+        }
+    }
+
+    impl AlgorithmState<f64> for TestState {
+        fn get_best_solution(&self) -> Option<Solution<f64>> {
+            self.best_solution
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+
+        fn get_direction(&self) -> Direction {
+            self.direction
+        }
+    }
+
+    fn threshold_and_max_iterations() -> CompositeAll<'static, f64> {
+        CompositeAll::new(vec![
+            Box::new(Threshold::new(1.0)),
+            Box::new(MaxIterations::new(10)),
+        ])
+    }
+
+    #[test]
+    fn composite_all_none_stop() {
+        let mut checker = threshold_and_max_iterations();
+        assert!(!checker.can_stop(&TestState::new(Some(10.0), 0)));
+    }
+
+    #[test]
+    fn composite_all_threshold_only() {
+        let mut checker = threshold_and_max_iterations();
+        assert!(!checker.can_stop(&TestState::new(Some(0.5), 5)));
+    }
+
+    #[test]
+    fn composite_all_max_iterations_only() {
+        let mut checker = threshold_and_max_iterations();
+        assert!(!checker.can_stop(&TestState::new(Some(10.0), 10)));
+        assert!(!checker.can_stop(&TestState::new(None, 10)));
+    }
+
+    #[test]
+    fn composite_all_stop() {
+        let mut checker = threshold_and_max_iterations();
+        assert!(checker.can_stop(&TestState::new(Some(0.5), 10)));
+    }
+
+    #[test]
+    fn composite_all_single() {
+        let mut checker: CompositeAll<f64> =
+            CompositeAll::new(vec![Box::new(MaxIterations::new(10))]);
+        assert!(!checker.can_stop(&TestState::new(None, 9)));
+        assert!(checker.can_stop(&TestState::new(None, 10)));
+    }
+
+    #[test]
+    fn composite_all_goal_not_change() {
+        // GoalNotChange must get all iterations even if MaxIterations returns false
+        let mut checker: CompositeAll<f64> = CompositeAll::new(vec![
+            Box::new(MaxIterations::new(5)),
+            Box::new(GoalNotChange::new(2, 1e-3)),
+        ]);
+
+        assert!(!checker.can_stop(&TestState::new(Some(10.0), 0)));
+        assert!(!checker.can_stop(&TestState::new(Some(5.0), 1)));
+        assert!(!checker.can_stop(&TestState::new(Some(5.0), 2)));
+        assert!(!checker.can_stop(&TestState::new(Some(5.0), 3)));
+        assert!(!checker.can_stop(&TestState::new(Some(5.0), 4)));
+        assert!(checker.can_stop(&TestState::new(Some(5.0), 5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn composite_all_empty() {
+        let _checker: CompositeAll<f64> = CompositeAll::new(vec![]);
+    }
+
+    #[test]
+    fn threshold() {
+        let mut checker = Threshold::new(1.0);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(1.5), 1)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(1.0), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(0.5), 3)));
+    }
+
+    #[test]
+    fn threshold_maximize() {
+        let mut checker = Threshold::new(1.0);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(None, 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(0.5), 1)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(1.0), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(1.5), 3)));
+    }
+
+    #[test]
+    fn wall_time_limit_not_stop() {
+        let mut checker = WallTimeLimit::new(Duration::from_secs(1000));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 1)));
+    }
+
+    #[test]
+    fn wall_time_limit_stop_after_pause() {
+        let mut checker = WallTimeLimit::new(Duration::from_millis(50));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+
+        // Slow goal function
+        thread::sleep(Duration::from_millis(60));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 1)));
+    }
+
+    #[test]
+    fn wall_time_limit_start_on_first_call() {
+        let mut checker = WallTimeLimit::new(Duration::from_millis(50));
+
+        // The time before the first call must not be counted
+        thread::sleep(Duration::from_millis(60));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+    }
+
+    #[test]
+    fn wall_time_limit_restart() {
+        let mut checker = WallTimeLimit::new(Duration::from_millis(50));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        thread::sleep(Duration::from_millis(60));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 5)));
+
+        // New run of the algorithm
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+    }
+
+    #[test]
+    fn function_call_budget() {
+        let call_count = CallCountData::new();
+        let mut checker = FunctionCallBudget::new(&call_count, 10);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+
+        call_count.add(9);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 1)));
+
+        call_count.increment();
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 2)));
+    }
+
+    #[test]
+    fn function_call_budget_next_run() {
+        let call_count = CallCountData::new();
+        call_count.add(20);
+        call_count.next_run();
+
+        let mut checker = FunctionCallBudget::new(&call_count, 10);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+    }
+
+    #[test]
+    fn relative_improvement_none() {
+        let mut checker = RelativeImprovement::new(2, 0.1);
+        for iteration in 0..10 {
+            assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, iteration)));
         }
     }
+
+    #[test]
+    fn relative_improvement_stagnation() {
+        let mut checker = RelativeImprovement::new(2, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(95.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(91.0), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(91.0), 3)));
+    }
+
+    #[test]
+    fn relative_improvement_restart() {
+        let mut checker = RelativeImprovement::new(2, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 2)));
+
+        // Improvement is 20%
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 3)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 4)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 5)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 6)));
+    }
+
+    #[test]
+    fn relative_improvement_small_steps() {
+        // The small improvements are accumulated
+        let mut checker = RelativeImprovement::new(3, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(96.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(92.0), 2)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(88.0), 3)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(88.0), 4)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(88.0), 5)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(88.0), 6)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(88.0), 7)));
+    }
+
+    #[test]
+    fn relative_improvement_negative() {
+        let mut checker = RelativeImprovement::new(1, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-100.0), 0)));
+
+        // Improvement is 20%
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-120.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-120.0), 2)));
+
+        // Improvement is 5%
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-126.0), 3)));
+    }
+
+    #[test]
+    fn relative_improvement_zero() {
+        let mut checker = RelativeImprovement::new(1, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(0.0), 0)));
+
+        // Absolute improvement is used for zero
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-0.5), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-0.5), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-0.5), 3)));
+
+        let mut checker = RelativeImprovement::new(1, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(0.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-0.01), 1)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(-0.01), 2)));
+    }
+
+    #[test]
+    fn relative_improvement_maximize() {
+        let mut checker = RelativeImprovement::new(1, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(100.0), 0)));
+
+        // Improvement is 20%
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(120.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(120.0), 2)));
+
+        // Improvement is 5%
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(126.0), 3)));
+    }
+
+    #[test]
+    fn relative_improvement_maximize_negative() {
+        let mut checker = RelativeImprovement::new(1, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(-100.0), 0)));
+
+        // Improvement is 20%
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(-80.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(-80.0), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new_max(Some(-80.0), 3)));
+    }
+
+    /// The algorithm state with agents for tests.
+    struct TestAgentsState {
+        agents: Vec<Vec<f64>>,
+        iteration: usize,
+    }
+
+    impl AlgorithmState<Vec<f64>> for TestAgentsState {
+        fn get_best_solution(&self) -> Option<Solution<Vec<f64>>> {
+            None
+        }
+
+        fn get_iteration(&self) -> usize {
+            self.iteration
+        }
+
+        fn get_agents_parameters(&self) -> Vec<&Vec<f64>> {
+            self.agents.iter().collect()
+        }
+    }
+
+    #[test]
+    fn population_convergence_converged() {
+        let mut checker = PopulationConvergence::new(0.1, 0);
+        let state = TestAgentsState {
+            agents: vec![vec![1.0, 2.0], vec![1.01, 2.0], vec![0.99, 2.05]],
+            iteration: 1,
+        };
+        assert!(checker.can_stop(&state));
+    }
+
+    #[test]
+    fn population_convergence_single_dimension_spread() {
+        let mut checker = PopulationConvergence::new(0.1, 0);
+        let state = TestAgentsState {
+            agents: vec![vec![1.0, 2.0], vec![1.01, 10.0], vec![0.99, -5.0]],
+            iteration: 1,
+        };
+        assert!(!checker.can_stop(&state));
+    }
+
+    #[test]
+    fn population_convergence_min_iterations() {
+        let mut checker = PopulationConvergence::new(0.1, 10);
+        let mut state = TestAgentsState {
+            agents: vec![vec![1.0], vec![1.0]],
+            iteration: 9,
+        };
+        assert!(!checker.can_stop(&state));
+
+        state.iteration = 10;
+        assert!(checker.can_stop(&state));
+    }
+
+    #[test]
+    fn population_convergence_few_agents() {
+        let mut checker = PopulationConvergence::new(0.1, 0);
+        let state = TestAgentsState {
+            agents: vec![vec![1.0]],
+            iteration: 1,
+        };
+        assert!(!checker.can_stop(&state));
+    }
+
+    #[test]
+    fn population_convergence_nan() {
+        let mut checker = PopulationConvergence::new(0.1, 0);
+        let state = TestAgentsState {
+            agents: vec![vec![1.0], vec![f64::NAN]],
+            iteration: 1,
+        };
+        assert!(!checker.can_stop(&state));
+    }
+
+    fn threshold_or_max_iterations() -> CompositeAny<'static, f64> {
+        CompositeAny::new(vec![
+            Box::new(Threshold::new(1.0)),
+            Box::new(MaxIterations::new(10)),
+        ])
+    }
+
+    #[test]
+    fn composite_any_triggered_none() {
+        let mut checker = threshold_or_max_iterations();
+        assert_eq!(checker.triggered_index(), None);
+
+        assert!(!checker.can_stop(&TestState::new(Some(10.0), 0)));
+        assert_eq!(checker.triggered_index(), None);
+    }
+
+    #[test]
+    fn composite_any_triggered_threshold() {
+        let mut checker = threshold_or_max_iterations();
+        assert!(checker.can_stop(&TestState::new(Some(0.5), 1)));
+        assert_eq!(checker.triggered_index(), Some(0));
+    }
+
+    #[test]
+    fn composite_any_triggered_max_iterations() {
+        let mut checker = threshold_or_max_iterations();
+        assert!(checker.can_stop(&TestState::new(Some(10.0), 10)));
+        assert_eq!(checker.triggered_index(), Some(1));
+    }
+
+    #[test]
+    fn composite_any_triggered_first() {
+        let mut checker = threshold_or_max_iterations();
+        assert!(checker.can_stop(&TestState::new(Some(0.5), 10)));
+        assert_eq!(checker.triggered_index(), Some(0));
+    }
+
+    #[test]
+    fn composite_any_triggered_reset() {
+        let mut checker = threshold_or_max_iterations();
+        assert!(checker.can_stop(&TestState::new(Some(0.5), 10)));
+        assert!(!checker.can_stop(&TestState::new(Some(10.0), 0)));
+        assert_eq!(checker.triggered_index(), None);
+    }
+
+    #[test]
+    fn composite_any_triggered_by_reference() {
+        let mut checker = threshold_or_max_iterations();
+        {
+            let mut checker_ref: Box<dyn StopChecker<f64>> = Box::new(&mut checker);
+            assert!(checker_ref.can_stop(&TestState::new(Some(10.0), 10)));
+        }
+        assert_eq!(checker.triggered_index(), Some(1));
+    }
+
+    #[test]
+    fn max_iterations_reset() {
+        let mut checker = MaxIterations::new(5);
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 5)));
+
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 5)));
+    }
+
+    #[test]
+    fn goal_not_change_reset() {
+        let mut checker = GoalNotChange::new(2, 1e-3);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(10.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 8)));
+
+        // New run of the algorithm with the same value of the goal function
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 2)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 3)));
+    }
+
+    #[test]
+    fn relative_improvement_reset() {
+        let mut checker = RelativeImprovement::new(2, 0.1);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(50.0), 8)));
+
+        // 100.0 is not an improvement for 50.0, but it is the first value after reset
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(100.0), 0)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 1)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 3)));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(80.0), 4)));
+    }
+
+    #[test]
+    fn wall_time_limit_reset() {
+        let mut checker = WallTimeLimit::new(Duration::from_millis(50));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        thread::sleep(Duration::from_millis(60));
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 5)));
+
+        // The time is counted from the first call after reset even if the iteration is not 0
+        StopChecker::<f64>::reset(&mut checker);
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 5)));
+    }
+
+    #[test]
+    fn composite_reset() {
+        let mut checker_any: CompositeAny<f64> = CompositeAny::new(vec![
+            Box::new(MaxIterations::new(100)),
+            Box::new(GoalNotChange::new(2, 1e-3)),
+        ]);
+        let mut checker_all: CompositeAll<f64> = CompositeAll::new(vec![
+            Box::new(MaxIterations::new(0)),
+            Box::new(GoalNotChange::new(2, 1e-3)),
+        ]);
+
+        for checker in [
+            &mut checker_any as &mut dyn StopChecker<f64>,
+            &mut checker_all as &mut dyn StopChecker<f64>,
+        ]
+        .iter_mut()
+        {
+            assert!(!checker.can_stop(&TestState::new(Some(5.0), 8)));
+            checker.reset();
+            assert!(!checker.can_stop(&TestState::new(Some(5.0), 0)));
+            assert!(checker.can_stop(&TestState::new(Some(5.0), 3)));
+        }
+
+        assert_eq!(checker_any.triggered_index(), Some(1));
+        checker_any.reset();
+        assert_eq!(checker_any.triggered_index(), None);
+    }
+
+    #[test]
+    fn reset_by_reference() {
+        let mut checker = GoalNotChange::new(2, 1e-3);
+        {
+            let mut checker_ref: Box<dyn StopChecker<f64>> = Box::new(&mut checker);
+            assert!(!checker_ref.can_stop(&TestState::new(Some(5.0), 8)));
+            checker_ref.reset();
+        }
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 0)));
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(format!("{:?}", MaxIterations::new(10)), "MaxIterations { max_iter: 10, iteration: 0 }");
+        assert_eq!(format!("{:?}", Threshold::new(0.5)), "Threshold { threshold: 0.5 }");
+        assert!(format!("{:?}", GoalNotChange::new(5, 0.1)).starts_with("GoalNotChange {"));
+        assert!(format!("{:?}", RelativeImprovement::new(5, 0.1)).starts_with("RelativeImprovement {"));
+        assert!(format!("{:?}", WallTimeLimit::new(Duration::from_secs(1))).contains("duration: 1s"));
+        assert!(format!("{:?}", PopulationConvergence::new(0.1, 5)).contains("min_iterations: 5"));
+
+        let call_count = CallCountData::new();
+        assert!(format!("{:?}", FunctionCallBudget::new(&call_count, 7)).contains("max_calls: 7"));
+    }
+
+    #[test]
+    fn debug_format_composite() {
+        let mut checker = threshold_or_max_iterations();
+        assert_eq!(
+            format!("{:?}", checker),
+            "CompositeAny { stop_checkers_count: 2, triggered_index: None, iteration: 0 }"
+        );
+
+        checker.can_stop(&TestState::new(Some(10.0), 10));
+        assert_eq!(
+            format!("{:?}", checker),
+            "CompositeAny { stop_checkers_count: 2, triggered_index: Some(1), iteration: 10 }"
+        );
+
+        assert_eq!(
+            format!("{:?}", threshold_and_max_iterations()),
+            "CompositeAll { stop_checkers_count: 2 }"
+        );
+    }
+
+    #[test]
+    fn goal_not_change_getters() {
+        let mut checker = GoalNotChange::new(2, 1e-3);
+        assert_eq!(checker.stagnant_iterations(), 0);
+        assert_eq!(checker.last_goal_value(), None);
+
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(None, 0)));
+        assert_eq!(checker.last_goal_value(), None);
+
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(10.0), 1)));
+        assert_eq!(checker.stagnant_iterations(), 0);
+        assert_eq!(checker.last_goal_value(), Some(10.0));
+
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(10.0001), 2)));
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(10.0), 3)));
+        assert_eq!(checker.stagnant_iterations(), 2);
+        assert_eq!(checker.last_goal_value(), Some(10.0));
+
+        assert!(StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(10.0), 4)));
+        assert_eq!(checker.stagnant_iterations(), 3);
+
+        assert!(!StopChecker::<f64>::can_stop(&mut checker, &TestState::new(Some(5.0), 5)));
+        assert_eq!(checker.stagnant_iterations(), 0);
+        assert_eq!(checker.last_goal_value(), Some(5.0));
+
+        StopChecker::<f64>::reset(&mut checker);
+        assert_eq!(checker.stagnant_iterations(), 0);
+        assert_eq!(checker.last_goal_value(), None);
+    }
+
+    #[test]
+    fn current_iteration() {
+        let mut max_iterations = MaxIterations::new(10);
+        let mut goal_not_change = GoalNotChange::new(5, 1e-3);
+        let mut composite = threshold_or_max_iterations();
+        assert_eq!(max_iterations.current_iteration(), 0);
+        assert_eq!(goal_not_change.current_iteration(), 0);
+        assert_eq!(composite.current_iteration(), 0);
+
+        for iteration in 0..4 {
+            let state = TestState::new(Some(10.0), iteration);
+            StopChecker::<f64>::can_stop(&mut max_iterations, &state);
+            StopChecker::<f64>::can_stop(&mut goal_not_change, &state);
+            composite.can_stop(&state);
+        }
+
+        assert_eq!(max_iterations.current_iteration(), 3);
+        assert_eq!(goal_not_change.current_iteration(), 3);
+        assert_eq!(composite.current_iteration(), 3);
+
+        StopChecker::<f64>::reset(&mut max_iterations);
+        StopChecker::<f64>::reset(&mut goal_not_change);
+        composite.reset();
+        assert_eq!(max_iterations.current_iteration(), 0);
+        assert_eq!(goal_not_change.current_iteration(), 0);
+        assert_eq!(composite.current_iteration(), 0);
+    }
 }
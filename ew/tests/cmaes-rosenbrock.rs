@@ -0,0 +1,105 @@
+//! Optimizing the Rosenbrock function with CMA-ES.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (1.0, 1.0, ...) for any xi.
+//! f(x') = 0
+
+use num::abs;
+
+use ew::cmaes::CmaEsOptimizer;
+use ew::tools::logging;
+use ew::tools::stopchecker;
+use ew::{AlgorithmState, GoalFromFunction, IterativeOptimizer, Optimizer};
+use ew_testfunc;
+
+fn create_stop_checker() -> stopchecker::CompositeAny<'static, Vec<f64>> {
+    stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-12)),
+        Box::new(stopchecker::MaxIterations::new(5000)),
+    ])
+}
+
+fn create_optimizer(dimension: usize, lambda: Option<usize>, seed: u64) -> CmaEsOptimizer<'static> {
+    let mut optimizer = CmaEsOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(create_stop_checker()),
+        vec![-1.0; dimension],
+        0.5,
+        lambda,
+    );
+    optimizer.set_seed(seed);
+    optimizer
+}
+
+fn check_solution(solution: &Vec<f64>, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 1.0) < 1e-3);
+    }
+
+    assert!(abs(goal_value) < 1e-6);
+}
+
+#[test]
+fn cmaes_rosenbrock_2() {
+    let mut optimizer = create_optimizer(2, None, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn cmaes_rosenbrock_10() {
+    let mut optimizer = create_optimizer(10, None, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn cmaes_rosenbrock_lambda() {
+    let mut optimizer = create_optimizer(5, Some(50), 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn cmaes_rosenbrock_seed() {
+    let result_1 = create_optimizer(5, None, 42).find_min().unwrap();
+    let result_2 = create_optimizer(5, None, 42).find_min().unwrap();
+
+    assert_eq!(result_1, result_2);
+}
+
+#[test]
+fn cmaes_rosenbrock_step() {
+    let mut optimizer = CmaEsOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(stopchecker::MaxIterations::new(10)),
+        vec![-1.0, 1.0],
+        0.5,
+        None,
+    );
+
+    let mut prev_goal_value = f64::INFINITY;
+    let mut steps = 0;
+    while let Some((_, goal_value)) = optimizer.step() {
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+        steps += 1;
+    }
+
+    assert_eq!(steps, 10);
+    assert_eq!(optimizer.get_state().get_iteration(), 10);
+    assert_eq!(optimizer.get_state().get_offspring().len(), 6);
+}
+
+#[test]
+fn cmaes_rosenbrock_logger() {
+    let mut buffer = Vec::new();
+    {
+        let mut optimizer = create_optimizer(2, None, 42);
+        optimizer.set_stop_checker(Box::new(stopchecker::MaxIterations::new(10)));
+        optimizer.set_loggers(vec![Box::new(logging::VerboseLogger::new(&mut buffer, 6))]);
+        optimizer.find_min();
+    }
+
+    assert!(!buffer.is_empty());
+}
@@ -0,0 +1,60 @@
+//! Optimizing the Schwefel function with CMA-ES.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (420.9687, 420.9687, ...) for any xi lying in [-500.0; 500.0].
+//! f(x') = 0
+
+use num::abs;
+
+use ew::cmaes::CmaEsOptimizer;
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+use ew_testfunc;
+
+type Coordinate = Vec<f64>;
+
+/// The Schwefel function with the penalty outside the search space.
+fn schwefel_bounded(x: &Coordinate) -> f64 {
+    let penalty: f64 = x.iter().map(|xi| (xi.abs() - 500.0).max(0.0).powi(2)).sum();
+    ew_testfunc::schwefel(x) + penalty
+}
+
+/// The Schwefel function has many local minima, so the tests use the large population.
+fn create_optimizer(dimension: usize, lambda: usize, seed: u64) -> CmaEsOptimizer<'static> {
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-6)),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    let mut optimizer = CmaEsOptimizer::new(
+        Box::new(GoalFromFunction::new(schwefel_bounded)),
+        Box::new(stop_checker),
+        vec![0.0; dimension],
+        300.0,
+        Some(lambda),
+    );
+    optimizer.set_seed(seed);
+    optimizer
+}
+
+fn check_solution(solution: &Coordinate, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 420.9687) < 0.1);
+    }
+
+    assert!(abs(goal_value) < 1e-3);
+}
+
+#[test]
+fn cmaes_schwefel_2() {
+    let mut optimizer = create_optimizer(2, 200, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn cmaes_schwefel_5() {
+    let mut optimizer = create_optimizer(5, 800, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
@@ -0,0 +1,127 @@
+//! Optimizing the Rosenbrock function with the differential evolution.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (1.0, 1.0, ...) for any xi.
+//! f(x') = 0
+
+use std::cell::Cell;
+
+use num::abs;
+
+use ew::de::{BinomialCrossover, DifferentialEvolutionOptimizer, RandMutation, Strategy};
+use ew::tools::stopchecker;
+use ew::{AlgorithmState, GoalFromFunction, Optimizer};
+use ew_testfunc;
+
+fn create_optimizer(strategy: Strategy) -> DifferentialEvolutionOptimizer<'static, f64> {
+    let dimension = 3;
+    let intervals = vec![(-5.0, 5.0); dimension];
+    let goal = GoalFromFunction::new(ew_testfunc::rosenbrock);
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-10)),
+        Box::new(stopchecker::MaxIterations::new(5000)),
+    ]);
+
+    let mut optimizer = DifferentialEvolutionOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        40,
+        intervals,
+        0.8,
+        0.9,
+        strategy,
+    );
+    optimizer.set_seed(42);
+    optimizer
+}
+
+fn check_solution(solution: &Vec<f64>, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 1.0) < 1e-3);
+    }
+
+    assert!(abs(goal_value) < 1e-6);
+}
+
+#[test]
+fn de_rosenbrock_rand_1_bin() {
+    let (solution, goal_value) = create_optimizer(Strategy::Rand1Bin).find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn de_rosenbrock_best_1_bin() {
+    let (solution, goal_value) = create_optimizer(Strategy::Best1Bin).find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn de_rosenbrock_custom_operators() {
+    let mut optimizer = create_optimizer(Strategy::Best1Bin);
+    optimizer.set_mutation(Box::new(RandMutation::new(0.6)));
+    optimizer.set_crossover(Box::new(BinomialCrossover::new(0.5)));
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+/// The stop checker counts its calls and stops the algorithm after the given iterations.
+struct CountingStopChecker {
+    max_iterations: usize,
+    calls: usize,
+}
+
+impl stopchecker::StopChecker<Vec<f64>> for CountingStopChecker {
+    fn can_stop(&mut self, state: &dyn AlgorithmState<Vec<f64>>) -> bool {
+        self.calls += 1;
+        state.get_iteration() >= self.max_iterations
+    }
+}
+
+#[test]
+fn de_rosenbrock_callback_nan() {
+    let population_size = 40;
+
+    // The goal function returns NaN for the first generation and the first iteration,
+    // so the algorithm has no best solution after the first iteration.
+    let goal_calls = Cell::new(0);
+    let goal = GoalFromFunction::new(|x: &Vec<f64>| {
+        goal_calls.set(goal_calls.get() + 1);
+        if goal_calls.get() <= 2 * population_size {
+            f64::NAN
+        } else {
+            ew_testfunc::rosenbrock(x)
+        }
+    });
+
+    let mut stop_checker = CountingStopChecker {
+        max_iterations: 10,
+        calls: 0,
+    };
+
+    let mut best_values = vec![];
+    let result = {
+        let mut optimizer = DifferentialEvolutionOptimizer::new(
+            Box::new(goal),
+            Box::new(&mut stop_checker),
+            population_size,
+            vec![(-5.0, 5.0); 3],
+            0.8,
+            0.9,
+            Strategy::Rand1Bin,
+        );
+        optimizer.set_seed(42);
+        optimizer.find_min_with_callback(|iteration, best: Option<(&Vec<f64>, f64)>| {
+            best_values.push((iteration, best.map(|(_, goal_value)| goal_value)));
+        })
+    };
+
+    let (_, goal_value) = result.unwrap();
+    assert_eq!(best_values.len(), 10);
+    assert_eq!(best_values[0], (1, None));
+    assert_eq!(best_values[9], (10, Some(goal_value)));
+
+    // The stop checker is called once for every iteration and once to stop the algorithm
+    assert_eq!(stop_checker.calls, 11);
+    assert_eq!(goal_calls.get(), 11 * population_size);
+}
@@ -0,0 +1,122 @@
+//! Optimizing the Schwefel function with the differential evolution.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (420.9687, 420.9687, ...) for any xi lying in [-500.0; 500.0].
+//! f(x') = 0
+
+use num::abs;
+
+use ew::de::{DifferentialEvolutionOptimizer, Strategy};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, IterativeOptimizer, Optimizer};
+use ew_testfunc;
+
+type Coordinate = Vec<f64>;
+
+fn create_optimizer(
+    strategy: Strategy,
+    f: f64,
+    cr: f64,
+    seed: u64,
+) -> DifferentialEvolutionOptimizer<'static, f64> {
+    let dimension = 5;
+    let intervals = vec![(-500.0, 500.0); dimension];
+    let goal = GoalFromFunction::new(ew_testfunc::schwefel);
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-6)),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    let mut optimizer = DifferentialEvolutionOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        50,
+        intervals,
+        f,
+        cr,
+        strategy,
+    );
+    optimizer.set_seed(seed);
+    optimizer
+}
+
+fn check_solution(solution: &Coordinate, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 420.9687) < 0.1);
+    }
+
+    assert!(abs(goal_value) < 1e-3);
+}
+
+#[test]
+fn de_schwefel_rand_1_bin() {
+    let mut optimizer = create_optimizer(Strategy::Rand1Bin, 0.5, 0.1, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn de_schwefel_best_1_bin() {
+    let mut optimizer = create_optimizer(Strategy::Best1Bin, 0.9, 0.1, 42);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn de_schwefel_seed() {
+    let result_1 = create_optimizer(Strategy::Rand1Bin, 0.5, 0.1, 1).find_min().unwrap();
+    let result_2 = create_optimizer(Strategy::Rand1Bin, 0.5, 0.1, 1).find_min().unwrap();
+
+    assert_eq!(result_1.0, result_2.0);
+    assert_eq!(result_1.1, result_2.1);
+}
+
+#[test]
+fn de_schwefel_step() {
+    let mut optimizer = create_optimizer(Strategy::Rand1Bin, 0.5, 0.1, 42);
+
+    // The best solution never gets worse
+    let mut prev_goal_value = f64::INFINITY;
+    let mut last_result = None;
+    while let Some(result) = optimizer.step() {
+        assert!(result.1 <= prev_goal_value);
+        prev_goal_value = result.1;
+
+        let population = optimizer.get_population();
+        assert_eq!(population.len(), 50);
+        for individual in population.get_individuals() {
+            for x in individual.get_coordinates() {
+                assert!(*x >= -500.0 && *x <= 500.0);
+            }
+        }
+        last_result = Some(result);
+    }
+
+    // Step by step run must give the same result as `find_min`
+    let result_step = last_result.unwrap();
+    let result_find_min = create_optimizer(Strategy::Rand1Bin, 0.5, 0.1, 42).find_min().unwrap();
+    assert_eq!(result_step.0, result_find_min.0);
+    assert_eq!(result_step.1, result_find_min.1);
+}
+
+fn schwefel_negative(x: &Coordinate) -> f64 {
+    -ew_testfunc::schwefel(x)
+}
+
+#[test]
+fn de_schwefel_max() {
+    let mut optimizer = DifferentialEvolutionOptimizer::new(
+        Box::new(GoalFromFunction::new(schwefel_negative)),
+        Box::new(stopchecker::MaxIterations::new(1000)),
+        50,
+        vec![(-500.0, 500.0); 5],
+        0.5,
+        0.1,
+        Strategy::Rand1Bin,
+    );
+    optimizer.set_seed(42);
+
+    let (solution, goal_value) = optimizer.find_max().unwrap();
+    check_solution(&solution, goal_value);
+    assert!(goal_value <= 0.0);
+}
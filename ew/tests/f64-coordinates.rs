@@ -0,0 +1,328 @@
+//! Checking that the particle swarm and genetic components work with the `f64` coordinates.
+//! The examples use `f32` mostly, the tests use the same components with `f64`.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (1.0, 2.0, ..., i + 1, ...) for any xi.
+//! f(x') = 0
+
+use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::particleswarm::{
+    self, initializing, postmove, postvelocitycalc, topology, velocitycalc, PostMove,
+    PostVelocityCalc, VelocityCalculator,
+};
+use ew::tools::{statistics, stopchecker};
+use ew::{GoalFromFunction, Optimizer};
+
+type Coordinate = f64;
+type Chromosomes = Vec<Coordinate>;
+
+const DIMENSION: usize = 3;
+const MINVAL: Coordinate = -10.0;
+const MAXVAL: Coordinate = 10.0;
+
+fn get_intervals() -> Vec<(Coordinate, Coordinate)> {
+    vec![(MINVAL, MAXVAL); DIMENSION]
+}
+
+fn get_distance_to_minimum(solution: &[Coordinate]) -> f64 {
+    solution
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (x - (i as f64 + 1.0)).abs())
+        .fold(0.0, f64::max)
+}
+
+fn run_particleswarm(
+    velocity_calculator: Box<dyn VelocityCalculator<Coordinate>>,
+    post_moves: Vec<Box<dyn PostMove<Coordinate>>>,
+    post_velocity_calc: Vec<Box<dyn PostVelocityCalc<Coordinate>>>,
+) -> (Chromosomes, f64) {
+    let particles_count = 30;
+
+    let mut coord_initializer =
+        initializing::RandomCoordinatesInitializer::new(get_intervals(), particles_count);
+    coord_initializer.set_seed(1);
+    let velocity_initializer =
+        initializing::ZeroVelocityInitializer::new(DIMENSION, particles_count);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)),
+        Box::new(stopchecker::MaxIterations::new(300)),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        velocity_calculator,
+    );
+    optimizer.set_post_moves(post_moves);
+    optimizer.set_post_velocity_calc(post_velocity_calc);
+    optimizer.find_min().unwrap()
+}
+
+#[test]
+fn particleswarm_f64_velocity_calculators() {
+    let calculators: Vec<Box<dyn VelocityCalculator<Coordinate>>> = vec![
+        Box::new(velocitycalc::ClassicVelocityCalculator::new(2.0, 2.0)),
+        Box::new(velocitycalc::CanonicalVelocityCalculator::new(
+            2.05, 2.05, 0.7663,
+        )),
+        Box::new(velocitycalc::InertiaVelocityCalculator::new(
+            1.5,
+            1.5,
+            Box::new(velocitycalc::ConstInertia::new(0.7)),
+        )),
+        Box::new(velocitycalc::InertiaVelocityCalculator::new(
+            1.5,
+            1.5,
+            Box::new(velocitycalc::LinearInertia::new(0.4, 0.9, 300)),
+        )),
+        Box::new(velocitycalc::LinearInertiaVelocityCalculator::new(
+            1.5, 1.5, 0.9, 0.4, 300,
+        )),
+        Box::new(velocitycalc::FIPSVelocityCalculator::new(
+            4.1,
+            Box::new(topology::RingTopology::new(1)),
+        )),
+        Box::new(velocitycalc::LocalBestVelocityCalculator::new(
+            2.05,
+            2.05,
+            Box::new(topology::VonNeumannTopology::new(6, 5)),
+        )),
+        Box::new(velocitycalc::BareBonesVelocityCalculator::new()),
+    ];
+
+    for calculator in calculators {
+        let post_moves: Vec<Box<dyn PostMove<Coordinate>>> =
+            vec![Box::new(postmove::MoveToBoundary::new(get_intervals()))];
+        let post_velocity_calc: Vec<Box<dyn PostVelocityCalc<Coordinate>>> =
+            vec![Box::new(postvelocitycalc::MaxVelocityAbs::new(5.0))];
+
+        let (solution, goal_value) = run_particleswarm(calculator, post_moves, post_velocity_calc);
+        assert!(get_distance_to_minimum(&solution) < 0.1);
+        assert!(goal_value < 1e-2);
+    }
+}
+
+#[test]
+fn particleswarm_f64_post_moves() {
+    let intervals = get_intervals();
+    let post_moves_list: Vec<Vec<Box<dyn PostMove<Coordinate>>>> = vec![
+        vec![Box::new(postmove::MoveToBoundary::new(intervals.clone()))],
+        vec![Box::new(postmove::ReflectBoundary::new(intervals.clone()))],
+        vec![Box::new(postmove::AbsorbingBoundary::new(
+            intervals.clone(),
+        ))],
+        vec![Box::new(postmove::ToroidalBoundary::new(intervals.clone()))],
+        vec![
+            Box::new(postmove::RandomTeleport::new(intervals.clone(), 0.001)),
+            Box::new(postmove::MoveToBoundary::new(intervals.clone())),
+        ],
+        vec![
+            Box::new(postmove::GaussianPerturbation::new(1e-3)),
+            Box::new(postmove::MoveToBoundary::new(intervals.clone())),
+        ],
+        vec![
+            Box::new(postmove::StagnationReset::new(intervals.clone(), 50)),
+            Box::new(postmove::MoveToBoundary::new(intervals.clone())),
+        ],
+    ];
+
+    for post_moves in post_moves_list {
+        let calculator = velocitycalc::CanonicalVelocityCalculator::new(2.05, 2.05, 0.7663);
+        let (solution, _) = run_particleswarm(Box::new(calculator), post_moves, vec![]);
+        assert!(get_distance_to_minimum(&solution) < 0.1);
+    }
+}
+
+#[test]
+fn particleswarm_f64_post_velocity_calc() {
+    let post_velocity_calc_list: Vec<Box<dyn PostVelocityCalc<Coordinate>>> = vec![
+        Box::new(postvelocitycalc::MaxVelocityAbs::new(5.0)),
+        Box::new(postvelocitycalc::MaxVelocityDimensions::new(vec![
+            5.0;
+            DIMENSION
+        ])),
+        Box::new(postvelocitycalc::AdaptiveMaxVelocity::new(5.0, 0.5, 300)),
+        Box::new(postvelocitycalc::AdaptiveMaxVelocity::new_exponential(
+            5.0, 0.5, 300,
+        )),
+        Box::new(postvelocitycalc::VelocityClampingWithReflection::new(5.0)),
+    ];
+
+    for post_velocity_calc in post_velocity_calc_list {
+        let calculator = velocitycalc::CanonicalVelocityCalculator::new(2.05, 2.05, 0.7663);
+        let post_moves: Vec<Box<dyn PostMove<Coordinate>>> =
+            vec![Box::new(postmove::MoveToBoundary::new(get_intervals()))];
+        let (solution, _) =
+            run_particleswarm(Box::new(calculator), post_moves, vec![post_velocity_calc]);
+        assert!(get_distance_to_minimum(&solution) < 0.1);
+    }
+}
+
+fn run_genetic(
+    creator: Box<dyn genetic::Creator<Chromosomes>>,
+    cross: Box<dyn genetic::Cross<Chromosomes>>,
+    mutation: Box<dyn genetic::Mutation<Chromosomes>>,
+    pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>>,
+) -> (Chromosomes, f64) {
+    let population_size = 100;
+    let mut pairing = pairing::Tournament::new(population_size / 2);
+    pairing.set_seed(1);
+
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::vec_float::CheckChromoInterval::new(
+            get_intervals(),
+        )),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    let mut optimizer = genetic::GeneticOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)),
+        Box::new(stopchecker::MaxIterations::new(200)),
+        creator,
+        Box::new(pairing),
+        cross,
+        mutation,
+        selections,
+        pre_births,
+    );
+    optimizer.find_min().unwrap()
+}
+
+#[test]
+fn genetic_f64_creators() {
+    let population_size = 100;
+    let creators: Vec<Box<dyn genetic::Creator<Chromosomes>>> = vec![
+        Box::new(creation::vec_float::RandomCreator::new(
+            population_size,
+            get_intervals(),
+        )),
+        Box::new(creation::vec_float::LatinHypercubeCreator::new(
+            population_size,
+            get_intervals(),
+        )),
+        Box::new(creation::vec_float::OppositionCreator::new(
+            population_size,
+            get_intervals(),
+        )),
+    ];
+
+    for creator in creators {
+        let mut cross = cross::SimulatedBinaryCrossover::new(5.0);
+        cross.set_seed(1);
+        let mutation = mutation::VecMutation::new(
+            10.0,
+            Box::new(mutation::PolynomialMutation::new(20.0, get_intervals())),
+        );
+
+        let (solution, _) = run_genetic(creator, Box::new(cross), Box::new(mutation), vec![]);
+        assert!(get_distance_to_minimum(&solution) < 0.1);
+    }
+}
+
+#[test]
+fn genetic_f64_opposition_creator_calls() {
+    let population_size = 100;
+    let call_count = statistics::CallCountData::new();
+    call_count.next_run();
+    {
+        let mut goal_object = GoalFromFunction::new(ew_testfunc::paraboloid);
+        let goal = statistics::GoalCalcStatistics::new(&mut goal_object, &call_count);
+
+        let mut optimizer = genetic::GeneticOptimizer::new(
+            Box::new(goal),
+            Box::new(stopchecker::MaxIterations::new(0)),
+            Box::new(creation::vec_float::OppositionCreator::new(
+                population_size,
+                get_intervals(),
+            )),
+            Box::new(pairing::RandomPairing::new()),
+            Box::new(cross::VecCrossAllGenes::new(Box::new(cross::CrossMean::new()))),
+            Box::new(mutation::VecMutation::new(
+                10.0,
+                Box::new(mutation::PolynomialMutation::new(20.0, get_intervals())),
+            )),
+            vec![],
+            vec![],
+        );
+        optimizer.find_min();
+    }
+
+    // The optimizer does not calculate the goal function for the created individuals again
+    assert_eq!(call_count.get_call_count()[0], 2 * population_size);
+}
+
+#[test]
+fn genetic_f64_crosses() {
+    let crosses: Vec<Box<dyn genetic::Cross<Chromosomes>>> = vec![
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::CrossMean::new(),
+        ))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::CrossBitwise::new(),
+        ))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::FloatCrossExp::new(),
+        ))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::BLXAlphaCrossover::new(0.5),
+        ))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::ArithmeticCrossover::new(0.3),
+        ))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(
+            cross::RandomArithmeticCrossover::new(),
+        ))),
+        Box::new(cross::VecCrossSinglePoint::new(Box::new(
+            cross::BLXAlphaCrossover::new(0.5),
+        ))),
+        Box::new(cross::VecCrossTwoPoint::new(Box::new(
+            cross::BLXAlphaCrossover::new(0.5),
+        ))),
+        Box::new(cross::UniformCrossover::new(0.5)),
+        Box::new(cross::SimulatedBinaryCrossover::new(5.0)),
+    ];
+
+    for cross in crosses {
+        let mut creator = creation::vec_float::RandomCreator::new(100, get_intervals());
+        creator.set_seed(1);
+        let mutation =
+            mutation::VecMutation::new(10.0, Box::new(mutation::GaussianMutation::new(0.1)));
+
+        let (solution, goal_value) =
+            run_genetic(Box::new(creator), cross, Box::new(mutation), vec![]);
+        assert!(goal_value.is_finite());
+        assert!(solution.len() == DIMENSION);
+    }
+}
+
+#[test]
+fn genetic_f64_mutations_and_pre_births() {
+    let mutations: Vec<Box<dyn genetic::Mutation<Coordinate>>> = vec![
+        Box::new(mutation::BitwiseMutation::new(1)),
+        Box::new(mutation::GaussianMutation::new(0.1)),
+        Box::new(mutation::PolynomialMutation::new(20.0, get_intervals())),
+        Box::new(mutation::NonUniformMutation::new(5.0, 200, get_intervals())),
+    ];
+
+    for single_mutation in mutations {
+        let mut creator = creation::vec_float::RandomCreator::new(100, get_intervals());
+        creator.set_seed(1);
+        let cross = cross::SimulatedBinaryCrossover::new(5.0);
+        let mutation = mutation::VecMutation::new(10.0, single_mutation);
+        let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![
+            Box::new(pre_birth::vec_float::WrapAroundRepair::new(get_intervals())),
+            Box::new(pre_birth::vec_float::RepairToBoundary::new(get_intervals())),
+            Box::new(pre_birth::vec_float::CheckChromoInterval::new(
+                get_intervals(),
+            )),
+        ];
+
+        let (solution, _) = run_genetic(
+            Box::new(creator),
+            Box::new(cross),
+            Box::new(mutation),
+            pre_births,
+        );
+        assert!(get_distance_to_minimum(&solution) < 0.5);
+    }
+}
@@ -0,0 +1,128 @@
+//! Solving the bounded knapsack problem with the integer-valued genetic algorithm.
+//!
+//! Every gene is the count of the items of the same kind in the knapsack (from 0 to the
+//! maximum count). The goal is the total value of the items. If the total weight exceeds
+//! the capacity, the goal is the negative overweight.
+
+use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+
+type Chromosomes = Vec<i64>;
+
+const WEIGHTS: [i64; 8] = [12, 7, 11, 8, 9, 23, 5, 14];
+const VALUES: [i64; 8] = [24, 13, 23, 15, 16, 49, 8, 27];
+const MAX_COUNTS: [i64; 8] = [3, 2, 3, 1, 2, 2, 3, 1];
+const CAPACITY: i64 = 100;
+
+fn get_weight_value(x: &[i64]) -> (i64, i64) {
+    x.iter()
+        .enumerate()
+        .fold((0, 0), |(weight, value), (i, count)| {
+            (weight + count * WEIGHTS[i], value + count * VALUES[i])
+        })
+}
+
+fn knapsack(x: &Chromosomes) -> f64 {
+    let (weight, value) = get_weight_value(x);
+    if weight <= CAPACITY {
+        value as f64
+    } else {
+        (CAPACITY - weight) as f64
+    }
+}
+
+/// Find the best value by the exhaustive search.
+fn get_best_value() -> f64 {
+    let mut best_value = 0;
+    let mut x = vec![0_i64; MAX_COUNTS.len()];
+    loop {
+        let (weight, value) = get_weight_value(&x);
+        if weight <= CAPACITY && value > best_value {
+            best_value = value;
+        }
+
+        // Next combination of the counts
+        let mut i = 0;
+        while i < x.len() && x[i] == MAX_COUNTS[i] {
+            x[i] = 0;
+            i += 1;
+        }
+
+        if i == x.len() {
+            break;
+        }
+        x[i] += 1;
+    }
+
+    best_value as f64
+}
+
+fn create_optimizer(seed: u64) -> genetic::GeneticOptimizer<'static, Chromosomes> {
+    let population_size = 100;
+    let intervals: Vec<(i64, i64)> = MAX_COUNTS.iter().map(|&count| (0, count)).collect();
+
+    let mut creator = creation::vec_int::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2);
+    pairing.set_seed(seed);
+
+    let mut cross = cross::UniformCrossover::new(0.5);
+    cross.set_seed(seed);
+
+    let mut single_mutation = mutation::RandomResetMutation::new(intervals.clone());
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(10.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![Box::new(
+        pre_birth::vec_int::CheckChromoInterval::new(intervals),
+    )];
+
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    genetic::GeneticOptimizer::new(
+        Box::new(GoalFromFunction::new(knapsack)),
+        Box::new(stopchecker::MaxIterations::new(100)),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(mutation),
+        selections,
+        pre_births,
+    )
+}
+
+#[test]
+fn genetic_int_knapsack() {
+    let best_value = get_best_value();
+    let run_count = 5;
+    let mut optimum_count = 0;
+
+    for seed in 0..run_count {
+        let mut optimizer = create_optimizer(seed);
+        let (solution, goal_value) = optimizer.find_max().unwrap();
+
+        for individual in optimizer.get_current_population() {
+            for (gene, max_count) in individual.get_chromosomes().iter().zip(MAX_COUNTS.iter()) {
+                assert!(*gene >= 0);
+                assert!(gene <= max_count);
+            }
+        }
+
+        let (weight, value) = get_weight_value(&solution);
+        assert!(weight <= CAPACITY);
+        assert_eq!(value as f64, goal_value);
+        assert!(goal_value >= best_value * 0.95);
+
+        if goal_value == best_value {
+            optimum_count += 1;
+        }
+    }
+
+    assert!(optimum_count > 0);
+}
@@ -0,0 +1,109 @@
+//! Optimizing the Schwefel function with the island model of the genetic algorithm.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (420.9687, 420.9687, ...) for any xi lying in [-500.0; 500.0].
+//! f(x') = 0
+
+use num::abs;
+
+use ew::genetic::island::{IslandGeneticOptimizer, MigrationPolicy};
+use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+use ew_testfunc;
+
+/// Gene type
+type Gene = f32;
+
+/// Chromosomes type
+type Chromosomes = Vec<Gene>;
+
+const CHROMO_COUNT: usize = 5;
+
+fn create_island<'a>(population_size: usize, parallel: bool) -> genetic::GeneticOptimizer<'a, Chromosomes> {
+    let intervals = vec![(-500.0, 500.0); CHROMO_COUNT];
+
+    let creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    let pairing = pairing::Tournament::new(population_size / 2).rounds_count(5);
+    let cross = cross::VecCrossAllGenes::new(Box::new(cross::FloatCrossExp::new()));
+    let single_mutation = mutation::BitwiseMutation::new(3);
+    let mutation = mutation::VecMutation::new(15.0, Box::new(single_mutation));
+
+    // The stop checker of the island is not used by the island optimizer
+    let builder = genetic::GeneticOptimizer::builder()
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(0)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(
+            pre_birth::vec_float::CheckChromoInterval::new(intervals),
+        )])
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ]);
+
+    let goal = GoalFromFunction::new(ew_testfunc::schwefel);
+
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return builder.parallel_goal(Box::new(goal)).build();
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    assert!(!parallel, "the parallel goal function requires the \"parallel\" feature");
+
+    builder.goal(Box::new(goal)).build()
+}
+
+fn create_optimizer<'a>(policy: MigrationPolicy, parallel: bool) -> IslandGeneticOptimizer<'a, Chromosomes> {
+    let islands = (0..4).map(|_| create_island(200, parallel)).collect();
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-4)),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    let mut optimizer = IslandGeneticOptimizer::new(islands, Box::new(stop_checker), 20, 5);
+    optimizer.set_migration_policy(policy);
+    optimizer
+}
+
+fn check_solution(solution: &Chromosomes, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 421.0) < 0.1);
+    }
+
+    assert!(abs(goal_value) < 1e-3);
+}
+
+#[test]
+fn genetic_island_schwefel_ring() {
+    let (solution, goal_value) = create_optimizer(MigrationPolicy::Ring, false).find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn genetic_island_schwefel_random() {
+    let mut optimizer = create_optimizer(MigrationPolicy::Random, false);
+    optimizer.set_seed(1);
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn genetic_island_schwefel_fully_connected() {
+    let (solution, goal_value) = create_optimizer(MigrationPolicy::FullyConnected, false)
+        .find_min()
+        .unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn genetic_island_schwefel_parallel_goal() {
+    let (solution, goal_value) = create_optimizer(MigrationPolicy::Ring, true)
+        .find_min()
+        .unwrap();
+    check_solution(&solution, goal_value);
+}
@@ -1,9 +1,11 @@
 use num::abs;
 
-use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::genetic::{
+    self, creation, cross, mutation, pairing, population_size, pre_birth, selection,
+};
 use ew::tools::logging;
 use ew::tools::stopchecker;
-use ew::{GoalFromFunction, Optimizer};
+use ew::{GoalFromFunction, IterativeOptimizer, Optimizer};
 use ew_testfunc;
 
 type Gene = f32;
@@ -94,3 +96,714 @@ fn genetic_paraboloid() {
         }
     }
 }
+
+fn paraboloid_negative(x: &Chromosomes) -> f64 {
+    -ew_testfunc::paraboloid(x)
+}
+
+#[test]
+fn genetic_paraboloid_max() {
+    // General parameters
+    let minval: Gene = -100.0;
+    let maxval: Gene = 100.0;
+    let population_size = 800;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    // Goal function
+    let goal = GoalFromFunction::new(paraboloid_negative);
+
+    // Creator
+    let creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+
+    // Pairing
+    let families_count = population_size / 2;
+    let partners_count = 2;
+    let rounds_count = 2;
+    let pairing = pairing::Tournament::new(families_count)
+        .partners_count(partners_count)
+        .rounds_count(rounds_count);
+
+    // Cross
+    let single_cross = cross::FloatCrossExp::new();
+    let cross = cross::VecCrossAllGenes::new(Box::new(single_cross));
+
+    // Mutation
+    let mutation_probability = 15.0;
+    let mutation_gene_count = 3;
+    let single_mutation = mutation::BitwiseMutation::new(mutation_gene_count);
+    let mutation = mutation::VecMutation::new(mutation_probability, Box::new(single_mutation));
+
+    // Pre birth
+    let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![Box::new(
+        pre_birth::vec_float::CheckChromoInterval::new(intervals.clone()),
+    )];
+
+    // Selection
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    // Stop checker
+    let change_max_iterations = 150;
+    let change_delta = 1e-7;
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::GoalNotChange::new(
+            change_max_iterations,
+            change_delta,
+        )),
+        Box::new(stopchecker::MaxIterations::new(5000)),
+    ]);
+
+    let mut optimizer = genetic::GeneticOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(mutation),
+        selections,
+        pre_births,
+    );
+
+    // Run genetic algorithm
+    match optimizer.find_max() {
+        None => assert!(false),
+        Some((solution, goal_value)) => {
+            for i in 0..chromo_count {
+                assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.1);
+            }
+
+            assert!(goal_value <= 0.0);
+            assert!(abs(goal_value) < 1e-3);
+        }
+    }
+}
+
+fn create_seeded_optimizer(seed: u64) -> genetic::GeneticOptimizer<'static, Chromosomes> {
+    // General parameters
+    let minval: Gene = -100.0;
+    let maxval: Gene = 100.0;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    // Goal function
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+
+    // Creator
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    // Pairing
+    let families_count = population_size / 2;
+    let mut pairing = pairing::Tournament::new(families_count).rounds_count(2);
+    pairing.set_seed(seed);
+
+    // Cross
+    let mut single_cross = cross::FloatCrossExp::new();
+    single_cross.set_seed(seed);
+    let cross = cross::VecCrossAllGenes::new(Box::new(single_cross));
+
+    // Mutation
+    let mutation_probability = 15.0;
+    let mutation_gene_count = 3;
+    let mut single_mutation = mutation::BitwiseMutation::new(mutation_gene_count);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(mutation_probability, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    // Pre birth
+    let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![Box::new(
+        pre_birth::vec_float::CheckChromoInterval::new(intervals.clone()),
+    )];
+
+    // Selection
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    // Stop checker
+    let stop_checker = stopchecker::MaxIterations::new(100);
+
+    genetic::GeneticOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(creator),
+        Box::new(pairing),
+        Box::new(cross),
+        Box::new(mutation),
+        selections,
+        pre_births,
+    )
+}
+
+fn run_seeded_optimizer(seed: u64) -> Option<(Chromosomes, f64)> {
+    create_seeded_optimizer(seed).find_min()
+}
+
+#[test]
+fn genetic_paraboloid_seed() {
+    let result_1 = run_seeded_optimizer(42).unwrap();
+    let result_2 = run_seeded_optimizer(42).unwrap();
+
+    assert_eq!(result_1.0, result_2.0);
+    assert_eq!(result_1.1, result_2.1);
+}
+
+#[test]
+fn genetic_paraboloid_builder() {
+    let seed = 42;
+    let minval: Gene = -100.0;
+    let maxval: Gene = 100.0;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let mut single_cross = cross::FloatCrossExp::new();
+    single_cross.set_seed(seed);
+
+    let mut single_mutation = mutation::BitwiseMutation::new(3);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(15.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    let mut optimizer = genetic::GeneticOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(100)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(single_cross))))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(pre_birth::vec_float::CheckChromoInterval::new(intervals))])
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build();
+
+    // The builder must create the same optimizer as the constructor
+    let result_builder = optimizer.find_min().unwrap();
+    let result_new = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_builder.0, result_new.0);
+    assert_eq!(result_builder.1, result_new.1);
+}
+
+#[test]
+fn genetic_paraboloid_step() {
+    let seed = 42;
+    let mut optimizer = create_seeded_optimizer(seed);
+
+    // The stop checker allows 100 generations
+    let mut steps = 0;
+    let mut last_result = None;
+    while let Some(result) = optimizer.step() {
+        steps += 1;
+        last_result = Some(result);
+    }
+
+    assert_eq!(steps, 100);
+    assert_eq!(optimizer.step(), None);
+
+    // Step by step run must give the same result as `find_min`
+    let result_step = last_result.unwrap();
+    let result_find_min = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_step.0, result_find_min.0);
+    assert_eq!(result_step.1, result_find_min.1);
+}
+
+#[test]
+fn genetic_paraboloid_reset() {
+    let mut optimizer = create_seeded_optimizer(42);
+
+    for _ in 0..10 {
+        optimizer.step().unwrap();
+    }
+
+    optimizer.reset();
+
+    // After reset the stop checker allows 100 generations again
+    let mut steps = 0;
+    while optimizer.step().is_some() {
+        steps += 1;
+    }
+
+    assert_eq!(steps, 100);
+}
+
+#[test]
+fn genetic_paraboloid_introspection() {
+    let mut optimizer = create_seeded_optimizer(42);
+
+    assert_eq!(optimizer.get_best_individual(), None);
+    assert!(optimizer.get_current_population().is_empty());
+
+    for _ in 0..10 {
+        let (solution, goal_value) = optimizer.step().unwrap();
+
+        let (best_chromosomes, best_goal_value) = optimizer.get_best_individual().unwrap();
+        assert_eq!(best_chromosomes, &solution);
+        assert_eq!(best_goal_value, goal_value);
+
+        // Population size is limited by the selection
+        let population = optimizer.get_current_population();
+        assert_eq!(population.len(), 100);
+        for individual in population {
+            assert!(individual.get_fitness() >= best_goal_value);
+        }
+    }
+}
+
+#[test]
+fn genetic_paraboloid_csv_logger() {
+    let mut output = vec![];
+    {
+        let mut optimizer = create_seeded_optimizer(42);
+        let loggers: Vec<Box<dyn logging::Logger<Chromosomes>>> =
+            vec![Box::new(logging::CsvLogger::new(&mut output))];
+        optimizer.set_loggers(loggers);
+        optimizer.find_min();
+    }
+
+    let output = String::from_utf8(output).unwrap();
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("iteration,goal_value,elapsed_ms"));
+
+    // The best individual passes to the next generation, so goal value can't increase
+    let mut prev_goal_value = f64::INFINITY;
+    let mut rows_count = 0;
+    for line in lines {
+        let columns: Vec<&str> = line.split(',').collect();
+        assert_eq!(columns.len(), 3);
+
+        let iteration: usize = columns[0].parse().unwrap();
+        let goal_value: f64 = columns[1].parse().unwrap();
+        columns[2].parse::<u128>().unwrap();
+
+        rows_count += 1;
+        assert_eq!(iteration, rows_count);
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+    }
+
+    assert_eq!(rows_count, 100);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn genetic_paraboloid_parallel_goal() {
+    let seed = 42;
+    let minval: Gene = -100.0;
+    let maxval: Gene = 100.0;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(minval, maxval); chromo_count];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let mut single_cross = cross::FloatCrossExp::new();
+    single_cross.set_seed(seed);
+
+    let mut single_mutation = mutation::BitwiseMutation::new(3);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(15.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    let mut optimizer = genetic::GeneticOptimizer::builder()
+        .parallel_goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(100)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(single_cross))))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(pre_birth::vec_float::CheckChromoInterval::new(intervals))])
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build();
+
+    // Parallel calculation of the goal function must not change the result
+    let result_parallel = optimizer.find_min().unwrap();
+    let result_sequential = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_parallel.0, result_sequential.0);
+    assert_eq!(result_parallel.1, result_sequential.1);
+}
+
+#[test]
+fn genetic_paraboloid_self_adaptive() {
+    type SelfAdaptiveChromosomes = mutation::SelfAdaptiveMutationChromosome<f64>;
+
+    let seed = 42;
+    let population_size = 100;
+    let chromo_count = 5;
+    let intervals = vec![(-100.0_f64, 100.0_f64); chromo_count];
+
+    let goal = GoalFromFunction::new(|x: &SelfAdaptiveChromosomes| {
+        ew_testfunc::paraboloid(&x.variables)
+    });
+
+    let initial_sigma = 10.0;
+    let mut creator =
+        creation::vec_float::SelfAdaptiveCreator::new(population_size, intervals, initial_sigma);
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let cross = cross::SelfAdaptiveCross::new(
+        Box::new(cross::VecCrossAllGenes::new(Box::new(cross::CrossMean::new()))),
+        Box::new(cross::VecCrossAllGenes::new(Box::new(cross::CrossMean::new()))),
+    );
+
+    let mut mutation = mutation::SelfAdaptiveMutation::new();
+    mutation.set_seed(seed);
+
+    let mut optimizer = genetic::GeneticOptimizer::builder()
+        .goal(Box::new(goal))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(1000)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross))
+        .mutation(Box::new(mutation))
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build();
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    for i in 0..chromo_count {
+        assert!(abs(solution.variables[i] - (i as f64 + 1.0)) < 0.1);
+    }
+
+    assert!(goal_value < 1e-3);
+}
+
+#[test]
+fn genetic_paraboloid_non_uniform_mutation() {
+    let seed = 42;
+    let population_size = 100;
+    let chromo_count = 5;
+    let max_generations = 300;
+    let intervals = vec![(-100.0_f64, 100.0_f64); chromo_count];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let mut single_cross = cross::BLXAlphaCrossover::new(0.5);
+    single_cross.set_seed(seed);
+
+    let mut single_mutation = mutation::NonUniformMutation::new(5.0, max_generations, intervals);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(20.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    let mut optimizer = genetic::GeneticOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(max_generations)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(single_cross))))
+        .mutation(Box::new(mutation))
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build();
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    for i in 0..chromo_count {
+        assert!(abs(solution[i] - (i as f64 + 1.0)) < 0.1);
+    }
+
+    assert!(goal_value < 1e-3);
+}
+
+/// The selection kills every second individual regardless of the goal function.
+struct KillEverySecond;
+
+impl genetic::Selection<Chromosomes> for KillEverySecond {
+    fn kill(&mut self, population: &mut genetic::Population<Chromosomes>) {
+        for (n, individual) in population.iter_mut().enumerate() {
+            if n % 2 == 1 {
+                individual.kill();
+            }
+        }
+    }
+}
+
+#[test]
+fn genetic_paraboloid_elitism() {
+    let mut optimizer = create_seeded_optimizer(42);
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::ElitismSelection::new(5)),
+        Box::new(KillEverySecond),
+        Box::new(selection::LimitPopulation::new(100)),
+    ];
+    optimizer.set_selection(selections);
+
+    // The best solution is never lost
+    let mut prev_goal_value = f64::INFINITY;
+    while let Some((_, goal_value)) = optimizer.step() {
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+
+        // The protection is removed after the selection
+        assert!(optimizer
+            .get_current_population()
+            .iter()
+            .all(|individual| !individual.is_protected()));
+    }
+}
+
+#[test]
+fn genetic_paraboloid_age_based() {
+    let max_age = 3;
+    let mut optimizer = create_seeded_optimizer(42);
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::ElitismSelection::new(1)),
+        Box::new(selection::AgeBasedSelection::new(max_age)),
+        Box::new(selection::LimitPopulation::new(100)),
+    ];
+    optimizer.set_selection(selections);
+
+    let mut generation = 0;
+    let mut prev_goal_value = f64::INFINITY;
+    while let Some((_, goal_value)) = optimizer.step() {
+        generation += 1;
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+
+        // Only the elite individual may be older than max age
+        let old_count = optimizer
+            .get_current_population()
+            .iter()
+            .filter(|individual| generation - 1 - individual.get_birth_generation() > max_age)
+            .count();
+        assert!(old_count <= 1);
+    }
+}
+
+#[test]
+fn genetic_paraboloid_repair_to_boundary() {
+    let seed = 42;
+    let population_size = 100;
+
+    // The optimum (1, 2, 3, 4, 5) lies outside the search space for the last genes
+    let intervals: Vec<(Gene, Gene)> = vec![(-2.0, 2.0); 5];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let mut single_cross = cross::BLXAlphaCrossover::new(0.5);
+    single_cross.set_seed(seed);
+
+    let mut single_mutation = mutation::GaussianMutation::new(0.5);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(20.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    let mut optimizer = genetic::GeneticOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(200)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(single_cross))))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(pre_birth::vec_float::RepairToBoundary::new(
+            intervals.clone(),
+        ))])
+        .selections(vec![
+            Box::new(selection::KillFitnessNaN::new()),
+            Box::new(selection::LimitPopulation::new(population_size)),
+        ])
+        .build();
+
+    let mut last_result = None;
+    while let Some(result) = optimizer.step() {
+        for individual in optimizer.get_current_population() {
+            for (gene, interval) in individual.get_chromosomes().iter().zip(intervals.iter()) {
+                assert!(*gene >= interval.0);
+                assert!(*gene <= interval.1);
+            }
+        }
+        last_result = Some(result);
+    }
+
+    // The last genes of the solution lie on the boundary
+    let (solution, _) = last_result.unwrap();
+    let expected = [1.0, 2.0, 2.0, 2.0, 2.0];
+    for (gene, expected_gene) in solution.iter().zip(expected.iter()) {
+        assert!(abs(gene - expected_gene) < 0.1);
+    }
+}
+
+fn create_controlled_optimizer(
+    seed: u64,
+    controller: Box<dyn genetic::PopulationSizeController<Chromosomes>>,
+) -> genetic::GeneticOptimizer<'static, Chromosomes> {
+    let population_size = 100;
+    let intervals: Vec<(Gene, Gene)> = vec![(-100.0, 100.0); 5];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2).rounds_count(2);
+    pairing.set_seed(seed);
+
+    let mut single_cross = cross::BLXAlphaCrossover::new(0.5);
+    single_cross.set_seed(seed);
+
+    let mut single_mutation = mutation::GaussianMutation::new(0.5);
+    single_mutation.set_seed(seed);
+    let mut mutation = mutation::VecMutation::new(20.0, Box::new(single_mutation));
+    mutation.set_seed(seed);
+
+    // The population size is limited by the controller only
+    genetic::GeneticOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(200)))
+        .creator(Box::new(creator))
+        .pairing(Box::new(pairing))
+        .cross(Box::new(cross::VecCrossAllGenes::new(Box::new(single_cross))))
+        .mutation(Box::new(mutation))
+        .pre_births(vec![Box::new(
+            pre_birth::vec_float::CheckChromoInterval::new(intervals),
+        )])
+        .selections(vec![Box::new(selection::KillFitnessNaN::new())])
+        .population_size_controller(controller)
+        .build()
+}
+
+struct ConstantSizeController {
+    size: usize,
+}
+
+impl genetic::PopulationSizeController<Chromosomes> for ConstantSizeController {
+    fn desired_size(&mut self, _current_population: &[genetic::Individual<Chromosomes>]) -> usize {
+        self.size
+    }
+}
+
+#[test]
+fn genetic_paraboloid_population_size_constant() {
+    // The first generation contains 100 individuals
+    for &size in &[30, 250] {
+        let mut optimizer =
+            create_controlled_optimizer(42, Box::new(ConstantSizeController { size }));
+
+        while optimizer.step().is_some() {
+            assert_eq!(optimizer.get_current_population().len(), size);
+        }
+    }
+}
+
+#[test]
+fn genetic_paraboloid_population_size_controller() {
+    let min_size = 50;
+    let max_size = 200;
+    let controller = population_size::DiversityBasedController::new(
+        min_size,
+        max_size,
+        1.0,
+        Box::new(population_size::vec_float::get_average_distance),
+    );
+    let mut optimizer = create_controlled_optimizer(42, Box::new(controller));
+
+    let mut last_result = None;
+    while let Some(result) = optimizer.step() {
+        let size = optimizer.get_current_population().len();
+        assert!(size >= min_size);
+        assert!(size <= max_size);
+        last_result = Some(result);
+    }
+
+    let (solution, _) = last_result.unwrap();
+    for (n, gene) in solution.iter().enumerate() {
+        assert!(abs(gene - (n as Gene + 1.0)) < 0.1);
+    }
+}
+
+#[cfg(feature = "checkpoint")]
+#[test]
+fn genetic_paraboloid_checkpoint() {
+    let path = std::env::temp_dir().join(format!(
+        "ew-genetic-paraboloid-checkpoint-{}.json",
+        std::process::id()
+    ));
+
+    let mut optimizer = create_seeded_optimizer(42);
+    let mut saved_result = None;
+    for _ in 0..50 {
+        saved_result = optimizer.step();
+    }
+    let saved_result = saved_result.unwrap();
+    optimizer.save_checkpoint(&path).unwrap();
+
+    // The other seed must not change the loaded population
+    let mut loaded_optimizer = create_seeded_optimizer(1);
+    loaded_optimizer.load_checkpoint(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let population = optimizer.get_current_population();
+    let loaded_population = loaded_optimizer.get_current_population();
+    assert_eq!(loaded_population.len(), population.len());
+    for (loaded_individual, individual) in loaded_population.iter().zip(population) {
+        assert_eq!(loaded_individual.get_chromosomes(), individual.get_chromosomes());
+        assert_eq!(loaded_individual.get_fitness(), individual.get_fitness());
+        assert_eq!(
+            loaded_individual.get_birth_generation(),
+            individual.get_birth_generation()
+        );
+    }
+
+    assert_eq!(
+        loaded_optimizer.get_best_individual(),
+        optimizer.get_best_individual()
+    );
+
+    // The generation number is restored, so the stop checker allows 50 generations more
+    let mut steps = 0;
+    let mut last_result = None;
+    while let Some(result) = loaded_optimizer.step() {
+        steps += 1;
+        last_result = Some(result);
+    }
+
+    assert_eq!(steps, 50);
+    assert!(last_result.unwrap().1 <= saved_result.1);
+}
+
+#[cfg(feature = "checkpoint")]
+#[test]
+fn genetic_paraboloid_checkpoint_missing_file() {
+    let path = std::env::temp_dir().join("ew-genetic-missing-checkpoint.json");
+    let mut optimizer = create_seeded_optimizer(42);
+
+    let error = optimizer.load_checkpoint(&path).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
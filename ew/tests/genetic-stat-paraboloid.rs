@@ -2,22 +2,28 @@ use ew::genetic::{
     self, creation, cross, mutation, pairing, pre_birth, selection, GeneticOptimizer,
 };
 use ew::tools::{statistics, logging, stopchecker};
-use ew::{GoalFromFunction, Optimizer};
+use ew::{Goal, GoalFromFunction, Optimizer};
 use ew_testfunc;
 
 type Gene = f32;
 type Chromosomes = Vec<Gene>;
 
 fn _create_optimizer<'a>(chromo_count: usize) -> GeneticOptimizer<'a, Chromosomes> {
+    // Goal function
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+    create_optimizer_with_goal(chromo_count, Box::new(goal))
+}
+
+fn create_optimizer_with_goal<'a>(
+    chromo_count: usize,
+    goal: Box<dyn Goal<Chromosomes> + 'a>,
+) -> GeneticOptimizer<'a, Chromosomes> {
     // General parameters
     let minval: Gene = -100.0;
     let maxval: Gene = 100.0;
     let population_size = 800;
     let intervals = vec![(minval, maxval); chromo_count];
 
-    // Goal function
-    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
-
     // Creator
     let creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
 
@@ -63,7 +69,7 @@ fn _create_optimizer<'a>(chromo_count: usize) -> GeneticOptimizer<'a, Chromosome
     ]);
 
     let optimizer = genetic::GeneticOptimizer::new(
-        Box::new(goal),
+        goal,
         Box::new(stop_checker),
         Box::new(creator),
         Box::new(pairing),
@@ -141,3 +147,33 @@ fn genetic_paraboloid_two() {
     assert_eq!(result_2.0, *stat_solution_2);
     assert_eq!(result_2.1, *stat_goal_2);
 }
+
+#[test]
+fn genetic_paraboloid_run_parallel() {
+    let run_count = 5;
+    let threads_count = 2;
+    let chromo_count = 3;
+
+    let (statistics, call_count) = statistics::run_parallel(
+        || Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)),
+        |goal, logger| {
+            let mut optimizer = create_optimizer_with_goal(chromo_count, goal);
+            optimizer.set_loggers(vec![logger]);
+            Box::new(optimizer)
+        },
+        run_count,
+        threads_count,
+    );
+
+    assert_eq!(statistics.get_run_count(), run_count);
+    assert_eq!(statistics.get_convergence().len(), run_count);
+    assert_eq!(call_count.get_call_count().len(), run_count);
+
+    for result in statistics.get_results() {
+        assert!(result.is_some());
+    }
+
+    for count in call_count.get_call_count() {
+        assert!(count > 0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Solving the traveling salesman problem with the genetic algorithm for the permutation
+//! chromosomes.
+//!
+//! The cities lie on the circle, the index of the city does not match the position of the city
+//! on the circle. The shortest tour visits the cities in the order of the position on the
+//! circle, the tour length is the perimeter of the regular polygon.
+
+use std::f64::consts::PI;
+
+use ew::genetic::{self, creation, cross, mutation, pairing, pre_birth, selection};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+
+type Chromosomes = Vec<usize>;
+
+const CITIES_COUNT: usize = 12;
+const RADIUS: f64 = 10.0;
+
+fn get_city(index: usize) -> (f64, f64) {
+    // 5 and 12 are coprime, so every position on the circle has single city
+    let angle = 2.0 * PI * ((index * 5) % CITIES_COUNT) as f64 / CITIES_COUNT as f64;
+    (RADIUS * angle.cos(), RADIUS * angle.sin())
+}
+
+fn tour_length(tour: &Chromosomes) -> f64 {
+    (0..tour.len())
+        .map(|n| {
+            let (x_1, y_1) = get_city(tour[n]);
+            let (x_2, y_2) = get_city(tour[(n + 1) % tour.len()]);
+            ((x_2 - x_1).powi(2) + (y_2 - y_1).powi(2)).sqrt()
+        })
+        .sum()
+}
+
+fn get_best_length() -> f64 {
+    CITIES_COUNT as f64 * 2.0 * RADIUS * (PI / CITIES_COUNT as f64).sin()
+}
+
+fn create_optimizer<'a>(
+    cross: Box<dyn genetic::Cross<Chromosomes> + 'a>,
+    mutation: Box<dyn genetic::Mutation<Chromosomes> + 'a>,
+    seed: u64,
+) -> genetic::GeneticOptimizer<'a, Chromosomes> {
+    let population_size = 100;
+
+    let mut creator =
+        creation::permutation::RandomPermutationCreator::new(population_size, CITIES_COUNT);
+    creator.set_seed(seed);
+
+    let mut pairing = pairing::Tournament::new(population_size / 2);
+    pairing.set_seed(seed);
+
+    let pre_births: Vec<Box<dyn genetic::PreBirth<Chromosomes>>> = vec![Box::new(
+        pre_birth::permutation::ValidPermutationChecker::new(CITIES_COUNT),
+    )];
+
+    let selections: Vec<Box<dyn genetic::Selection<Chromosomes>>> = vec![
+        Box::new(selection::KillFitnessNaN::new()),
+        Box::new(selection::LimitPopulation::new(population_size)),
+    ];
+
+    genetic::GeneticOptimizer::new(
+        Box::new(GoalFromFunction::new(tour_length)),
+        Box::new(stopchecker::MaxIterations::new(300)),
+        Box::new(creator),
+        Box::new(pairing),
+        cross,
+        mutation,
+        selections,
+        pre_births,
+    )
+}
+
+/// Run the optimizer for several seeds and check the found tours.
+fn check_tsp(
+    create_cross: &dyn Fn(u64) -> Box<dyn genetic::Cross<Chromosomes>>,
+    create_mutation: &dyn Fn(u64) -> Box<dyn genetic::Mutation<Chromosomes>>,
+) {
+    let best_length = get_best_length();
+    let run_count = 5;
+    let mut optimum_count = 0;
+
+    for seed in 0..run_count {
+        let mut optimizer = create_optimizer(create_cross(seed), create_mutation(seed), seed);
+        let (solution, goal_value) = optimizer.find_min().unwrap();
+
+        for individual in optimizer.get_current_population() {
+            assert!(pre_birth::permutation::is_permutation(
+                individual.get_chromosomes()
+            ));
+        }
+
+        assert!((tour_length(&solution) - goal_value).abs() < 1e-10);
+        assert!(goal_value < best_length * 1.1);
+
+        if goal_value < best_length + 1e-6 {
+            optimum_count += 1;
+        }
+    }
+
+    assert!(optimum_count > 0);
+}
+
+#[test]
+fn genetic_tsp_order_crossover_swap_mutation() {
+    check_tsp(
+        &|seed| {
+            let mut cross = cross::OrderCrossover::new();
+            cross.set_seed(seed);
+            Box::new(cross)
+        },
+        &|seed| {
+            let mut mutation = mutation::SwapMutation::new(5.0);
+            mutation.set_seed(seed);
+            Box::new(mutation)
+        },
+    );
+}
+
+#[test]
+fn genetic_tsp_partially_mapped_crossover_inversion_mutation() {
+    check_tsp(
+        &|seed| {
+            let mut cross = cross::PartiallyMappedCrossover::new();
+            cross.set_seed(seed);
+            Box::new(cross)
+        },
+        &|seed| {
+            let mut mutation = mutation::InversionMutation::new(20.0);
+            mutation.set_seed(seed);
+            Box::new(mutation)
+        },
+    );
+}
+
+#[test]
+fn genetic_tsp_order_crossover_scramble_mutation() {
+    check_tsp(
+        &|seed| {
+            let mut cross = cross::OrderCrossover::new();
+            cross.set_seed(seed);
+            Box::new(cross)
+        },
+        &|seed| {
+            let mut mutation = mutation::ScrambleMutation::new(20.0);
+            mutation.set_seed(seed);
+            Box::new(mutation)
+        },
+    );
+}
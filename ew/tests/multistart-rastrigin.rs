@@ -0,0 +1,98 @@
+//! Optimizing the Rastrigin function with the multi-start Nelder-Mead algorithm.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (0.0, 0.0, ...) for any xi.
+//! f(x') = 0
+
+use std::sync::Mutex;
+
+use num::abs;
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use ew::multistart::MultiStartOptimizer;
+use ew::neldermead::NelderMeadOptimizer;
+use ew::tools::statistics::StatFunctionsGoal;
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+use ew_testfunc;
+
+fn create_optimizer<'a>(
+    start: Option<&Vec<f64>>,
+    random: &Mutex<StdRng>,
+) -> Box<dyn Optimizer<Vec<f64>> + 'a> {
+    let dimension = 2;
+    let initial_point = match start {
+        Some(point) => point.clone(),
+        None => {
+            let between = Uniform::new_inclusive(-5.12, 5.12);
+            let mut random = random.lock().unwrap();
+            (0..dimension)
+                .map(|_| between.sample(&mut *random))
+                .collect()
+        }
+    };
+
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-12)),
+        Box::new(stopchecker::MaxIterations::new(500)),
+    ]);
+
+    Box::new(NelderMeadOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rastrigin)),
+        Box::new(stop_checker),
+        initial_point,
+        vec![0.5; dimension],
+    ))
+}
+
+fn check_solution(solution: &Vec<f64>, goal_value: f64) {
+    for x in solution {
+        assert!(abs(*x) < 1e-3);
+    }
+
+    assert!(goal_value < 1e-6);
+}
+
+#[test]
+fn multistart_rastrigin() {
+    let random = Mutex::new(StdRng::seed_from_u64(1));
+    let mut optimizer = MultiStartOptimizer::new(|start| create_optimizer(start, &random), 100);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+
+    let statistics = optimizer.get_statistics();
+    assert_eq!(statistics.get_run_count(), 100);
+    assert_eq!(statistics.get_best_result().unwrap().1, goal_value);
+    assert!(statistics.get_results().get_average_goal().unwrap() > goal_value);
+}
+
+#[test]
+fn multistart_rastrigin_warm_start() {
+    let random = Mutex::new(StdRng::seed_from_u64(1));
+    let mut optimizer = MultiStartOptimizer::new(|start| create_optimizer(start, &random), 100);
+    optimizer.set_warm_start_fraction(0.2);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+
+    // The warm starts begin from the best solution, so they can not be worse
+    let results = optimizer.get_statistics().get_results();
+    for (_, value) in results[80..].iter().flatten() {
+        assert!(*value < 1e-6);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn multistart_rastrigin_parallel() {
+    let random = Mutex::new(StdRng::seed_from_u64(1));
+    let mut optimizer = MultiStartOptimizer::new(|start| create_optimizer(start, &random), 100);
+    optimizer.set_warm_start_fraction(0.2);
+
+    let (solution, goal_value) = optimizer.find_min_parallel().unwrap();
+    check_solution(&solution, goal_value);
+    assert_eq!(optimizer.get_statistics().get_run_count(), 100);
+}
@@ -0,0 +1,105 @@
+//! Optimizing the Rosenbrock function with the Nelder-Mead algorithm.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (1.0, 1.0, ...) for any xi.
+//! f(x') = 0
+
+use num::abs;
+
+use ew::neldermead::NelderMeadOptimizer;
+use ew::tools::logging;
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, IterativeOptimizer, Optimizer};
+use ew_testfunc;
+
+fn create_stop_checker() -> stopchecker::CompositeAny<'static, Vec<f64>> {
+    stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(1e-12)),
+        Box::new(stopchecker::MaxIterations::new(5000)),
+    ])
+}
+
+fn check_solution(solution: &Vec<f64>, goal_value: f64) {
+    for x in solution {
+        assert!(abs(x - 1.0) < 1e-3);
+    }
+
+    assert!(abs(goal_value) < 1e-6);
+}
+
+#[test]
+fn neldermead_rosenbrock_steps() {
+    let mut optimizer = NelderMeadOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(create_stop_checker()),
+        vec![-1.2, 1.0, 0.5],
+        vec![0.5, 0.5, 0.5],
+    );
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn neldermead_rosenbrock_vertices() {
+    let mut optimizer = NelderMeadOptimizer::from_vertices(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(create_stop_checker()),
+        vec![vec![-2.0, 2.0], vec![-1.0, 2.0], vec![-2.0, 3.0]],
+    );
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn neldermead_rosenbrock_coefficients() {
+    let mut optimizer = NelderMeadOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(create_stop_checker()),
+        vec![-1.2, 1.0],
+        vec![0.1, 0.1],
+    );
+    optimizer.set_coefficients(1.0, 0.75, 1.5, 0.8);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn neldermead_rosenbrock_step() {
+    let mut optimizer = NelderMeadOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+        Box::new(stopchecker::MaxIterations::new(10)),
+        vec![-1.2, 1.0],
+        vec![0.5, 0.5],
+    );
+
+    let mut prev_goal_value = f64::INFINITY;
+    let mut steps = 0;
+    while let Some((_, goal_value)) = optimizer.step() {
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+        steps += 1;
+    }
+
+    assert_eq!(steps, 10);
+    assert_eq!(optimizer.get_simplex().get_vertices().len(), 3);
+}
+
+#[test]
+fn neldermead_rosenbrock_logger() {
+    let mut buffer = Vec::new();
+    {
+        let mut optimizer = NelderMeadOptimizer::new(
+            Box::new(GoalFromFunction::new(ew_testfunc::rosenbrock)),
+            Box::new(stopchecker::MaxIterations::new(10)),
+            vec![-1.2, 1.0],
+            vec![0.5, 0.5],
+        );
+        optimizer.set_loggers(vec![Box::new(logging::VerboseLogger::new(&mut buffer, 6))]);
+        optimizer.find_min();
+    }
+
+    assert!(!buffer.is_empty());
+}
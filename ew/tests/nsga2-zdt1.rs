@@ -0,0 +1,134 @@
+//! Multi-objective optimization of the ZDT1 function with NSGA-II.
+//!
+//! f1(x) = x0,
+//! f2(x) = g(x) * (1 - sqrt(x0 / g(x))),
+//! g(x) = 1 + 9 * (x1 + x2 + ... + xn) / (n - 1),
+//! where xi lies in [0.0; 1.0].
+//! The Pareto front is f2 = 1 - sqrt(f1) for g(x) = 1 (x1 = x2 = ... = xn = 0).
+
+use ew::genetic::{creation, cross, mutation, Mutation};
+use ew::nsga2::{MultiObjectiveGoalFromFunction, Nsga2Optimizer};
+use ew::tools::statistics::ParetoFront;
+use ew::tools::stopchecker;
+
+type Coordinate = Vec<f64>;
+
+fn zdt1(x: &Coordinate) -> Vec<f64> {
+    let n = x.len();
+    let g = 1.0 + 9.0 * x[1..].iter().sum::<f64>() / (n - 1) as f64;
+    let f1 = x[0];
+    let f2 = g * (1.0 - (f1 / g).sqrt());
+    vec![f1, f2]
+}
+
+/// The mutation which limits the genes by the interval [0; 1] after the inner mutation.
+/// The simulated binary crossover may create the genes outside the interval.
+struct LimitedMutation {
+    mutation: mutation::VecMutation<f64>,
+}
+
+impl Mutation<Coordinate> for LimitedMutation {
+    fn mutation(&mut self, chromosomes: &Coordinate) -> Coordinate {
+        self.mutation
+            .mutation(chromosomes)
+            .iter()
+            .map(|x| x.max(0.0).min(1.0))
+            .collect()
+    }
+}
+
+fn create_optimizer(
+    dimension: usize,
+    population_size: usize,
+    iterations: usize,
+    seed: u64,
+) -> Nsga2Optimizer<'static, Coordinate> {
+    let intervals = vec![(0.0, 1.0); dimension];
+
+    let mut creator = creation::vec_float::RandomCreator::new(population_size, intervals.clone());
+    creator.set_seed(seed);
+
+    let mut cross = cross::SimulatedBinaryCrossover::new(15.0);
+    cross.set_seed(seed);
+
+    // The mutation probability is 1 / n
+    let mut single_mutation = mutation::PolynomialMutation::new(20.0, intervals);
+    single_mutation.set_seed(seed);
+    let mut vec_mutation =
+        mutation::VecMutation::new(100.0 / dimension as f64, Box::new(single_mutation));
+    vec_mutation.set_seed(seed);
+
+    let mut optimizer = Nsga2Optimizer::new(
+        Box::new(MultiObjectiveGoalFromFunction::new(zdt1)),
+        Box::new(stopchecker::MaxIterations::new(iterations)),
+        Box::new(creator),
+        Box::new(cross),
+        Box::new(LimitedMutation {
+            mutation: vec_mutation,
+        }),
+    );
+    optimizer.set_seed(seed);
+    optimizer
+}
+
+#[test]
+fn nsga2_zdt1() {
+    let mut optimizer = create_optimizer(30, 100, 500, 42);
+    let front = optimizer.find_pareto_front();
+
+    // The whole population converges to the Pareto front
+    assert_eq!(front.len(), 100);
+    for (x, objectives) in &front {
+        assert_eq!(objectives, &zdt1(x));
+        assert!(x.iter().all(|&xi| (0.0..=1.0).contains(&xi)));
+
+        let f1 = objectives[0];
+        let f2 = objectives[1];
+        assert!(f2 - (1.0 - f1.sqrt()) < 0.05);
+    }
+
+    // The front covers the whole range of f1
+    let min_f1 = front
+        .iter()
+        .map(|(_, objectives)| objectives[0])
+        .fold(1.0, f64::min);
+    let max_f1 = front
+        .iter()
+        .map(|(_, objectives)| objectives[0])
+        .fold(0.0, f64::max);
+    assert!(min_f1 < 0.01);
+    assert!(max_f1 > 0.99);
+
+    // The hypervolume of the true Pareto front is 2 / 3 + 0.21 for the reference point
+    let mut pareto_front = ParetoFront::new();
+    for (x, objectives) in front {
+        pareto_front.insert(x, objectives);
+    }
+    assert!(pareto_front.hypervolume(&[1.1, 1.1]) > 0.85);
+}
+
+#[test]
+fn nsga2_zdt1_seed() {
+    let front_1 = create_optimizer(10, 40, 20, 42).find_pareto_front();
+    let front_2 = create_optimizer(10, 40, 20, 42).find_pareto_front();
+    assert_eq!(front_1, front_2);
+}
+
+#[test]
+fn nsga2_zdt1_step() {
+    let mut optimizer = create_optimizer(10, 40, 10, 42);
+
+    let mut steps = 0;
+    while let Some(front) = optimizer.step() {
+        assert!(!front.is_empty());
+        for (_, objectives) in &front {
+            assert!(front
+                .iter()
+                .all(|(_, other)| !ew::nsga2::dominates(other, objectives)));
+        }
+        steps += 1;
+    }
+
+    assert_eq!(steps, 10);
+    assert_eq!(optimizer.get_population().len(), 40);
+}
@@ -0,0 +1,108 @@
+//! Solving the binary knapsack problem with the binary particle swarm.
+//!
+//! Every coordinate is 0 or 1 and shows if the item is put into the knapsack.
+//! The goal is the total value of the items. If the total weight exceeds the capacity,
+//! the goal is the negative overweight.
+
+use ew::particleswarm::{
+    self, initializing, postmove, velocitycalc, ParticleSwarmOptimizer, PostMove,
+};
+use ew::tools::stopchecker;
+use ew::{GoalFromFunction, Optimizer};
+
+const WEIGHTS: [u32; 15] = [23, 31, 29, 44, 53, 38, 63, 85, 89, 82, 12, 27, 41, 57, 35];
+const VALUES: [u32; 15] = [92, 57, 49, 68, 60, 43, 67, 84, 87, 72, 21, 38, 55, 70, 44];
+const CAPACITY: u32 = 300;
+
+fn get_weight_value(bits: &[bool]) -> (u32, u32) {
+    bits.iter()
+        .enumerate()
+        .filter(|(_, &bit)| bit)
+        .fold((0, 0), |(weight, value), (i, _)| {
+            (weight + WEIGHTS[i], value + VALUES[i])
+        })
+}
+
+fn knapsack(x: &Vec<f64>) -> f64 {
+    let bits: Vec<bool> = x.iter().map(|&item| item == 1.0).collect();
+    let (weight, value) = get_weight_value(&bits);
+    if weight <= CAPACITY {
+        value as f64
+    } else {
+        CAPACITY as f64 - weight as f64
+    }
+}
+
+/// Find the best value by the exhaustive search.
+fn get_best_value() -> f64 {
+    let count = WEIGHTS.len();
+    (0_u32..(1 << count))
+        .map(|mask| {
+            let bits: Vec<bool> = (0..count).map(|i| mask & (1 << i) != 0).collect();
+            get_weight_value(&bits)
+        })
+        .filter(|(weight, _)| *weight <= CAPACITY)
+        .map(|(_, value)| value as f64)
+        .fold(0.0, f64::max)
+}
+
+fn create_optimizer(seed: u64) -> ParticleSwarmOptimizer<'static, f64> {
+    let particles_count = 30;
+    let dimension = WEIGHTS.len();
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(
+        vec![(0.0, 1.0); dimension],
+        particles_count,
+    );
+    coord_initializer.set_seed(seed);
+    let velocity_initializer =
+        initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    let mut velocity_calculator = velocitycalc::BinaryVelocityCalculator::new(2.0, 2.0, 4.0);
+    velocity_calculator.set_seed(seed);
+
+    let mut binary_post_move = postmove::BinaryPostMove::new();
+    binary_post_move.set_seed(seed);
+    let post_moves: Vec<Box<dyn PostMove<f64>>> = vec![Box::new(binary_post_move)];
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(GoalFromFunction::new(knapsack)),
+        Box::new(stopchecker::MaxIterations::new(200)),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        Box::new(velocity_calculator),
+    );
+    optimizer.set_post_moves(post_moves);
+    optimizer
+}
+
+#[test]
+fn test_particleswarm_knapsack() {
+    let best_value = get_best_value();
+    let run_count = 5;
+    let mut optimum_count = 0;
+
+    for seed in 0..run_count {
+        let mut optimizer = create_optimizer(seed);
+        let (solution, goal_value) = optimizer.find_max().unwrap();
+
+        for particle in optimizer.get_particles() {
+            assert!(particle
+                .get_coordinates()
+                .iter()
+                .all(|&item| item == 0.0 || item == 1.0));
+        }
+
+        let bits: Vec<bool> = solution.iter().map(|&item| item == 1.0).collect();
+        let (weight, value) = get_weight_value(&bits);
+        assert!(weight <= CAPACITY);
+        assert_eq!(value as f64, goal_value);
+        assert!(goal_value >= best_value * 0.95);
+
+        if goal_value == best_value {
+            optimum_count += 1;
+        }
+    }
+
+    assert!(optimum_count > 0);
+}
@@ -1,12 +1,15 @@
 use num::abs;
 
 use ew::{
-    tools::{logging, stopchecker},
-    GoalFromFunction, Optimizer,
+    tools::{logging, statistics, stopchecker},
+    GoalFromFunction, IterativeOptimizer, Optimizer,
     particleswarm::{
         self,
         initializing,
         postmove,
+        postvelocitycalc,
+        resizer,
+        topology,
         velocitycalc,
         PostMove,
     },
@@ -77,3 +80,867 @@ fn test_particleswarm_paraboloid() {
         }
     }
 }
+
+fn paraboloid_negative(x: &Vec<Coordinate>) -> f64 {
+    -ew_testfunc::paraboloid(x)
+}
+
+#[test]
+fn test_particleswarm_paraboloid_max() {
+    // General parameters
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 150;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi_personal = 2.0;
+    let phi_global = 6.0;
+    let k = 0.2;
+
+    // Goal function
+    let goal = GoalFromFunction::new(paraboloid_negative);
+
+    let seed = 42;
+
+    // Particles initializers
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    // PostMove
+    let post_moves: Vec<Box<dyn PostMove<Coordinate>>> = vec![Box::new(postmove::MoveToBoundary::new(intervals.clone()))];
+
+    // Velocity calculator
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
+    velocity_calculator.set_seed(seed);
+
+    // Stop checker
+    let change_max_iterations = 150;
+    let change_delta = 1e-7;
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::GoalNotChange::new(
+            change_max_iterations,
+            change_delta,
+        )),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    // Logger
+    let mut statistics_data = statistics::Statistics::new();
+    let result;
+    {
+        let loggers: Vec<Box<dyn logging::Logger<Vec<Coordinate>>>> =
+            vec![Box::new(statistics::StatisticsLogger::new(&mut statistics_data))];
+
+        let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+            Box::new(goal),
+            Box::new(stop_checker),
+            Box::new(coord_initializer),
+            Box::new(velocity_initializer),
+            Box::new(velocity_calculator),
+            );
+        optimizer.set_loggers(loggers);
+        optimizer.set_post_moves(post_moves);
+
+        result = optimizer.find_max();
+    }
+
+    match result {
+        None => assert!(false),
+        Some((solution, goal_value)) => {
+            for i in 0..dimension {
+                assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.3);
+            }
+
+            assert!(goal_value <= 0.0);
+            assert!(abs(goal_value) < 0.1);
+
+            // Statistics must store not negated goal values
+            let (_, stat_goal) = statistics_data.get_results()[0].as_ref().unwrap();
+            assert_eq!(*stat_goal, goal_value);
+        }
+    }
+}
+
+#[test]
+fn test_particleswarm_paraboloid_max_threshold() {
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 150;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+
+    // The threshold is the min value of the goal function for maximization
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::Threshold::new(-1e-6)),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    let seed = 42;
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.0, 6.0, 0.2);
+    velocity_calculator.set_seed(seed);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(paraboloid_negative)))
+        .stop_checker(Box::new(stop_checker))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(
+            dimension,
+            particles_count,
+        )))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(vec![Box::new(postmove::MoveToBoundary::new(intervals))])
+        .build();
+
+    let (solution, goal_value) = optimizer.find_max().unwrap();
+    for i in 0..dimension {
+        assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.3);
+    }
+
+    assert!(goal_value <= 0.0);
+    assert!(abs(goal_value) < 0.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_max_relative_improvement() {
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 150;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+
+    // The improvement for maximization is the increase of the goal function
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::RelativeImprovement::new(10, 1e-3)),
+        Box::new(stopchecker::MaxIterations::new(3000)),
+    ]);
+
+    let seed = 42;
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.0, 6.0, 0.2);
+    velocity_calculator.set_seed(seed);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(paraboloid_negative)))
+        .stop_checker(Box::new(stop_checker))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(
+            dimension,
+            particles_count,
+        )))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(vec![Box::new(postmove::MoveToBoundary::new(intervals))])
+        .build();
+
+    let (solution, goal_value) = optimizer.find_max().unwrap();
+    for i in 0..dimension {
+        assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.3);
+    }
+
+    assert!(goal_value <= 0.0);
+    assert!(abs(goal_value) < 0.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_call_budget() {
+    // General parameters
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 150;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi_personal = 2.0;
+    let phi_global = 6.0;
+    let k = 0.2;
+    let max_calls = 1000;
+
+    let call_count = statistics::CallCountData::new();
+    call_count.next_run();
+    {
+        // Goal function
+        let mut goal_object = GoalFromFunction::new(ew_testfunc::paraboloid);
+        let goal = statistics::GoalCalcStatistics::new(&mut goal_object, &call_count);
+
+        // Particles initializers
+        let coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+        let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+        // Velocity calculator
+        let velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
+
+        // Stop checker
+        let stop_checker = stopchecker::CompositeAny::new(vec![
+            Box::new(stopchecker::FunctionCallBudget::new(&call_count, max_calls)),
+            Box::new(stopchecker::MaxIterations::new(3000)),
+        ]);
+
+        let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+            Box::new(goal),
+            Box::new(stop_checker),
+            Box::new(coord_initializer),
+            Box::new(velocity_initializer),
+            Box::new(velocity_calculator),
+            );
+
+        assert!(optimizer.find_min().is_some());
+    }
+
+    // The budget is checked after every iteration
+    let calls = call_count.get_call_count()[0];
+    assert!(calls >= max_calls);
+    assert!(calls < max_calls + particles_count);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_population_convergence() {
+    // General parameters
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 150;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi_personal = 2.0;
+    let phi_global = 6.0;
+    let k = 0.2;
+
+    // Goal function
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+
+    // Particles initializers
+    let coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    // Velocity calculator
+    let velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
+
+    // Stop checker
+    let max_iterations = 3000;
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::PopulationConvergence::new(1e-3, 10)),
+        Box::new(stopchecker::MaxIterations::new(max_iterations)),
+    ]);
+
+    // Logger
+    let mut statistics_data = statistics::Statistics::new();
+    let result;
+    {
+        let loggers: Vec<Box<dyn logging::Logger<Vec<Coordinate>>>> =
+            vec![Box::new(statistics::StatisticsLogger::new(&mut statistics_data))];
+
+        let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+            Box::new(goal),
+            Box::new(stop_checker),
+            Box::new(coord_initializer),
+            Box::new(velocity_initializer),
+            Box::new(velocity_calculator),
+            );
+        optimizer.set_loggers(loggers);
+
+        result = optimizer.find_min();
+    }
+
+    // The swarm must converge before max iterations
+    let iterations = statistics_data.get_convergence()[0].len();
+    assert!(iterations >= 10);
+    assert!(iterations < max_iterations);
+
+    let (solution, _) = result.unwrap();
+    for i in 0..dimension {
+        assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.3);
+    }
+}
+
+fn create_seeded_optimizer(seed: u64) -> particleswarm::ParticleSwarmOptimizer<'static, Coordinate> {
+    // General parameters
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 50;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi_personal = 2.0;
+    let phi_global = 6.0;
+    let k = 0.2;
+    let teleport_probability = 0.05;
+
+    // Goal function
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+
+    // Particles initializers
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    // PostMove
+    let mut teleport = postmove::RandomTeleport::new(intervals.clone(), teleport_probability);
+    teleport.set_seed(seed);
+    let post_moves: Vec<Box<dyn PostMove<Coordinate>>> = vec![
+        Box::new(teleport),
+        Box::new(postmove::MoveToBoundary::new(intervals.clone())),
+    ];
+
+    // Velocity calculator
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(phi_personal, phi_global, k);
+    velocity_calculator.set_seed(seed);
+
+    // Stop checker
+    let stop_checker = stopchecker::MaxIterations::new(100);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        Box::new(velocity_calculator),
+        );
+    optimizer.set_post_moves(post_moves);
+
+    optimizer
+}
+
+fn run_seeded_optimizer(seed: u64) -> Option<(Vec<Coordinate>, f64)> {
+    create_seeded_optimizer(seed).find_min()
+}
+
+#[test]
+fn test_particleswarm_paraboloid_seed() {
+    let result_1 = run_seeded_optimizer(42).unwrap();
+    let result_2 = run_seeded_optimizer(42).unwrap();
+
+    assert_eq!(result_1.0, result_2.0);
+    assert_eq!(result_1.1, result_2.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_callback() {
+    let mut calls = vec![];
+    let result_1 = create_seeded_optimizer(42)
+        .find_min_with_callback(|iteration, best: Option<(&Vec<Coordinate>, f64)>| {
+            calls.push((iteration, best.unwrap().1));
+        })
+        .unwrap();
+    let result_2 = run_seeded_optimizer(42).unwrap();
+
+    assert_eq!(result_1, result_2);
+    assert_eq!(calls.len(), 100);
+    assert_eq!(calls[0].0, 1);
+    assert_eq!(calls[99], (100, result_2.1));
+}
+
+#[test]
+fn test_particleswarm_paraboloid_builder() {
+    let seed = 42;
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 50;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+
+    let mut teleport = postmove::RandomTeleport::new(intervals.clone(), 0.05);
+    teleport.set_seed(seed);
+
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.0, 6.0, 0.2);
+    velocity_calculator.set_seed(seed);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(100)))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(dimension, particles_count)))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(vec![
+            Box::new(teleport),
+            Box::new(postmove::MoveToBoundary::new(intervals)),
+        ])
+        .build();
+
+    // The builder must create the same optimizer as the constructor
+    let result_builder = optimizer.find_min().unwrap();
+    let result_new = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_builder.0, result_new.0);
+    assert_eq!(result_builder.1, result_new.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_step() {
+    let seed = 42;
+    let mut optimizer = create_seeded_optimizer(seed);
+
+    // The stop checker allows 100 iterations
+    let mut steps = 0;
+    let mut last_result = None;
+    while let Some(result) = optimizer.step() {
+        steps += 1;
+        last_result = Some(result);
+    }
+
+    assert_eq!(steps, 100);
+    assert_eq!(optimizer.step(), None);
+
+    // Step by step run must give the same result as `find_min`
+    let result_step = last_result.unwrap();
+    let result_find_min = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_step.0, result_find_min.0);
+    assert_eq!(result_step.1, result_find_min.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_add_remove_particle() {
+    let mut optimizer = create_seeded_optimizer(42);
+    optimizer.step();
+    assert_eq!(optimizer.get_particles().len(), 50);
+
+    // The particle in the minimum of the paraboloid
+    optimizer.add_particle(vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![0.0; 5]);
+    assert_eq!(optimizer.get_particles().len(), 51);
+    assert_eq!(optimizer.get_particles()[50].get_value(), 0.0);
+
+    optimizer.remove_particle(50);
+    optimizer.remove_particle(0);
+    assert_eq!(optimizer.get_particles().len(), 49);
+
+    // The invalid index is ignored
+    optimizer.remove_particle(49);
+    assert_eq!(optimizer.get_particles().len(), 49);
+
+    // The best solution is kept after the removing
+    let (solution, goal_value) = optimizer.step().unwrap();
+    assert_eq!(solution, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(goal_value, 0.0);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_diversity_resizer() {
+    let particles_count = 30;
+    let min_particles = 10;
+    let max_particles = 60;
+    let dimension = 5;
+    let intervals = vec![(-100.0, 100.0); dimension];
+    let seed = 42;
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.0, 6.0, 0.2);
+    velocity_calculator.set_seed(seed);
+
+    // The swarm grows when the particles gather together
+    let mut resizer = resizer::DiversityBasedResizer::new(min_particles, max_particles, 1.0);
+    resizer.set_seed(seed);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+        .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(500)))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(dimension, particles_count)))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(vec![Box::new(postmove::MoveToBoundary::new(intervals))])
+        .swarm_resizer(Box::new(resizer))
+        .build();
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    assert_eq!(optimizer.get_particles().len(), max_particles);
+
+    for (i, x) in solution.iter().enumerate() {
+        assert!(abs(x - (i as f32 + 1.0)) < 0.1);
+    }
+    assert!(goal_value < 0.01);
+}
+
+/// The resizer which tries to remove all the particles and the particle outside the swarm.
+struct RemoveAllResizer;
+
+impl particleswarm::SwarmResizer<Coordinate> for RemoveAllResizer {
+    fn resize(&mut self, swarm: &particleswarm::Swarm<Coordinate>, changes: &mut particleswarm::SwarmChanges<Coordinate>) {
+        for index in 0..=swarm.len() {
+            changes.remove_particle(index);
+        }
+    }
+}
+
+#[test]
+fn test_particleswarm_paraboloid_remove_all_resizer() {
+    let mut optimizer = create_seeded_optimizer(42);
+    optimizer.set_swarm_resizer(Box::new(RemoveAllResizer));
+
+    // The last particle is kept in the swarm
+    assert!(optimizer.find_min().is_some());
+    assert_eq!(optimizer.get_particles().len(), 1);
+
+    optimizer.remove_particle(0);
+    assert_eq!(optimizer.get_particles().len(), 1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_reset() {
+    let mut optimizer = create_seeded_optimizer(42);
+
+    for _ in 0..10 {
+        optimizer.step().unwrap();
+    }
+
+    optimizer.reset();
+
+    // After reset the stop checker allows 100 iterations again
+    let mut steps = 0;
+    while optimizer.step().is_some() {
+        steps += 1;
+    }
+
+    assert_eq!(steps, 100);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_reset_stop_checker() {
+    let particles_count = 30;
+    let dimension = 5;
+    let intervals = vec![(-100.0, 100.0); dimension];
+    let mut stop_checker = stopchecker::GoalNotChange::new(10, 1e-7);
+
+    {
+        let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+            .goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+            .stop_checker(Box::new(&mut stop_checker))
+            .coordinates_initializer(Box::new(initializing::RandomCoordinatesInitializer::new(
+                intervals,
+                particles_count,
+            )))
+            .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(
+                dimension,
+                particles_count,
+            )))
+            .velocity_calculator(Box::new(velocitycalc::CanonicalVelocityCalculator::new(
+                2.0, 6.0, 0.2,
+            )))
+            .build();
+
+        for _ in 0..5 {
+            optimizer.step().unwrap();
+        }
+
+        optimizer.reset();
+    }
+
+    // The stop checker must forget the previous running
+    assert_eq!(stop_checker.current_iteration(), 0);
+    assert_eq!(stop_checker.last_goal_value(), None);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_get_particles() {
+    let mut optimizer = create_seeded_optimizer(42);
+    assert!(optimizer.get_particles().is_empty());
+
+    for _ in 0..10 {
+        let (_, goal_value) = optimizer.step().unwrap();
+
+        let particles = optimizer.get_particles();
+        assert_eq!(particles.len(), 50);
+
+        for particle in particles {
+            assert_eq!(particle.get_coordinates().len(), 5);
+            assert_eq!(particle.get_velocity().len(), 5);
+            assert_eq!(particle.get_best_personal_coordinates().len(), 5);
+            assert!(particle.get_best_personal_value() <= particle.get_value());
+            assert!(particle.get_value() >= goal_value);
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn particleswarm_paraboloid_parallel_goal() {
+    let seed = 42;
+    let particles_count = 50;
+    let dimension = 5;
+    let intervals = vec![(-100.0, 100.0); dimension];
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+
+    let mut teleport = postmove::RandomTeleport::new(intervals.clone(), 0.05);
+    teleport.set_seed(seed);
+
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.0, 6.0, 0.2);
+    velocity_calculator.set_seed(seed);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::builder()
+        .parallel_goal(Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)))
+        .stop_checker(Box::new(stopchecker::MaxIterations::new(100)))
+        .coordinates_initializer(Box::new(coord_initializer))
+        .velocity_initializer(Box::new(initializing::ZeroVelocityInitializer::new(dimension, particles_count)))
+        .velocity_calculator(Box::new(velocity_calculator))
+        .post_moves(vec![
+            Box::new(teleport),
+            Box::new(postmove::MoveToBoundary::new(intervals)),
+        ])
+        .build();
+
+    // Parallel calculation of the goal function must not change the result
+    let result_parallel = optimizer.find_min().unwrap();
+    let result_sequential = run_seeded_optimizer(seed).unwrap();
+
+    assert_eq!(result_parallel.0, result_sequential.0);
+    assert_eq!(result_parallel.1, result_sequential.1);
+}
+
+#[test]
+fn test_particleswarm_paraboloid_fips() {
+    let seed = 42;
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 50;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+    let phi = 4.1;
+
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    let post_moves: Vec<Box<dyn PostMove<Coordinate>>> = vec![Box::new(postmove::MoveToBoundary::new(intervals.clone()))];
+
+    let mut velocity_calculator =
+        velocitycalc::FIPSVelocityCalculator::new(phi, Box::new(topology::RingTopology::new(2)));
+    velocity_calculator.set_seed(seed);
+
+    let stop_checker = stopchecker::MaxIterations::new(500);
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        Box::new(velocity_calculator),
+        );
+    optimizer.set_post_moves(post_moves);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+    for i in 0..dimension {
+        assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.3);
+    }
+
+    assert!(abs(goal_value) < 0.1);
+}
+
+fn create_optimizer_with_velocity_calculator(
+    velocity_calculator: Box<dyn particleswarm::VelocityCalculator<Coordinate>>,
+    seed: u64,
+) -> particleswarm::ParticleSwarmOptimizer<'static, Coordinate> {
+    let minval: Coordinate = -100.0;
+    let maxval: Coordinate = 100.0;
+    let particles_count = 50;
+    let dimension = 5;
+    let intervals = vec![(minval, maxval); dimension];
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(seed);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+
+    // The classic velocity equation does not restrict the velocity, so the velocity is limited
+    let post_velocity_calc: Vec<Box<dyn particleswarm::PostVelocityCalc<Coordinate>>> =
+        vec![Box::new(postvelocitycalc::MaxVelocityAbs::new(10.0))];
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(GoalFromFunction::new(ew_testfunc::paraboloid)),
+        Box::new(stopchecker::MaxIterations::new(300)),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        velocity_calculator,
+        );
+    optimizer.set_post_moves(vec![Box::new(postmove::MoveToBoundary::new(intervals))]);
+    optimizer.set_post_velocity_calc(post_velocity_calc);
+    optimizer
+}
+
+#[test]
+fn test_particleswarm_paraboloid_classic_vs_canonical() {
+    let run_count = 10;
+    let mut classic_sum = 0.0;
+    let mut canonical_sum = 0.0;
+    for seed in 0..run_count {
+        let mut classic = velocitycalc::ClassicVelocityCalculator::new(2.0, 2.0);
+        classic.set_seed(seed);
+        let (solution, goal_value) = create_optimizer_with_velocity_calculator(Box::new(classic), seed)
+            .find_min()
+            .unwrap();
+        for i in 0..solution.len() {
+            assert!(abs(solution[i] - (i as f32 + 1.0)) < 1.0);
+        }
+        classic_sum += goal_value;
+
+        // The constriction coefficient xi = 0.7298
+        let mut canonical = velocitycalc::CanonicalVelocityCalculator::new(2.05, 2.05, 0.7663);
+        canonical.set_seed(seed);
+        let (_, goal_value) = create_optimizer_with_velocity_calculator(Box::new(canonical), seed)
+            .find_min()
+            .unwrap();
+        canonical_sum += goal_value;
+    }
+
+    let classic_average = classic_sum / run_count as f64;
+    let canonical_average = canonical_sum / run_count as f64;
+
+    // Both calculators find the neighborhood of the minimum, but the constriction coefficient
+    // gives the faster convergence
+    assert!(classic_average < 1.0);
+    assert!(canonical_average < 1e-6);
+    assert!(canonical_average < classic_average);
+}
+
+fn check_local_best(create_topology: &dyn Fn() -> Box<dyn topology::Topology>) {
+    for seed in 0..3 {
+        let mut velocity_calculator = velocitycalc::LocalBestVelocityCalculator::new(2.05, 2.05, create_topology());
+        velocity_calculator.set_seed(seed);
+        let (solution, goal_value) = create_optimizer_with_velocity_calculator(Box::new(velocity_calculator), seed)
+            .find_min()
+            .unwrap();
+
+        for i in 0..solution.len() {
+            assert!(abs(solution[i] - (i as f32 + 1.0)) < 0.01);
+        }
+        assert!(goal_value < 1e-4);
+    }
+}
+
+#[test]
+fn test_particleswarm_paraboloid_local_best_ring() {
+    check_local_best(&|| Box::new(topology::RingTopology::new(1)));
+}
+
+#[test]
+fn test_particleswarm_paraboloid_local_best_von_neumann() {
+    // The swarm contains 50 particles
+    check_local_best(&|| Box::new(topology::VonNeumannTopology::new(10, 5)));
+}
+
+#[test]
+fn test_particleswarm_paraboloid_local_best_star() {
+    check_local_best(&|| Box::new(topology::StarTopology::new()));
+}
+
+/// The paraboloid with the minimum in the point (1.3, 2.3, 3.3, ...)
+fn shifted_paraboloid(x: &Vec<Coordinate>) -> f64 {
+    x.iter()
+        .enumerate()
+        .map(|(i, &x_i)| {
+            let delta = (x_i - (i as Coordinate + 1.3)) as f64;
+            delta * delta
+        })
+        .sum()
+}
+
+#[test]
+fn test_particleswarm_paraboloid_mixed_integer() {
+    let particles_count = 50;
+    let dimension = 5;
+    let integer_dimensions = vec![0, 2, 4];
+    let intervals = vec![(-100.0, 100.0); dimension];
+
+    let mut coord_initializer = initializing::RandomCoordinatesInitializer::new(intervals.clone(), particles_count);
+    coord_initializer.set_seed(1);
+    let velocity_initializer = initializing::ZeroVelocityInitializer::new(dimension, particles_count);
+    let mut velocity_calculator = velocitycalc::CanonicalVelocityCalculator::new(2.05, 2.05, 0.7663);
+    velocity_calculator.set_seed(1);
+
+    // The integer coordinates are rounded after the clamping, so the integer boundaries are used
+    let post_moves: Vec<Box<dyn PostMove<Coordinate>>> = vec![
+        Box::new(postmove::MoveToBoundary::new(intervals)),
+        Box::new(postmove::MixedIntegerPostMove::new(integer_dimensions.clone())),
+    ];
+
+    let mut optimizer = particleswarm::ParticleSwarmOptimizer::new(
+        Box::new(GoalFromFunction::new(shifted_paraboloid)),
+        Box::new(stopchecker::MaxIterations::new(300)),
+        Box::new(coord_initializer),
+        Box::new(velocity_initializer),
+        Box::new(velocity_calculator),
+        );
+    optimizer.set_post_moves(post_moves);
+
+    let (solution, goal_value) = optimizer.find_min().unwrap();
+
+    // The integer coordinates are rounded to the nearest integer: 1.3 -> 1, 3.3 -> 3, 5.3 -> 5
+    assert_eq!(solution[0], 1.0);
+    assert_eq!(solution[2], 3.0);
+    assert_eq!(solution[4], 5.0);
+    assert!(abs(solution[1] - 2.3) < 0.01);
+    assert!(abs(solution[3] - 4.3) < 0.01);
+    assert!(abs(goal_value - 0.27) < 1e-3);
+
+    for particle in optimizer.get_particles() {
+        for &dimension in &integer_dimensions {
+            assert_eq!(particle.get_coordinates()[dimension].fract(), 0.0);
+            assert_eq!(particle.get_best_personal_coordinates()[dimension].fract(), 0.0);
+        }
+    }
+}
+
+#[cfg(feature = "checkpoint")]
+#[test]
+fn test_particleswarm_paraboloid_checkpoint() {
+    let path = std::env::temp_dir().join(format!(
+        "ew-particleswarm-paraboloid-checkpoint-{}.json",
+        std::process::id()
+    ));
+
+    let mut optimizer = create_seeded_optimizer(42);
+    let mut saved_result = None;
+    for _ in 0..50 {
+        saved_result = optimizer.step();
+    }
+    let saved_result = saved_result.unwrap();
+    optimizer.save_checkpoint(&path).unwrap();
+
+    // The other seed must not change the loaded swarm
+    let mut loaded_optimizer = create_seeded_optimizer(1);
+    loaded_optimizer.load_checkpoint(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let particles = optimizer.get_particles();
+    let loaded_particles = loaded_optimizer.get_particles();
+    assert_eq!(loaded_particles.len(), particles.len());
+    for (loaded_particle, particle) in loaded_particles.iter().zip(particles) {
+        assert_eq!(loaded_particle.get_coordinates(), particle.get_coordinates());
+        assert_eq!(loaded_particle.get_velocity(), particle.get_velocity());
+        assert_eq!(loaded_particle.get_value(), particle.get_value());
+        assert_eq!(
+            loaded_particle.get_best_personal_coordinates(),
+            particle.get_best_personal_coordinates()
+        );
+        assert_eq!(
+            loaded_particle.get_best_personal_value(),
+            particle.get_best_personal_value()
+        );
+    }
+
+    // The iteration number is restored, so the stop checker allows 50 iterations more
+    let mut steps = 0;
+    let mut last_result = None;
+    while let Some(result) = loaded_optimizer.step() {
+        steps += 1;
+        last_result = Some(result);
+    }
+
+    assert_eq!(steps, 50);
+    assert!(last_result.unwrap().1 <= saved_result.1);
+}
+
+#[cfg(feature = "checkpoint")]
+#[test]
+fn test_particleswarm_paraboloid_checkpoint_missing_file() {
+    let path = std::env::temp_dir().join("ew-particleswarm-missing-checkpoint.json");
+    let mut optimizer = create_seeded_optimizer(42);
+
+    let error = optimizer.load_checkpoint(&path).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
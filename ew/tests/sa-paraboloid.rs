@@ -0,0 +1,149 @@
+//! Optimizing the paraboloid with the simulated annealing.
+//!
+//! y = f(x), where x = (x0, x1, ..., xi,... xn).
+//! Global minimum is x' = (1.0, 2.0, ..., i + 1, ...) for any xi.
+//! f(x') = 0
+
+use num::abs;
+
+use ew::sa::{
+    BoltzmannCooling, CoolingSchedule, ExponentialCooling, GaussianNeighbor, LinearCooling,
+    SimulatedAnnealingOptimizer,
+};
+use ew::tools::stopchecker;
+use ew::{AlgorithmState, GoalFromFunction, IterativeOptimizer, Optimizer};
+use ew_testfunc;
+
+fn create_optimizer<'a>(
+    cooling: Box<dyn CoolingSchedule + 'a>,
+    iterations: usize,
+) -> SimulatedAnnealingOptimizer<'a, Vec<f64>> {
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+    let stop_checker = stopchecker::MaxIterations::new(iterations);
+    let mut neighbor = GaussianNeighbor::new(0.05);
+    neighbor.set_seed(1);
+
+    let mut optimizer = SimulatedAnnealingOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        vec![-3.0, 5.0, 0.0],
+        Box::new(neighbor),
+        cooling,
+    );
+    optimizer.set_seed(2);
+    optimizer
+}
+
+fn check_solution(solution: &Vec<f64>, goal_value: f64) {
+    for (n, x) in solution.iter().enumerate() {
+        assert!(abs(x - (n as f64 + 1.0)) < 0.1);
+    }
+
+    assert!(goal_value < 0.01);
+}
+
+#[test]
+fn sa_paraboloid_linear() {
+    let cooling = LinearCooling::new(1.0, 0.0, 10000);
+    let (solution, goal_value) = create_optimizer(Box::new(cooling), 10000)
+        .find_min()
+        .unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn sa_paraboloid_exponential() {
+    let cooling = ExponentialCooling::new(1.0, 0.999);
+    let (solution, goal_value) = create_optimizer(Box::new(cooling), 10000)
+        .find_min()
+        .unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn sa_paraboloid_boltzmann() {
+    let cooling = BoltzmannCooling::new(0.01);
+    let (solution, goal_value) = create_optimizer(Box::new(cooling), 10000)
+        .find_min()
+        .unwrap();
+    check_solution(&solution, goal_value);
+}
+
+#[test]
+fn sa_paraboloid_step() {
+    let cooling = ExponentialCooling::new(1.0, 0.999);
+    let mut optimizer = create_optimizer(Box::new(cooling), 100);
+
+    let mut prev_goal_value = f64::INFINITY;
+    let mut steps = 0;
+    while let Some((_, goal_value)) = optimizer.step() {
+        assert!(goal_value <= prev_goal_value);
+        prev_goal_value = goal_value;
+        steps += 1;
+    }
+
+    assert_eq!(steps, 100);
+    assert_eq!(optimizer.get_state().get_current_solution().is_some(), true);
+}
+
+#[test]
+fn sa_paraboloid_seed() {
+    let cooling_1 = ExponentialCooling::new(1.0, 0.99);
+    let cooling_2 = ExponentialCooling::new(1.0, 0.99);
+    let result_1 = create_optimizer(Box::new(cooling_1), 500).find_min();
+    let result_2 = create_optimizer(Box::new(cooling_2), 500).find_min();
+    assert_eq!(result_1, result_2);
+}
+
+#[test]
+fn sa_paraboloid_callback() {
+    let cooling_1 = ExponentialCooling::new(1.0, 0.99);
+    let cooling_2 = ExponentialCooling::new(1.0, 0.99);
+
+    let mut iterations = vec![];
+    let mut prev_goal_value = f64::INFINITY;
+    let result_1 = create_optimizer(Box::new(cooling_1), 500).find_min_with_callback(
+        |iteration, best: Option<(&Vec<f64>, f64)>| {
+            let (_, goal_value) = best.unwrap();
+            assert!(goal_value <= prev_goal_value);
+            prev_goal_value = goal_value;
+            iterations.push(iteration);
+        },
+    );
+    let result_2 = create_optimizer(Box::new(cooling_2), 500).find_min();
+
+    assert_eq!(result_1, result_2);
+    assert_eq!(iterations, (1..=500).collect::<Vec<usize>>());
+    assert_eq!(result_1.unwrap().1, prev_goal_value);
+}
+
+#[test]
+fn sa_paraboloid_reuse_stop_checker() {
+    // The stop checker state must be reset for the second running
+    let goal = GoalFromFunction::new(ew_testfunc::paraboloid);
+    let stop_checker = stopchecker::CompositeAny::new(vec![
+        Box::new(stopchecker::RelativeImprovement::new(500, 1e-3)),
+        Box::new(stopchecker::MaxIterations::new(10000)),
+    ]);
+    let mut neighbor = GaussianNeighbor::new(0.05);
+    neighbor.set_seed(1);
+
+    let mut optimizer = SimulatedAnnealingOptimizer::new(
+        Box::new(goal),
+        Box::new(stop_checker),
+        vec![-3.0, 5.0, 0.0],
+        Box::new(neighbor),
+        Box::new(ExponentialCooling::new(1.0, 0.999)),
+    );
+    optimizer.set_seed(2);
+
+    let (solution_1, goal_value_1) = optimizer.find_min().unwrap();
+    let iterations_1 = optimizer.get_state().get_iteration();
+    let (solution_2, goal_value_2) = optimizer.find_min().unwrap();
+    let iterations_2 = optimizer.get_state().get_iteration();
+
+    check_solution(&solution_1, goal_value_1);
+    check_solution(&solution_2, goal_value_2);
+    assert!(iterations_1 > 500);
+    assert!(iterations_2 > 500);
+}